@@ -201,6 +201,64 @@ fn main() {
     println!("cargo:rerun-if-changed={}", beaver_init_path.display());
     println!("cargo:rerun-if-env-changed=BV_SYFTBOX_DEFAULT_BACKEND");
 
+    // Extract the embedded biovault CLI library version from its Cargo.toml.
+    let cli_manifest_path = match env::var("WORKSPACE_ROOT") {
+        Ok(root) => PathBuf::from(root).join("biovault/cli/Cargo.toml"),
+        Err(_) => manifest_dir
+            .parent()
+            .expect("src-tauri has a parent")
+            .join("biovault/cli/Cargo.toml"),
+    };
+    let cli_version = fs::read_to_string(&cli_manifest_path)
+        .ok()
+        .and_then(|content| {
+            // First `version = "X.Y.Z"` under [package]
+            let mut in_package = false;
+            for line in content.lines() {
+                let trimmed = line.trim();
+                if trimmed.starts_with('[') {
+                    in_package = trimmed == "[package]";
+                    continue;
+                }
+                if in_package && trimmed.starts_with("version") {
+                    let start = trimmed.find('"')?;
+                    let end = trimmed[start + 1..].find('"')?;
+                    return Some(trimmed[start + 1..start + 1 + end].to_string());
+                }
+            }
+            None
+        })
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=BIOVAULT_CLI_VERSION={}", cli_version);
+    println!("cargo:rerun-if-changed={}", cli_manifest_path.display());
+    println!("cargo:rerun-if-env-changed=WORKSPACE_ROOT");
+
+    // Extract the schema version the embedded CLI stamps into the DB
+    // (`PRAGMA user_version = N;` in its schema.sql).
+    let schema_path = cli_manifest_path
+        .parent()
+        .expect("CLI manifest has a parent")
+        .join("src/data/schema.sql");
+    let schema_version = fs::read_to_string(&schema_path)
+        .ok()
+        .and_then(|content| {
+            content.lines().find_map(|line| {
+                let lower = line.trim().to_ascii_lowercase();
+                let rest = lower.strip_prefix("pragma user_version")?;
+                let value = rest.trim_start().strip_prefix('=')?;
+                value
+                    .trim()
+                    .trim_end_matches(';')
+                    .trim()
+                    .parse::<i64>()
+                    .ok()
+            })
+        })
+        .map(|v| v.to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=BIOVAULT_SCHEMA_VERSION={}", schema_version);
+    println!("cargo:rerun-if-changed={}", schema_path.display());
+
     // Windows defaults to a small thread stack reserve (commonly 1MB), which can overflow during
     // PQXDH/Kyber crypto operations (e.g. when sending an encrypted message). Increase the stack
     // reserve for the desktop executable to prevent STATUS_STACK_OVERFLOW crashes.
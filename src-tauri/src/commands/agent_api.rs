@@ -50,3 +50,158 @@ pub async fn restart_agent_bridge(app: AppHandle) -> Result<(), String> {
 
     crate::ws_bridge::restart_agent_bridge(app, bridge_port, http_port, ws_bridge_enabled).await
 }
+
+/// A curated, stable action exposed through `agent_execute`. Action names are
+/// part of the scripting contract; the internal command they map to may change.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct AgentAction {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub read_only: bool,
+    /// Accepted keys in `params` (camelCase, as on the WebSocket bridge)
+    pub params: &'static [&'static str],
+    #[serde(skip)]
+    command: &'static str,
+}
+
+const AGENT_ACTIONS: &[AgentAction] = &[
+    AgentAction {
+        name: "list_files",
+        description: "List imported files",
+        read_only: true,
+        params: &[],
+        command: "get_files",
+    },
+    AgentAction {
+        name: "list_files_page",
+        description: "List one page of imported files matching a filter, with the total count",
+        read_only: true,
+        params: &["filter", "limit", "offset"],
+        command: "get_files_page",
+    },
+    AgentAction {
+        name: "list_participants",
+        description: "List participants",
+        read_only: true,
+        params: &[],
+        command: "get_participants",
+    },
+    AgentAction {
+        name: "list_flows",
+        description: "List installed flows",
+        read_only: true,
+        params: &[],
+        command: "get_flows",
+    },
+    AgentAction {
+        name: "list_runs",
+        description: "List flow runs with their status",
+        read_only: true,
+        params: &[],
+        command: "get_flow_runs",
+    },
+    AgentAction {
+        name: "get_run_logs",
+        description: "Get the log output of a run",
+        read_only: true,
+        params: &["runId"],
+        command: "get_run_logs",
+    },
+    AgentAction {
+        name: "start_run",
+        description: "Start a flow run",
+        read_only: false,
+        params: &["flowId", "inputOverrides", "resultsDir", "selection"],
+        command: "run_flow",
+    },
+    AgentAction {
+        name: "list_message_threads",
+        description: "List message threads",
+        read_only: true,
+        params: &["scope", "limit"],
+        command: "list_message_threads",
+    },
+    AgentAction {
+        name: "send_message",
+        description: "Send a message ({ request: { recipient, body, threadId? } })",
+        read_only: false,
+        params: &["request"],
+        command: "send_message",
+    },
+];
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct AgentExecuteError {
+    /// `unknown_action`, `invalid_params` or `command_failed`
+    pub code: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct AgentExecuteResponse {
+    pub action: String,
+    pub ok: bool,
+    pub result: Option<serde_json::Value>,
+    pub error: Option<AgentExecuteError>,
+}
+
+fn agent_error(action: &str, code: &str, message: String) -> AgentExecuteResponse {
+    AgentExecuteResponse {
+        action: action.to_string(),
+        ok: false,
+        result: None,
+        error: Some(AgentExecuteError {
+            code: code.to_string(),
+            message,
+        }),
+    }
+}
+
+#[tauri::command]
+pub fn agent_list_actions() -> Vec<AgentAction> {
+    AGENT_ACTIONS.to_vec()
+}
+
+/// Single entry point for scripts and agents: run one of `AGENT_ACTIONS`.
+/// Errors are returned in the response body so callers get a stable shape.
+#[tauri::command]
+pub async fn agent_execute(
+    app: AppHandle,
+    action: String,
+    params: Option<serde_json::Value>,
+) -> Result<AgentExecuteResponse, String> {
+    let Some(entry) = AGENT_ACTIONS.iter().find(|a| a.name == action) else {
+        return Ok(agent_error(
+            &action,
+            "unknown_action",
+            format!("Unknown action '{}'; see agent_list_actions", action),
+        ));
+    };
+    let params = params.unwrap_or_else(|| serde_json::json!({}));
+    let Some(obj) = params.as_object() else {
+        return Ok(agent_error(
+            &action,
+            "invalid_params",
+            "params must be an object".to_string(),
+        ));
+    };
+    if let Some(unknown) = obj.keys().find(|k| !entry.params.contains(&k.as_str())) {
+        return Ok(agent_error(
+            &action,
+            "invalid_params",
+            format!("Unexpected parameter '{}' for action '{}'", unknown, action),
+        ));
+    }
+
+    crate::desktop_log!("🤖 agent_execute: {} → {}", action, entry.command);
+    let dispatch = crate::ws_bridge::dispatch_command_boxed(&app, entry.command, params);
+    Ok(match dispatch.await {
+        Ok(result) => AgentExecuteResponse {
+            action,
+            ok: true,
+            result: Some(result),
+            error: None,
+        },
+        Err(message) => agent_error(&action, "command_failed", message),
+    })
+}
@@ -0,0 +1,486 @@
+use crate::types::AppState;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Serialize;
+use serde_json::json;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io::{BufRead, BufReader, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::Emitter;
+use walkdir::WalkDir;
+
+use super::flows::{get_container_runtime, get_modules_dir, should_skip_request_path};
+
+const IMAGE_PULL_EVENT: &str = "runs:image-pull-progress";
+const IMAGE_POLL_INTERVAL: Duration = Duration::from_secs(3);
+
+/// `container 'img'` / `container = "img"` in Nextflow scripts and configs.
+static NF_CONTAINER_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"\bcontainer\s*[=:]?\s*['"]([^'"]+)['"]"#).unwrap());
+/// `image: img` / `container: img` keys in module YAML.
+static YAML_IMAGE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"^\s*(?:image|container)\s*:\s*['"]?([^'"\s#]+)"#).unwrap());
+/// Nextflow log lines announcing an image pull (Singularity/Apptainer/Docker).
+static PULL_LOG_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i)pulling\s+(?:\w+\s+)?(?:image\s+)?['"]?([A-Za-z0-9][\w./:@-]*)"#).unwrap()
+});
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RunImageStatus {
+    pub image: String,
+    pub available: bool,
+    /// Files (relative to the flow or module dir) that reference the image
+    pub sources: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RunImageReport {
+    pub run_id: i64,
+    pub runtime: Option<String>,
+    pub images: Vec<RunImageStatus>,
+}
+
+fn is_static_image(value: &str) -> bool {
+    let value = value.trim();
+    !value.is_empty()
+        && !value.contains('$')
+        && !value.contains('{')
+        && !value.contains(' ')
+        && value != "null"
+}
+
+/// Module directories a flow pulls in: local `source.path` entries relative to
+/// the flow, plus modules referenced by name from the managed modules dir.
+fn flow_module_dirs(flow_dir: &Path) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    let Ok(raw) = fs::read_to_string(flow_dir.join(biovault::flow_spec::FLOW_YAML_FILE)) else {
+        return dirs;
+    };
+    let Ok(doc) = serde_yaml::from_str::<serde_yaml::Value>(&raw) else {
+        return dirs;
+    };
+    let spec = doc.get("spec").unwrap_or(&doc);
+    let modules_dir = get_modules_dir().ok();
+
+    if let Some(modules) = spec.get("modules").and_then(|m| m.as_mapping()) {
+        for (name, def) in modules {
+            let local = def
+                .get("source")
+                .and_then(|s| s.get("path").or_else(|| s.get("subpath")))
+                .and_then(|p| p.as_str());
+            if let Some(path) = local {
+                let candidate = Path::new(path);
+                dirs.push(if candidate.is_absolute() {
+                    candidate.to_path_buf()
+                } else {
+                    flow_dir.join(candidate)
+                });
+            } else if let (Some(name), Some(root)) = (name.as_str(), modules_dir.as_ref()) {
+                dirs.push(root.join(name));
+            }
+        }
+    }
+
+    if let (Some(steps), Some(root)) = (
+        spec.get("steps").and_then(|s| s.as_sequence()),
+        modules_dir.as_ref(),
+    ) {
+        for step in steps {
+            if let Some(uses) = step.get("uses").and_then(|u| u.as_str()) {
+                dirs.push(root.join(uses));
+            }
+        }
+    }
+
+    dirs.retain(|d| d.is_dir() && !d.starts_with(flow_dir));
+    dirs.sort();
+    dirs.dedup();
+    dirs
+}
+
+fn scan_dir_for_images(root: &Path, images: &mut BTreeMap<String, Vec<String>>) {
+    let walker = WalkDir::new(root)
+        .max_depth(6)
+        .into_iter()
+        .filter_entry(|e| {
+            !should_skip_request_path(e.path().strip_prefix(root).unwrap_or(e.path()))
+        });
+    for entry in walker.filter_map(|e| e.ok()) {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().to_string();
+        let is_nf = name.ends_with(".nf") || name.ends_with(".config");
+        let is_yaml = name == "module.yaml" || name == "module.yml";
+        if !is_nf && !is_yaml {
+            continue;
+        }
+        let Ok(content) = fs::read_to_string(path) else {
+            continue;
+        };
+        let source = format!(
+            "{}/{}",
+            root.file_name().unwrap_or_default().to_string_lossy(),
+            path.strip_prefix(root).unwrap_or(path).to_string_lossy()
+        );
+        for line in content.lines() {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("//") || trimmed.starts_with('#') {
+                continue;
+            }
+            let captured = if is_nf {
+                NF_CONTAINER_RE.captures(line)
+            } else {
+                YAML_IMAGE_RE.captures(line)
+            };
+            if let Some(image) = captured.and_then(|c| c.get(1)).map(|m| m.as_str()) {
+                if is_static_image(image) {
+                    let sources = images.entry(image.to_string()).or_default();
+                    if !sources.contains(&source) {
+                        sources.push(source.clone());
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Container images referenced by a flow and its modules, with the files
+/// that mention each one.
+pub(crate) fn collect_flow_images(flow_dir: &Path) -> BTreeMap<String, Vec<String>> {
+    let mut images = BTreeMap::new();
+    scan_dir_for_images(flow_dir, &mut images);
+    for module_dir in flow_module_dirs(flow_dir) {
+        scan_dir_for_images(&module_dir, &mut images);
+    }
+    images
+}
+
+pub(crate) fn image_available(runtime: &str, image: &str) -> bool {
+    let mut cmd = Command::new(runtime);
+    cmd.args(["image", "inspect", image]);
+    super::hide_console_window(&mut cmd);
+    cmd.output().map(|o| o.status.success()).unwrap_or(false)
+}
+
+/// Flow directory for a flow run, looked up through its flow record.
+pub(crate) fn flow_dir_for_run(
+    db: &biovault::data::BioVaultDb,
+    run_id: i64,
+) -> Result<PathBuf, String> {
+    let run = db
+        .get_flow_run(run_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Flow run {} not found", run_id))?;
+    let flow_id = run
+        .flow_id
+        .ok_or_else(|| format!("Flow run {} has no associated flow", run_id))?;
+    let flow = db
+        .get_flow(flow_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Flow {} not found", flow_id))?;
+    Ok(PathBuf::from(flow.flow_path))
+}
+
+fn emit_image_event(
+    window: Option<&tauri::WebviewWindow>,
+    run_id: i64,
+    image: &str,
+    status: &str,
+    detail: Option<&str>,
+) {
+    if let Some(w) = window {
+        let _ = w.emit(
+            IMAGE_PULL_EVENT,
+            json!({
+                "runId": run_id,
+                "image": image,
+                "status": status,
+                "detail": detail,
+            }),
+        );
+    }
+}
+
+/// Watch a running flow for image pulls until `done` is set. Missing images are
+/// reported as `pulling` once the log mentions them (or on the first poll if the log
+/// is silent) and as `available` when they show up in the local runtime.
+pub(crate) fn spawn_image_pull_monitor(
+    window: Option<tauri::WebviewWindow>,
+    run_id: i64,
+    flow_dir: PathBuf,
+    log_path: PathBuf,
+    done: Arc<AtomicBool>,
+) {
+    std::thread::spawn(move || {
+        let Some(runtime) = get_container_runtime() else {
+            return;
+        };
+        let mut pending: HashMap<String, bool> = collect_flow_images(&flow_dir)
+            .into_keys()
+            .filter(|image| !image_available(&runtime, image))
+            .map(|image| (image, false))
+            .collect();
+        if pending.is_empty() {
+            return;
+        }
+
+        for image in pending.keys() {
+            emit_image_event(window.as_ref(), run_id, image, "missing", None);
+        }
+
+        let mut log_offset = fs::metadata(&log_path).map(|m| m.len()).unwrap_or(0);
+        while !done.load(Ordering::SeqCst) && !pending.is_empty() {
+            std::thread::sleep(IMAGE_POLL_INTERVAL);
+
+            if let Ok(mut file) = fs::File::open(&log_path) {
+                if file.seek(SeekFrom::Start(log_offset)).is_ok() {
+                    let mut reader = BufReader::new(file);
+                    let mut line = String::new();
+                    while reader.read_line(&mut line).unwrap_or(0) > 0 {
+                        log_offset += line.len() as u64;
+                        if let Some(name) = PULL_LOG_RE
+                            .captures(&line)
+                            .and_then(|c| c.get(1))
+                            .map(|m| m.as_str().to_string())
+                        {
+                            for (image, announced) in pending.iter_mut() {
+                                if !*announced
+                                    && (image.contains(&name) || name.contains(image.as_str()))
+                                {
+                                    *announced = true;
+                                    emit_image_event(
+                                        window.as_ref(),
+                                        run_id,
+                                        image,
+                                        "pulling",
+                                        Some(line.trim()),
+                                    );
+                                }
+                            }
+                        }
+                        line.clear();
+                    }
+                }
+            }
+
+            let mut arrived = Vec::new();
+            for (image, announced) in pending.iter_mut() {
+                if image_available(&runtime, image) {
+                    arrived.push(image.clone());
+                } else if !*announced {
+                    // Docker pulls don't show up in the Nextflow log.
+                    *announced = true;
+                    emit_image_event(window.as_ref(), run_id, image, "pulling", None);
+                }
+            }
+            for image in arrived {
+                pending.remove(&image);
+                crate::desktop_log!("🐳 Image available for run {}: {}", run_id, image);
+                emit_image_event(window.as_ref(), run_id, &image, "available", None);
+            }
+        }
+    });
+}
+
+/// List the container images a flow run needs and whether each is already
+/// present in the local container runtime.
+#[tauri::command]
+pub async fn get_run_image_status(
+    state: tauri::State<'_, AppState>,
+    run_id: i64,
+) -> Result<RunImageReport, String> {
+    let flow_dir = {
+        let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+        flow_dir_for_run(&db, run_id)?
+    };
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let runtime = get_container_runtime();
+        let images = collect_flow_images(&flow_dir)
+            .into_iter()
+            .map(|(image, sources)| RunImageStatus {
+                available: runtime
+                    .as_deref()
+                    .map(|rt| image_available(rt, &image))
+                    .unwrap_or(false),
+                image,
+                sources,
+            })
+            .collect();
+        RunImageReport {
+            run_id,
+            runtime,
+            images,
+        }
+    })
+    .await
+    .map_err(|e| format!("Image status check failed: {}", e))
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ImagePrefetchResult {
+    pub config_id: i64,
+    pub runtime: String,
+    pub already_present: Vec<String>,
+    pub pulled: Vec<String>,
+    pub failed: Vec<ImagePullFailure>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImagePullFailure {
+    pub image: String,
+    pub error: String,
+}
+
+/// Pull an image, forwarding each line of runtime output as a progress event.
+fn pull_image(
+    window: &tauri::WebviewWindow,
+    config_id: i64,
+    runtime: &str,
+    image: &str,
+) -> Result<(), String> {
+    let mut cmd = Command::new(runtime);
+    cmd.args(["pull", image])
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped());
+    super::hide_console_window(&mut cmd);
+    let mut child = cmd
+        .spawn()
+        .map_err(|e| format!("Failed to start {} pull: {}", runtime, e))?;
+
+    let stderr = child.stderr.take();
+    let stderr_reader = std::thread::spawn(move || {
+        let mut tail = Vec::new();
+        if let Some(stderr) = stderr {
+            for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                tail.push(line);
+                if tail.len() > 5 {
+                    tail.remove(0);
+                }
+            }
+        }
+        tail.join("\n")
+    });
+
+    if let Some(stdout) = child.stdout.take() {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            let _ = window.emit(
+                IMAGE_PULL_EVENT,
+                json!({
+                    "configId": config_id,
+                    "image": image,
+                    "status": "pulling",
+                    "detail": line,
+                }),
+            );
+        }
+    }
+
+    let status = child
+        .wait()
+        .map_err(|e| format!("Failed to wait for {} pull: {}", runtime, e))?;
+    let stderr_tail = stderr_reader.join().unwrap_or_default();
+    if status.success() {
+        Ok(())
+    } else if stderr_tail.is_empty() {
+        Err(format!("{} pull exited with {}", runtime, status))
+    } else {
+        Err(stderr_tail)
+    }
+}
+
+/// Pull every container image a saved run configuration's flow needs, using the
+/// same runtime resolution as the run itself, so the run doesn't stall on pulls.
+#[tauri::command]
+pub async fn prefetch_run_images(
+    window: tauri::WebviewWindow,
+    state: tauri::State<'_, AppState>,
+    config_id: i64,
+) -> Result<ImagePrefetchResult, String> {
+    let flow_dir = {
+        let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+        let config = db
+            .get_flow_run_config(config_id)
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| format!("Run configuration {} not found", config_id))?;
+        let flow = db
+            .get_flow(config.flow_id)
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| format!("Flow {} not found", config.flow_id))?;
+        PathBuf::from(flow.flow_path)
+    };
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let runtime = get_container_runtime()
+            .ok_or_else(|| "No container runtime (docker or podman) found".to_string())?;
+        let images: Vec<String> = collect_flow_images(&flow_dir).into_keys().collect();
+        crate::desktop_log!(
+            "🐳 Prefetching {} image(s) for run config {} via {}",
+            images.len(),
+            config_id,
+            runtime
+        );
+
+        let mut result = ImagePrefetchResult {
+            config_id,
+            runtime: runtime.clone(),
+            ..Default::default()
+        };
+        for (idx, image) in images.iter().enumerate() {
+            let _ = window.emit(
+                IMAGE_PULL_EVENT,
+                json!({
+                    "configId": config_id,
+                    "image": image,
+                    "status": "checking",
+                    "index": idx + 1,
+                    "total": images.len(),
+                }),
+            );
+            if image_available(&runtime, image) {
+                result.already_present.push(image.clone());
+                let _ = window.emit(
+                    IMAGE_PULL_EVENT,
+                    json!({ "configId": config_id, "image": image, "status": "available" }),
+                );
+                continue;
+            }
+            match pull_image(&window, config_id, &runtime, image) {
+                Ok(()) => {
+                    crate::desktop_log!("✅ Pulled image {}", image);
+                    result.pulled.push(image.clone());
+                    let _ = window.emit(
+                        IMAGE_PULL_EVENT,
+                        json!({ "configId": config_id, "image": image, "status": "pulled" }),
+                    );
+                }
+                Err(error) => {
+                    crate::desktop_log!("❌ Failed to pull image {}: {}", image, error);
+                    let _ = window.emit(
+                        IMAGE_PULL_EVENT,
+                        json!({
+                            "configId": config_id,
+                            "image": image,
+                            "status": "failed",
+                            "detail": error,
+                        }),
+                    );
+                    result.failed.push(ImagePullFailure {
+                        image: image.clone(),
+                        error,
+                    });
+                }
+            }
+        }
+        Ok(result)
+    })
+    .await
+    .map_err(|e| format!("Image prefetch failed: {}", e))?
+}
@@ -4,6 +4,7 @@ use biovault::data::BioVaultDb;
 use rusqlite::OptionalExtension;
 use serde::Serialize;
 use serde_yaml;
+use std::collections::{BTreeMap, BTreeSet};
 use std::env;
 use std::path::{Path, PathBuf};
 use uuid::Uuid;
@@ -209,6 +210,146 @@ pub async fn publish_dataset(
     result.map_err(|e| format!("Failed to publish dataset: {}", e))
 }
 
+#[derive(Serialize, Clone, Debug)]
+pub struct BulkPublishItem {
+    pub name: String,
+    pub success: bool,
+    pub public_url: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct BulkPublishResult {
+    pub published: usize,
+    pub failed: usize,
+    pub results: Vec<BulkPublishItem>,
+}
+
+/// Restrict a published dataset folder to `recipients` (owner keeps admin).
+fn write_dataset_recipients(
+    dataset_dir: &Path,
+    owner: &str,
+    recipients: &[String],
+) -> Result<(), String> {
+    let doc = serde_json::json!({
+        "rules": [
+            {
+                "pattern": "**",
+                "access": {
+                    "admin": [owner],
+                    "read": recipients,
+                    "write": Vec::<String>::new(),
+                },
+            },
+        ],
+    });
+    let yaml = serde_yaml::to_string(&doc)
+        .map_err(|e| format!("Failed to serialize syft.pub.yaml: {}", e))?;
+    std::fs::write(dataset_dir.join("syft.pub.yaml"), yaml)
+        .map_err(|e| format!("Failed to write syft.pub.yaml: {}", e))
+}
+
+/// Publish several datasets with the same recipients. Each dataset goes
+/// through `publish_dataset`; a failure is recorded and the rest continue.
+/// Emits `datasets:bulk-publish-progress` after each dataset.
+#[tauri::command]
+pub async fn publish_datasets_bulk(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+    names: Vec<String>,
+    recipients: Option<Vec<String>>,
+    copy_mock: Option<bool>,
+) -> Result<BulkPublishResult, String> {
+    use tauri::Emitter;
+
+    let mut names: Vec<String> = names
+        .into_iter()
+        .map(|n| n.trim().to_string())
+        .filter(|n| !n.is_empty())
+        .collect();
+    let mut seen = BTreeSet::new();
+    names.retain(|n| seen.insert(n.clone()));
+    if names.is_empty() {
+        return Err("No datasets selected".to_string());
+    }
+    let recipients: Vec<String> = recipients
+        .unwrap_or_default()
+        .into_iter()
+        .map(|r| r.trim().to_string())
+        .filter(|r| !r.is_empty())
+        .collect();
+
+    let config =
+        biovault::config::Config::load().map_err(|e| format!("Failed to load config: {}", e))?;
+    let email = config.email.clone();
+    let datasets_dir = config
+        .get_syftbox_data_dir()
+        .map_err(|e| format!("Failed to resolve SyftBox data dir: {}", e))?
+        .join("datasites")
+        .join(&email)
+        .join("public")
+        .join("biovault")
+        .join("datasets");
+
+    crate::desktop_log!(
+        "📤 Publishing {} dataset(s) for {} recipient(s)",
+        names.len(),
+        recipients.len()
+    );
+
+    let total = names.len();
+    let mut results = Vec::with_capacity(total);
+    for (index, name) in names.into_iter().enumerate() {
+        let mut outcome = publish_dataset(
+            state.clone(),
+            None,
+            Some(name.clone()),
+            copy_mock.unwrap_or(false),
+        )
+        .await;
+        if outcome.is_ok() && !recipients.is_empty() {
+            outcome = write_dataset_recipients(&datasets_dir.join(&name), &email, &recipients);
+        }
+        let item = match outcome {
+            Ok(()) => BulkPublishItem {
+                public_url: Some(format!(
+                    "syft://{}/public/biovault/datasets/{}/dataset.yaml",
+                    email, name
+                )),
+                name,
+                success: true,
+                error: None,
+            },
+            Err(e) => {
+                crate::desktop_log!("⚠️ Failed to publish dataset '{}': {}", name, e);
+                BulkPublishItem {
+                    name,
+                    success: false,
+                    public_url: None,
+                    error: Some(e),
+                }
+            }
+        };
+        let _ = app.emit(
+            "datasets:bulk-publish-progress",
+            serde_json::json!({
+                "completed": index + 1,
+                "total": total,
+                "name": item.name,
+                "success": item.success,
+            }),
+        );
+        results.push(item);
+    }
+
+    let published = results.iter().filter(|r| r.success).count();
+    Ok(BulkPublishResult {
+        published,
+        failed: results.len() - published,
+        results,
+    })
+}
+
 #[tauri::command]
 pub fn unpublish_dataset(name: String) -> Result<(), String> {
     let config =
@@ -552,6 +693,686 @@ pub fn is_dataset_published(name: String) -> Result<bool, String> {
     Ok(public_dir.exists())
 }
 
+// ============================================================================
+// Duplicate detection and merging
+// ============================================================================
+
+#[derive(Serialize, Clone, Debug)]
+pub struct DuplicateDatasetGroup {
+    pub datasets: Vec<String>,
+    pub asset_count: usize,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct DatasetOverlap {
+    pub dataset_a: String,
+    pub dataset_b: String,
+    pub shared_assets: usize,
+    /// Shared assets divided by the union of both asset sets.
+    pub similarity: f64,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct DuplicateDatasetReport {
+    /// Datasets whose resolved asset sets are identical.
+    pub groups: Vec<DuplicateDatasetGroup>,
+    /// Datasets that share some, but not all, assets.
+    pub overlaps: Vec<DatasetOverlap>,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct DatasetMergeResult {
+    pub source: String,
+    pub target: String,
+    pub moved_assets: Vec<String>,
+    pub skipped_duplicate_assets: Vec<String>,
+    pub updated_run_configs: Vec<i64>,
+    pub source_deleted: bool,
+}
+
+/// Identify a file by content hash, falling back to its path while the hash
+/// is still a `pending_<size>` placeholder.
+fn file_fingerprint(db: &BioVaultDb, file_id: Option<i64>, path: Option<&str>) -> Option<String> {
+    if let Some(fid) = file_id {
+        let row: Option<(String, Option<String>)> = db
+            .conn
+            .query_row(
+                "SELECT file_path, file_hash FROM files WHERE id = ?1",
+                [fid],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()
+            .ok()
+            .flatten();
+        if let Some((file_path, hash)) = row {
+            return Some(
+                match hash.filter(|h| !h.is_empty() && !h.starts_with("pending_")) {
+                    Some(hash) => format!("sha:{}", hash),
+                    None => format!("path:{}", file_path),
+                },
+            );
+        }
+    }
+    path.filter(|p| !p.trim().is_empty())
+        .map(|p| format!("path:{}", p))
+}
+
+/// Map each asset key to a fingerprint of its private + mock files.
+/// Assets without any resolvable file are left out.
+fn asset_fingerprints(
+    db: &BioVaultDb,
+    assets: &[biovault::data::DatasetAssetRecord],
+) -> BTreeMap<String, String> {
+    let mut out = BTreeMap::new();
+    for asset in assets {
+        let private = file_fingerprint(db, asset.private_file_id, asset.private_path.as_deref());
+        let mock = file_fingerprint(db, asset.mock_file_id, asset.mock_path.as_deref());
+        if private.is_none() && mock.is_none() {
+            continue;
+        }
+        out.insert(
+            asset.asset_key.clone(),
+            format!(
+                "{}|{}",
+                private.unwrap_or_default(),
+                mock.unwrap_or_default()
+            ),
+        );
+    }
+    out
+}
+
+#[tauri::command]
+pub fn find_duplicate_datasets(
+    state: tauri::State<AppState>,
+) -> Result<DuplicateDatasetReport, String> {
+    let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+    let rows = biovault::data::list_datasets_with_assets(&db)
+        .map_err(|e| format!("Failed to list datasets: {}", e))?;
+
+    let signatures: Vec<(String, BTreeSet<String>)> = rows
+        .iter()
+        .map(|(ds, assets)| {
+            let set: BTreeSet<String> = asset_fingerprints(&db, assets).into_values().collect();
+            (ds.name.clone(), set)
+        })
+        .filter(|(_, set)| !set.is_empty())
+        .collect();
+
+    let mut report = DuplicateDatasetReport::default();
+
+    let mut by_signature: BTreeMap<&BTreeSet<String>, Vec<String>> = BTreeMap::new();
+    for (name, set) in &signatures {
+        by_signature.entry(set).or_default().push(name.clone());
+    }
+    for (set, mut names) in by_signature {
+        if names.len() > 1 {
+            names.sort();
+            report.groups.push(DuplicateDatasetGroup {
+                datasets: names,
+                asset_count: set.len(),
+            });
+        }
+    }
+
+    for (i, (name_a, set_a)) in signatures.iter().enumerate() {
+        for (name_b, set_b) in signatures.iter().skip(i + 1) {
+            if set_a == set_b {
+                continue;
+            }
+            let shared = set_a.intersection(set_b).count();
+            if shared == 0 {
+                continue;
+            }
+            let union = set_a.union(set_b).count();
+            report.overlaps.push(DatasetOverlap {
+                dataset_a: name_a.clone(),
+                dataset_b: name_b.clone(),
+                shared_assets: shared,
+                similarity: shared as f64 / union as f64,
+            });
+        }
+    }
+    report
+        .overlaps
+        .sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+
+    Ok(report)
+}
+
+/// Keys under which run configurations store a dataset name.
+const DATASET_REF_KEYS: &[&str] = &["dataset", "datasetName", "dataset_name"];
+
+/// Re-point dataset references from `from` to `to`, returning whether anything
+/// changed. Only values stored under `DATASET_REF_KEYS` are touched, so labels,
+/// parameters and sample names that happen to equal the dataset name survive.
+fn replace_dataset_refs(value: &mut serde_json::Value, from: &str, to: &str) -> bool {
+    match value {
+        serde_json::Value::Array(items) => items.iter_mut().fold(false, |changed, v| {
+            replace_dataset_refs(v, from, to) || changed
+        }),
+        serde_json::Value::Object(map) => map.iter_mut().fold(false, |changed, (key, v)| {
+            let replaced = match v {
+                serde_json::Value::String(s)
+                    if s == from && DATASET_REF_KEYS.contains(&key.as_str()) =>
+                {
+                    *s = to.to_string();
+                    true
+                }
+                _ => replace_dataset_refs(v, from, to),
+            };
+            replaced || changed
+        }),
+        _ => false,
+    }
+}
+
+/// Move `source`'s assets into `target`, skipping assets whose files already
+/// exist in `target`, re-point saved run configurations, then delete `source`.
+/// Published datasets are refused; the database changes are all-or-nothing.
+#[tauri::command]
+pub fn merge_datasets(
+    state: tauri::State<AppState>,
+    source: String,
+    target: String,
+) -> Result<DatasetMergeResult, String> {
+    if source == target {
+        return Err("Cannot merge a dataset into itself".to_string());
+    }
+
+    let source_published = is_dataset_published(source.clone())?;
+    let target_published = is_dataset_published(target.clone())?;
+    if source_published && target_published {
+        return Err(format!(
+            "Cannot merge '{}' into '{}' while both are published; unpublish '{}' first",
+            source, target, source
+        ));
+    }
+    if source_published || target_published {
+        let published = if source_published { &source } else { &target };
+        return Err(format!(
+            "Cannot merge '{}' into '{}' while '{}' is published; unpublish it first",
+            source, target, published
+        ));
+    }
+
+    let mut db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+
+    let Some((source_ds, source_assets)) = get_dataset_with_assets(&db, &source)
+        .map_err(|e| format!("Failed to load dataset '{}': {}", source, e))?
+    else {
+        return Err(format!("Dataset '{}' not found", source));
+    };
+    let Some((target_ds, target_assets)) = get_dataset_with_assets(&db, &target)
+        .map_err(|e| format!("Failed to load dataset '{}': {}", target, e))?
+    else {
+        return Err(format!("Dataset '{}' not found", target));
+    };
+
+    let source_prints = asset_fingerprints(&db, &source_assets);
+    let target_prints: BTreeSet<String> = asset_fingerprints(&db, &target_assets)
+        .into_values()
+        .collect();
+
+    let source_manifest = build_manifest_from_db(&source_ds, &source_assets);
+    let mut target_manifest = build_manifest_from_db(&target_ds, &target_assets);
+
+    let mut moved_assets = Vec::new();
+    let mut skipped_duplicate_assets = Vec::new();
+    for (key, mut asset) in source_manifest.assets {
+        if source_prints
+            .get(&key)
+            .is_some_and(|print| target_prints.contains(print))
+        {
+            skipped_duplicate_assets.push(key);
+            continue;
+        }
+
+        let mut new_key = key.clone();
+        let mut suffix = 2;
+        while target_manifest.assets.contains_key(&new_key) {
+            new_key = format!("{}_{}", key, suffix);
+            suffix += 1;
+        }
+        if new_key != key {
+            asset.url = Some(format!("{{root.private_url}}#assets.{}", new_key));
+        }
+        asset.id = Some(Uuid::new_v4().to_string());
+        target_manifest.assets.insert(new_key.clone(), asset);
+        moved_assets.push(new_key);
+    }
+
+    // A savepoint rather than `transaction()` so the library calls below can
+    // keep borrowing the connection; it rolls back everything on any error.
+    db.conn
+        .execute_batch("SAVEPOINT merge_datasets")
+        .map_err(|e| format!("Failed to start merge: {}", e))?;
+    let merged = merge_datasets_in_db(
+        &mut db,
+        &source,
+        &target,
+        &target_manifest,
+        !moved_assets.is_empty(),
+    );
+    let updated_run_configs = match merged {
+        Ok(updated) => {
+            db.conn
+                .execute_batch("RELEASE merge_datasets")
+                .map_err(|e| format!("Failed to commit merge: {}", e))?;
+            updated
+        }
+        Err(err) => {
+            let _ = db
+                .conn
+                .execute_batch("ROLLBACK TO merge_datasets; RELEASE merge_datasets");
+            return Err(err);
+        }
+    };
+
+    // mapping.yaml lives outside the database, so it is only touched once the
+    // merge has committed.
+    if let Some(priv_url) = &target_manifest.private_url {
+        let mut mapping_entries: Vec<(String, String)> = Vec::new();
+        for key in &moved_assets {
+            let path = target_manifest
+                .assets
+                .get(key)
+                .and_then(|a| a.mappings.as_ref())
+                .and_then(|m| m.private.as_ref())
+                .and_then(|ep| {
+                    ep.file_path.clone().or_else(|| {
+                        ep.db_file_id.and_then(|fid| {
+                            db.conn
+                                .query_row(
+                                    "SELECT file_path FROM files WHERE id = ?1",
+                                    [fid],
+                                    |row| row.get::<_, String>(0),
+                                )
+                                .ok()
+                        })
+                    })
+                });
+            if let Some(path) = path {
+                mapping_entries.push((format!("{}#assets.{}", priv_url, key), path));
+            }
+        }
+        if !mapping_entries.is_empty() {
+            if let Err(err) = biovault::data::datasets::update_local_mappings(&mapping_entries) {
+                crate::desktop_log!(
+                    "⚠️ Merged '{}' into '{}' but failed to update mapping.yaml: {}",
+                    source,
+                    target,
+                    err
+                );
+            }
+        }
+    }
+
+    crate::desktop_log!(
+        "🔀 Merged dataset '{}' into '{}' ({} moved, {} duplicate, {} run config(s) updated)",
+        source,
+        target,
+        moved_assets.len(),
+        skipped_duplicate_assets.len(),
+        updated_run_configs.len()
+    );
+
+    Ok(DatasetMergeResult {
+        source,
+        target,
+        moved_assets,
+        skipped_duplicate_assets,
+        updated_run_configs,
+        source_deleted: true,
+    })
+}
+
+/// Database half of `merge_datasets`; runs inside its savepoint. Run
+/// configurations are updated in place so their ids stay stable.
+fn merge_datasets_in_db(
+    db: &mut BioVaultDb,
+    source: &str,
+    target: &str,
+    target_manifest: &biovault::cli::commands::datasets::DatasetManifest,
+    has_moved_assets: bool,
+) -> Result<Vec<i64>, String> {
+    if has_moved_assets {
+        biovault::data::upsert_dataset(db, target_manifest)
+            .map_err(|e| format!("Failed to save dataset: {}", e))?;
+    }
+
+    let mut updated_run_configs = Vec::new();
+    let flows = db.list_flows().map_err(|e| e.to_string())?;
+    for flow in flows {
+        let configs = db
+            .list_flow_run_configs(flow.id)
+            .map_err(|e| e.to_string())?;
+        for config in configs {
+            let mut data = config.config_data.clone();
+            if !replace_dataset_refs(&mut data, source, target) {
+                continue;
+            }
+            let json = serde_json::to_string(&data)
+                .map_err(|e| format!("Failed to serialize run config {}: {}", config.id, e))?;
+            db.conn
+                .execute(
+                    "UPDATE flow_run_configs SET config_data = ?1 WHERE id = ?2",
+                    rusqlite::params![json, config.id],
+                )
+                .map_err(|e| format!("Failed to update run config {}: {}", config.id, e))?;
+            updated_run_configs.push(config.id);
+        }
+    }
+
+    let deleted = biovault::data::delete_dataset(db, source)
+        .map_err(|e| format!("Failed to delete dataset '{}': {}", source, e))?;
+    if deleted == 0 {
+        return Err(format!("Dataset '{}' disappeared during merge", source));
+    }
+    Ok(updated_run_configs)
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct AssetLinkSide {
+    pub stored_path: Option<String>,
+    pub stored_file_id: Option<i64>,
+    pub path_exists: bool,
+    /// Path of the `files` row behind `stored_file_id`, if that row still exists.
+    pub file_id_path: Option<String>,
+    pub file_id_path_exists: bool,
+    /// Which fallback produced a file on disk: "path", "file_id", or None.
+    pub matched: Option<String>,
+    pub resolved_path: Option<String>,
+    /// A path or file id is stored but doesn't lead to a file on disk.
+    pub stale: bool,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct AssetLinkAudit {
+    pub asset_key: String,
+    pub private: AssetLinkSide,
+    pub mock: AssetLinkSide,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct DatasetLinkAudit {
+    pub dataset_name: String,
+    pub assets: Vec<AssetLinkAudit>,
+    pub broken_count: usize,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct AssetLinkRepair {
+    pub asset_key: String,
+    /// "private" or "mock"
+    pub side: String,
+    pub old_file_id: Option<i64>,
+    pub new_file_id: i64,
+    pub old_path: Option<String>,
+    pub new_path: String,
+    pub file_hash: String,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct DatasetLinkRepairResult {
+    pub dataset_name: String,
+    pub repaired: Vec<AssetLinkRepair>,
+    /// "asset_key (side): reason" for stale links that couldn't be re-bound.
+    pub unresolved: Vec<String>,
+}
+
+/// Mirror the path-then-file-id fallback order used when flows resolve assets,
+/// recording which step (if any) reaches a file that actually exists.
+fn audit_asset_link(db: &BioVaultDb, path: Option<&str>, file_id: Option<i64>) -> AssetLinkSide {
+    let stored_path = path.map(|p| p.trim().to_string()).filter(|p| !p.is_empty());
+    let path_exists = stored_path
+        .as_deref()
+        .is_some_and(|p| Path::new(p).is_file());
+    let file_id_path: Option<String> = file_id.and_then(|fid| {
+        db.conn
+            .query_row("SELECT file_path FROM files WHERE id = ?1", [fid], |row| {
+                row.get(0)
+            })
+            .optional()
+            .ok()
+            .flatten()
+    });
+    let file_id_path_exists = file_id_path
+        .as_deref()
+        .is_some_and(|p| Path::new(p).is_file());
+
+    let (matched, resolved_path) = if path_exists {
+        (Some("path".to_string()), stored_path.clone())
+    } else if file_id_path_exists {
+        (Some("file_id".to_string()), file_id_path.clone())
+    } else {
+        (None, None)
+    };
+    let stale =
+        (stored_path.is_some() && !path_exists) || (file_id.is_some() && !file_id_path_exists);
+
+    AssetLinkSide {
+        stored_path,
+        stored_file_id: file_id,
+        path_exists,
+        file_id_path,
+        file_id_path_exists,
+        matched,
+        resolved_path,
+        stale,
+    }
+}
+
+/// Content hash the link was meant to point at: the stored file row's hash,
+/// else the catalog hash (or a fresh hash) of the stored path if it exists.
+fn expected_link_hash(db: &BioVaultDb, link: &AssetLinkSide) -> Option<String> {
+    let known = |hash: Option<String>| hash.filter(|h| !h.is_empty() && !h.starts_with("pending_"));
+    if let Some(fid) = link.stored_file_id {
+        let hash: Option<String> = db
+            .conn
+            .query_row("SELECT file_hash FROM files WHERE id = ?1", [fid], |row| {
+                row.get(0)
+            })
+            .optional()
+            .ok()
+            .flatten()
+            .flatten();
+        if let Some(hash) = known(hash) {
+            return Some(hash);
+        }
+    }
+    let path = link.stored_path.as_deref().filter(|_| link.path_exists)?;
+    let catalog: Option<String> = db
+        .conn
+        .query_row(
+            "SELECT file_hash FROM files WHERE file_path = ?1",
+            [path],
+            |row| row.get(0),
+        )
+        .optional()
+        .ok()
+        .flatten()
+        .flatten();
+    known(catalog).or_else(|| biovault::data::hash_file(path).ok())
+}
+
+/// An existing catalogued file with this hash, preferring the stored path.
+fn find_file_by_hash(
+    db: &BioVaultDb,
+    hash: &str,
+    prefer_path: Option<&str>,
+) -> Option<(i64, String)> {
+    let mut stmt = db
+        .conn
+        .prepare("SELECT id, file_path FROM files WHERE file_hash = ?1 ORDER BY id DESC")
+        .ok()?;
+    let rows: Vec<(i64, String)> = stmt
+        .query_map([hash], |row| Ok((row.get(0)?, row.get(1)?)))
+        .ok()?
+        .filter_map(|r| r.ok())
+        .filter(|(_, path)| Path::new(path).is_file())
+        .collect();
+    rows.iter()
+        .find(|(_, path)| Some(path.as_str()) == prefer_path)
+        .or_else(|| rows.first())
+        .cloned()
+}
+
+/// Report, per asset, which of the stored path / file id fallbacks still
+/// resolves to a file on disk.
+#[tauri::command]
+pub fn audit_dataset_links(
+    state: tauri::State<AppState>,
+    dataset_name: String,
+) -> Result<DatasetLinkAudit, String> {
+    let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+    let Some((_, assets)) = get_dataset_with_assets(&db, &dataset_name)
+        .map_err(|e| format!("Failed to load dataset '{}': {}", dataset_name, e))?
+    else {
+        return Err(format!("Dataset '{}' not found", dataset_name));
+    };
+
+    let assets: Vec<AssetLinkAudit> = assets
+        .iter()
+        .map(|asset| AssetLinkAudit {
+            asset_key: asset.asset_key.clone(),
+            private: audit_asset_link(&db, asset.private_path.as_deref(), asset.private_file_id),
+            mock: audit_asset_link(&db, asset.mock_path.as_deref(), asset.mock_file_id),
+        })
+        .collect();
+    let broken_count = assets
+        .iter()
+        .filter(|a| a.private.stale || a.mock.stale)
+        .count();
+
+    Ok(DatasetLinkAudit {
+        dataset_name,
+        assets,
+        broken_count,
+    })
+}
+
+/// Re-bind stale asset links to catalogued files with the same content hash,
+/// e.g. after a re-import gave the files new ids.
+#[tauri::command]
+pub fn repair_dataset_links(
+    state: tauri::State<AppState>,
+    dataset_name: String,
+) -> Result<DatasetLinkRepairResult, String> {
+    let mut db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+    let Some((dataset, assets)) = get_dataset_with_assets(&db, &dataset_name)
+        .map_err(|e| format!("Failed to load dataset '{}': {}", dataset_name, e))?
+    else {
+        return Err(format!("Dataset '{}' not found", dataset_name));
+    };
+
+    let mut manifest = build_manifest_from_db(&dataset, &assets);
+    let mut repaired = Vec::new();
+    let mut unresolved = Vec::new();
+
+    for asset in &assets {
+        let sides = [
+            (
+                "private",
+                audit_asset_link(&db, asset.private_path.as_deref(), asset.private_file_id),
+            ),
+            (
+                "mock",
+                audit_asset_link(&db, asset.mock_path.as_deref(), asset.mock_file_id),
+            ),
+        ];
+        for (side, link) in sides {
+            if !link.stale {
+                continue;
+            }
+            let Some(hash) = expected_link_hash(&db, &link) else {
+                unresolved.push(format!(
+                    "{} ({}): no content hash to match against",
+                    asset.asset_key, side
+                ));
+                continue;
+            };
+            let Some((new_file_id, new_path)) =
+                find_file_by_hash(&db, &hash, link.stored_path.as_deref())
+            else {
+                unresolved.push(format!(
+                    "{} ({}): no imported file with matching hash",
+                    asset.asset_key, side
+                ));
+                continue;
+            };
+
+            let endpoint = manifest
+                .assets
+                .get_mut(&asset.asset_key)
+                .and_then(|a| a.mappings.as_mut())
+                .and_then(|m| {
+                    if side == "private" {
+                        m.private.as_mut()
+                    } else {
+                        m.mock.as_mut()
+                    }
+                });
+            let Some(endpoint) = endpoint else {
+                unresolved.push(format!(
+                    "{} ({}): asset has no {} mapping",
+                    asset.asset_key, side, side
+                ));
+                continue;
+            };
+            endpoint.db_file_id = Some(new_file_id);
+            if link.stored_path.is_some() {
+                endpoint.file_path = Some(new_path.clone());
+            }
+
+            repaired.push(AssetLinkRepair {
+                asset_key: asset.asset_key.clone(),
+                side: side.to_string(),
+                old_file_id: link.stored_file_id,
+                new_file_id,
+                old_path: link.stored_path,
+                new_path,
+                file_hash: hash,
+            });
+        }
+    }
+
+    if !repaired.is_empty() {
+        biovault::data::upsert_dataset(&mut db, &manifest)
+            .map_err(|e| format!("Failed to save dataset: {}", e))?;
+
+        if let Some(priv_url) = &manifest.private_url {
+            let mapping_entries: Vec<(String, String)> = repaired
+                .iter()
+                .filter(|r| r.side == "private")
+                .map(|r| {
+                    (
+                        format!("{}#assets.{}", priv_url, r.asset_key),
+                        r.new_path.clone(),
+                    )
+                })
+                .collect();
+            if !mapping_entries.is_empty() {
+                biovault::data::datasets::update_local_mappings(&mapping_entries)
+                    .map_err(|e| format!("Failed to update mapping.yaml: {}", e))?;
+            }
+        }
+    }
+
+    crate::desktop_log!(
+        "🔗 Repaired {} link(s) in dataset '{}' ({} unresolved)",
+        repaired.len(),
+        dataset_name,
+        unresolved.len()
+    );
+
+    Ok(DatasetLinkRepairResult {
+        dataset_name,
+        repaired,
+        unresolved,
+    })
+}
+
 #[tauri::command]
 pub fn get_datasets_folder_path() -> Result<String, String> {
     let config =
@@ -134,19 +134,164 @@ pub fn save_dependency_states(biovault_path: &Path) -> Result<DependencyCheckRes
     Ok(check_result)
 }
 
+/// The library's dependency check plus the Java version check, which the
+/// library doesn't do: a java that is too old/new only fails once Nextflow starts.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DesktopDependencyCheckResult {
+    #[serde(flatten)]
+    pub result: DependencyCheckResult,
+    pub java_compatibility: JavaCompatibility,
+}
+
 #[tauri::command]
-pub async fn check_dependencies() -> Result<DependencyCheckResult, String> {
+pub async fn check_dependencies() -> Result<DesktopDependencyCheckResult, String> {
     crate::desktop_log!("🔍 check_dependencies called");
 
     // Run in blocking thread pool since this calls subprocess checks (java, docker, etc.)
     tokio::task::spawn_blocking(|| {
-        biovault::cli::commands::check::check_dependencies_result()
-            .map_err(|e| format!("Failed to check dependencies: {}", e))
+        let result = biovault::cli::commands::check::check_dependencies_result()
+            .map_err(|e| format!("Failed to check dependencies: {}", e))?;
+        let java = resolve_java_compatibility();
+        if java.version.is_some() && !java.compatible {
+            crate::desktop_log!(
+                "⚠️ Java {} at {} is outside Nextflow's supported range ({})",
+                java.version.as_deref().unwrap_or("?"),
+                java.path,
+                java.required_range
+            );
+        }
+        Ok(DesktopDependencyCheckResult {
+            result,
+            java_compatibility: java,
+        })
     })
     .await
     .map_err(|e| format!("Task join error: {}", e))?
 }
 
+/// Java major versions supported by the bundled Nextflow release.
+const NEXTFLOW_JAVA_MIN: u32 = 17;
+const NEXTFLOW_JAVA_MAX: u32 = 25;
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct JavaCompatibility {
+    /// The java binary that was checked.
+    pub path: String,
+    /// Where the path came from: bundled, config, java_home or path.
+    pub source: String,
+    pub version: Option<String>,
+    pub major: Option<u32>,
+    pub compatible: bool,
+    pub required_range: String,
+    pub error: Option<String>,
+}
+
+/// Same precedence as the runtime: bundled java, configured path, JAVA_HOME, then PATH.
+fn resolve_java_binary() -> (String, &'static str) {
+    if let Ok(path) = env::var("BIOVAULT_BUNDLED_JAVA") {
+        if !path.trim().is_empty() && Path::new(path.trim()).exists() {
+            return (path.trim().to_string(), "bundled");
+        }
+    }
+    if let Ok(cfg) = biovault::config::Config::load() {
+        if let Some(path) = cfg.get_binary_path("java") {
+            if !path.trim().is_empty() {
+                return (path.trim().to_string(), "config");
+            }
+        }
+    }
+    if let Ok(home) = env::var("JAVA_HOME") {
+        let exe = if cfg!(windows) { "java.exe" } else { "java" };
+        let candidate = PathBuf::from(home.trim()).join("bin").join(exe);
+        if !home.trim().is_empty() && candidate.exists() {
+            return (candidate.to_string_lossy().to_string(), "java_home");
+        }
+    }
+    ("java".to_string(), "path")
+}
+
+/// Pull the version out of `java -version` output, e.g. `openjdk version "17.0.9"`
+/// or `java version "1.8.0_392"`. Returns the raw string and its major version.
+fn parse_java_version(output: &str) -> Option<(String, u32)> {
+    let line = output.lines().find(|l| l.contains("version"))?;
+    let start = line.find('"')? + 1;
+    let end = start + line[start..].find('"')?;
+    let version = line[start..end].trim().to_string();
+    let mut parts = version.split(|c: char| !c.is_ascii_digit());
+    let first: u32 = parts.next()?.parse().ok()?;
+    let major = if first == 1 {
+        parts.next().and_then(|p| p.parse().ok()).unwrap_or(first)
+    } else {
+        first
+    };
+    Some((version, major))
+}
+
+fn resolve_java_compatibility() -> JavaCompatibility {
+    let (path, source) = resolve_java_binary();
+    let mut result = JavaCompatibility {
+        path: path.clone(),
+        source: source.to_string(),
+        version: None,
+        major: None,
+        compatible: false,
+        required_range: format!("{}-{}", NEXTFLOW_JAVA_MIN, NEXTFLOW_JAVA_MAX),
+        error: None,
+    };
+
+    let mut cmd = Command::new(&path);
+    cmd.arg("-version");
+    cmd.stdin(Stdio::null());
+    configure_child_process(&mut cmd);
+    let output = match cmd.output() {
+        Ok(output) => output,
+        Err(e) => {
+            result.error = Some(format!("Failed to run {}: {}", path, e));
+            return result;
+        }
+    };
+
+    // java prints its version banner to stderr.
+    let text = format!(
+        "{}\n{}",
+        String::from_utf8_lossy(&output.stderr),
+        String::from_utf8_lossy(&output.stdout)
+    );
+    match parse_java_version(&text) {
+        Some((version, major)) => {
+            result.compatible = (NEXTFLOW_JAVA_MIN..=NEXTFLOW_JAVA_MAX).contains(&major);
+            if !result.compatible {
+                result.error = Some(format!(
+                    "Nextflow requires Java {}, found Java {}",
+                    result.required_range, major
+                ));
+            }
+            result.version = Some(version);
+            result.major = Some(major);
+        }
+        None => {
+            result.error = Some(format!("Could not parse version output from {}", path));
+        }
+    }
+    result
+}
+
+/// Run the java that flows would use and check it against Nextflow's supported range.
+#[tauri::command]
+pub async fn check_java_compatibility() -> Result<JavaCompatibility, String> {
+    let result = tokio::task::spawn_blocking(resolve_java_compatibility)
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?;
+    crate::desktop_log!(
+        "☕ Java compatibility: {} ({}) version={:?} compatible={}",
+        result.path,
+        result.source,
+        result.version,
+        result.compatible
+    );
+    Ok(result)
+}
+
 #[tauri::command]
 pub async fn check_single_dependency(
     name: String,
@@ -361,6 +506,74 @@ pub async fn save_custom_path(name: String, path: String) -> Result<(), String>
     Ok(())
 }
 
+const BUNDLED_BINARY_ENV_KEYS: &[&str] = &[
+    "BIOVAULT_BUNDLED_JAVA",
+    "BIOVAULT_BUNDLED_JAVA_HOME",
+    "BIOVAULT_BUNDLED_NEXTFLOW",
+    "BIOVAULT_BUNDLED_UV",
+    "SEQURE_NATIVE_BIN",
+    "SYFTBOX_BINARY",
+];
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct BundledBinaryResolution {
+    pub env_key: String,
+    pub previous: Option<String>,
+    pub current: Option<String>,
+    pub changed: bool,
+}
+
+/// Re-run bundled binary resolution at runtime so newly installed or updated
+/// binaries are picked up without restarting the app.
+#[tauri::command]
+pub fn refresh_bundled_binaries(
+    app: tauri::AppHandle,
+) -> Result<Vec<BundledBinaryResolution>, String> {
+    let previous: Vec<(&str, Option<String>)> = BUNDLED_BINARY_ENV_KEYS
+        .iter()
+        .map(|key| (*key, env::var(key).ok()))
+        .collect();
+
+    crate::desktop_log!("🔄 Refreshing bundled binary resolution");
+    crate::expose_bundled_binaries(&app);
+
+    // Resolved paths feed dependency checks; drop the cached result so the next check sees them.
+    if let Ok(mut cache) = DEPENDENCY_CACHE.lock() {
+        *cache = None;
+    }
+
+    let resolutions: Vec<BundledBinaryResolution> = previous
+        .into_iter()
+        .map(|(key, before)| {
+            let current = env::var(key).ok();
+            let changed = before != current;
+            if changed {
+                crate::desktop_log!(
+                    "🔧 {} changed: {} -> {}",
+                    key,
+                    before.as_deref().unwrap_or("<unset>"),
+                    current.as_deref().unwrap_or("<unset>")
+                );
+            }
+            BundledBinaryResolution {
+                env_key: key.to_string(),
+                previous: before,
+                current,
+                changed,
+            }
+        })
+        .collect();
+
+    let changed = resolutions.iter().filter(|r| r.changed).count();
+    crate::desktop_log!(
+        "✅ Bundled binaries refreshed ({} of {} changed)",
+        changed,
+        resolutions.len()
+    );
+
+    Ok(resolutions)
+}
+
 #[tauri::command]
 pub fn update_saved_dependency_states() -> Result<(), String> {
     crate::desktop_log!("🔄 Updating saved dependency states");
@@ -1,12 +1,105 @@
-use crate::types::AppState;
+use crate::types::{AppState, FileRecord};
+use flate2::read::MultiGzDecoder;
 use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
 
 // Re-export GenotypeMetadata from parent module
 use super::GenotypeMetadata;
 
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Gzip is detected by its magic bytes; the `.gz` extension is only trusted
+/// when the header cannot be read.
+pub(crate) fn is_gzip_file(path: &Path) -> bool {
+    let mut magic = [0u8; 2];
+    match File::open(path).and_then(|mut f| f.read_exact(&mut magic)) {
+        Ok(()) => magic == GZIP_MAGIC,
+        Err(_) => path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("gz")),
+    }
+}
+
+/// Line reader over a genotype file, decompressing gzip transparently.
+pub(crate) fn open_genotype_reader(path: &str) -> Result<Box<dyn BufRead>, String> {
+    let file = File::open(path).map_err(|e| format!("Failed to open {}: {}", path, e))?;
+    if is_gzip_file(Path::new(path)) {
+        Ok(Box::new(BufReader::new(MultiGzDecoder::new(file))))
+    } else {
+        Ok(Box::new(BufReader::new(file)))
+    }
+}
+
+/// Decompress a gzipped file to a temp file named like the inner file
+/// (`sample.txt.gz` -> `*.txt`) so the library detectors see plaintext.
+fn decompress_to_temp(path: &Path) -> Result<tempfile::NamedTempFile, String> {
+    let inner_ext = path
+        .file_stem()
+        .map(Path::new)
+        .and_then(|stem| stem.extension())
+        .map(|ext| format!(".{}", ext.to_string_lossy()))
+        .unwrap_or_else(|| ".txt".to_string());
+    let mut temp = tempfile::Builder::new()
+        .prefix("biovault-gunzip-")
+        .suffix(&inner_ext)
+        .tempfile()
+        .map_err(|e| format!("Failed to create temp file: {}", e))?;
+    let file = File::open(path).map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    std::io::copy(&mut MultiGzDecoder::new(file), temp.as_file_mut())
+        .map_err(|e| format!("Failed to decompress {}: {}", path.display(), e))?;
+    Ok(temp)
+}
+
+fn with_plaintext<T, E: std::fmt::Display>(
+    file_path: &str,
+    f: impl FnOnce(&str) -> Result<T, E>,
+) -> Result<T, String> {
+    let path = Path::new(file_path);
+    if !is_gzip_file(path) {
+        return f(file_path).map_err(|e| e.to_string());
+    }
+    let temp = decompress_to_temp(path)?;
+    f(&temp.path().to_string_lossy()).map_err(|e| e.to_string())
+}
+
+/// `biovault::data::detect_genotype_metadata` that also accepts gzipped input.
+pub(crate) fn detect_genotype_metadata(
+    file_path: &str,
+) -> Result<biovault::data::GenotypeMetadata, String> {
+    with_plaintext(file_path, |p| biovault::data::detect_genotype_metadata(p))
+}
+
+/// `biovault::data::analyze_genotype_file` that also accepts gzipped input.
+/// Row and chromosome counts come from the decompressed lines. The sex-call
+/// confidence is scored in the same pass, on the same plaintext; a scoring
+/// failure is logged and leaves it empty.
+pub(crate) fn analyze_genotype_file_with_sex_confidence(
+    file_path: &str,
+) -> Result<
+    (
+        biovault::data::GenotypeMetadata,
+        Option<super::SexInferenceConfidence>,
+    ),
+    String,
+> {
+    with_plaintext(file_path, |p| {
+        let metadata = biovault::data::analyze_genotype_file(p).map_err(|e| e.to_string())?;
+        let confidence = metadata.inferred_sex.as_deref().and_then(|sex| {
+            super::estimate_sex_confidence(p, Some(sex))
+                .map_err(|e| {
+                    crate::desktop_log!("⚠️  Failed to score sex call for {}: {}", file_path, e)
+                })
+                .ok()
+        });
+        Ok::<_, String>((metadata, confidence))
+    })
+}
+
 #[tauri::command]
 pub async fn detect_file_types(
-    _state: tauri::State<'_, AppState>,
+    state: tauri::State<'_, AppState>,
     files: Vec<String>,
 ) -> Result<HashMap<String, GenotypeMetadata>, String> {
     if files.is_empty() {
@@ -18,10 +111,14 @@ pub async fn detect_file_types(
         files.len()
     );
 
+    let custom_types = {
+        let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+        super::load_custom_data_types(&db).unwrap_or_default()
+    };
     let mut results = HashMap::new();
 
     for file_path in files {
-        let metadata = biovault::data::detect_genotype_metadata(&file_path).unwrap_or_else(|e| {
+        let metadata = super::detect_file_metadata(&custom_types, &file_path).unwrap_or_else(|e| {
             crate::desktop_log!("⚠️  Failed to detect {}: {}", file_path, e);
             biovault::data::GenotypeMetadata::default()
         });
@@ -43,6 +140,8 @@ pub async fn detect_file_types(
                 row_count: metadata.row_count,
                 chromosome_count: metadata.chromosome_count,
                 inferred_sex: metadata.inferred_sex,
+                inferred_sex_confidence: None,
+                inferred_sex_low_confidence: None,
             },
         );
     }
@@ -68,10 +167,11 @@ pub async fn analyze_file_types(
     let mut results = HashMap::new();
 
     for file_path in files {
-        let metadata = biovault::data::analyze_genotype_file(&file_path).unwrap_or_else(|e| {
-            crate::desktop_log!("⚠️  Failed to analyze {}: {}", file_path, e);
-            biovault::data::GenotypeMetadata::default()
-        });
+        let (metadata, sex_confidence) = analyze_genotype_file_with_sex_confidence(&file_path)
+            .unwrap_or_else(|e| {
+                crate::desktop_log!("⚠️  Failed to analyze {}: {}", file_path, e);
+                (biovault::data::GenotypeMetadata::default(), None)
+            });
 
         results.insert(
             file_path.clone(),
@@ -82,6 +182,8 @@ pub async fn analyze_file_types(
                 row_count: metadata.row_count,
                 chromosome_count: metadata.chromosome_count,
                 inferred_sex: metadata.inferred_sex,
+                inferred_sex_confidence: sex_confidence.as_ref().map(|c| c.confidence),
+                inferred_sex_low_confidence: sex_confidence.as_ref().map(|c| c.low_confidence),
             },
         );
     }
@@ -89,3 +191,59 @@ pub async fn analyze_file_types(
     crate::desktop_log!("✅ Analyzed {} files", results.len());
     Ok(results)
 }
+
+/// Send an already-imported file back through the queue so it is re-hashed
+/// and re-analyzed in place, keeping its participant association.
+#[tauri::command]
+pub fn reanalyze_file(state: tauri::State<AppState>, file_id: i64) -> Result<FileRecord, String> {
+    crate::desktop_log!("🔁 reanalyze_file called for file {}", file_id);
+
+    let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+    let existing = biovault::data::get_file_by_id(&db, file_id)
+        .map_err(|e| format!("Failed to load file {}: {}", file_id, e))?
+        .ok_or_else(|| format!("File {} not found", file_id))?;
+    if existing.status.as_deref() == Some("processing") {
+        return Err(format!("File {} is already being processed", file_id));
+    }
+    if !std::path::Path::new(&existing.file_path).is_file() {
+        return Err(format!("File not found on disk: {}", existing.file_path));
+    }
+
+    db.connection()
+        .execute(
+            "UPDATE files SET status = 'pending', processing_error = NULL,
+                 updated_at = CURRENT_TIMESTAMP
+             WHERE id = ?1",
+            [file_id],
+        )
+        .map_err(|e| format!("Failed to requeue file {}: {}", file_id, e))?;
+
+    let record = biovault::data::get_file_by_id(&db, file_id)
+        .map_err(|e| format!("Failed to load file {}: {}", file_id, e))?
+        .ok_or_else(|| format!("File {} not found", file_id))?;
+    let sex_confidence = super::load_sex_confidence(&db).unwrap_or_default();
+
+    crate::desktop_log!("✅ Requeued {} for analysis", record.file_path);
+    Ok(FileRecord {
+        id: record.id,
+        participant_id: record.participant_id,
+        participant_name: record.participant_name,
+        file_path: record.file_path,
+        file_hash: record.file_hash,
+        file_type: record.file_type,
+        file_size: record.file_size,
+        data_type: record.data_type,
+        source: record.source,
+        grch_version: record.grch_version,
+        row_count: record.row_count,
+        chromosome_count: record.chromosome_count,
+        inferred_sex_confidence: sex_confidence.get(&record.id).map(|(c, _)| *c),
+        inferred_sex_low_confidence: sex_confidence.get(&record.id).map(|(_, low)| *low),
+        inferred_sex: record.inferred_sex,
+        status: record.status,
+        processing_error: record.processing_error,
+        import_origin: None,
+        created_at: record.created_at,
+        updated_at: record.updated_at,
+    })
+}
@@ -1,41 +1,259 @@
 use crate::types::{AppState, FileRecord};
+use biovault::data::BioVaultDb;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
+/// Without `limit`, a filtered query still returns (effectively) every match.
+const DEFAULT_FILES_PAGE_LIMIT: i64 = 100_000;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileFilter {
+    pub status: Option<String>,
+    pub data_type: Option<String>,
+    /// Substring of the participant ID (ASCII case-insensitive)
+    pub participant_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FilePage {
+    pub files: Vec<FileRecord>,
+    pub total: i64,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+fn to_file_record(
+    f: biovault::data::FileRecord,
+    sex_confidence: &HashMap<i64, (f64, bool)>,
+    import_origins: &HashMap<i64, String>,
+) -> FileRecord {
+    FileRecord {
+        id: f.id,
+        participant_id: f.participant_id,
+        participant_name: f.participant_name,
+        file_path: f.file_path,
+        file_hash: f.file_hash,
+        file_type: f.file_type,
+        file_size: f.file_size,
+        data_type: f.data_type,
+        source: f.source,
+        grch_version: f.grch_version,
+        row_count: f.row_count,
+        chromosome_count: f.chromosome_count,
+        inferred_sex_confidence: sex_confidence.get(&f.id).map(|(c, _)| *c),
+        inferred_sex_low_confidence: sex_confidence.get(&f.id).map(|(_, low)| *low),
+        inferred_sex: f.inferred_sex,
+        status: f.status,
+        processing_error: f.processing_error,
+        import_origin: import_origins.get(&f.id).cloned(),
+        created_at: f.created_at,
+        updated_at: f.updated_at,
+    }
+}
+
+/// Pages longer than this load every file record once instead of one at a time.
+const FILES_PAGE_LOOKUP_LIMIT: usize = 500;
+
+/// Files matching `filter`, newest first. Only the matching ids come from SQL;
+/// the records themselves are the library's, mapped through `to_file_record`,
+/// so a page can't drift from `get_files`.
+fn query_files_page(
+    db: &BioVaultDb,
+    filter: FileFilter,
+    limit: Option<i64>,
+    offset: Option<i64>,
+) -> Result<FilePage, String> {
+    let limit = limit.unwrap_or(DEFAULT_FILES_PAGE_LIMIT).max(0);
+    let offset = offset.unwrap_or(0).max(0);
+
+    let mut clauses = Vec::new();
+    let mut params: Vec<rusqlite::types::Value> = Vec::new();
+    let mut push = |clause: &str, value: String| {
+        params.push(rusqlite::types::Value::Text(value));
+        clauses.push(clause.replace('?', &format!("?{}", params.len())));
+    };
+    if let Some(status) = filter.status.filter(|s| !s.trim().is_empty()) {
+        push("f.status = ?", status.trim().to_string());
+    }
+    if let Some(data_type) = filter.data_type.filter(|s| !s.trim().is_empty()) {
+        push("f.data_type = ?", data_type.trim().to_string());
+    }
+    if let Some(participant) = filter.participant_id.filter(|s| !s.trim().is_empty()) {
+        let escaped = participant
+            .trim()
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_");
+        push(
+            "p.participant_id LIKE ? ESCAPE '\\'",
+            format!("%{}%", escaped),
+        );
+    }
+    let where_sql = if clauses.is_empty() {
+        String::new()
+    } else {
+        format!("WHERE {}", clauses.join(" AND "))
+    };
+    let from_sql = format!(
+        "FROM files f LEFT JOIN participants p ON p.id = f.participant_id {}",
+        where_sql
+    );
+
+    let conn = db.connection();
+    let total: i64 = conn
+        .query_row(
+            &format!("SELECT COUNT(*) {}", from_sql),
+            rusqlite::params_from_iter(params.iter()),
+            |row| row.get(0),
+        )
+        .map_err(|e| format!("Failed to count files: {}", e))?;
+
+    let page_sql = format!(
+        "SELECT f.id {} ORDER BY f.created_at DESC, f.id DESC LIMIT ?{} OFFSET ?{}",
+        from_sql,
+        params.len() + 1,
+        params.len() + 2
+    );
+    let mut page_params = params.clone();
+    page_params.push(rusqlite::types::Value::Integer(limit));
+    page_params.push(rusqlite::types::Value::Integer(offset));
+    let mut stmt = conn
+        .prepare(&page_sql)
+        .map_err(|e| format!("Failed to query files: {}", e))?;
+    let ids: Vec<i64> = stmt
+        .query_map(rusqlite::params_from_iter(page_params.iter()), |row| {
+            row.get(0)
+        })
+        .map_err(|e| format!("Failed to query files: {}", e))?
+        .collect::<Result<_, _>>()
+        .map_err(|e| format!("Failed to read files: {}", e))?;
+
+    let sex_confidence = super::load_sex_confidence(db).unwrap_or_default();
+    let import_origins = super::load_import_origins(db).unwrap_or_default();
+    let mut files = Vec::with_capacity(ids.len());
+    if ids.len() > FILES_PAGE_LOOKUP_LIMIT {
+        let mut by_id: HashMap<i64, biovault::data::FileRecord> =
+            biovault::data::list_files(db, None, None, false, None)
+                .map_err(|e| format!("Failed to list files: {}", e))?
+                .into_iter()
+                .map(|f| (f.id, f))
+                .collect();
+        for id in ids {
+            if let Some(record) = by_id.remove(&id) {
+                files.push(to_file_record(record, &sex_confidence, &import_origins));
+            }
+        }
+    } else {
+        for id in ids {
+            if let Some(record) = biovault::data::get_file_by_id(db, id)
+                .map_err(|e| format!("Failed to load file {}: {}", id, e))?
+            {
+                files.push(to_file_record(record, &sex_confidence, &import_origins));
+            }
+        }
+    }
+
+    Ok(FilePage {
+        files,
+        total,
+        limit,
+        offset,
+    })
+}
+
+/// Every file, or when any of `filter` / `limit` / `offset` is given, the
+/// matching slice of them (newest first, as in `get_files_page`).
 #[tauri::command]
-pub fn get_files(state: tauri::State<AppState>) -> Result<Vec<FileRecord>, String> {
+pub fn get_files(
+    state: tauri::State<AppState>,
+    filter: Option<FileFilter>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+) -> Result<Vec<FileRecord>, String> {
     crate::desktop_log!("🔍 get_files called (using library)");
 
     let db = state.biovault_db.lock().unwrap();
+    if filter.is_some() || limit.is_some() || offset.is_some() {
+        let page = query_files_page(&db, filter.unwrap_or_default(), limit, offset)?;
+        crate::desktop_log!(
+            "✅ Returning {} of {} matching files",
+            page.files.len(),
+            page.total
+        );
+        return Ok(page.files);
+    }
+
+    let sex_confidence = super::load_sex_confidence(&db).unwrap_or_default();
+    let import_origins = super::load_import_origins(&db).unwrap_or_default();
     let cli_files = biovault::data::list_files(&db, None, None, false, None)
         .map_err(|e| format!("Failed to list files: {}", e))?;
-
-    // Convert CLI FileRecords to desktop FileRecords
     let files: Vec<FileRecord> = cli_files
         .into_iter()
-        .map(|f| FileRecord {
-            id: f.id,
-            participant_id: f.participant_id,
-            participant_name: f.participant_name,
-            file_path: f.file_path,
-            file_hash: f.file_hash,
-            file_type: f.file_type,
-            file_size: f.file_size,
-            data_type: f.data_type,
-            source: f.source,
-            grch_version: f.grch_version,
-            row_count: f.row_count,
-            chromosome_count: f.chromosome_count,
-            inferred_sex: f.inferred_sex,
-            status: f.status,
-            processing_error: f.processing_error,
-            created_at: f.created_at,
-            updated_at: f.updated_at,
-        })
+        .map(|f| to_file_record(f, &sex_confidence, &import_origins))
         .collect();
-
     crate::desktop_log!("✅ Returning {} files", files.len());
     Ok(files)
 }
 
+/// One page of files matching `filter`, newest first, with the total match count.
+#[tauri::command]
+pub fn get_files_page(
+    state: tauri::State<AppState>,
+    filter: Option<FileFilter>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+) -> Result<FilePage, String> {
+    let db = state.biovault_db.lock().unwrap();
+    let page = query_files_page(&db, filter.unwrap_or_default(), limit, offset)?;
+    crate::desktop_log!(
+        "✅ Returning {} of {} matching files (offset {})",
+        page.files.len(),
+        page.total,
+        page.offset
+    );
+    Ok(page)
+}
+
+/// Files whose import scan started at `origin` or at a folder beneath it.
+#[tauri::command]
+pub fn get_files_by_origin(
+    state: tauri::State<AppState>,
+    origin: String,
+) -> Result<Vec<FileRecord>, String> {
+    let origin = origin.trim().trim_end_matches(['/', '\\']).to_string();
+    if origin.is_empty() {
+        return Err("Import origin is required".to_string());
+    }
+
+    let db = state.biovault_db.lock().unwrap();
+    let sex_confidence = super::load_sex_confidence(&db).unwrap_or_default();
+    let import_origins = super::load_import_origins(&db)?;
+
+    let mut ids: Vec<i64> = import_origins
+        .iter()
+        .filter(|(_, o)| std::path::Path::new(o.as_str()).starts_with(&origin))
+        .map(|(id, _)| *id)
+        .collect();
+    ids.sort_unstable();
+
+    let mut files = Vec::with_capacity(ids.len());
+    for id in ids {
+        if let Some(record) = biovault::data::get_file_by_id(&db, id)
+            .map_err(|e| format!("Failed to load file {}: {}", id, e))?
+        {
+            files.push(to_file_record(record, &sex_confidence, &import_origins));
+        }
+    }
+
+    crate::desktop_log!(
+        "📂 Returning {} file(s) imported from {}",
+        files.len(),
+        origin
+    );
+    Ok(files)
+}
+
 #[tauri::command]
 pub fn delete_file(state: tauri::State<AppState>, file_id: i64) -> Result<(), String> {
     crate::desktop_log!("🗑️ delete_file called (using library)");
@@ -43,6 +261,7 @@ pub fn delete_file(state: tauri::State<AppState>, file_id: i64) -> Result<(), St
     let db = state.biovault_db.lock().unwrap();
     biovault::data::delete_file(&db, file_id)
         .map_err(|e| format!("Failed to delete file: {}", e))?;
+    super::remove_orphaned_sex_confidence(&db)?;
 
     Ok(())
 }
@@ -64,6 +283,7 @@ pub fn delete_files_bulk(
     let db = state.biovault_db.lock().unwrap();
     let deleted = biovault::data::delete_files_bulk(&db, &file_ids)
         .map_err(|e| format!("Failed to delete files: {}", e))?;
+    super::remove_orphaned_sex_confidence(&db)?;
 
     crate::desktop_log!("✅ Deleted {} files", deleted);
     Ok(deleted)
@@ -100,3 +320,282 @@ pub fn get_file_reference(
     biovault::data::get_file_reference(&db, file_id)
         .map_err(|e| format!("Failed to get file reference: {}", e))
 }
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct MissingFileEntry {
+    pub id: i64,
+    pub file_path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub participant_id: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct FileExistenceReport {
+    pub checked: usize,
+    pub missing: Vec<MissingFileEntry>,
+}
+
+const EXISTENCE_PROGRESS_BATCH: usize = 250;
+
+/// Stat each file's path (all files when `file_ids` is None) and report the ones
+/// that no longer exist. Emits `files:existence-progress` while scanning.
+#[tauri::command]
+pub async fn check_files_exist(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+    file_ids: Option<Vec<i64>>,
+) -> Result<FileExistenceReport, String> {
+    use tauri::Emitter;
+
+    // Snapshot paths and release the lock before touching the filesystem.
+    let candidates: Vec<(i64, String, Option<String>)> = {
+        let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+        let files = biovault::data::list_files(&db, None, None, false, None)
+            .map_err(|e| format!("Failed to list files: {}", e))?;
+        let wanted: Option<std::collections::HashSet<i64>> =
+            file_ids.map(|ids| ids.into_iter().collect());
+        files
+            .into_iter()
+            .filter(|f| wanted.as_ref().map(|w| w.contains(&f.id)).unwrap_or(true))
+            .map(|f| (f.id, f.file_path, f.participant_id))
+            .collect()
+    };
+
+    let total = candidates.len();
+    crate::desktop_log!("🔎 Checking {} file(s) for existence on disk", total);
+
+    let missing = tauri::async_runtime::spawn_blocking(move || {
+        let mut missing = Vec::new();
+        for (idx, (id, file_path, participant_id)) in candidates.into_iter().enumerate() {
+            if !std::path::Path::new(&file_path).exists() {
+                missing.push(MissingFileEntry {
+                    id,
+                    file_path,
+                    participant_id,
+                });
+            }
+            let checked = idx + 1;
+            if checked % EXISTENCE_PROGRESS_BATCH == 0 || checked == total {
+                let _ = app.emit(
+                    "files:existence-progress",
+                    serde_json::json!({
+                        "checked": checked,
+                        "total": total,
+                        "missing": missing.len(),
+                    }),
+                );
+            }
+        }
+        missing
+    })
+    .await
+    .map_err(|e| format!("Failed to check files: {}", e))?;
+
+    crate::desktop_log!("✅ {} of {} file(s) missing on disk", missing.len(), total);
+    Ok(FileExistenceReport {
+        checked: total,
+        missing,
+    })
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub enum FileVerificationStatus {
+    Ok,
+    Changed,
+    Missing,
+    /// Present on disk but could not be read for hashing
+    Unreadable,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FileVerification {
+    pub file_id: i64,
+    pub path: String,
+    pub status: FileVerificationStatus,
+    pub expected_hash: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actual_hash: Option<String>,
+}
+
+/// Re-hash files (all when `file_ids` is None) and compare against the hash
+/// stored at import. Read-only; files the queue has not hashed yet are skipped.
+#[tauri::command]
+pub async fn verify_files(
+    state: tauri::State<'_, AppState>,
+    file_ids: Option<Vec<i64>>,
+) -> Result<Vec<FileVerification>, String> {
+    let candidates: Vec<(i64, String, String)> = {
+        let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+        let files = biovault::data::list_files(&db, None, None, false, None)
+            .map_err(|e| format!("Failed to list files: {}", e))?;
+        let wanted: Option<std::collections::HashSet<i64>> =
+            file_ids.map(|ids| ids.into_iter().collect());
+        files
+            .into_iter()
+            .filter(|f| wanted.as_ref().map(|w| w.contains(&f.id)).unwrap_or(true))
+            .filter(|f| !f.file_hash.is_empty() && !f.file_hash.starts_with("pending_"))
+            .map(|f| (f.id, f.file_path, f.file_hash))
+            .collect()
+    };
+
+    crate::desktop_log!("🔏 Verifying hashes of {} file(s)", candidates.len());
+    let results = tauri::async_runtime::spawn_blocking(move || {
+        candidates
+            .into_iter()
+            .map(|(file_id, path, expected_hash)| {
+                let (status, actual_hash) = if !std::path::Path::new(&path).is_file() {
+                    (FileVerificationStatus::Missing, None)
+                } else {
+                    match biovault::data::hash_file(&path) {
+                        Ok(hash) if hash == expected_hash => {
+                            (FileVerificationStatus::Ok, Some(hash))
+                        }
+                        Ok(hash) => (FileVerificationStatus::Changed, Some(hash)),
+                        Err(_) => (FileVerificationStatus::Unreadable, None),
+                    }
+                };
+                FileVerification {
+                    file_id,
+                    path,
+                    status,
+                    expected_hash,
+                    actual_hash,
+                }
+            })
+            .collect::<Vec<_>>()
+    })
+    .await
+    .map_err(|e| format!("Failed to verify files: {}", e))?;
+
+    let drifted = results
+        .iter()
+        .filter(|r| r.status != FileVerificationStatus::Ok)
+        .count();
+    crate::desktop_log!(
+        "✅ Verified {} file(s), {} changed, missing or unreadable",
+        results.len(),
+        drifted
+    );
+    Ok(results)
+}
+
+/// Remove DB rows for files whose paths no longer exist. Requires `confirm`, and
+/// re-checks each path so files that reappeared since the scan are kept.
+#[tauri::command]
+pub fn prune_missing_files(
+    state: tauri::State<AppState>,
+    file_ids: Vec<i64>,
+    confirm: bool,
+) -> Result<usize, String> {
+    if !confirm {
+        return Err("Pruning missing files requires confirmation".to_string());
+    }
+    if file_ids.is_empty() {
+        return Ok(0);
+    }
+
+    let db = state.biovault_db.lock().unwrap();
+    let mut still_missing = Vec::new();
+    for file_id in file_ids {
+        let record = biovault::data::get_file_by_id(&db, file_id)
+            .map_err(|e| format!("Failed to load file {}: {}", file_id, e))?;
+        if let Some(record) = record {
+            if !std::path::Path::new(&record.file_path).exists() {
+                still_missing.push(file_id);
+            }
+        }
+    }
+
+    if still_missing.is_empty() {
+        return Ok(0);
+    }
+
+    let deleted = biovault::data::delete_files_bulk(&db, &still_missing)
+        .map_err(|e| format!("Failed to delete files: {}", e))?;
+    super::remove_orphaned_sex_confidence(&db)?;
+    crate::desktop_log!("🧹 Pruned {} missing file record(s)", deleted);
+    Ok(deleted)
+}
+
+/// Apply the provided (`Some`) metadata fields to every file in `file_ids`,
+/// leaving the rest untouched. All ids must exist; the batch is all-or-nothing.
+#[tauri::command]
+pub fn update_files_metadata_bulk(
+    state: tauri::State<AppState>,
+    mut file_ids: Vec<i64>,
+    metadata: super::FileMetadata,
+) -> Result<usize, String> {
+    use rusqlite::types::Value;
+
+    file_ids.sort_unstable();
+    file_ids.dedup();
+    if file_ids.is_empty() {
+        return Ok(0);
+    }
+    if metadata.participant_id.is_some()
+        || metadata.reference_path.is_some()
+        || metadata.reference_index_path.is_some()
+    {
+        return Err(
+            "Participant and reference links cannot be bulk-updated; use the dedicated commands"
+                .to_string(),
+        );
+    }
+
+    let text = |v: Option<String>| v.map(|s| Value::Text(s.trim().to_string()));
+    let int = |v: Option<i64>| v.map(Value::Integer);
+    let fields: Vec<(&str, Value)> = [
+        ("data_type", text(metadata.data_type)),
+        ("source", text(metadata.source)),
+        ("grch_version", text(metadata.grch_version)),
+        ("row_count", int(metadata.row_count)),
+        ("chromosome_count", int(metadata.chromosome_count)),
+        ("inferred_sex", text(metadata.inferred_sex)),
+    ]
+    .into_iter()
+    .filter_map(|(column, value)| value.map(|v| (column, v)))
+    .collect();
+    if fields.is_empty() {
+        return Err("No metadata fields provided".to_string());
+    }
+
+    crate::desktop_log!(
+        "🏷️ Updating {} field(s) on {} file(s)",
+        fields.len(),
+        file_ids.len()
+    );
+
+    let assignments: Vec<String> = fields
+        .iter()
+        .enumerate()
+        .map(|(idx, (column, _))| format!("{} = ?{}", column, idx + 2))
+        .collect();
+    let sql = format!(
+        "UPDATE files SET {}, updated_at = CURRENT_TIMESTAMP WHERE id = ?1",
+        assignments.join(", ")
+    );
+
+    let mut db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+    let tx = db
+        .conn
+        .transaction()
+        .map_err(|e| format!("Failed to start transaction: {}", e))?;
+    let mut updated = 0;
+    for file_id in &file_ids {
+        let mut values = vec![Value::Integer(*file_id)];
+        values.extend(fields.iter().map(|(_, v)| v.clone()));
+        let changed = tx
+            .execute(&sql, rusqlite::params_from_iter(values))
+            .map_err(|e| format!("Failed to update file {}: {}", file_id, e))?;
+        if changed == 0 {
+            // Dropping the transaction rolls back the files already updated.
+            return Err(format!("File {} not found", file_id));
+        }
+        updated += changed;
+    }
+    tx.commit()
+        .map_err(|e| format!("Failed to save file metadata: {}", e))?;
+
+    crate::desktop_log!("✅ Updated metadata on {} file(s)", updated);
+    Ok(updated)
+}
@@ -0,0 +1,287 @@
+use crate::types::AppState;
+use biovault::data::BioVaultDb;
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+use std::io::Read;
+use std::path::Path;
+
+/// Types the analyzer knows natively; these cannot be registered or removed.
+pub const BUILTIN_DATA_TYPES: &[&str] = &["Genotype", "Unknown"];
+
+/// Only the start of a file is read when matching header signatures.
+const HEADER_SNIFF_BYTES: u64 = 16 * 1024;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DataTypeDetectionRules {
+    /// File name suffixes without the leading dot, e.g. `csv` or `vcf.gz`.
+    #[serde(default)]
+    pub extensions: Vec<String>,
+    /// Text that must appear near the top of the file, e.g. a header line.
+    #[serde(default)]
+    pub header_signatures: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CustomDataType {
+    pub name: String,
+    pub rules: DataTypeDetectionRules,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DataTypeInfo {
+    pub name: String,
+    pub builtin: bool,
+    pub rules: Option<DataTypeDetectionRules>,
+    pub created_at: Option<String>,
+}
+
+fn ensure_data_types_table(db: &BioVaultDb) -> Result<(), String> {
+    db.connection()
+        .execute(
+            "CREATE TABLE IF NOT EXISTS custom_data_types (
+                name TEXT PRIMARY KEY COLLATE NOCASE,
+                rules TEXT NOT NULL,
+                created_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .map_err(|e| format!("Failed to create custom data types table: {}", e))?;
+    Ok(())
+}
+
+fn is_builtin_data_type(name: &str) -> bool {
+    BUILTIN_DATA_TYPES
+        .iter()
+        .any(|builtin| builtin.eq_ignore_ascii_case(name))
+}
+
+fn normalize_rules(rules: DataTypeDetectionRules) -> Result<DataTypeDetectionRules, String> {
+    let mut extensions: Vec<String> = rules
+        .extensions
+        .iter()
+        .map(|ext| ext.trim().trim_start_matches('.').to_ascii_lowercase())
+        .filter(|ext| !ext.is_empty())
+        .collect();
+    extensions.sort();
+    extensions.dedup();
+    let header_signatures: Vec<String> = rules
+        .header_signatures
+        .iter()
+        .map(|sig| sig.trim().to_string())
+        .filter(|sig| !sig.is_empty())
+        .collect();
+    if extensions.is_empty() && header_signatures.is_empty() {
+        return Err("At least one extension or header signature is required".to_string());
+    }
+    Ok(DataTypeDetectionRules {
+        extensions,
+        header_signatures,
+    })
+}
+
+pub(crate) fn load_custom_data_types(db: &BioVaultDb) -> Result<Vec<CustomDataType>, String> {
+    ensure_data_types_table(db)?;
+    let mut stmt = db
+        .connection()
+        .prepare("SELECT name, rules, created_at FROM custom_data_types ORDER BY name")
+        .map_err(|e| format!("Failed to prepare data type query: {}", e))?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })
+        .map_err(|e| format!("Failed to query custom data types: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read custom data types: {}", e))?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|(name, rules, created_at)| {
+            let rules = match serde_json::from_str(&rules) {
+                Ok(rules) => rules,
+                Err(e) => {
+                    crate::desktop_log!("⚠️ Ignoring data type '{}': {}", name, e);
+                    return None;
+                }
+            };
+            Some(CustomDataType {
+                name,
+                rules,
+                created_at,
+            })
+        })
+        .collect())
+}
+
+fn read_file_head(path: &Path) -> Option<String> {
+    let file = std::fs::File::open(path).ok()?;
+    let mut buf = Vec::new();
+    file.take(HEADER_SNIFF_BYTES).read_to_end(&mut buf).ok()?;
+    Some(String::from_utf8_lossy(&buf).into_owned())
+}
+
+/// Every rule group that is present must match: an extension (if any are
+/// listed) and a header signature (if any are listed).
+fn matches_rules(
+    rules: &DataTypeDetectionRules,
+    path: &Path,
+    head: &mut Option<Option<String>>,
+) -> bool {
+    if !rules.extensions.is_empty() {
+        let file_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_ascii_lowercase())
+            .unwrap_or_default();
+        let ext_match = rules
+            .extensions
+            .iter()
+            .any(|ext| file_name.ends_with(&format!(".{}", ext)));
+        if !ext_match {
+            return false;
+        }
+    }
+    if !rules.header_signatures.is_empty() {
+        let head = head.get_or_insert_with(|| read_file_head(path));
+        let Some(head) = head else {
+            return false;
+        };
+        if !rules
+            .header_signatures
+            .iter()
+            .any(|sig| head.contains(sig.as_str()))
+        {
+            return false;
+        }
+    }
+    true
+}
+
+/// Run the library detector and fall back to registered custom types when it
+/// reports Unknown (or cannot read the file as genotype data).
+pub(crate) fn detect_file_metadata(
+    custom_types: &[CustomDataType],
+    file_path: &str,
+) -> Result<biovault::data::GenotypeMetadata, String> {
+    let detected = super::detect_genotype_metadata(file_path);
+    if let Ok(metadata) = &detected {
+        let data_type = metadata.data_type.trim();
+        if !data_type.is_empty() && !data_type.eq_ignore_ascii_case("unknown") {
+            return detected;
+        }
+    }
+
+    let path = Path::new(file_path);
+    let mut head = None;
+    if let Some(custom) = custom_types
+        .iter()
+        .find(|t| matches_rules(&t.rules, path, &mut head))
+    {
+        return Ok(biovault::data::GenotypeMetadata {
+            data_type: custom.name.clone(),
+            ..Default::default()
+        });
+    }
+    detected
+}
+
+/// Validate and store a custom data type. Re-registering a name replaces its rules.
+pub(crate) fn save_custom_data_type(
+    db: &BioVaultDb,
+    name: &str,
+    detection_rules: DataTypeDetectionRules,
+) -> Result<CustomDataType, String> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("Data type name is required".to_string());
+    }
+    if is_builtin_data_type(&name) {
+        return Err(format!("'{}' is a built-in data type", name));
+    }
+    let rules = normalize_rules(detection_rules)?;
+    let payload = serde_json::to_string(&rules)
+        .map_err(|e| format!("Failed to serialize detection rules: {}", e))?;
+    let created_at = chrono::Utc::now().to_rfc3339();
+
+    ensure_data_types_table(db)?;
+    db.connection()
+        .execute(
+            "INSERT INTO custom_data_types (name, rules, created_at) VALUES (?1, ?2, ?3)
+             ON CONFLICT(name) DO UPDATE SET rules = excluded.rules",
+            params![name, payload, created_at],
+        )
+        .map_err(|e| format!("Failed to save data type: {}", e))?;
+
+    crate::desktop_log!(
+        "🏷️ Registered data type '{}' ({} extension(s), {} signature(s))",
+        name,
+        rules.extensions.len(),
+        rules.header_signatures.len()
+    );
+    Ok(CustomDataType {
+        name,
+        rules,
+        created_at,
+    })
+}
+
+/// Teach the analyzer a lab-specific format. Re-registering a name replaces its rules.
+#[tauri::command]
+pub fn register_data_type(
+    state: tauri::State<AppState>,
+    name: String,
+    detection_rules: DataTypeDetectionRules,
+) -> Result<CustomDataType, String> {
+    let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+    save_custom_data_type(&db, &name, detection_rules)
+}
+
+#[tauri::command]
+pub fn list_data_types(state: tauri::State<AppState>) -> Result<Vec<DataTypeInfo>, String> {
+    let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+    let mut types: Vec<DataTypeInfo> = BUILTIN_DATA_TYPES
+        .iter()
+        .map(|name| DataTypeInfo {
+            name: name.to_string(),
+            builtin: true,
+            rules: None,
+            created_at: None,
+        })
+        .collect();
+    types.extend(
+        load_custom_data_types(&db)?
+            .into_iter()
+            .map(|t| DataTypeInfo {
+                name: t.name,
+                builtin: false,
+                rules: Some(t.rules),
+                created_at: Some(t.created_at),
+            }),
+    );
+    Ok(types)
+}
+
+/// Remove a custom data type. Files already classified keep their type.
+#[tauri::command]
+pub fn remove_data_type(state: tauri::State<AppState>, name: String) -> Result<bool, String> {
+    let name = name.trim();
+    if is_builtin_data_type(name) {
+        return Err(format!(
+            "'{}' is a built-in data type and cannot be removed",
+            name
+        ));
+    }
+    let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+    ensure_data_types_table(&db)?;
+    let deleted = db
+        .connection()
+        .execute(
+            "DELETE FROM custom_data_types WHERE name = ?1",
+            params![name],
+        )
+        .map_err(|e| format!("Failed to remove data type: {}", e))?;
+    Ok(deleted > 0)
+}
@@ -1,5 +1,7 @@
-use crate::types::{AppState, FileRecord, ImportResult};
-use std::collections::HashSet;
+use crate::types::{AppState, FileConflict, FileRecord, ImportResult};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fs;
 use std::path::{Path, PathBuf};
 
 // Re-export FileMetadata from parent module
@@ -70,8 +72,11 @@ pub async fn import_files_with_metadata(
             row_count: f.row_count,
             chromosome_count: f.chromosome_count,
             inferred_sex: f.inferred_sex,
+            inferred_sex_confidence: None,
+            inferred_sex_low_confidence: None,
             status: f.status,
             processing_error: f.processing_error,
+            import_origin: None,
             created_at: f.created_at,
             updated_at: f.updated_at,
         })
@@ -96,10 +101,137 @@ pub async fn import_files_with_metadata(
     })
 }
 
+#[derive(Serialize)]
+pub struct ImportPreview {
+    /// Files already imported whose content hash differs from the file on disk.
+    pub conflicts: Vec<FileConflict>,
+    /// Rows that would be inserted (id 0, status "pending").
+    pub would_import: Vec<FileRecord>,
+    /// Files already imported with identical content; an import skips them.
+    pub unchanged: Vec<String>,
+    /// Selected paths that no longer exist or can't be hashed.
+    pub unreadable: Vec<String>,
+}
+
+/// Dry run of `import_files_with_metadata`: hashes each file and compares it
+/// with any existing row, without writing to the files table.
+#[tauri::command]
+pub async fn preview_import(
+    state: tauri::State<'_, AppState>,
+    file_metadata: std::collections::HashMap<String, FileMetadata>,
+) -> Result<ImportPreview, String> {
+    crate::desktop_log!(
+        "🔎 preview_import called with {} files",
+        file_metadata.len()
+    );
+
+    if file_metadata.is_empty() {
+        return Err("No files selected".to_string());
+    }
+
+    // Existing rows by path, plus custom types for detection; lock only briefly.
+    let (existing, custom_types) = {
+        let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+        let mut stmt = db
+            .connection()
+            .prepare("SELECT file_path, file_hash FROM files")
+            .map_err(|e| format!("Failed to query files: {}", e))?;
+        let existing: std::collections::HashMap<String, Option<String>> = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+            .map_err(|e| format!("Failed to query files: {}", e))?
+            .collect::<Result<_, _>>()
+            .map_err(|e| format!("Failed to read files: {}", e))?;
+        (
+            existing,
+            super::load_custom_data_types(&db).unwrap_or_default(),
+        )
+    };
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut preview = ImportPreview {
+            conflicts: Vec::new(),
+            would_import: Vec::new(),
+            unchanged: Vec::new(),
+            unreadable: Vec::new(),
+        };
+
+        let mut entries: Vec<(String, FileMetadata)> = file_metadata.into_iter().collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        for (file_path, metadata) in entries {
+            let new_hash = match biovault::data::hash_file(&file_path) {
+                Ok(hash) => hash,
+                Err(e) => {
+                    crate::desktop_log!("⚠️  Preview could not hash {}: {}", file_path, e);
+                    preview.unreadable.push(file_path);
+                    continue;
+                }
+            };
+
+            if let Some(existing_hash) = existing.get(&file_path) {
+                // Pending rows have no real hash yet, so there's nothing to compare.
+                match existing_hash.as_deref() {
+                    Some(hash) if hash != new_hash && !hash.starts_with("pending_") => {
+                        preview.conflicts.push(FileConflict {
+                            path: file_path,
+                            existing_hash: hash.to_string(),
+                            new_hash,
+                        });
+                    }
+                    _ => preview.unchanged.push(file_path),
+                }
+                continue;
+            }
+
+            let data_type = metadata.data_type.clone().or_else(|| {
+                super::detect_file_metadata(&custom_types, &file_path)
+                    .ok()
+                    .map(|detected| detected.data_type)
+            });
+            preview.would_import.push(FileRecord {
+                id: 0,
+                participant_id: metadata.participant_id,
+                participant_name: None,
+                file_type: Path::new(&file_path)
+                    .extension()
+                    .and_then(|e| e.to_str())
+                    .map(|e| e.to_ascii_lowercase()),
+                file_size: fs::metadata(&file_path).ok().map(|m| m.len()),
+                file_path,
+                file_hash: new_hash,
+                data_type,
+                source: metadata.source,
+                grch_version: metadata.grch_version,
+                row_count: metadata.row_count,
+                chromosome_count: metadata.chromosome_count,
+                inferred_sex: metadata.inferred_sex,
+                inferred_sex_confidence: None,
+                inferred_sex_low_confidence: None,
+                status: Some("pending".to_string()),
+                processing_error: None,
+                import_origin: None,
+                created_at: String::new(),
+                updated_at: String::new(),
+            });
+        }
+
+        crate::desktop_log!(
+            "✅ Preview: {} new, {} conflicting, {} unchanged, {} unreadable",
+            preview.would_import.len(),
+            preview.conflicts.len(),
+            preview.unchanged.len(),
+            preview.unreadable.len()
+        );
+        preview
+    })
+    .await
+    .map_err(|e| format!("Failed to preview import: {}", e))
+}
+
 #[tauri::command]
 pub async fn import_files_pending(
     state: tauri::State<'_, AppState>,
     file_metadata: std::collections::HashMap<String, FileMetadata>,
+    import_origin: Option<String>,
 ) -> Result<ImportResult, String> {
     crate::desktop_log!(
         "🚀 import_files_pending called with {} files (fast import using library)",
@@ -110,6 +242,16 @@ pub async fn import_files_pending(
         return Err("No files selected".to_string());
     }
 
+    // Without an explicit scan folder, fall back to the folder the files share
+    let file_paths: Vec<String> = file_metadata.keys().cloned().collect();
+    let import_origin = import_origin
+        .map(|o| o.trim().to_string())
+        .filter(|o| !o.is_empty())
+        .or_else(|| {
+            let paths: Vec<PathBuf> = file_paths.iter().map(PathBuf::from).collect();
+            find_common_root(&paths).map(|root| root.to_string_lossy().to_string())
+        });
+
     // Convert desktop FileMetadata to library CsvFileImport
     let csv_imports: Vec<biovault::data::CsvFileImport> = file_metadata
         .into_iter()
@@ -132,6 +274,12 @@ pub async fn import_files_pending(
     let lib_result = biovault::data::import_files_as_pending(&db, csv_imports)
         .map_err(|e| format!("Failed to import files: {}", e))?;
 
+    if let Some(origin) = &import_origin {
+        if let Err(err) = record_import_origin(&db, &file_paths, origin) {
+            crate::desktop_log!("⚠️  Failed to record import origin: {}", err);
+        }
+    }
+
     crate::desktop_log!(
         "✅ Imported {} files, skipped {} (using library)",
         lib_result.imported,
@@ -269,6 +417,10 @@ pub async fn import_files(
     crate::desktop_log!("\n=== END EXTRACTION ===\n");
 
     // Fast import to pending queue (no hashing/analysis)
+    let scanned_paths: Vec<String> = all_csv_imports
+        .iter()
+        .map(|c| c.file_path.clone())
+        .collect();
     let db = state.biovault_db.lock().unwrap();
     let lib_result = biovault::data::import_files_as_pending(&db, all_csv_imports)
         .map_err(|e| format!("Failed to import files: {}", e))?;
@@ -279,6 +431,11 @@ pub async fn import_files(
         lib_result.skipped
     );
 
+    let import_origin = common_root.to_string_lossy().to_string();
+    if let Err(err) = record_import_origin(&db, &scanned_paths, &import_origin) {
+        crate::desktop_log!("⚠️  Failed to record import origin: {}", err);
+    }
+
     // Link files to participants in bulk if needed
     if !file_id_map.is_empty() {
         crate::desktop_log!(
@@ -297,6 +454,8 @@ pub async fn import_files(
     let cli_files = biovault::data::list_files(&db, None, None, false, None)
         .map_err(|e| format!("Failed to list files: {}", e))?;
 
+    let origins = load_import_origins(&db).unwrap_or_default();
+
     // Convert library FileRecords to desktop FileRecords
     let all_files: Vec<FileRecord> = cli_files
         .into_iter()
@@ -314,8 +473,11 @@ pub async fn import_files(
             row_count: f.row_count,
             chromosome_count: f.chromosome_count,
             inferred_sex: f.inferred_sex,
+            inferred_sex_confidence: None,
+            inferred_sex_low_confidence: None,
             status: f.status,
             processing_error: f.processing_error,
+            import_origin: origins.get(&f.id).cloned(),
             created_at: f.created_at,
             updated_at: f.updated_at,
         })
@@ -337,6 +499,326 @@ pub async fn import_files(
     })
 }
 
+const GENOTYPE_ZIP_EXTENSIONS: &[&str] = &[".txt", ".csv", ".tsv", ".vcf", ".vcf.gz"];
+/// Total bytes unpacked from one genotype zip; guards against zip bombs.
+const GENOTYPE_ZIP_MAX_UNCOMPRESSED_BYTES: u64 = 4 * 1024 * 1024 * 1024;
+
+#[derive(Serialize)]
+pub struct GenotypeZipImportResult {
+    /// Set when exactly one genotype file was found (or `entry` picked one) and imported.
+    pub imported: Option<FileRecord>,
+    /// Archive entries that look like genotype data; populated when the caller must choose.
+    pub candidates: Vec<String>,
+}
+
+fn is_genotype_zip_candidate(entry_name: &str) -> bool {
+    let lower = entry_name.to_ascii_lowercase();
+    let file_name = lower.rsplit('/').next().unwrap_or(&lower);
+    if lower.starts_with("__macosx/") || file_name.starts_with('.') {
+        return false;
+    }
+    if file_name.starts_with("readme") || file_name.starts_with("license") {
+        return false;
+    }
+    GENOTYPE_ZIP_EXTENSIONS
+        .iter()
+        .any(|ext| file_name.ends_with(ext))
+}
+
+fn files_have_same_contents(a: &Path, b: &Path) -> bool {
+    use std::io::Read;
+    let (Ok(meta_a), Ok(meta_b)) = (fs::metadata(a), fs::metadata(b)) else {
+        return false;
+    };
+    if meta_a.len() != meta_b.len() {
+        return false;
+    }
+    let (Ok(file_a), Ok(file_b)) = (fs::File::open(a), fs::File::open(b)) else {
+        return false;
+    };
+    let (mut reader_a, mut reader_b) = (
+        std::io::BufReader::new(file_a),
+        std::io::BufReader::new(file_b),
+    );
+    let (mut buf_a, mut buf_b) = ([0u8; 8192], [0u8; 8192]);
+    loop {
+        let Ok(n) = reader_a.read(&mut buf_a) else {
+            return false;
+        };
+        if n == 0 {
+            return true;
+        }
+        if reader_b.read_exact(&mut buf_b[..n]).is_err() || buf_a[..n] != buf_b[..n] {
+            return false;
+        }
+    }
+}
+
+/// Pick where an extracted file lives under `imports/<zip_stem>`. An existing
+/// file is reused only when its contents match; otherwise a numbered sibling
+/// directory is used so earlier imports (and their hashes) stay untouched.
+fn genotype_zip_destination(
+    imports_root: &Path,
+    zip_stem: &str,
+    extracted_path: &Path,
+) -> Result<PathBuf, String> {
+    let file_name = extracted_path
+        .file_name()
+        .ok_or_else(|| "Invalid zip entry name".to_string())?;
+    let mut attempt = 1;
+    loop {
+        let dir_name = if attempt == 1 {
+            zip_stem.to_string()
+        } else {
+            format!("{}-{}", zip_stem, attempt)
+        };
+        let candidate = imports_root.join(dir_name).join(file_name);
+        if !candidate.exists() || files_have_same_contents(&candidate, extracted_path) {
+            return Ok(candidate);
+        }
+        attempt += 1;
+    }
+}
+
+fn detected_genotype_type(path: &Path) -> Option<biovault::data::GenotypeMetadata> {
+    let metadata = super::detect_genotype_metadata(&path.to_string_lossy()).ok()?;
+    let data_type = metadata.data_type.trim();
+    if data_type.is_empty() || data_type.eq_ignore_ascii_case("unknown") {
+        return None;
+    }
+    Some(metadata)
+}
+
+/// Import a genotype export (23andMe, AncestryDNA, ...) straight from its zip.
+/// Candidates are unpacked to a temp dir and sniffed; if more than one looks like
+/// genotype data and `entry` is not given, the choices are returned instead.
+#[tauri::command]
+pub async fn import_genotype_zip(
+    state: tauri::State<'_, AppState>,
+    path: String,
+    participant_id: String,
+    entry: Option<String>,
+) -> Result<GenotypeZipImportResult, String> {
+    let zip_path = PathBuf::from(&path);
+    let participant_id = participant_id.trim().to_string();
+    if participant_id.is_empty() {
+        return Err("Participant ID is required".to_string());
+    }
+    crate::desktop_log!("📦 import_genotype_zip: {} → {}", path, participant_id);
+
+    let file = fs::File::open(&zip_path).map_err(|e| format!("Failed to open zip: {}", e))?;
+    let mut archive =
+        zip::ZipArchive::new(file).map_err(|e| format!("Failed to read zip archive: {}", e))?;
+    let temp_dir =
+        tempfile::tempdir().map_err(|e| format!("Failed to create temp directory: {}", e))?;
+
+    // Unpack only plausible genotype files, using enclosed_name() to reject path traversal.
+    let mut extracted: Vec<(String, PathBuf)> = Vec::new();
+    let mut unpacked_bytes: u64 = 0;
+    for index in 0..archive.len() {
+        let mut zip_entry = archive
+            .by_index(index)
+            .map_err(|e| format!("Failed to read zip entry: {}", e))?;
+        if zip_entry.is_dir() || !is_genotype_zip_candidate(zip_entry.name()) {
+            continue;
+        }
+        let Some(relative) = zip_entry.enclosed_name() else {
+            continue;
+        };
+        if let Some(wanted) = entry.as_deref() {
+            if zip_entry.name() != wanted {
+                continue;
+            }
+        }
+        let dest = temp_dir.path().join(&relative);
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create temp directory: {}", e))?;
+        }
+        let mut out =
+            fs::File::create(&dest).map_err(|e| format!("Failed to extract zip entry: {}", e))?;
+        // Count what is actually inflated; header sizes can lie.
+        let remaining = GENOTYPE_ZIP_MAX_UNCOMPRESSED_BYTES - unpacked_bytes;
+        let written = std::io::copy(
+            &mut std::io::Read::take(&mut zip_entry, remaining + 1),
+            &mut out,
+        )
+        .map_err(|e| format!("Failed to extract zip entry: {}", e))?;
+        if written > remaining {
+            return Err(format!(
+                "Zip archive expands beyond the {} GiB import limit",
+                GENOTYPE_ZIP_MAX_UNCOMPRESSED_BYTES / (1024 * 1024 * 1024)
+            ));
+        }
+        unpacked_bytes += written;
+        extracted.push((zip_entry.name().to_string(), dest));
+    }
+
+    if let Some(wanted) = entry.as_deref() {
+        if extracted.is_empty() {
+            return Err(format!("Entry not found in zip: {}", wanted));
+        }
+    }
+
+    let mut detected: Vec<(String, PathBuf, biovault::data::GenotypeMetadata)> = extracted
+        .into_iter()
+        .filter_map(|(name, path)| detected_genotype_type(&path).map(|meta| (name, path, meta)))
+        .collect();
+
+    if detected.is_empty() {
+        return Err("No genotype file found in zip".to_string());
+    }
+    if detected.len() > 1 {
+        let candidates: Vec<String> = detected.into_iter().map(|(name, _, _)| name).collect();
+        crate::desktop_log!(
+            "📦 import_genotype_zip: {} candidate files, asking user to choose",
+            candidates.len()
+        );
+        return Ok(GenotypeZipImportResult {
+            imported: None,
+            candidates,
+        });
+    }
+    let (entry_name, extracted_path, metadata) = detected.remove(0);
+
+    // The temp dir goes away, so the imported file needs a permanent home.
+    let biovault_home = biovault::config::get_biovault_home()
+        .map_err(|e| format!("Failed to get BioVault home: {}", e))?;
+    let zip_stem = zip_path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| "genotype".to_string());
+    let imports_root = biovault_home.join("data").join("imports");
+    let dest_path = genotype_zip_destination(&imports_root, &zip_stem, &extracted_path)?;
+    if !dest_path.exists() {
+        if let Some(dest_dir) = dest_path.parent() {
+            fs::create_dir_all(dest_dir)
+                .map_err(|e| format!("Failed to create import directory: {}", e))?;
+        }
+        fs::copy(&extracted_path, &dest_path)
+            .map_err(|e| format!("Failed to copy genotype file: {}", e))?;
+    }
+    drop(temp_dir);
+
+    let dest_str = dest_path.to_string_lossy().to_string();
+    let csv_import = biovault::data::CsvFileImport {
+        file_path: dest_str.clone(),
+        participant_id: Some(participant_id),
+        data_type: Some(metadata.data_type),
+        source: metadata.source,
+        grch_version: metadata.grch_version,
+        reference_path: None,
+        reference_index_path: None,
+        row_count: metadata.row_count,
+        chromosome_count: metadata.chromosome_count,
+        inferred_sex: metadata.inferred_sex,
+    };
+
+    let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+    let lib_result = biovault::data::import_from_csv(&db, vec![csv_import], true)
+        .map_err(|e| format!("Failed to import files: {}", e))?;
+    if let Some(error) = lib_result.errors.first() {
+        return Err(format!("Failed to import {}: {}", entry_name, error));
+    }
+
+    // Already-imported files are skipped by the library, so fall back to a lookup.
+    let record = match lib_result.files.into_iter().next() {
+        Some(f) => f,
+        None => biovault::data::list_files(&db, None, None, false, None)
+            .map_err(|e| format!("Failed to list files: {}", e))?
+            .into_iter()
+            .find(|f| f.file_path == dest_str)
+            .ok_or_else(|| format!("Imported file not found: {}", dest_str))?,
+    };
+
+    crate::desktop_log!("✅ Imported {} from zip as {}", entry_name, dest_str);
+    Ok(GenotypeZipImportResult {
+        imported: Some(FileRecord {
+            id: record.id,
+            participant_id: record.participant_id,
+            participant_name: record.participant_name,
+            file_path: record.file_path,
+            file_hash: record.file_hash,
+            file_type: record.file_type,
+            file_size: record.file_size,
+            data_type: record.data_type,
+            source: record.source,
+            grch_version: record.grch_version,
+            row_count: record.row_count,
+            chromosome_count: record.chromosome_count,
+            inferred_sex: record.inferred_sex,
+            inferred_sex_confidence: None,
+            inferred_sex_low_confidence: None,
+            status: record.status,
+            processing_error: record.processing_error,
+            import_origin: None,
+            created_at: record.created_at,
+            updated_at: record.updated_at,
+        }),
+        candidates: Vec::new(),
+    })
+}
+
+/// Add the `import_origin` column to the files table on databases created
+/// before it existed. Part of [`super::ensure_file_columns`].
+pub(crate) fn ensure_import_origin_column(conn: &rusqlite::Connection) -> Result<(), String> {
+    let has_origin = conn
+        .query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('files') WHERE name='import_origin'",
+            [],
+            |row| row.get::<_, i32>(0),
+        )
+        .map(|count| count > 0)
+        .unwrap_or(false);
+
+    if !has_origin {
+        conn.execute("ALTER TABLE files ADD COLUMN import_origin TEXT", [])
+            .map_err(|e| format!("Failed to add import_origin column: {}", e))?;
+    }
+
+    Ok(())
+}
+
+/// Remember the folder a scan started from. Files that already had an origin
+/// (re-imported from a different folder) keep their first one.
+pub(crate) fn record_import_origin(
+    db: &biovault::data::BioVaultDb,
+    file_paths: &[String],
+    origin: &str,
+) -> Result<usize, String> {
+    let conn = db.connection();
+    let mut stmt = conn
+        .prepare(
+            "UPDATE files SET import_origin = ?1 WHERE file_path = ?2 AND import_origin IS NULL",
+        )
+        .map_err(|e| format!("Failed to prepare import origin update: {}", e))?;
+    let mut updated = 0;
+    for path in file_paths {
+        updated += stmt
+            .execute(rusqlite::params![origin, path])
+            .map_err(|e| format!("Failed to record import origin for {}: {}", path, e))?;
+    }
+    Ok(updated)
+}
+
+/// Import origin per file id, for files that have one.
+pub(crate) fn load_import_origins(
+    db: &biovault::data::BioVaultDb,
+) -> Result<HashMap<i64, String>, String> {
+    let conn = db.connection();
+    let mut stmt = conn
+        .prepare("SELECT id, import_origin FROM files WHERE import_origin IS NOT NULL")
+        .map_err(|e| format!("Failed to prepare import origin query: {}", e))?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+        })
+        .map_err(|e| format!("Failed to query import origins: {}", e))?
+        .collect::<Result<HashMap<_, _>, _>>()
+        .map_err(|e| format!("Failed to read import origins: {}", e))?;
+    Ok(rows)
+}
+
 /// Find the common root directory of multiple paths
 fn find_common_root(paths: &[PathBuf]) -> Option<PathBuf> {
     if paths.is_empty() {
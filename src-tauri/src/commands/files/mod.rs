@@ -24,22 +24,34 @@ pub struct GenotypeMetadata {
     pub row_count: Option<i64>,
     pub chromosome_count: Option<i64>,
     pub inferred_sex: Option<String>,
+    #[serde(default)]
+    pub inferred_sex_confidence: Option<f64>,
+    #[serde(default)]
+    pub inferred_sex_low_confidence: Option<bool>,
 }
 
 // Sub-modules
 pub mod analyze;
 pub mod crud;
+pub mod data_types;
 pub mod import;
 pub mod queue;
 pub mod reference_data;
 pub mod sample_data;
 pub mod scan;
+pub mod sex_inference;
+pub mod snapshots;
+pub mod watch;
 
 // Re-export all commands for convenience
 pub use analyze::*;
 pub use crud::*;
+pub use data_types::*;
 pub use import::*;
 pub use queue::*;
 pub use reference_data::*;
 pub use sample_data::*;
 pub use scan::*;
+pub use sex_inference::*;
+pub use snapshots::*;
+pub use watch::*;
@@ -1,5 +1,10 @@
-use crate::types::AppState;
+use crate::types::{
+    AppState, QueueMetrics, QueueSample, QueueThroughputStats, QueueThroughputWindow,
+    QUEUE_METRICS_CAPACITY,
+};
 use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+use std::time::Duration;
 
 #[tauri::command]
 pub async fn process_queue(
@@ -14,8 +19,7 @@ pub async fn process_queue(
     let db = state.biovault_db.lock().unwrap();
 
     // Get pending files from database
-    let pending_files = biovault::data::get_pending_files(&db, limit)
-        .map_err(|e| format!("Failed to get pending files: {}", e))?;
+    let pending_files = get_prioritized_pending_files(&db, limit)?;
 
     if pending_files.is_empty() {
         crate::desktop_log!("No pending files in queue");
@@ -88,7 +92,7 @@ pub async fn process_queue(
 /// Process a single file from the queue (synchronous version for desktop)
 fn process_single_file_sync(
     db: &biovault::data::BioVaultDb,
-    file: &biovault::data::PendingFile,
+    file: &PendingQueueFile,
 ) -> Result<(), String> {
     // 1. Hash the file
     let hash = biovault::data::hash_file(&file.file_path)
@@ -96,7 +100,8 @@ fn process_single_file_sync(
 
     // 2. Detect genotype metadata if not already set
     let mut metadata = if file.data_type.as_deref() == Some("Unknown") || file.data_type.is_none() {
-        biovault::data::detect_genotype_metadata(&file.file_path).ok()
+        let custom_types = super::load_custom_data_types(db).unwrap_or_default();
+        super::detect_file_metadata(&custom_types, &file.file_path).ok()
     } else if file.data_type.as_deref() == Some("Genotype") {
         // Already detected as Genotype, load existing metadata if available
         match biovault::data::get_genotype_metadata(db, file.id) {
@@ -118,10 +123,15 @@ fn process_single_file_sync(
     };
 
     // 3. If this is a Genotype file, analyze it for row counts, chromosomes, sex
+    let mut sex_confidence = None;
     if let Some(ref mut meta) = metadata {
         if meta.data_type == "Genotype" {
-            match biovault::data::analyze_genotype_file(&file.file_path) {
-                Ok(analysis) => {
+            match super::analyze_genotype_file_with_sex_confidence(&file.file_path) {
+                Ok((analysis, confidence)) => {
+                    // The score only applies to the call it was computed for.
+                    if meta.inferred_sex.is_none() || meta.inferred_sex == analysis.inferred_sex {
+                        sex_confidence = confidence;
+                    }
                     // Merge analysis data into metadata
                     if meta.row_count.is_none() {
                         meta.row_count = analysis.row_count;
@@ -144,6 +154,9 @@ fn process_single_file_sync(
     // 4. Update the file in database
     biovault::data::update_file_from_queue(db, file.id, &hash, metadata.as_ref())
         .map_err(|e| format!("Failed to update file: {}", e))?;
+    if let Some(confidence) = &sex_confidence {
+        let _ = super::save_sex_confidence(db, file.id, confidence);
+    }
 
     Ok(())
 }
@@ -180,6 +193,150 @@ pub fn pause_queue_processor(state: tauri::State<AppState>) -> Result<bool, Stri
     Ok(true)
 }
 
+/// Requeue files a crashed or force-quit session left in "processing". Only
+/// rows untouched for `older_than_minutes` are reset, so a second running
+/// instance keeps its in-flight work.
+pub(crate) fn reset_stale_processing_files(
+    db: &biovault::data::BioVaultDb,
+    older_than_minutes: u64,
+) -> Result<usize, String> {
+    db.connection()
+        .execute(
+            "UPDATE files SET status = 'pending'
+             WHERE status = 'processing'
+               AND (updated_at IS NULL OR datetime(updated_at) < datetime('now', ?1))",
+            [format!("-{} minutes", older_than_minutes)],
+        )
+        .map_err(|e| format!("Failed to reset stale processing files: {}", e))
+}
+
+/// Append a finished file's timings, dropping the oldest past capacity.
+pub(crate) fn record_queue_sample(
+    metrics: &Mutex<std::collections::VecDeque<QueueSample>>,
+    sample: QueueSample,
+) {
+    if let Ok(mut samples) = metrics.lock() {
+        samples.push_back(sample);
+        while samples.len() > QUEUE_METRICS_CAPACITY {
+            samples.pop_front();
+        }
+    }
+}
+
+fn average(values: impl Iterator<Item = u64>) -> Option<f64> {
+    let (sum, count) = values.fold((0u64, 0u64), |(sum, count), v| (sum + v, count + 1));
+    (count > 0).then(|| sum as f64 / count as f64)
+}
+
+/// Throughput over the recent sample window, for progress and ETA display.
+#[tauri::command]
+pub fn get_queue_metrics(state: tauri::State<AppState>) -> Result<QueueMetrics, String> {
+    let samples: Vec<QueueSample> = state
+        .queue_metrics
+        .lock()
+        .map_err(|e| e.to_string())?
+        .iter()
+        .cloned()
+        .collect();
+
+    let pending_count: i64 = {
+        let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+        db.connection()
+            .query_row(
+                "SELECT COUNT(*) FROM files WHERE status IN ('pending', 'processing')",
+                [],
+                |row| row.get(0),
+            )
+            .map_err(|e| format!("Failed to count pending files: {}", e))?
+    };
+
+    let processed_last_minute = samples
+        .iter()
+        .filter(|s| s.finished_at.elapsed() <= Duration::from_secs(60))
+        .count();
+    let errors = samples.iter().filter(|s| s.failed).count();
+    let error_rate = if samples.is_empty() {
+        0.0
+    } else {
+        errors as f64 / samples.len() as f64
+    };
+    let eta_seconds = (processed_last_minute > 0 && pending_count > 0)
+        .then(|| (pending_count as u64 * 60).div_ceil(processed_last_minute as u64));
+
+    Ok(QueueMetrics {
+        processed_last_minute,
+        avg_hash_ms: average(samples.iter().map(|s| s.hash_ms)),
+        avg_analyze_ms: average(samples.iter().filter_map(|s| s.analyze_ms)),
+        error_rate,
+        sample_count: samples.len(),
+        pending_count,
+        eta_seconds,
+    })
+}
+
+const THROUGHPUT_WINDOWS_SECS: [u64; 3] = [60, 300, 900];
+
+/// Files/second and bytes/second over the last 1, 5 and 15 minutes, from the
+/// in-memory sample buffer, to judge whether more workers or a faster disk would help.
+#[tauri::command]
+pub fn get_queue_throughput_stats(
+    state: tauri::State<AppState>,
+) -> Result<QueueThroughputStats, String> {
+    let samples: Vec<QueueSample> = state
+        .queue_metrics
+        .lock()
+        .map_err(|e| e.to_string())?
+        .iter()
+        .cloned()
+        .collect();
+    let oldest_secs = samples
+        .first()
+        .map(|s| s.finished_at.elapsed().as_secs_f64());
+
+    let windows = THROUGHPUT_WINDOWS_SECS
+        .iter()
+        .map(|&window_seconds| {
+            let window = Duration::from_secs(window_seconds);
+            let in_window: Vec<&QueueSample> = samples
+                .iter()
+                .filter(|s| s.finished_at.elapsed() <= window)
+                .collect();
+            // Older samples were evicted (or never existed), so only divide by
+            // the span the buffer can vouch for.
+            let covered_seconds = oldest_secs
+                .map(|oldest| oldest.clamp(1.0, window_seconds as f64))
+                .unwrap_or(window_seconds as f64);
+            let files = in_window.len();
+            let bytes: u64 = in_window.iter().map(|s| s.bytes).sum();
+            QueueThroughputWindow {
+                window_seconds,
+                covered_seconds,
+                files,
+                bytes,
+                files_per_second: files as f64 / covered_seconds,
+                bytes_per_second: bytes as f64 / covered_seconds,
+                avg_hash_ms: average(in_window.iter().map(|s| s.hash_ms)),
+            }
+        })
+        .collect();
+
+    let total_hash_ms: u64 = samples.iter().map(|s| s.hash_ms).sum();
+    let total_bytes: u64 = samples.iter().map(|s| s.bytes).sum();
+    let hash_bytes_per_second =
+        (total_hash_ms > 0).then(|| total_bytes as f64 / (total_hash_ms as f64 / 1000.0));
+
+    let biovault_home = biovault::config::get_biovault_home()
+        .map_err(|e| format!("Failed to get BioVault home: {}", e))?;
+
+    Ok(QueueThroughputStats {
+        windows,
+        avg_hash_ms: average(samples.iter().map(|s| s.hash_ms)),
+        hash_bytes_per_second,
+        configured_workers: crate::commands::settings::load_queue_workers(&biovault_home),
+        sample_count: samples.len(),
+    })
+}
+
 #[tauri::command]
 pub fn resume_queue_processor(state: tauri::State<AppState>) -> Result<bool, String> {
     state.queue_processor_paused.store(false, Ordering::SeqCst);
@@ -212,6 +369,7 @@ pub fn clear_pending_queue(state: tauri::State<AppState>) -> Result<usize, Strin
             [],
         )
         .map_err(|e| format!("Failed to clear pending queue: {}", e))?;
+    super::remove_orphaned_sex_confidence(&db)?;
 
     crate::desktop_log!(
         "✅ Cleared {} files (pending + processing) from queue",
@@ -236,6 +394,22 @@ pub struct QueueFileInfo {
     pub file_path: String,
 }
 
+/// Add desktop-only columns to the files table. Runs whenever a BioVault
+/// database is opened (startup, reset, profile switch) so commands can rely
+/// on them.
+pub(crate) fn ensure_file_columns(db: &biovault::data::BioVaultDb) {
+    let conn = db.connection();
+    for result in [
+        ensure_processing_columns(conn),
+        ensure_priority_column(conn),
+        super::ensure_import_origin_column(conn),
+    ] {
+        if let Err(err) = result {
+            crate::desktop_log!("⚠️ Failed to migrate files table: {}", err);
+        }
+    }
+}
+
 fn ensure_processing_columns(conn: &rusqlite::Connection) -> Result<(), String> {
     let has_processing_started = conn
         .query_row(
@@ -291,3 +465,247 @@ pub fn get_queue_info(
         estimated_time_remaining_seconds: lib_info.estimated_time_remaining_seconds,
     })
 }
+
+#[derive(serde::Serialize)]
+pub struct QueuedFile {
+    pub id: i64,
+    pub file_path: String,
+    pub status: String,
+    pub file_size: Option<i64>,
+    pub priority: i64,
+    pub position: usize,
+    pub created_at: String,
+}
+
+#[derive(serde::Serialize)]
+pub struct CancelQueuedFilesResult {
+    pub cancelled: Vec<i64>,
+    /// Files already being processed (or no longer queued) are left alone
+    pub skipped: Vec<i64>,
+}
+
+pub(crate) fn emit_queue_changed(app: &tauri::AppHandle) {
+    use tauri::Emitter;
+    let _ = app.emit("files:queue-changed", ());
+}
+
+#[tauri::command]
+pub fn list_queued_files(state: tauri::State<AppState>) -> Result<Vec<QueuedFile>, String> {
+    let db = state.biovault_db.lock().unwrap();
+    ensure_priority_column(db.connection())?;
+    let mut stmt = db
+        .connection()
+        .prepare(
+            "SELECT id, file_path, status, file_size, created_at, priority FROM files
+             WHERE status IN ('pending', 'processing')
+             ORDER BY CASE status WHEN 'processing' THEN 0 ELSE 1 END,
+                      priority DESC, created_at, id",
+        )
+        .map_err(|e| format!("Failed to query queue: {}", e))?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, Option<i64>>(3)?,
+                row.get::<_, String>(4)?,
+                row.get::<_, i64>(5)?,
+            ))
+        })
+        .map_err(|e| format!("Failed to query queue: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read queue: {}", e))?;
+
+    Ok(rows
+        .into_iter()
+        .enumerate()
+        .map(
+            |(idx, (id, file_path, status, file_size, created_at, priority))| QueuedFile {
+                id,
+                file_path,
+                status,
+                file_size,
+                priority,
+                position: idx + 1,
+                created_at,
+            },
+        )
+        .collect())
+}
+
+/// Remove specific pending files from the import queue.
+#[tauri::command]
+pub fn cancel_queued_files(
+    app: tauri::AppHandle,
+    state: tauri::State<AppState>,
+    file_ids: Vec<i64>,
+) -> Result<CancelQueuedFilesResult, String> {
+    crate::desktop_log!(
+        "🗑️ cancel_queued_files called for {} file(s)",
+        file_ids.len()
+    );
+
+    let db = state.biovault_db.lock().unwrap();
+    let conn = db.connection();
+    let mut result = CancelQueuedFilesResult {
+        cancelled: Vec::new(),
+        skipped: Vec::new(),
+    };
+
+    for file_id in file_ids {
+        let deleted = conn
+            .execute(
+                "DELETE FROM files WHERE id = ?1 AND status = 'pending'",
+                [file_id],
+            )
+            .map_err(|e| format!("Failed to cancel queued file {}: {}", file_id, e))?;
+        if deleted > 0 {
+            result.cancelled.push(file_id);
+        } else {
+            result.skipped.push(file_id);
+        }
+    }
+    if !result.cancelled.is_empty() {
+        super::remove_orphaned_sex_confidence(&db)?;
+    }
+    drop(db);
+
+    crate::desktop_log!(
+        "✅ Cancelled {} queued file(s), skipped {}",
+        result.cancelled.len(),
+        result.skipped.len()
+    );
+    if !result.cancelled.is_empty() {
+        emit_queue_changed(&app);
+    }
+    Ok(result)
+}
+
+fn ensure_priority_column(conn: &rusqlite::Connection) -> Result<(), String> {
+    let has_priority = conn
+        .query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('files') WHERE name='priority'",
+            [],
+            |row| row.get::<_, i32>(0),
+        )
+        .map(|count| count > 0)
+        .unwrap_or(false);
+
+    if !has_priority {
+        conn.execute(
+            "ALTER TABLE files ADD COLUMN priority INTEGER NOT NULL DEFAULT 0",
+            [],
+        )
+        .map_err(|e| format!("Failed to add priority column: {}", e))?;
+    }
+
+    Ok(())
+}
+
+/// The parts of a pending file row the queue processors need.
+#[derive(Debug, Clone)]
+pub(crate) struct PendingQueueFile {
+    pub id: i64,
+    pub file_path: String,
+    pub data_type: Option<String>,
+}
+
+/// Pending files ordered by priority (highest first), then insertion order.
+/// With no prioritized files this is plain FIFO order.
+pub(crate) fn get_prioritized_pending_files(
+    db: &biovault::data::BioVaultDb,
+    limit: usize,
+) -> Result<Vec<PendingQueueFile>, String> {
+    let conn = db.connection();
+    ensure_priority_column(conn)?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, file_path, data_type FROM files
+             WHERE status = 'pending'
+             ORDER BY priority DESC, id ASC
+             LIMIT ?1",
+        )
+        .map_err(|e| format!("Failed to prepare pending files query: {}", e))?;
+    let files = stmt
+        .query_map([limit as i64], |row| {
+            Ok(PendingQueueFile {
+                id: row.get(0)?,
+                file_path: row.get(1)?,
+                data_type: row.get(2)?,
+            })
+        })
+        .map_err(|e| format!("Failed to get pending files: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read pending files: {}", e))?;
+    Ok(files)
+}
+
+/// Set a queued file's priority; higher runs first, 0 is the FIFO default.
+#[tauri::command]
+pub fn set_file_priority(
+    app: tauri::AppHandle,
+    state: tauri::State<AppState>,
+    file_id: i64,
+    priority: i64,
+) -> Result<(), String> {
+    let db = state.biovault_db.lock().unwrap();
+    ensure_priority_column(db.connection())?;
+    let updated = db
+        .connection()
+        .execute(
+            "UPDATE files SET priority = ?2 WHERE id = ?1",
+            rusqlite::params![file_id, priority],
+        )
+        .map_err(|e| format!("Failed to set file priority: {}", e))?;
+    drop(db);
+
+    if updated == 0 {
+        return Err(format!("File {} not found", file_id));
+    }
+    emit_queue_changed(&app);
+    Ok(())
+}
+
+/// Move the given files to the front of the queue, keeping their relative order.
+#[tauri::command]
+pub fn prioritize_files(
+    app: tauri::AppHandle,
+    state: tauri::State<AppState>,
+    file_ids: Vec<i64>,
+) -> Result<usize, String> {
+    if file_ids.is_empty() {
+        return Ok(0);
+    }
+
+    let db = state.biovault_db.lock().unwrap();
+    let conn = db.connection();
+    ensure_priority_column(conn)?;
+
+    let current_max: i64 = conn
+        .query_row("SELECT COALESCE(MAX(priority), 0) FROM files", [], |row| {
+            row.get(0)
+        })
+        .map_err(|e| format!("Failed to read file priorities: {}", e))?;
+
+    // Earlier ids in the list get the higher priority.
+    let top = current_max + file_ids.len() as i64;
+    let mut updated = 0;
+    for (idx, file_id) in file_ids.iter().enumerate() {
+        updated += conn
+            .execute(
+                "UPDATE files SET priority = ?2 WHERE id = ?1 AND status = 'pending'",
+                rusqlite::params![file_id, top - idx as i64],
+            )
+            .map_err(|e| format!("Failed to prioritize file {}: {}", file_id, e))?;
+    }
+    drop(db);
+
+    crate::desktop_log!("⏫ Prioritized {} queued file(s)", updated);
+    if updated > 0 {
+        emit_queue_changed(&app);
+    }
+    Ok(updated)
+}
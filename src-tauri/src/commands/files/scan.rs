@@ -138,7 +138,7 @@ pub fn suggest_patterns(files: Vec<String>) -> Result<Vec<PatternSuggestion>, St
     }
     crate::desktop_log!("\n=== END SUGGESTIONS ===\n");
 
-    let suggestions: Vec<PatternSuggestion> = result
+    let mut suggestions: Vec<PatternSuggestion> = result
         .suggestions
         .into_iter()
         .map(|s| PatternSuggestion {
@@ -157,10 +157,123 @@ pub fn suggest_patterns(files: Vec<String>) -> Result<Vec<PatternSuggestion>, St
         })
         .collect();
 
+    if let Some(numeric) = suggest_numeric_run_pattern(&files) {
+        crate::desktop_log!("   Numeric run: {}", numeric.description);
+        suggestions.retain(|s| s.pattern != numeric.pattern);
+        suggestions.insert(0, numeric);
+    }
+
     crate::desktop_log!("✅ Found {} pattern suggestions", suggestions.len());
     Ok(suggestions)
 }
 
+/// Split a filename into alternating digit / non-digit runs.
+fn digit_runs(name: &str) -> Vec<(bool, String)> {
+    let mut runs: Vec<(bool, String)> = Vec::new();
+    for ch in name.chars() {
+        let is_digit = ch.is_ascii_digit();
+        match runs.last_mut() {
+            Some((digits, run)) if *digits == is_digit => run.push(ch),
+            _ => runs.push((is_digit, ch.to_string())),
+        }
+    }
+    runs
+}
+
+/// Sequential cohorts (`sample_001.txt` .. `sample_250.txt`) share every part
+/// of the filename except one numeric run; that run becomes the participant ID.
+fn suggest_numeric_run_pattern(files: &[String]) -> Option<PatternSuggestion> {
+    if files.len() < 2 {
+        return None;
+    }
+    let names: Vec<(&String, Vec<(bool, String)>)> = files
+        .iter()
+        .map(|f| {
+            let name = std::path::Path::new(f).file_name()?.to_str()?;
+            Some((f, digit_runs(name)))
+        })
+        .collect::<Option<_>>()?;
+
+    let template = &names[0].1;
+    if names.iter().any(|(_, runs)| {
+        runs.len() != template.len()
+            || runs
+                .iter()
+                .zip(template)
+                .any(|(a, b)| a.0 != b.0 || (!a.0 && a.1 != b.1))
+    }) {
+        return None;
+    }
+    let mut varying = (0..template.len())
+        .filter(|&i| template[i].0 && names.iter().any(|(_, runs)| runs[i].1 != template[i].1));
+    let index = varying.next()?;
+    if varying.next().is_some() {
+        return None;
+    }
+
+    let tokens: Vec<&str> = names
+        .iter()
+        .map(|(_, runs)| runs[index].1.as_str())
+        .collect();
+    let width = tokens[0].len();
+    let fixed_width = tokens.iter().all(|t| t.len() == width);
+    let padded = fixed_width && tokens.iter().any(|t| t.len() > 1 && t.starts_with('0'));
+
+    let mut pattern = String::new();
+    let mut regex_pattern = String::new();
+    for (i, (_, run)) in template.iter().enumerate() {
+        if i == index {
+            pattern.push_str("{id}");
+            if fixed_width {
+                regex_pattern.push_str(&format!(r"(?P<id>\d{{{}}})", width));
+            } else {
+                regex_pattern.push_str(r"(?P<id>\d+)");
+            }
+        } else {
+            pattern.push_str(run);
+            regex_pattern.push_str(&regex::escape(run));
+        }
+    }
+
+    let numbers: Vec<u64> = tokens.iter().filter_map(|t| t.parse().ok()).collect();
+    let (min, max) = (numbers.iter().min()?, numbers.iter().max()?);
+    let padding = if padded {
+        format!("zero-padded to {} digits", width)
+    } else if fixed_width {
+        format!("{} digits, no padding", width)
+    } else {
+        "variable width, no padding".to_string()
+    };
+    let description = format!(
+        "Sequential numeric ID ({}), {} files numbered {} to {}",
+        padding,
+        names.len(),
+        min,
+        max
+    );
+
+    let mut sample_extractions: Vec<SampleExtraction> = names
+        .iter()
+        .map(|(path, runs)| SampleExtraction {
+            path: path.to_string(),
+            participant_id: runs[index].1.clone(),
+        })
+        .collect();
+    sample_extractions.sort_by_key(|s| s.participant_id.parse::<u64>().unwrap_or(u64::MAX));
+
+    Some(PatternSuggestion {
+        example: sample_extractions
+            .first()
+            .and_then(|s| std::path::Path::new(&s.path).file_name())
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        pattern,
+        regex_pattern,
+        description,
+        sample_extractions,
+    })
+}
+
 #[tauri::command]
 pub fn extract_ids_for_files(
     files: Vec<String>,
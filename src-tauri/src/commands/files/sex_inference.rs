@@ -0,0 +1,266 @@
+use biovault::data::BioVaultDb;
+use rusqlite::params;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+/// Calls scoring below this are flagged for manual review.
+pub const LOW_SEX_CONFIDENCE_THRESHOLD: f64 = 0.7;
+
+/// Marker counts at which coverage stops discounting the score. Consumer
+/// arrays typically carry thousands of X and a few hundred Y markers.
+const FULL_COVERAGE_X_MARKERS: f64 = 500.0;
+const FULL_COVERAGE_Y_MARKERS: f64 = 20.0;
+
+/// X heterozygosity above this is treated as clearly female.
+const FEMALE_X_HETEROZYGOSITY: f64 = 0.15;
+/// Y call rate above this is treated as clearly male.
+const MALE_Y_CALL_RATE: f64 = 0.5;
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SexInferenceConfidence {
+    pub inferred_sex: Option<String>,
+    /// 0.0 (a guess) to 1.0 (both X and Y evidence agree with good coverage).
+    pub confidence: f64,
+    pub low_confidence: bool,
+    pub x_markers: u64,
+    pub x_heterozygous: u64,
+    pub y_markers: u64,
+    pub y_called: u64,
+    pub x_heterozygosity: Option<f64>,
+    pub y_call_rate: Option<f64>,
+}
+
+#[derive(Debug, Default)]
+struct SexMarkerCounts {
+    x_called: u64,
+    x_het: u64,
+    y_total: u64,
+    y_called: u64,
+}
+
+fn is_no_call(allele: &str) -> bool {
+    allele.is_empty()
+        || allele
+            .chars()
+            .all(|c| matches!(c, '-' | '0' | '.' | 'N' | '?'))
+}
+
+/// Count X/Y markers in a 23andMe/AncestryDNA style export
+/// (`rsid, chromosome, position, genotype` or `..., allele1, allele2`).
+fn count_sex_markers(path: &str) -> Result<SexMarkerCounts, String> {
+    let reader = super::open_genotype_reader(path)?;
+    let mut counts = SexMarkerCounts::default();
+    for line in reader.lines().map_while(Result::ok) {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = line
+            .split(|c| c == '\t' || c == ',')
+            .map(|f| f.trim().trim_matches('"'))
+            .collect();
+        if fields.len() < 4 {
+            continue;
+        }
+        let chrom = fields[1].trim_start_matches("chr").to_ascii_uppercase();
+        let alleles: Vec<char> = if fields.len() >= 5 {
+            fields[3].chars().chain(fields[4].chars()).collect()
+        } else {
+            fields[3].chars().collect()
+        };
+        let genotype: String = alleles.iter().collect();
+        let called = !is_no_call(&genotype);
+        match chrom.as_str() {
+            "X" | "23" => {
+                if called {
+                    counts.x_called += 1;
+                    if alleles.len() == 2 && alleles[0] != alleles[1] {
+                        counts.x_het += 1;
+                    }
+                }
+            }
+            "Y" | "24" => {
+                counts.y_total += 1;
+                if called {
+                    counts.y_called += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(counts)
+}
+
+fn score_sex_inference(
+    inferred_sex: Option<&str>,
+    counts: &SexMarkerCounts,
+) -> SexInferenceConfidence {
+    let x_heterozygosity =
+        (counts.x_called > 0).then(|| counts.x_het as f64 / counts.x_called as f64);
+    let y_call_rate = (counts.y_total > 0).then(|| counts.y_called as f64 / counts.y_total as f64);
+
+    let is_male = match inferred_sex.map(|s| s.trim().to_ascii_lowercase()) {
+        Some(s) if s == "male" || s == "m" => Some(true),
+        Some(s) if s == "female" || s == "f" => Some(false),
+        _ => None,
+    };
+
+    let confidence = match is_male {
+        None => 0.0,
+        Some(male) => {
+            // Each signal is "how male does this look" in 0..1, weighted by its coverage.
+            let mut signals: Vec<(f64, f64)> = Vec::new();
+            if let Some(het) = x_heterozygosity {
+                let maleness = 1.0 - (het / FEMALE_X_HETEROZYGOSITY).clamp(0.0, 1.0);
+                let coverage = (counts.x_called as f64 / FULL_COVERAGE_X_MARKERS).min(1.0);
+                signals.push((maleness, coverage));
+            }
+            if let Some(rate) = y_call_rate {
+                let maleness = (rate / MALE_Y_CALL_RATE).clamp(0.0, 1.0);
+                let coverage = (counts.y_total as f64 / FULL_COVERAGE_Y_MARKERS).min(1.0);
+                signals.push((maleness, coverage));
+            }
+            if signals.is_empty() {
+                0.0
+            } else {
+                let agreement: f64 = signals
+                    .iter()
+                    .map(|(maleness, coverage)| {
+                        let support = if male { *maleness } else { 1.0 - maleness };
+                        support * coverage
+                    })
+                    .sum();
+                // A single signal can't corroborate itself, so it caps lower.
+                let max = if signals.len() == 1 { 1.5 } else { 2.0 };
+                agreement / max
+            }
+        }
+    };
+    let confidence = (confidence * 100.0).round() / 100.0;
+
+    SexInferenceConfidence {
+        inferred_sex: inferred_sex.map(str::to_string),
+        confidence,
+        low_confidence: confidence < LOW_SEX_CONFIDENCE_THRESHOLD,
+        x_markers: counts.x_called,
+        x_heterozygous: counts.x_het,
+        y_markers: counts.y_total,
+        y_called: counts.y_called,
+        x_heterozygosity: x_heterozygosity.map(|h| (h * 10000.0).round() / 10000.0),
+        y_call_rate: y_call_rate.map(|r| (r * 10000.0).round() / 10000.0),
+    }
+}
+
+/// Score how well the X/Y markers in a genotype file support `inferred_sex`.
+pub(crate) fn estimate_sex_confidence(
+    file_path: &str,
+    inferred_sex: Option<&str>,
+) -> Result<SexInferenceConfidence, String> {
+    let counts = count_sex_markers(file_path)?;
+    Ok(score_sex_inference(inferred_sex, &counts))
+}
+
+fn ensure_sex_confidence_table(db: &BioVaultDb) -> Result<(), String> {
+    db.connection()
+        .execute(
+            "CREATE TABLE IF NOT EXISTS file_sex_confidence (
+                file_id INTEGER PRIMARY KEY,
+                confidence REAL NOT NULL,
+                low_confidence INTEGER NOT NULL,
+                details TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .map_err(|e| format!("Failed to create sex confidence table: {}", e))?;
+    Ok(())
+}
+
+pub(crate) fn save_sex_confidence(
+    db: &BioVaultDb,
+    file_id: i64,
+    result: &SexInferenceConfidence,
+) -> Result<(), String> {
+    ensure_sex_confidence_table(db)?;
+    let details = serde_json::to_string(result)
+        .map_err(|e| format!("Failed to serialize sex confidence: {}", e))?;
+    db.connection()
+        .execute(
+            "INSERT INTO file_sex_confidence (file_id, confidence, low_confidence, details, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(file_id) DO UPDATE SET confidence = excluded.confidence,
+                 low_confidence = excluded.low_confidence, details = excluded.details,
+                 updated_at = excluded.updated_at",
+            params![
+                file_id,
+                result.confidence,
+                result.low_confidence,
+                details,
+                chrono::Utc::now().to_rfc3339()
+            ],
+        )
+        .map_err(|e| format!("Failed to save sex confidence: {}", e))?;
+    if result.low_confidence && result.inferred_sex.is_some() {
+        crate::desktop_log!(
+            "⚠️ Low-confidence sex call for file {}: {:?} (confidence {:.2})",
+            file_id,
+            result.inferred_sex,
+            result.confidence
+        );
+    }
+    Ok(())
+}
+
+/// Drop scores whose file row is gone. Call right after deleting files, under
+/// the same lock, so a reused file id never inherits a stale score.
+pub(crate) fn remove_orphaned_sex_confidence(db: &BioVaultDb) -> Result<(), String> {
+    ensure_sex_confidence_table(db)?;
+    db.connection()
+        .execute(
+            "DELETE FROM file_sex_confidence WHERE file_id NOT IN (SELECT id FROM files)",
+            [],
+        )
+        .map_err(|e| format!("Failed to clean up sex confidence: {}", e))?;
+    Ok(())
+}
+
+/// Confidence and low-confidence flag per file id.
+pub(crate) fn load_sex_confidence(db: &BioVaultDb) -> Result<HashMap<i64, (f64, bool)>, String> {
+    ensure_sex_confidence_table(db)?;
+    let mut stmt = db
+        .connection()
+        .prepare("SELECT file_id, confidence, low_confidence FROM file_sex_confidence")
+        .map_err(|e| format!("Failed to prepare sex confidence query: {}", e))?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                (row.get::<_, f64>(1)?, row.get::<_, bool>(2)?),
+            ))
+        })
+        .map_err(|e| format!("Failed to query sex confidence: {}", e))?
+        .collect::<Result<HashMap<_, _>, _>>()
+        .map_err(|e| format!("Failed to read sex confidence: {}", e))?;
+    Ok(rows)
+}
+
+/// Recompute (and store) how confident the sex call for a catalog file is.
+#[tauri::command]
+pub fn get_sex_inference_confidence(
+    state: tauri::State<crate::types::AppState>,
+    file_id: i64,
+) -> Result<SexInferenceConfidence, String> {
+    let (file_path, inferred_sex) = {
+        let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+        let record = biovault::data::get_file_by_id(&db, file_id)
+            .map_err(|e| format!("Failed to load file {}: {}", file_id, e))?
+            .ok_or_else(|| format!("File {} not found", file_id))?;
+        (record.file_path, record.inferred_sex)
+    };
+    // Read the file without holding the DB lock; genotype files can be large.
+    let result = estimate_sex_confidence(&file_path, inferred_sex.as_deref())?;
+    let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+    save_sex_confidence(&db, file_id, &result)?;
+    Ok(result)
+}
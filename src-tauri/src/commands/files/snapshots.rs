@@ -0,0 +1,248 @@
+use crate::types::AppState;
+use biovault::data::BioVaultDb;
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// One file as captured in a snapshot. Stored as a compact JSON tuple
+/// `[id, path, hash, participant_id]` to keep snapshots small.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SnapshotFileEntry(pub i64, pub String, pub String, pub Option<String>);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LibrarySnapshotSummary {
+    pub id: i64,
+    pub label: String,
+    pub created_at: String,
+    pub file_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SnapshotFileRef {
+    pub file_id: i64,
+    pub file_path: String,
+    pub file_hash: String,
+    pub participant_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SnapshotFileChange {
+    pub file_id: i64,
+    pub file_path: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LibrarySnapshotDiff {
+    pub from: LibrarySnapshotSummary,
+    pub to: LibrarySnapshotSummary,
+    pub added: Vec<SnapshotFileRef>,
+    pub removed: Vec<SnapshotFileRef>,
+    pub reassigned: Vec<SnapshotFileChange>,
+    pub rehashed: Vec<SnapshotFileChange>,
+    pub unchanged_count: usize,
+}
+
+impl From<&SnapshotFileEntry> for SnapshotFileRef {
+    fn from(entry: &SnapshotFileEntry) -> Self {
+        SnapshotFileRef {
+            file_id: entry.0,
+            file_path: entry.1.clone(),
+            file_hash: entry.2.clone(),
+            participant_id: entry.3.clone(),
+        }
+    }
+}
+
+fn ensure_snapshot_table(db: &BioVaultDb) -> Result<(), String> {
+    db.connection()
+        .execute(
+            "CREATE TABLE IF NOT EXISTS library_snapshots (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                label TEXT NOT NULL,
+                created_at TEXT NOT NULL,
+                file_count INTEGER NOT NULL,
+                entries TEXT NOT NULL
+            )",
+            [],
+        )
+        .map_err(|e| format!("Failed to create library snapshot table: {}", e))?;
+    Ok(())
+}
+
+fn load_snapshot(
+    db: &BioVaultDb,
+    id: i64,
+) -> Result<(LibrarySnapshotSummary, Vec<SnapshotFileEntry>), String> {
+    let (label, created_at, file_count, entries): (String, String, i64, String) = db
+        .connection()
+        .query_row(
+            "SELECT label, created_at, file_count, entries FROM library_snapshots WHERE id = ?1",
+            params![id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+        )
+        .map_err(|_| format!("Library snapshot {} not found", id))?;
+    let entries: Vec<SnapshotFileEntry> = serde_json::from_str(&entries)
+        .map_err(|e| format!("Failed to parse library snapshot {}: {}", id, e))?;
+    Ok((
+        LibrarySnapshotSummary {
+            id,
+            label,
+            created_at,
+            file_count: file_count as usize,
+        },
+        entries,
+    ))
+}
+
+fn diff_snapshot_entries(
+    from: LibrarySnapshotSummary,
+    from_entries: &[SnapshotFileEntry],
+    to: LibrarySnapshotSummary,
+    to_entries: &[SnapshotFileEntry],
+) -> LibrarySnapshotDiff {
+    let before: BTreeMap<i64, &SnapshotFileEntry> = from_entries.iter().map(|e| (e.0, e)).collect();
+    let after: BTreeMap<i64, &SnapshotFileEntry> = to_entries.iter().map(|e| (e.0, e)).collect();
+
+    let mut diff = LibrarySnapshotDiff {
+        from,
+        to,
+        added: Vec::new(),
+        removed: Vec::new(),
+        reassigned: Vec::new(),
+        rehashed: Vec::new(),
+        unchanged_count: 0,
+    };
+
+    for (id, old) in &before {
+        let Some(new) = after.get(id) else {
+            diff.removed.push((*old).into());
+            continue;
+        };
+        let mut changed = false;
+        if old.3 != new.3 {
+            diff.reassigned.push(SnapshotFileChange {
+                file_id: *id,
+                file_path: new.1.clone(),
+                before: old.3.clone(),
+                after: new.3.clone(),
+            });
+            changed = true;
+        }
+        if old.2 != new.2 {
+            diff.rehashed.push(SnapshotFileChange {
+                file_id: *id,
+                file_path: new.1.clone(),
+                before: Some(old.2.clone()),
+                after: Some(new.2.clone()),
+            });
+            changed = true;
+        }
+        if !changed {
+            diff.unchanged_count += 1;
+        }
+    }
+    diff.added = after
+        .iter()
+        .filter(|(id, _)| !before.contains_key(id))
+        .map(|(_, entry)| (*entry).into())
+        .collect();
+    diff
+}
+
+/// Record the current file library (ids, hashes, participant assignments) under `label`.
+#[tauri::command]
+pub fn snapshot_library(
+    state: tauri::State<AppState>,
+    label: String,
+) -> Result<LibrarySnapshotSummary, String> {
+    let label = label.trim().to_string();
+    if label.is_empty() {
+        return Err("Snapshot label is required".to_string());
+    }
+
+    let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+    ensure_snapshot_table(&db)?;
+    let entries: Vec<SnapshotFileEntry> = biovault::data::list_files(&db, None, None, false, None)
+        .map_err(|e| format!("Failed to list files: {}", e))?
+        .into_iter()
+        .map(|f| SnapshotFileEntry(f.id, f.file_path, f.file_hash, f.participant_id))
+        .collect();
+    let payload = serde_json::to_string(&entries)
+        .map_err(|e| format!("Failed to serialize library snapshot: {}", e))?;
+    let created_at = chrono::Utc::now().to_rfc3339();
+    db.connection()
+        .execute(
+            "INSERT INTO library_snapshots (label, created_at, file_count, entries)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![label, created_at, entries.len() as i64, payload],
+        )
+        .map_err(|e| format!("Failed to save library snapshot: {}", e))?;
+    let id = db.connection().last_insert_rowid();
+
+    crate::desktop_log!(
+        "📸 Library snapshot '{}' saved ({} files)",
+        label,
+        entries.len()
+    );
+    Ok(LibrarySnapshotSummary {
+        id,
+        label,
+        created_at,
+        file_count: entries.len(),
+    })
+}
+
+#[tauri::command]
+pub fn list_library_snapshots(
+    state: tauri::State<AppState>,
+) -> Result<Vec<LibrarySnapshotSummary>, String> {
+    let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+    ensure_snapshot_table(&db)?;
+    let mut stmt = db
+        .connection()
+        .prepare(
+            "SELECT id, label, created_at, file_count FROM library_snapshots
+             ORDER BY created_at DESC, id DESC",
+        )
+        .map_err(|e| format!("Failed to prepare snapshot query: {}", e))?;
+    let snapshots = stmt
+        .query_map([], |row| {
+            Ok(LibrarySnapshotSummary {
+                id: row.get(0)?,
+                label: row.get(1)?,
+                created_at: row.get(2)?,
+                file_count: row.get::<_, i64>(3)? as usize,
+            })
+        })
+        .map_err(|e| format!("Failed to query library snapshots: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read library snapshots: {}", e))?;
+    Ok(snapshots)
+}
+
+/// Compare two snapshots: `a` is the earlier state, `b` the later one.
+#[tauri::command]
+pub fn diff_library_snapshots(
+    state: tauri::State<AppState>,
+    a: i64,
+    b: i64,
+) -> Result<LibrarySnapshotDiff, String> {
+    let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+    ensure_snapshot_table(&db)?;
+    let (from, from_entries) = load_snapshot(&db, a)?;
+    let (to, to_entries) = load_snapshot(&db, b)?;
+    Ok(diff_snapshot_entries(from, &from_entries, to, &to_entries))
+}
+
+#[tauri::command]
+pub fn delete_library_snapshot(state: tauri::State<AppState>, id: i64) -> Result<bool, String> {
+    let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+    ensure_snapshot_table(&db)?;
+    let deleted = db
+        .connection()
+        .execute("DELETE FROM library_snapshots WHERE id = ?1", params![id])
+        .map_err(|e| format!("Failed to delete library snapshot: {}", e))?;
+    Ok(deleted > 0)
+}
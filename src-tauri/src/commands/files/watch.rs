@@ -0,0 +1,379 @@
+use crate::types::AppState;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{Emitter, Manager};
+
+const WATCH_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Stop flags for running folder watchers, keyed by folder path.
+static IMPORT_WATCHERS: Lazy<Mutex<HashMap<String, Arc<AtomicBool>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct WatchedFolder {
+    pub path: String,
+    /// Participant-ID extraction pattern; empty imports every file unlinked.
+    #[serde(default)]
+    pub pattern: String,
+    pub created_at: String,
+    /// Whether a watcher is running for it in this session (not persisted).
+    #[serde(default, skip_deserializing)]
+    pub active: bool,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct ImportedCopy {
+    pub file_id: i64,
+    pub file_path: String,
+    pub participant_id: Option<String>,
+    /// "watched" or "manual".
+    pub source: String,
+    pub watched_folder: Option<String>,
+    pub created_at: String,
+}
+
+/// The same content imported once by a folder watcher and once by hand.
+#[derive(Serialize, Clone, Debug)]
+pub struct ImportConflictPair {
+    pub file_hash: String,
+    pub watched: ImportedCopy,
+    pub manual: ImportedCopy,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct AutoImportEvent {
+    pub folder: String,
+    pub files: Vec<String>,
+    pub imported: usize,
+    pub skipped: usize,
+}
+
+fn watched_folders_path() -> Result<PathBuf, String> {
+    let biovault_home = biovault::config::get_biovault_home()
+        .map_err(|e| format!("Failed to get BioVault home: {}", e))?;
+    Ok(biovault_home
+        .join(".biovault")
+        .join("watched_import_folders.json"))
+}
+
+fn load_watched_folders() -> Result<Vec<WatchedFolder>, String> {
+    let path = watched_folders_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Invalid watched folders file: {}", e))
+}
+
+fn save_watched_folders(folders: &[WatchedFolder]) -> Result<(), String> {
+    let path = watched_folders_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    let json = serde_json::to_string_pretty(folders)
+        .map_err(|e| format!("Failed to serialize watched folders: {}", e))?;
+    fs::write(&path, json).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+fn is_watcher_running(path: &str) -> bool {
+    IMPORT_WATCHERS
+        .lock()
+        .map(|w| w.contains_key(path))
+        .unwrap_or(false)
+}
+
+fn watched_folder_for<'a>(file_path: &str, folders: &'a [WatchedFolder]) -> Option<&'a str> {
+    let path = Path::new(file_path);
+    folders
+        .iter()
+        .filter(|f| path.starts_with(&f.path))
+        .max_by_key(|f| f.path.len())
+        .map(|f| f.path.as_str())
+}
+
+fn is_ignored_file(path: &Path) -> bool {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_ascii_lowercase())
+        .unwrap_or_default();
+    name.starts_with('.')
+        || name.starts_with('~')
+        || name.ends_with(".tmp")
+        || name.ends_with(".part")
+        || name.ends_with(".crdownload")
+}
+
+/// One scan of the folder. Files are only imported once their size has held
+/// steady since the previous scan, so half-written instrument output is skipped.
+fn scan_and_import(
+    app: &tauri::AppHandle,
+    folder: &WatchedFolder,
+    last_sizes: &mut HashMap<String, u64>,
+    handled: &mut HashSet<String>,
+) -> Result<(), String> {
+    let scan_result = biovault::data::scan(&folder.path, None, true)
+        .map_err(|e| format!("Failed to scan directory: {}", e))?;
+
+    let mut sizes = HashMap::new();
+    let mut ready = Vec::new();
+    for file_info in scan_result.files {
+        if handled.contains(&file_info.path) || is_ignored_file(Path::new(&file_info.path)) {
+            continue;
+        }
+        let Ok(size) = fs::metadata(&file_info.path).map(|m| m.len()) else {
+            continue;
+        };
+        if last_sizes.get(&file_info.path) == Some(&size) {
+            ready.push(file_info.path.clone());
+        }
+        sizes.insert(file_info.path, size);
+    }
+    *last_sizes = sizes;
+    if ready.is_empty() {
+        return Ok(());
+    }
+
+    let state = app.state::<AppState>();
+    let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+
+    let mut csv_imports = Vec::new();
+    for path in ready {
+        handled.insert(path.clone());
+        let already_imported = db
+            .connection()
+            .query_row(
+                "SELECT COUNT(*) FROM files WHERE file_path = ?1",
+                [&path],
+                |row| Ok(row.get::<_, i64>(0)? > 0),
+            )
+            .unwrap_or(false);
+        if already_imported {
+            continue;
+        }
+
+        let participant_id = if folder.pattern.trim().is_empty() {
+            None
+        } else {
+            match biovault::data::extract_id_from_pattern(&path, &folder.pattern) {
+                Ok(Some(id)) => Some(id),
+                // Not a file this watcher is meant to pick up.
+                Ok(None) | Err(_) => continue,
+            }
+        };
+
+        csv_imports.push(biovault::data::CsvFileImport {
+            file_path: path,
+            participant_id,
+            data_type: None,
+            source: None,
+            grch_version: None,
+            reference_path: None,
+            reference_index_path: None,
+            row_count: None,
+            chromosome_count: None,
+            inferred_sex: None,
+        });
+    }
+    if csv_imports.is_empty() {
+        return Ok(());
+    }
+
+    let files: Vec<String> = csv_imports.iter().map(|c| c.file_path.clone()).collect();
+    let lib_result = biovault::data::import_files_as_pending(&db, csv_imports)
+        .map_err(|e| format!("Failed to import files: {}", e))?;
+    drop(db);
+
+    crate::desktop_log!(
+        "👀 Auto-imported {} file(s) from {} ({} skipped)",
+        lib_result.imported,
+        folder.path,
+        lib_result.skipped
+    );
+    let _ = app.emit(
+        "import:auto-imported",
+        AutoImportEvent {
+            folder: folder.path.clone(),
+            files,
+            imported: lib_result.imported,
+            skipped: lib_result.skipped,
+        },
+    );
+    Ok(())
+}
+
+fn spawn_import_watcher(app: tauri::AppHandle, folder: WatchedFolder) {
+    let stop = Arc::new(AtomicBool::new(false));
+    {
+        let Ok(mut watchers) = IMPORT_WATCHERS.lock() else {
+            return;
+        };
+        if let Some(previous) = watchers.insert(folder.path.clone(), stop.clone()) {
+            previous.store(true, Ordering::SeqCst);
+        }
+    }
+
+    std::thread::spawn(move || {
+        crate::desktop_log!("👀 Watching {} for new files", folder.path);
+        let mut last_sizes = HashMap::new();
+        let mut handled = HashSet::new();
+        while !stop.load(Ordering::SeqCst) {
+            if !Path::new(&folder.path).is_dir() {
+                crate::desktop_log!("⚠️ Watched folder {} is not available", folder.path);
+            } else if let Err(err) = scan_and_import(&app, &folder, &mut last_sizes, &mut handled) {
+                crate::desktop_log!("⚠️ Auto-import from {} failed: {}", folder.path, err);
+            }
+            std::thread::sleep(WATCH_POLL_INTERVAL);
+        }
+        crate::desktop_log!("👀 Stopped watching {}", folder.path);
+    });
+}
+
+/// Start watchers for every folder saved by a previous session.
+pub(crate) fn resume_import_watchers(app: tauri::AppHandle) {
+    match load_watched_folders() {
+        Ok(folders) => {
+            for folder in folders {
+                spawn_import_watcher(app.clone(), folder);
+            }
+        }
+        Err(err) => crate::desktop_log!("⚠️ Failed to load watched folders: {}", err),
+    }
+}
+
+/// Auto-import new files that appear in `path`, extracting participant IDs
+/// with `pattern`. The folder stays watched across restarts until unwatched.
+#[tauri::command]
+pub fn watch_import_folder(
+    app: tauri::AppHandle,
+    path: String,
+    pattern: Option<String>,
+) -> Result<WatchedFolder, String> {
+    let dir = PathBuf::from(path.trim());
+    if !dir.is_dir() {
+        return Err(format!("Folder not found: {}", dir.display()));
+    }
+    let path = dir.to_string_lossy().to_string();
+
+    let mut folders = load_watched_folders()?;
+    folders.retain(|f| f.path != path);
+    let mut folder = WatchedFolder {
+        path: path.clone(),
+        pattern: pattern.unwrap_or_default(),
+        created_at: chrono::Utc::now().to_rfc3339(),
+        active: false,
+    };
+    folders.push(folder.clone());
+    save_watched_folders(&folders)?;
+
+    spawn_import_watcher(app, folder.clone());
+    folder.active = true;
+    Ok(folder)
+}
+
+#[tauri::command]
+pub fn unwatch_import_folder(path: String) -> Result<bool, String> {
+    let path = path.trim().to_string();
+    let mut folders = load_watched_folders()?;
+    let before = folders.len();
+    folders.retain(|f| f.path != path);
+    let removed = folders.len() != before;
+    if removed {
+        save_watched_folders(&folders)?;
+    }
+
+    if let Ok(mut watchers) = IMPORT_WATCHERS.lock() {
+        if let Some(stop) = watchers.remove(&path) {
+            stop.store(true, Ordering::SeqCst);
+            return Ok(true);
+        }
+    }
+    Ok(removed)
+}
+
+#[tauri::command]
+pub fn list_watched_folders() -> Result<Vec<WatchedFolder>, String> {
+    let mut folders = load_watched_folders()?;
+    for folder in &mut folders {
+        folder.active = is_watcher_running(&folder.path);
+    }
+    Ok(folders)
+}
+
+/// Pair up files with the same hash where one copy lives in a watched folder
+/// and the other was imported manually from elsewhere, so the user can pick
+/// which to keep. Files that have not been hashed yet are not considered.
+#[tauri::command]
+pub fn get_import_conflicts(
+    state: tauri::State<AppState>,
+) -> Result<Vec<ImportConflictPair>, String> {
+    let folders = load_watched_folders()?;
+    if folders.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let files = {
+        let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+        biovault::data::list_files(&db, None, None, false, None)
+            .map_err(|e| format!("Failed to list files: {}", e))?
+    };
+
+    let mut by_hash: HashMap<String, (Vec<ImportedCopy>, Vec<ImportedCopy>)> = HashMap::new();
+    for file in files {
+        if file.file_hash.trim().is_empty() || file.file_hash.starts_with("pending_") {
+            continue;
+        }
+        let watched_folder = watched_folder_for(&file.file_path, &folders).map(str::to_string);
+        let copy = ImportedCopy {
+            file_id: file.id,
+            file_path: file.file_path,
+            participant_id: file.participant_id,
+            source: if watched_folder.is_some() {
+                "watched"
+            } else {
+                "manual"
+            }
+            .to_string(),
+            watched_folder,
+            created_at: file.created_at,
+        };
+        let entry = by_hash.entry(file.file_hash).or_default();
+        if copy.watched_folder.is_some() {
+            entry.0.push(copy);
+        } else {
+            entry.1.push(copy);
+        }
+    }
+
+    let mut conflicts = Vec::new();
+    for (file_hash, (watched, manual)) in by_hash {
+        for w in &watched {
+            for m in manual.iter().filter(|m| m.file_path != w.file_path) {
+                conflicts.push(ImportConflictPair {
+                    file_hash: file_hash.clone(),
+                    watched: w.clone(),
+                    manual: m.clone(),
+                });
+            }
+        }
+    }
+    conflicts.sort_by(|a, b| {
+        a.watched
+            .file_path
+            .cmp(&b.watched.file_path)
+            .then_with(|| a.manual.file_path.cmp(&b.manual.file_path))
+    });
+
+    crate::desktop_log!(
+        "🔀 Found {} watched/manual import conflict(s)",
+        conflicts.len()
+    );
+    Ok(conflicts)
+}
@@ -1,5 +1,6 @@
 use crate::types::AppState;
 use biovault::syftbox::storage::SyftBoxStorage;
+use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::env;
@@ -8,7 +9,9 @@ use std::io::{BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::process::Output;
-use tauri::Emitter;
+use std::sync::Mutex;
+use std::time::SystemTime;
+use tauri::{Emitter, Manager};
 use walkdir::WalkDir;
 
 // Use CLI library types and functions
@@ -522,7 +525,7 @@ fn probe_container_runtime(window: Option<&tauri::WebviewWindow>, log_path: &Pat
     }
 }
 
-fn is_pid_running(pid: i32) -> bool {
+pub(crate) fn is_pid_running(pid: i32) -> bool {
     #[cfg(target_os = "windows")]
     {
         let mut cmd = Command::new("tasklist");
@@ -543,7 +546,7 @@ fn is_pid_running(pid: i32) -> bool {
 }
 
 /// Get the container runtime binary (docker or podman)
-fn get_container_runtime() -> Option<String> {
+pub(crate) fn get_container_runtime() -> Option<String> {
     // Check BIOVAULT_CONTAINER_RUNTIME env var first
     if let Ok(runtime) = env::var("BIOVAULT_CONTAINER_RUNTIME") {
         let runtime = runtime.to_lowercase();
@@ -931,6 +934,187 @@ fn parse_stem_and_ext(path: &str) -> Option<(String, String)> {
     Some((stem, ext))
 }
 
+struct DatasetSelectionResolution {
+    input_name: String,
+    shape: String,
+    data_type: String,
+    value: DatasetInputValue,
+    file_count: usize,
+    dataset_count: usize,
+}
+
+/// Resolve a local dataset selection to the flow input it feeds. Returns None
+/// for List-shaped datasets, which go through URL selection instead.
+fn resolve_dataset_selection(
+    db: &BioVaultDb,
+    yaml_path: &Path,
+    dataset_name: &str,
+    dataset_shape: Option<&str>,
+    dataset_data_type: Option<&str>,
+) -> Result<Option<DatasetSelectionResolution>, String> {
+    let data_type = dataset_data_type.unwrap_or("mock").to_string();
+    let (dataset_record, dataset_assets) =
+        biovault::data::get_dataset_with_assets(db, dataset_name)
+            .map_err(|e| format!("Failed to load dataset '{}': {}", dataset_name, e))?
+            .ok_or_else(|| format!("Dataset '{}' not found", dataset_name))?;
+
+    let manifest = biovault::data::build_manifest_from_db(&dataset_record, &dataset_assets);
+    let shape = dataset_shape
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .or_else(|| biovault::cli::commands::datasets::infer_dataset_shape(&manifest))
+        .ok_or_else(|| {
+            format!(
+                "Dataset '{}' does not declare a shape and none could be inferred.",
+                dataset_name
+            )
+        })?;
+
+    let shape_expr = parse_shape_expr(&shape)
+        .ok_or_else(|| format!("Unsupported dataset shape '{}' for selection.", shape))?;
+
+    if let ShapeExpr::List(inner_type) = &shape_expr {
+        eprintln!(
+            "[flow] Dataset '{}' has List shape (item type: {:?}), using URL selection path",
+            dataset_name, inner_type
+        );
+        return Ok(None);
+    }
+
+    let spec = FlowSpec::load(yaml_path).map_err(|e| format!("Failed to load flow spec: {}", e))?;
+    let input_name = spec
+        .inputs
+        .iter()
+        .find(|(_, input_spec)| {
+            biovault::module_spec::types_compatible(&shape, input_spec.raw_type())
+        })
+        .map(|(name, _)| name.clone())
+        .ok_or_else(|| format!("Flow does not declare an input compatible with '{}'", shape))?;
+
+    let (value, file_count) =
+        build_dataset_input_value(db, &dataset_assets, &data_type, &shape_expr)?;
+
+    let dataset_count = match &shape_expr {
+        ShapeExpr::Map(_) => match &value {
+            DatasetInputValue::Json(serde_json::Value::Object(map)) => map.len(),
+            _ => 0,
+        },
+        ShapeExpr::Record(_) | ShapeExpr::File | ShapeExpr::Directory => 1,
+        _ => 0,
+    };
+
+    Ok(Some(DatasetSelectionResolution {
+        input_name,
+        shape,
+        data_type,
+        value,
+        file_count,
+        dataset_count,
+    }))
+}
+
+/// URL selection resolved to local files, shared by `run_flow` and `preview_run_inputs`.
+struct UrlSelectionResolution {
+    /// Selected URLs with duplicates dropped, in selection order.
+    unique_urls: Vec<String>,
+    /// (participant, local path) for every URL whose file exists.
+    rows: Vec<(String, String)>,
+    /// (url, local path) for URLs that resolved but have no file on disk.
+    missing: Vec<(String, PathBuf)>,
+}
+
+/// Resolve `syft://` URLs to local paths. A participant comes from the
+/// matching `participant_ids` entry, else the file stem.
+fn resolve_url_selection(
+    urls: &[String],
+    participant_ids: &[String],
+) -> Result<UrlSelectionResolution, String> {
+    let data_dir = biovault::config::Config::load()
+        .map_err(|e| format!("Failed to load config: {}", e))?
+        .get_syftbox_data_dir()
+        .map_err(|e| format!("Failed to get SyftBox data dir: {}", e))?;
+
+    let mut seen_urls = HashSet::new();
+    let unique_urls: Vec<String> = urls
+        .iter()
+        .filter(|url| seen_urls.insert(url.as_str()))
+        .cloned()
+        .collect();
+
+    let mut rows = Vec::new();
+    let mut missing = Vec::new();
+    for (idx, url) in unique_urls.iter().enumerate() {
+        let local_path = biovault::data::resolve_syft_url(&data_dir, url)
+            .map_err(|e| format!("Failed to resolve URL '{}': {}", url, e))?;
+        if !local_path.exists() {
+            missing.push((url.clone(), local_path));
+            continue;
+        }
+        let participant = participant_ids
+            .get(idx)
+            .filter(|id| !id.is_empty())
+            .cloned()
+            .unwrap_or_else(|| {
+                local_path
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .unwrap_or("unknown")
+                    .to_string()
+            });
+        rows.push((participant, local_path.to_string_lossy().to_string()));
+    }
+
+    Ok(UrlSelectionResolution {
+        unique_urls,
+        rows,
+        missing,
+    })
+}
+
+/// Load catalog records for legacy file id selection (duplicates dropped),
+/// paired with their participant label (participant id, else the file stem).
+fn resolve_file_id_selection(
+    db: &BioVaultDb,
+    file_ids: &[i64],
+) -> Result<Vec<(String, biovault::data::FileRecord)>, String> {
+    let mut seen_files = HashSet::new();
+    let mut records = Vec::new();
+    for file_id in file_ids.iter().filter(|id| seen_files.insert(**id)) {
+        let record = biovault::data::get_file_by_id(db, *file_id)
+            .map_err(|e| format!("Failed to load file {}: {}", file_id, e))?
+            .ok_or_else(|| format!("File {} not found in the BioVault catalog", file_id))?;
+
+        if record.file_path.trim().is_empty() {
+            return Err(format!(
+                "File {} does not have a recorded path in the catalog.",
+                file_id
+            ));
+        }
+
+        let participant = record
+            .participant_id
+            .clone()
+            .filter(|value| !value.trim().is_empty())
+            .unwrap_or_else(|| {
+                Path::new(&record.file_path)
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .unwrap_or("unknown")
+                    .to_string()
+            });
+        records.push((participant, record));
+    }
+    Ok(records)
+}
+
+/// Aligned reads (CRAM/BAM) get a different samplesheet than genotype files.
+fn selection_has_aligned_files(records: &[(String, biovault::data::FileRecord)]) -> bool {
+    records.iter().any(|(_, r)| {
+        let dt = r.data_type.as_deref().unwrap_or("");
+        dt == "Aligned" || r.file_path.ends_with(".cram") || r.file_path.ends_with(".bam")
+    })
+}
+
 fn build_dataset_input_value(
     db: &BioVaultDb,
     assets: &[biovault::data::DatasetAssetRecord],
@@ -1094,7 +1278,7 @@ fn get_flows_dir() -> Result<PathBuf, String> {
     Ok(home.join("flows"))
 }
 
-fn get_modules_dir() -> Result<PathBuf, String> {
+pub(crate) fn get_modules_dir() -> Result<PathBuf, String> {
     let home = biovault::config::get_biovault_home()
         .map_err(|e| format!("Failed to get BioVault home: {}", e))?;
     Ok(home.join("modules"))
@@ -1734,6 +1918,111 @@ pub async fn import_flow_from_json(
     })
 }
 
+fn run_git(args: &[&str], cwd: &Path) -> Result<String, String> {
+    let mut cmd = Command::new("git");
+    cmd.args(args)
+        .current_dir(cwd)
+        .env("GIT_TERMINAL_PROMPT", "0");
+    super::hide_console_window(&mut cmd);
+    let output = cmd
+        .output()
+        .map_err(|e| format!("Failed to run git (is it installed?): {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "git {} failed: {}",
+            args.first().copied().unwrap_or_default(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Find the flow.yaml closest to the repository root.
+fn find_flow_yaml(root: &Path) -> Option<PathBuf> {
+    WalkDir::new(root)
+        .max_depth(4)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|e| {
+            !should_skip_request_path(e.path().strip_prefix(root).unwrap_or(e.path()))
+        })
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file() && e.file_name() == FLOW_YAML_FILE)
+        .min_by_key(|e| e.depth())
+        .map(|e| e.into_path())
+}
+
+/// Shallow-fetch `url` at `git_ref` (branch, tag or commit; default HEAD) and
+/// import the flow it contains. The temporary clone is removed afterwards.
+#[tauri::command]
+pub async fn import_flow_from_git(
+    state: tauri::State<'_, AppState>,
+    url: String,
+    git_ref: Option<String>,
+    overwrite: Option<bool>,
+) -> Result<Flow, String> {
+    let url = url.trim().to_string();
+    if url.is_empty() {
+        return Err("Git URL is required".to_string());
+    }
+    let git_ref = git_ref
+        .map(|r| r.trim().to_string())
+        .filter(|r| !r.is_empty())
+        .unwrap_or_else(|| "HEAD".to_string());
+
+    crate::desktop_log!("📥 Importing flow from git: {} @ {}", url, git_ref);
+
+    let clone_dir =
+        tempfile::tempdir().map_err(|e| format!("Failed to create temp directory: {}", e))?;
+    let clone_path = clone_dir.path().to_path_buf();
+    let fetch_url = url.clone();
+    let fetch_ref = git_ref.clone();
+
+    // init + fetch by ref works uniformly for branches, tags and commit SHAs.
+    let commit = tauri::async_runtime::spawn_blocking(move || -> Result<String, String> {
+        run_git(&["init", "--quiet"], &clone_path)?;
+        run_git(&["remote", "add", "origin", &fetch_url], &clone_path)?;
+        run_git(
+            &["fetch", "--depth", "1", "--quiet", "origin", &fetch_ref],
+            &clone_path,
+        )?;
+        run_git(&["checkout", "--quiet", "FETCH_HEAD"], &clone_path)?;
+        run_git(&["rev-parse", "HEAD"], &clone_path)
+    })
+    .await
+    .map_err(|e| format!("Git fetch task failed: {}", e))??;
+
+    let flow_yaml = find_flow_yaml(clone_dir.path())
+        .ok_or_else(|| format!("No {} found in {}", FLOW_YAML_FILE, url))?;
+
+    crate::desktop_log!(
+        "📄 Found {} at commit {}",
+        flow_yaml
+            .strip_prefix(clone_dir.path())
+            .unwrap_or(&flow_yaml)
+            .display(),
+        commit
+    );
+
+    let flow = create_flow(
+        state,
+        FlowCreateRequest {
+            name: String::new(),
+            directory: None,
+            flow_file: Some(flow_yaml.to_string_lossy().to_string()),
+            overwrite: overwrite.unwrap_or(false),
+        },
+    )
+    .await;
+
+    // Dropping the TempDir removes the clone whether or not the import worked.
+    drop(clone_dir);
+
+    let flow = flow?;
+    crate::desktop_log!("✅ Imported flow '{}' from {}@{}", flow.name, url, commit);
+    Ok(flow)
+}
+
 #[tauri::command]
 pub async fn load_flow_editor(
     state: tauri::State<'_, AppState>,
@@ -1788,6 +2077,74 @@ pub async fn load_flow_editor(
     })
 }
 
+/// Cached digest, valid while flow.yaml keeps the same path, mtime and size so
+/// edits made outside the editor are still picked up.
+struct CachedFlowDigest {
+    yaml_path: PathBuf,
+    modified: SystemTime,
+    size: u64,
+    digest: String,
+}
+
+static FLOW_SPEC_DIGESTS: Lazy<Mutex<HashMap<i64, CachedFlowDigest>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn invalidate_flow_spec_digest(flow_id: i64) {
+    if let Ok(mut cache) = FLOW_SPEC_DIGESTS.lock() {
+        cache.remove(&flow_id);
+    }
+}
+
+/// Stable digest of a flow's spec (key order and local paths don't count),
+/// cached until flow.yaml changes. None when the flow has no flow.yaml.
+#[tauri::command]
+pub fn get_flow_spec_digest(
+    state: tauri::State<AppState>,
+    flow_id: i64,
+) -> Result<Option<String>, String> {
+    let flow_path = {
+        let biovault_db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+        biovault_db
+            .get_flow(flow_id)
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| format!("Flow {} not found", flow_id))?
+            .flow_path
+    };
+    let yaml_path = PathBuf::from(&flow_path).join(FLOW_YAML_FILE);
+    let Ok(metadata) = fs::metadata(&yaml_path) else {
+        invalidate_flow_spec_digest(flow_id);
+        return Ok(None);
+    };
+    let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+    let size = metadata.len();
+
+    if let Ok(cache) = FLOW_SPEC_DIGESTS.lock() {
+        if let Some(cached) = cache.get(&flow_id) {
+            if cached.yaml_path == yaml_path && cached.modified == modified && cached.size == size {
+                return Ok(Some(cached.digest.clone()));
+            }
+        }
+    }
+
+    let content =
+        fs::read_to_string(&yaml_path).map_err(|e| format!("Failed to read flow.yaml: {}", e))?;
+    let spec: serde_json::Value =
+        serde_yaml::from_str(&content).map_err(|e| format!("Failed to parse flow.yaml: {}", e))?;
+    let digest = super::multiparty::flow_spec_digest(&spec);
+    if let Ok(mut cache) = FLOW_SPEC_DIGESTS.lock() {
+        cache.insert(
+            flow_id,
+            CachedFlowDigest {
+                yaml_path,
+                modified,
+                size,
+                digest: digest.clone(),
+            },
+        );
+    }
+    Ok(Some(digest))
+}
+
 #[tauri::command]
 pub async fn save_flow_editor(
     state: tauri::State<'_, AppState>,
@@ -1809,6 +2166,7 @@ pub async fn save_flow_editor(
 
     // Update or insert into database using CLI library
     if let Some(id) = flow_id {
+        invalidate_flow_spec_digest(id);
         // Update timestamp using CLI library
         biovault_db.touch_flow(id).map_err(|e| e.to_string())?;
 
@@ -1846,6 +2204,7 @@ pub async fn delete_flow(state: tauri::State<'_, AppState>, flow_id: i64) -> Res
         biovault_db
             .delete_flow(flow_id)
             .map_err(|e| e.to_string())?;
+        invalidate_flow_spec_digest(flow_id);
 
         // Delete directory if it exists and is in the flows folder
         let flows_dir = get_flows_dir()?;
@@ -1907,102 +2266,611 @@ pub async fn validate_flow(flow_path: String) -> Result<FlowValidationResult, St
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct FlowInputDescription {
+    pub name: String,
+    pub raw_type: String,
+    pub optional: bool,
+    /// Plain-language reading of the type, e.g. "a map of name to {vcf: File, tbi: File}".
+    pub description: String,
+    /// A local dataset of this shape can be bound directly.
+    pub dataset_selectable: bool,
+    /// Individual files picked by URL (a samplesheet or list of files) can feed it.
+    pub url_selectable: bool,
+}
+
+fn describe_shape(shape: &ShapeExpr) -> String {
+    match shape {
+        ShapeExpr::String => "text".to_string(),
+        ShapeExpr::Bool => "true/false".to_string(),
+        ShapeExpr::File => "a file".to_string(),
+        ShapeExpr::Directory => "a folder".to_string(),
+        ShapeExpr::GenotypeRecord => "a genotype file with participant metadata".to_string(),
+        ShapeExpr::List(inner) => format!("a list of {}", describe_shape_item(inner)),
+        ShapeExpr::Map(inner) => format!("a map of name to {}", describe_shape_item(inner)),
+        ShapeExpr::Record(fields) => format!(
+            "{{{}}}",
+            fields
+                .iter()
+                .map(|field| format!("{}: {}", field.name, describe_shape_item(&field.ty)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// Nested shapes read better by type name ("File") than by article ("a file").
+fn describe_shape_item(shape: &ShapeExpr) -> String {
+    match shape {
+        ShapeExpr::String => "String".to_string(),
+        ShapeExpr::Bool => "Bool".to_string(),
+        ShapeExpr::File => "File".to_string(),
+        ShapeExpr::Directory => "Directory".to_string(),
+        ShapeExpr::GenotypeRecord => "GenotypeRecord".to_string(),
+        other => describe_shape(other),
+    }
+}
+
+fn is_file_like(shape: &ShapeExpr) -> bool {
+    matches!(shape, ShapeExpr::File | ShapeExpr::Directory)
+}
+
+/// Mirrors what `build_dataset_input_value` can produce.
+fn is_dataset_selectable(shape: &ShapeExpr) -> bool {
+    match shape {
+        ShapeExpr::File | ShapeExpr::Directory => true,
+        ShapeExpr::Record(fields) => fields.iter().all(|f| is_file_like(&f.ty)),
+        ShapeExpr::Map(inner) => match inner.as_ref() {
+            ShapeExpr::Record(fields) => fields.iter().all(|f| is_file_like(&f.ty)),
+            other => is_file_like(other),
+        },
+        _ => false,
+    }
+}
+
+/// Explain each flow input's shape and which pickers can satisfy it.
 #[tauri::command]
-pub async fn run_flow(
+pub async fn describe_flow_inputs(
     state: tauri::State<'_, AppState>,
-    window: tauri::WebviewWindow,
     flow_id: i64,
-    input_overrides: HashMap<String, String>,
-    results_dir: Option<String>,
-    selection: Option<FlowRunSelection>,
-    nextflow_max_forks: Option<u32>,
-    resume: Option<bool>,
-) -> Result<Run, String> {
-    run_flow_impl(
-        state,
-        Some(window),
-        flow_id,
-        input_overrides,
-        results_dir,
-        selection,
-        None,
-        nextflow_max_forks,
-        resume.unwrap_or(false),
-        None,
-    )
-    .await
+) -> Result<Vec<FlowInputDescription>, String> {
+    let flow_path = {
+        let biovault_db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+        biovault_db
+            .get_flow(flow_id)
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| format!("Flow {} not found", flow_id))?
+            .flow_path
+    };
+    let spec = FlowSpec::load(&PathBuf::from(&flow_path).join(FLOW_YAML_FILE))
+        .map_err(|e| format!("Failed to load flow spec: {}", e))?;
+
+    Ok(spec
+        .inputs
+        .iter()
+        .map(|(name, input_spec)| {
+            let raw_type = input_spec.raw_type().to_string();
+            let optional = raw_type.trim().ends_with('?');
+            let shape = parse_shape_expr(&raw_type);
+            let (description, dataset_selectable, list_of_files) = match &shape {
+                Some(shape) => (
+                    describe_shape(shape),
+                    is_dataset_selectable(shape),
+                    matches!(shape, ShapeExpr::List(inner)
+                        if is_file_like(inner) || matches!(inner.as_ref(), ShapeExpr::GenotypeRecord)),
+                ),
+                None => (format!("a value of type {}", raw_type), false, false),
+            };
+            FlowInputDescription {
+                // URL selection is written out as the `samplesheet` input.
+                url_selectable: list_of_files || name == "samplesheet",
+                name: name.clone(),
+                raw_type,
+                optional,
+                description,
+                dataset_selectable,
+            }
+        })
+        .collect())
 }
 
-/// Internal implementation that takes an optional window (for WS bridge mode)
-pub async fn run_flow_impl(
-    state: tauri::State<'_, AppState>,
-    window: Option<tauri::WebviewWindow>,
-    flow_id: i64,
-    mut input_overrides: HashMap<String, String>,
-    results_dir: Option<String>,
-    selection: Option<FlowRunSelection>,
-    run_id: Option<String>,
-    nextflow_max_forks: Option<u32>,
-    resume: bool,
-    existing_run_id: Option<i64>,
-) -> Result<Run, String> {
-    use chrono::Local;
+#[derive(Debug, Serialize)]
+pub struct PreviewInputValue {
+    pub name: String,
+    pub input_type: String,
+    /// What the run would pass for this input; None when the selection doesn't feed it.
+    pub value: Option<serde_json::Value>,
+    pub source: Option<String>,
+}
 
-    let home = biovault::config::get_biovault_home()
-        .map_err(|e| format!("Failed to get BioVault home: {}", e))?;
+#[derive(Debug, Serialize)]
+pub struct PreviewInputFile {
+    pub participant: Option<String>,
+    pub path: String,
+    pub exists: bool,
+}
 
-    let mut selection_metadata: Option<serde_json::Value> = None;
-    let mut selection_counts: Option<(usize, usize)> = None;
-    let mut generated_samplesheet_path: Option<String> = None;
+#[derive(Debug, Serialize)]
+pub struct RunInputsPreview {
+    pub flow_id: i64,
+    pub inputs: Vec<PreviewInputValue>,
+    pub files: Vec<PreviewInputFile>,
+    pub errors: Vec<String>,
+    pub ready: bool,
+}
 
-    let biovault_db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+fn collect_json_paths(value: &serde_json::Value, out: &mut Vec<String>) {
+    match value {
+        serde_json::Value::String(path) => out.push(path.clone()),
+        serde_json::Value::Array(items) => items.iter().for_each(|v| collect_json_paths(v, out)),
+        serde_json::Value::Object(map) => map.values().for_each(|v| collect_json_paths(v, out)),
+        _ => {}
+    }
+}
 
-    // Get flow using CLI library
-    let flow = biovault_db
-        .get_flow(flow_id)
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Flow {} not found", flow_id))?;
+#[derive(Debug, Serialize)]
+pub struct DatasetShapeCompatibility {
+    pub input_name: String,
+    pub input_type: String,
+    pub dataset_name: String,
+    pub dataset_shape: Option<String>,
+    pub compatible: bool,
+    /// Record fields the dataset has no file for, as `field` or `entry.field`.
+    pub missing_fields: Vec<String>,
+    /// Resolved paths that don't exist on disk.
+    pub missing_files: Vec<String>,
+    pub error: Option<String>,
+    /// What the run would pass for the input, when the dataset fits.
+    pub value: Option<serde_json::Value>,
+}
 
-    let flow_name = flow.name.clone();
-    let flow_path = flow.flow_path.clone();
+/// Record fields (keyed by extension, like `build_dataset_input_value`) that no
+/// asset covers. Map-of-record entries are reported as `entry.field`.
+fn missing_dataset_fields(
+    db: &BioVaultDb,
+    assets: &[biovault::data::DatasetAssetRecord],
+    data_type: &str,
+    shape: &ShapeExpr,
+) -> Vec<String> {
+    let (fields, per_entry) = match shape {
+        ShapeExpr::Record(fields) => (fields, false),
+        ShapeExpr::Map(inner) => match inner.as_ref() {
+            ShapeExpr::Record(fields) => (fields, true),
+            _ => return Vec::new(),
+        },
+        _ => return Vec::new(),
+    };
 
-    let mut existing_run: Option<Run> = None;
-    if let Some(existing_id) = existing_run_id {
-        existing_run = biovault_db
-            .get_flow_run(existing_id)
-            .map_err(|e| e.to_string())?;
+    let mut present: HashMap<String, HashSet<String>> = HashMap::new();
+    for asset in assets {
+        let Some(path) = resolve_asset_path(db, asset, data_type) else {
+            continue;
+        };
+        let Some((stem, ext)) = parse_stem_and_ext(&path) else {
+            continue;
+        };
+        let entry = if per_entry { stem } else { String::new() };
+        present.entry(entry).or_default().insert(ext);
+    }
+    if present.is_empty() {
+        present.insert(String::new(), HashSet::new());
     }
 
-    let yaml_path = PathBuf::from(&flow_path).join(FLOW_YAML_FILE);
+    let mut entries: Vec<_> = present.into_iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    let mut missing = Vec::new();
+    for (entry, exts) in entries {
+        for field in fields {
+            if exts.contains(&field.name.to_ascii_lowercase()) {
+                continue;
+            }
+            if entry.is_empty() {
+                missing.push(field.name.clone());
+            } else {
+                missing.push(format!("{}.{}", entry, field.name));
+            }
+        }
+    }
+    missing
+}
 
-    // Generate results directory
-    let timestamp = Local::now().format("%Y%m%d_%H%M%S").to_string();
-    let results_path = if let Some(run) = existing_run.as_ref() {
-        run.results_dir
-            .as_ref()
-            .map(PathBuf::from)
-            .unwrap_or_else(|| PathBuf::from(&run.work_dir))
-    } else if let Some(dir) = &results_dir {
-        PathBuf::from(dir)
-    } else {
-        home.join("runs").join(format!("flow_{}", timestamp))
+/// Check whether a local dataset can feed a specific flow input, so a shape
+/// mismatch surfaces at selection time instead of at run launch.
+#[tauri::command]
+pub async fn check_dataset_shape_compatibility(
+    state: tauri::State<'_, AppState>,
+    flow_id: i64,
+    input_name: String,
+    dataset_name: String,
+    data_type: Option<String>,
+) -> Result<DatasetShapeCompatibility, String> {
+    let biovault_db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+    let flow = biovault_db
+        .get_flow(flow_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Flow {} not found", flow_id))?;
+    let spec = FlowSpec::load(&PathBuf::from(&flow.flow_path).join(FLOW_YAML_FILE))
+        .map_err(|e| format!("Failed to load flow spec: {}", e))?;
+    let input_type = spec
+        .inputs
+        .iter()
+        .find(|(name, _)| **name == input_name)
+        .map(|(_, input_spec)| input_spec.raw_type().to_string())
+        .ok_or_else(|| format!("Flow has no input named '{}'", input_name))?;
+
+    let (dataset_record, dataset_assets) =
+        biovault::data::get_dataset_with_assets(&biovault_db, &dataset_name)
+            .map_err(|e| format!("Failed to load dataset '{}': {}", dataset_name, e))?
+            .ok_or_else(|| format!("Dataset '{}' not found", dataset_name))?;
+    let manifest = biovault::data::build_manifest_from_db(&dataset_record, &dataset_assets);
+    let dataset_shape = biovault::cli::commands::datasets::infer_dataset_shape(&manifest);
+
+    let mut result = DatasetShapeCompatibility {
+        input_name,
+        input_type: input_type.clone(),
+        dataset_name,
+        dataset_shape: dataset_shape.clone(),
+        compatible: false,
+        missing_fields: Vec::new(),
+        missing_files: Vec::new(),
+        error: None,
+        value: None,
     };
 
-    // Create results directory
-    fs::create_dir_all(&results_path)
-        .map_err(|e| format!("Failed to create results directory: {}", e))?;
+    let Some(input_shape) = parse_shape_expr(&input_type) else {
+        result.error = Some(format!(
+            "Input type '{}' can't be filled from a dataset.",
+            input_type
+        ));
+        return Ok(result);
+    };
+    if let Some(shape) = dataset_shape.as_deref() {
+        if !biovault::module_spec::types_compatible(shape, &input_type) {
+            result.error = Some(format!(
+                "Dataset shape '{}' does not match input type '{}'.",
+                shape, input_type
+            ));
+        }
+    }
 
-    let log_path = results_path.join("flow.log");
-    append_flow_log(
-        window.as_ref(),
-        &log_path,
-        &format!("📦 Flow: {}", flow_name),
-    );
-    append_flow_log(
-        window.as_ref(),
+    let data_type = data_type.as_deref().unwrap_or("mock");
+    result.missing_fields =
+        missing_dataset_fields(&biovault_db, &dataset_assets, data_type, &input_shape);
+    match build_dataset_input_value(&biovault_db, &dataset_assets, data_type, &input_shape) {
+        Ok((value, _)) => {
+            let value = match value {
+                DatasetInputValue::Path(path) => serde_json::Value::String(path),
+                DatasetInputValue::Json(value) => value,
+            };
+            let mut paths = Vec::new();
+            collect_json_paths(&value, &mut paths);
+            result.missing_files = paths
+                .into_iter()
+                .filter(|path| !Path::new(path).exists())
+                .collect();
+            result.value = Some(value);
+        }
+        Err(e) => {
+            result.error.get_or_insert(e);
+        }
+    }
+    result.compatible = result.error.is_none()
+        && result.missing_fields.is_empty()
+        && result.missing_files.is_empty();
+    Ok(result)
+}
+
+/// Resolve a data selection exactly as `run_flow` would (dataset shapes, URLs,
+/// legacy file ids) and report the resulting input values, without creating
+/// a run or writing any files.
+#[tauri::command]
+pub async fn preview_run_inputs(
+    state: tauri::State<'_, AppState>,
+    flow_id: i64,
+    selection: FlowRunSelection,
+) -> Result<RunInputsPreview, String> {
+    let biovault_db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+    let flow = biovault_db
+        .get_flow(flow_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Flow {} not found", flow_id))?;
+    let yaml_path = PathBuf::from(&flow.flow_path).join(FLOW_YAML_FILE);
+    let spec =
+        FlowSpec::load(&yaml_path).map_err(|e| format!("Failed to load flow spec: {}", e))?;
+
+    let mut resolved: HashMap<String, (serde_json::Value, String)> = HashMap::new();
+    let mut files: Vec<PreviewInputFile> = Vec::new();
+    let mut errors: Vec<String> = Vec::new();
+
+    let dataset_name = selection
+        .dataset_name
+        .as_deref()
+        .map(str::trim)
+        .filter(|name| !name.is_empty());
+    let is_network_dataset = selection.data_source.as_deref() == Some("network_dataset");
+    let mut dataset_handled = false;
+    if let (Some(dataset_name), false) = (dataset_name, is_network_dataset) {
+        match resolve_dataset_selection(
+            &biovault_db,
+            &yaml_path,
+            dataset_name,
+            selection.dataset_shape.as_deref(),
+            selection.dataset_data_type.as_deref(),
+        ) {
+            Ok(Some(dataset)) => {
+                let value = match dataset.value {
+                    DatasetInputValue::Path(path) => serde_json::Value::String(path),
+                    DatasetInputValue::Json(value) => value,
+                };
+                let mut paths = Vec::new();
+                collect_json_paths(&value, &mut paths);
+                files.extend(paths.into_iter().map(|path| PreviewInputFile {
+                    participant: None,
+                    exists: Path::new(&path).exists(),
+                    path,
+                }));
+                resolved.insert(
+                    dataset.input_name,
+                    (
+                        value,
+                        format!("dataset '{}' ({})", dataset_name, dataset.shape),
+                    ),
+                );
+                dataset_handled = true;
+            }
+            Ok(None) => {}
+            Err(e) => {
+                errors.push(e);
+                dataset_handled = true;
+            }
+        }
+    }
+
+    if !dataset_handled {
+        let mut rows: Vec<(String, String)> = Vec::new();
+        let mut file_column = "genotype_file";
+        let mut source = None;
+        if !selection.urls.is_empty() {
+            source = Some("urls".to_string());
+            match resolve_url_selection(&selection.urls, &selection.participant_ids) {
+                Ok(resolution) => {
+                    errors.extend(resolution.missing.iter().map(|(url, local_path)| {
+                        format!(
+                            "File not found for URL: {} -> {}",
+                            url,
+                            local_path.display()
+                        )
+                    }));
+                    rows = resolution.rows;
+                }
+                Err(e) => errors.push(e),
+            }
+        } else if !selection.file_ids.is_empty() {
+            source = Some("file_ids".to_string());
+            match resolve_file_id_selection(&biovault_db, &selection.file_ids) {
+                Ok(records) => {
+                    if selection_has_aligned_files(&records) {
+                        file_column = "aligned_file";
+                    }
+                    rows = records
+                        .into_iter()
+                        .map(|(participant, record)| (participant, record.file_path))
+                        .collect();
+                }
+                Err(e) => errors.push(e),
+            }
+        }
+
+        if let Some(source) = source {
+            if rows.is_empty() {
+                errors.push("No files could be resolved from the selection.".to_string());
+            } else {
+                let sheet: Vec<serde_json::Value> = rows
+                    .iter()
+                    .map(|(participant, path)| {
+                        serde_json::json!({ "participant_id": participant, file_column: path })
+                    })
+                    .collect();
+                resolved.insert(
+                    "samplesheet".to_string(),
+                    (serde_json::Value::Array(sheet), source),
+                );
+                files.extend(
+                    rows.into_iter()
+                        .map(|(participant, path)| PreviewInputFile {
+                            participant: Some(participant),
+                            exists: Path::new(&path).exists(),
+                            path,
+                        }),
+                );
+            }
+        } else if dataset_name.is_none() {
+            errors.push("Selection is empty".to_string());
+        }
+    }
+
+    for file in files.iter().filter(|f| !f.exists) {
+        errors.push(format!("Input file does not exist: {}", file.path));
+    }
+
+    let mut inputs: Vec<PreviewInputValue> = spec
+        .inputs
+        .iter()
+        .map(|(name, input_spec)| {
+            let entry = resolved.remove(name);
+            PreviewInputValue {
+                name: name.clone(),
+                input_type: input_spec.raw_type().to_string(),
+                value: entry.as_ref().map(|(value, _)| value.clone()),
+                source: entry.map(|(_, source)| source),
+            }
+        })
+        .collect();
+    // Values for inputs the flow doesn't declare (e.g. an implicit samplesheet).
+    inputs.extend(
+        resolved
+            .into_iter()
+            .map(|(name, (value, source))| PreviewInputValue {
+                name,
+                input_type: "unknown".to_string(),
+                value: Some(value),
+                source: Some(source),
+            }),
+    );
+
+    let ready = errors.is_empty() && inputs.iter().any(|i| i.value.is_some());
+    Ok(RunInputsPreview {
+        flow_id,
+        inputs,
+        files,
+        errors,
+        ready,
+    })
+}
+
+#[tauri::command]
+pub async fn run_flow(
+    state: tauri::State<'_, AppState>,
+    window: tauri::WebviewWindow,
+    flow_id: i64,
+    input_overrides: HashMap<String, String>,
+    results_dir: Option<String>,
+    selection: Option<FlowRunSelection>,
+    nextflow_max_forks: Option<u32>,
+    resume: Option<bool>,
+) -> Result<Run, String> {
+    run_flow_impl(
+        state,
+        window.app_handle().clone(),
+        Some(window),
+        flow_id,
+        input_overrides,
+        results_dir,
+        selection,
+        None,
+        nextflow_max_forks,
+        resume.unwrap_or(false),
+        None,
+    )
+    .await
+}
+
+/// Internal implementation that takes an optional window (for WS bridge mode)
+pub async fn run_flow_impl(
+    state: tauri::State<'_, AppState>,
+    app: tauri::AppHandle,
+    window: Option<tauri::WebviewWindow>,
+    flow_id: i64,
+    mut input_overrides: HashMap<String, String>,
+    results_dir: Option<String>,
+    selection: Option<FlowRunSelection>,
+    run_id: Option<String>,
+    nextflow_max_forks: Option<u32>,
+    resume: bool,
+    existing_run_id: Option<i64>,
+) -> Result<Run, String> {
+    use chrono::Local;
+
+    let nextflow_max_forks = nextflow_max_forks.or_else(|| {
+        super::settings::get_settings()
+            .ok()
+            .and_then(|settings| settings.default_max_forks)
+    });
+    let mut selection_metadata: Option<serde_json::Value> = None;
+    let mut selection_counts: Option<(usize, usize)> = None;
+    let mut generated_samplesheet_path: Option<String> = None;
+
+    let mut biovault_db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+
+    // Get flow using CLI library
+    let flow = biovault_db
+        .get_flow(flow_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Flow {} not found", flow_id))?;
+
+    let flow_name = flow.name.clone();
+    let flow_path = flow.flow_path.clone();
+
+    let mut existing_run: Option<Run> = None;
+    if let Some(existing_id) = existing_run_id {
+        existing_run = biovault_db
+            .get_flow_run(existing_id)
+            .map_err(|e| e.to_string())?;
+    }
+
+    let yaml_path = PathBuf::from(&flow_path).join(FLOW_YAML_FILE);
+
+    // Generate results directory
+    let timestamp = Local::now().format("%Y%m%d_%H%M%S").to_string();
+    let run_dir_name = format!("flow_{}", timestamp);
+    let (results_path, work_path) = if let Some(run) = existing_run.as_ref() {
+        let results = run
+            .results_dir
+            .as_ref()
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(&run.work_dir));
+        (results, PathBuf::from(&run.work_dir))
+    } else if let Some(dir) = &results_dir {
+        (PathBuf::from(dir), PathBuf::from(dir))
+    } else {
+        // Work and results share one run dir unless separate roots are configured.
+        let (work_root, results_root) = super::settings::resolve_run_roots()?;
+        (
+            results_root.join(&run_dir_name),
+            work_root.join(&run_dir_name),
+        )
+    };
+    let separate_work_dir = work_path != results_path;
+
+    let mut space_warnings = Vec::new();
+    let mut input_bytes: Option<u64> = None;
+    if existing_run.is_none() {
+        let mut input_paths: Vec<PathBuf> = input_overrides.values().map(PathBuf::from).collect();
+        if let Some(sel) = &selection {
+            input_paths.extend(selection_file_paths(&biovault_db, &sel.file_ids));
+        }
+        let history = run_space_history(&biovault_db, flow_id);
+        // Sizing inputs and past runs walks the disk; don't block the app on the DB lock.
+        drop(biovault_db);
+        let space = check_run_space(&history, flow_id, &input_paths, &work_path, &results_path);
+        biovault_db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+        let space = space?;
+        if space.blocked {
+            return Err(space.warnings.join("; "));
+        }
+        input_bytes = Some(space.input_bytes);
+        space_warnings = space.warnings;
+    }
+
+    // Create results directory
+    fs::create_dir_all(&results_path)
+        .map_err(|e| format!("Failed to create results directory: {}", e))?;
+    if separate_work_dir {
+        fs::create_dir_all(&work_path)
+            .map_err(|e| format!("Failed to create work directory: {}", e))?;
+    }
+
+    let log_path = results_path.join("flow.log");
+    append_flow_log(
+        window.as_ref(),
+        &log_path,
+        &format!("📦 Flow: {}", flow_name),
+    );
+    append_flow_log(
+        window.as_ref(),
         &log_path,
         &format!("📂 Results directory: {}", results_path.display()),
     );
+    if separate_work_dir {
+        append_flow_log(
+            window.as_ref(),
+            &log_path,
+            &format!("🗂️  Work directory: {}", work_path.display()),
+        );
+    }
+    for warning in &space_warnings {
+        append_flow_log(window.as_ref(), &log_path, &format!("⚠️ {}", warning));
+    }
     let _ = fs::remove_file(flow_pause_marker(&results_path));
 
     if let Some(sel) = &selection {
@@ -2096,73 +2964,22 @@ pub async fn run_flow_impl(
                     dataset_name
                 );
             } else {
-                let data_type = dataset_data_type
-                    .clone()
-                    .unwrap_or_else(|| "mock".to_string());
-                let (dataset_record, dataset_assets) =
-                    biovault::data::get_dataset_with_assets(&biovault_db, &dataset_name)
-                        .map_err(|e| format!("Failed to load dataset '{}': {}", dataset_name, e))?
-                        .ok_or_else(|| format!("Dataset '{}' not found", dataset_name))?;
-
-                let manifest =
-                    biovault::data::build_manifest_from_db(&dataset_record, &dataset_assets);
-                let shape = dataset_shape
-                    .clone()
-                    .and_then(|value| {
-                        let trimmed = value.trim();
-                        if trimmed.is_empty() {
-                            None
-                        } else {
-                            Some(trimmed.to_string())
-                        }
-                    })
-                    .or_else(|| biovault::cli::commands::datasets::infer_dataset_shape(&manifest))
-                    .ok_or_else(|| {
-                        format!(
-                            "Dataset '{}' does not declare a shape and none could be inferred.",
-                            dataset_name
-                        )
-                    })?;
-
-                let shape_expr = parse_shape_expr(&shape).ok_or_else(|| {
-                    format!("Unsupported dataset shape '{}' for selection.", shape)
-                })?;
-
                 // List-shaped datasets need URL selection, fall through to URL/file_id paths
-                if let ShapeExpr::List(inner_type) = &shape_expr {
-                    eprintln!(
-                    "[flow] Dataset '{}' has List shape (item type: {:?}), using URL selection path",
-                    dataset_name, inner_type
-                );
-                } else {
-                    let spec = FlowSpec::load(&yaml_path)
-                        .map_err(|e| format!("Failed to load flow spec: {}", e))?;
-                    let input_name = spec
-                        .inputs
-                        .iter()
-                        .find(|(_, input_spec)| {
-                            biovault::module_spec::types_compatible(&shape, input_spec.raw_type())
-                        })
-                        .map(|(name, _)| name.clone())
-                        .ok_or_else(|| {
-                            format!("Flow does not declare an input compatible with '{}'", shape)
-                        })?;
-
-                    let (dataset_value, file_count) = build_dataset_input_value(
-                        &biovault_db,
-                        &dataset_assets,
-                        &data_type,
-                        &shape_expr,
-                    )?;
-
-                    let dataset_count = match &shape_expr {
-                        ShapeExpr::Map(_) => match &dataset_value {
-                            DatasetInputValue::Json(serde_json::Value::Object(map)) => map.len(),
-                            _ => 0,
-                        },
-                        ShapeExpr::Record(_) | ShapeExpr::File | ShapeExpr::Directory => 1,
-                        _ => 0,
-                    };
+                if let Some(resolved) = resolve_dataset_selection(
+                    &biovault_db,
+                    &yaml_path,
+                    &dataset_name,
+                    dataset_shape.as_deref(),
+                    dataset_data_type.as_deref(),
+                )? {
+                    let DatasetSelectionResolution {
+                        input_name,
+                        shape,
+                        data_type,
+                        value: dataset_value,
+                        file_count,
+                        dataset_count,
+                    } = resolved;
 
                     let input_path = match dataset_value {
                         DatasetInputValue::Path(path) => path,
@@ -2197,7 +3014,6 @@ pub async fn run_flow_impl(
 
                     dataset_handled = true;
                 }
-                // If List-shaped, fall through to URL/file_id handling below
             }
         }
 
@@ -2205,59 +3021,23 @@ pub async fn run_flow_impl(
             // dataset selection handled, skip legacy flows
         } else if use_urls {
             // Resolve syft:// URLs to local paths
-            let config = biovault::config::Config::load()
-                .map_err(|e| format!("Failed to load config: {}", e))?;
-            let data_dir = config
-                .get_syftbox_data_dir()
-                .map_err(|e| format!("Failed to get SyftBox data dir: {}", e))?;
-
-            let mut seen_urls = HashSet::new();
-            let mut unique_urls = Vec::new();
-            for url in urls {
-                if seen_urls.insert(url.clone()) {
-                    unique_urls.push(url);
-                }
-            }
-
-            if unique_urls.is_empty() {
-                return Err("No valid URLs were provided for the flow run.".to_string());
-            }
-
-            let mut rows = Vec::new();
-            let mut participant_labels_set: HashSet<String> = HashSet::new();
-            let mut resolved_count = 0;
-
-            for (idx, url) in unique_urls.iter().enumerate() {
-                let local_path = biovault::data::resolve_syft_url(&data_dir, url)
-                    .map_err(|e| format!("Failed to resolve URL '{}': {}", url, e))?;
-
-                if !local_path.exists() {
-                    append_flow_log(
-                        window.as_ref(),
-                        &log_path,
-                        &format!("⚠️  File not found for URL: {} -> {:?}", url, local_path),
-                    );
-                    continue;
-                }
-
-                resolved_count += 1;
-                let file_path = local_path.to_string_lossy().to_string();
-
-                // Use participant_id from selection if provided, otherwise extract from filename
-                let participant = if idx < participant_ids.len() && !participant_ids[idx].is_empty()
-                {
-                    participant_ids[idx].clone()
-                } else {
-                    local_path
-                        .file_stem()
-                        .and_then(|s| s.to_str())
-                        .unwrap_or("unknown")
-                        .to_string()
-                };
-
-                participant_labels_set.insert(participant.clone());
-                rows.push((participant, file_path));
+            let UrlSelectionResolution {
+                unique_urls,
+                rows,
+                missing,
+            } = resolve_url_selection(&urls, &participant_ids)?;
+            for (url, local_path) in &missing {
+                append_flow_log(
+                    window.as_ref(),
+                    &log_path,
+                    &format!("⚠️  File not found for URL: {} -> {:?}", url, local_path),
+                );
             }
+            let resolved_count = rows.len();
+            let participant_labels_set: HashSet<String> = rows
+                .iter()
+                .map(|(participant, _)| participant.clone())
+                .collect();
 
             if rows.is_empty() {
                 return Err("No files could be resolved from the provided URLs.".to_string());
@@ -2310,48 +3090,15 @@ pub async fn run_flow_impl(
             selection_metadata = Some(selection_value);
         } else if use_file_ids {
             // Legacy: use file_ids (deprecated)
-            let mut seen_files = HashSet::new();
-            let mut unique_file_ids = Vec::new();
-            for id in file_ids {
-                if seen_files.insert(id) {
-                    unique_file_ids.push(id);
-                }
-            }
-
-            if unique_file_ids.is_empty() {
+            let records = resolve_file_id_selection(&biovault_db, &file_ids)?;
+            if records.is_empty() {
                 return Err("No valid file IDs were provided for the flow run.".to_string());
             }
-
-            let mut records = Vec::new();
-            let mut participant_labels_set: HashSet<String> = HashSet::new();
-
-            for file_id in &unique_file_ids {
-                let record = biovault::data::get_file_by_id(&biovault_db, *file_id)
-                    .map_err(|e| format!("Failed to load file {}: {}", file_id, e))?
-                    .ok_or_else(|| format!("File {} not found in the BioVault catalog", file_id))?;
-
-                if record.file_path.trim().is_empty() {
-                    return Err(format!(
-                        "File {} does not have a recorded path in the catalog.",
-                        file_id
-                    ));
-                }
-
-                let participant = record
-                    .participant_id
-                    .clone()
-                    .filter(|value| !value.trim().is_empty())
-                    .unwrap_or_else(|| {
-                        Path::new(&record.file_path)
-                            .file_stem()
-                            .and_then(|s| s.to_str())
-                            .unwrap_or("unknown")
-                            .to_string()
-                    });
-
-                participant_labels_set.insert(participant.clone());
-                records.push((participant, record));
-            }
+            let participant_labels_set: HashSet<String> = records
+                .iter()
+                .map(|(participant, _)| participant.clone())
+                .collect();
+            let unique_file_ids: Vec<i64> = records.iter().map(|(_, r)| r.id).collect();
 
             let dedup_participant_ids: Vec<String> = {
                 let mut seen = HashSet::new();
@@ -2368,10 +3115,7 @@ pub async fn run_flow_impl(
             let sheet_path = inputs_dir.join("selected_participants.csv");
 
             // Detect if we have aligned files (CRAM/BAM) vs genotype files
-            let has_aligned = records.iter().any(|(_, r)| {
-                let dt = r.data_type.as_deref().unwrap_or("");
-                dt == "Aligned" || r.file_path.ends_with(".cram") || r.file_path.ends_with(".bam")
-            });
+            let has_aligned = selection_has_aligned_files(&records);
 
             let mut writer = csv::Writer::from_path(&sheet_path)
                 .map_err(|e| format!("Failed to create samplesheet: {}", e))?;
@@ -2621,7 +3365,7 @@ pub async fn run_flow_impl(
         let run_db_id = biovault_db
             .create_flow_run_with_metadata(
                 flow_id,
-                &results_path.to_string_lossy(),
+                &work_path.to_string_lossy(),
                 Some(&results_path.to_string_lossy()),
                 Some(&metadata_str),
             )
@@ -2644,8 +3388,13 @@ pub async fn run_flow_impl(
     let flow_name_clone = flow_name.clone();
     let yaml_path_spawn = yaml_path_str.clone();
     let results_dir_spawn = results_dir_str.clone();
+    let nxf_work_spawn =
+        separate_work_dir.then(|| work_path.join("work").to_string_lossy().to_string());
     let extra_args_spawn = extra_args.clone();
     let resume_flag = resume;
+    // Resumed runs and runs without a file selection aren't comparable to a
+    // fresh run over known inputs, so they stay out of the duration history.
+    let duration_inputs = selection_counts.zip(input_bytes);
 
     let run_id_override = run_id
         .as_ref()
@@ -2653,6 +3402,7 @@ pub async fn run_flow_impl(
         .filter(|v| !v.is_empty());
 
     tauri::async_runtime::spawn(async move {
+        let run_started = std::time::Instant::now();
         append_flow_log(
             window_clone.as_ref(),
             &log_path_clone,
@@ -2720,6 +3470,15 @@ pub async fn run_flow_impl(
             "BIOVAULT_DESKTOP_LOG_FILE",
             log_path_clone.to_string_lossy().to_string(),
         );
+        let previous_nxf_work = std::env::var("NXF_WORK").ok();
+        if let Some(dir) = nxf_work_spawn.as_ref() {
+            std::env::set_var("NXF_WORK", dir);
+            append_flow_log(
+                window_clone.as_ref(),
+                &log_path_clone,
+                &format!("🔧 Using NXF_WORK={}", dir),
+            );
+        }
         let previous_pid_file = std::env::var("BIOVAULT_FLOW_PID_FILE").ok();
         let pid_path = PathBuf::from(&results_dir_spawn).join("flow.pid");
         std::env::set_var(
@@ -2736,6 +3495,16 @@ pub async fn run_flow_impl(
         );
 
         let pause_marker_path = PathBuf::from(&results_dir_spawn).join(".flow.pause");
+        let image_monitor_done = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        if let Some(flow_dir) = Path::new(&yaml_path_spawn).parent() {
+            super::container_images::spawn_image_pull_monitor(
+                window_clone.clone(),
+                run_id_clone,
+                flow_dir.to_path_buf(),
+                log_path_clone.clone(),
+                image_monitor_done.clone(),
+            );
+        }
         let result = cli_run_flow(
             &yaml_path_spawn,
             extra_args_spawn.clone(),
@@ -2744,6 +3513,7 @@ pub async fn run_flow_impl(
             Some(results_dir_spawn.clone()),
         )
         .await;
+        image_monitor_done.store(true, std::sync::atomic::Ordering::SeqCst);
 
         match previous_desktop_log {
             Some(prev) => std::env::set_var("BIOVAULT_DESKTOP_LOG_FILE", prev),
@@ -2753,6 +3523,12 @@ pub async fn run_flow_impl(
             Some(prev) => std::env::set_var("BIOVAULT_FLOW_PID_FILE", prev),
             None => std::env::remove_var("BIOVAULT_FLOW_PID_FILE"),
         }
+        if nxf_work_spawn.is_some() {
+            match previous_nxf_work {
+                Some(prev) => std::env::set_var("NXF_WORK", prev),
+                None => std::env::remove_var("NXF_WORK"),
+            }
+        }
 
         match (run_id_override.as_ref(), previous_run_id) {
             (Some(_), Some(prev)) => std::env::set_var("BIOVAULT_FLOW_RUN_ID", prev),
@@ -2795,6 +3571,31 @@ pub async fn run_flow_impl(
         // Update status using CLI library
         if let Ok(biovault_db) = biovault_db_clone.lock() {
             let _ = biovault_db.update_flow_run_status(run_id_clone, status, true);
+            let recorded_inputs = duration_inputs.filter(|_| status == "success");
+            if let Some(((file_count, participant_count), input_bytes)) = recorded_inputs {
+                if let Err(err) = record_flow_run_duration(
+                    &biovault_db,
+                    run_id_clone,
+                    &flow_name_clone,
+                    RunInputSize {
+                        participant_count,
+                        file_count,
+                        input_bytes,
+                    },
+                    run_started.elapsed().as_secs(),
+                ) {
+                    crate::desktop_log!("⚠️ Failed to record run duration: {}", err);
+                }
+            }
+            if status != "paused" {
+                super::webhooks::notify_run_completion(
+                    &biovault_db,
+                    run_id_clone,
+                    status,
+                    Some(run_started.elapsed().as_secs()),
+                );
+                super::notifications::notify_run_finished(&app, &biovault_db, run_id_clone, status);
+            }
         }
 
         if let Some(w) = &window_clone {
@@ -2806,59 +3607,84 @@ pub async fn run_flow_impl(
 }
 
 #[tauri::command]
-pub async fn get_flow_runs(state: tauri::State<'_, AppState>) -> Result<Vec<Run>, String> {
+pub async fn get_flow_runs(
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<crate::types::FlowRunRecord>, String> {
     let biovault_db = state.biovault_db.lock().map_err(|e| e.to_string())?;
-    biovault_db.list_flow_runs().map_err(|e| e.to_string())
+    let runs = biovault_db.list_flow_runs().map_err(|e| e.to_string())?;
+    super::runs::with_flow_run_labels(&biovault_db.conn, runs)
 }
 
 #[tauri::command]
 pub async fn delete_flow_run(state: tauri::State<'_, AppState>, run_id: i64) -> Result<(), String> {
-    let biovault_db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+    let dirs = {
+        let biovault_db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+        delete_flow_run_rows(&biovault_db, run_id)?
+    };
+
+    // Delete work directory (and results, when kept on a separate root)
+    for dir in dirs {
+        if dir.exists() {
+            fs::remove_dir_all(&dir).ok();
+        }
+    }
+
+    Ok(())
+}
 
+/// Remove a flow run's rows and session bookkeeping. Returns the directories
+/// it owned so the caller can delete them after releasing the DB lock.
+pub(crate) fn delete_flow_run_rows(db: &BioVaultDb, run_id: i64) -> Result<Vec<PathBuf>, String> {
     // Get run details before deleting
-    let run = biovault_db
-        .get_flow_run(run_id)
-        .map_err(|e| e.to_string())?;
+    let run = db.get_flow_run(run_id).map_err(|e| e.to_string())?;
 
     // Delete from database
-    biovault_db
-        .delete_flow_run(run_id)
+    db.delete_flow_run(run_id).map_err(|e| e.to_string())?;
+    super::runs::ensure_flow_run_labels_table(&db.conn)?;
+    db.conn
+        .execute("DELETE FROM flow_run_labels WHERE run_id = ?1", [run_id])
+        .map_err(|e| e.to_string())?;
+    super::webhooks::ensure_webhook_override_table(db)?;
+    db.conn
+        .execute(
+            "DELETE FROM run_webhook_overrides WHERE run_id = ?1",
+            [run_id],
+        )
         .map_err(|e| e.to_string())?;
 
-    if let Some(r) = run {
-        // Clear multiparty session so the invitation can be re-accepted from messages
-        let mut multiparty_session_id: Option<String> = None;
-        let mut multiparty_party_count: Option<usize> = None;
-        if let Some(ref metadata_str) = r.metadata {
-            if let Ok(metadata) = serde_json::from_str::<serde_json::Value>(metadata_str) {
-                if let Some(sid) = metadata.get("session_id").and_then(|v| v.as_str()) {
-                    super::multiparty::clear_multiparty_session(sid);
-                    multiparty_session_id = Some(sid.to_string());
-                }
-                if let Some(participants) = metadata.get("participants").and_then(|v| v.as_array())
-                {
-                    if !participants.is_empty() {
-                        multiparty_party_count = Some(participants.len());
-                    }
+    let Some(r) = run else {
+        return Ok(Vec::new());
+    };
+
+    // Clear multiparty session so the invitation can be re-accepted from messages
+    let mut multiparty_session_id: Option<String> = None;
+    let mut multiparty_party_count: Option<usize> = None;
+    if let Some(ref metadata_str) = r.metadata {
+        if let Ok(metadata) = serde_json::from_str::<serde_json::Value>(metadata_str) {
+            if let Some(sid) = metadata.get("session_id").and_then(|v| v.as_str()) {
+                super::multiparty::clear_multiparty_session(sid);
+                multiparty_session_id = Some(sid.to_string());
+            }
+            if let Some(participants) = metadata.get("participants").and_then(|v| v.as_array()) {
+                if !participants.is_empty() {
+                    multiparty_party_count = Some(participants.len());
                 }
             }
         }
+    }
 
-        // Also clean stale per-run Syqure port-base hint files used for deterministic
-        // multiparty session wiring. This prevents deleted runs from leaving behind
-        // temp hints that can confuse later re-accept/retry flows.
-        if let (Some(sid), Some(party_count)) = (multiparty_session_id, multiparty_party_count) {
-            run_dynamic::cleanup_syqure_port_base_hint_for_run(&sid, party_count);
-        }
-
-        // Delete work directory if it exists
-        let path = PathBuf::from(r.work_dir);
-        if path.exists() {
-            fs::remove_dir_all(&path).ok();
-        }
+    // Also clean stale per-run Syqure port-base hint files used for deterministic
+    // multiparty session wiring. This prevents deleted runs from leaving behind
+    // temp hints that can confuse later re-accept/retry flows.
+    if let (Some(sid), Some(party_count)) = (multiparty_session_id, multiparty_party_count) {
+        run_dynamic::cleanup_syqure_port_base_hint_for_run(&sid, party_count);
     }
 
-    Ok(())
+    let mut dirs = vec![PathBuf::from(&r.work_dir)];
+    if let Some(results) = r.results_dir.as_ref().filter(|d| **d != r.work_dir) {
+        dirs.push(PathBuf::from(results));
+    }
+    Ok(dirs)
 }
 
 #[tauri::command]
@@ -3441,6 +4267,7 @@ pub async fn resume_flow_run(
 
     run_flow_impl(
         state,
+        window.app_handle().clone(),
         Some(window),
         flow_id,
         input_overrides,
@@ -3759,7 +4586,7 @@ pub async fn import_flow_from_message(
     Ok(flow_id)
 }
 
-fn should_skip_request_path(rel: &Path) -> bool {
+pub(crate) fn should_skip_request_path(rel: &Path) -> bool {
     if rel
         .file_name()
         .map(|n| n == "syft.pub.yaml")
@@ -4072,3 +4899,991 @@ pub async fn delete_run_config(
     db.delete_flow_run_config(config_id)
         .map_err(|e| e.to_string())
 }
+
+// ============================================================================
+// Run duration history
+// ============================================================================
+
+fn ensure_run_duration_table(db: &BioVaultDb) -> Result<(), String> {
+    let conn = db.connection();
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS flow_run_durations (
+            run_id INTEGER PRIMARY KEY,
+            flow_name TEXT NOT NULL,
+            participant_count INTEGER NOT NULL DEFAULT 0,
+            file_count INTEGER NOT NULL DEFAULT 0,
+            input_bytes INTEGER NOT NULL DEFAULT 0,
+            duration_secs INTEGER NOT NULL,
+            recorded_at TEXT NOT NULL
+        )",
+        [],
+    )
+    .map_err(|e| format!("Failed to create run duration table: {}", e))?;
+
+    let has_input_bytes = conn
+        .query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('flow_run_durations') WHERE name='input_bytes'",
+            [],
+            |row| row.get::<_, i32>(0),
+        )
+        .map(|count| count > 0)
+        .unwrap_or(false);
+    if !has_input_bytes {
+        conn.execute(
+            "ALTER TABLE flow_run_durations ADD COLUMN input_bytes INTEGER NOT NULL DEFAULT 0",
+            [],
+        )
+        .map_err(|e| format!("Failed to add input_bytes column: {}", e))?;
+    }
+    Ok(())
+}
+
+/// What a run was given, used to find past runs over comparable inputs.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RunInputSize {
+    pub participant_count: usize,
+    pub file_count: usize,
+    pub input_bytes: u64,
+}
+
+/// Remember how long a successful run took so future runs can be estimated.
+/// Only local runs are recorded: a multiparty run has no comparable input
+/// count, so it would skew the similar-input estimate.
+pub(crate) fn record_flow_run_duration(
+    db: &BioVaultDb,
+    run_id: i64,
+    flow_name: &str,
+    inputs: RunInputSize,
+    duration_secs: u64,
+) -> Result<(), String> {
+    ensure_run_duration_table(db)?;
+    db.connection()
+        .execute(
+            "INSERT OR REPLACE INTO flow_run_durations
+                (run_id, flow_name, participant_count, file_count, input_bytes, duration_secs, recorded_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            rusqlite::params![
+                run_id,
+                flow_name,
+                inputs.participant_count as i64,
+                inputs.file_count as i64,
+                inputs.input_bytes as i64,
+                duration_secs as i64,
+                chrono::Utc::now().to_rfc3339(),
+            ],
+        )
+        .map_err(|e| format!("Failed to record run duration: {}", e))?;
+    Ok(())
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RunDurationEstimate {
+    pub flow_name: String,
+    pub participant_count: Option<usize>,
+    pub file_count: Option<usize>,
+    pub input_bytes: Option<u64>,
+    pub sample_size: usize,
+    /// True when the estimate only uses runs whose participant count, file
+    /// count and input size (whichever were given) are all comparable
+    pub similar_inputs_only: bool,
+    pub median_secs: Option<u64>,
+    pub p25_secs: Option<u64>,
+    pub p75_secs: Option<u64>,
+    pub p90_secs: Option<u64>,
+    /// none | low | medium | high, based on sample size
+    pub confidence: String,
+}
+
+fn percentile(sorted: &[u64], pct: f64) -> Option<u64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = ((sorted.len() - 1) as f64 * pct).round() as usize;
+    sorted.get(rank).copied()
+}
+
+/// Within ±50% of `target`; an unset target matches anything.
+fn within_half(value: u64, target: Option<u64>) -> bool {
+    match target {
+        Some(target) => value >= target / 2 && value <= target + target.div_ceil(2),
+        None => true,
+    }
+}
+
+#[tauri::command]
+pub fn estimate_run_duration(
+    state: tauri::State<AppState>,
+    flow_name: String,
+    participant_count: Option<usize>,
+    file_count: Option<usize>,
+    input_bytes: Option<u64>,
+) -> Result<RunDurationEstimate, String> {
+    let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+    ensure_run_duration_table(&db)?;
+
+    let mut stmt = db
+        .connection()
+        .prepare(
+            "SELECT participant_count, file_count, input_bytes, duration_secs
+             FROM flow_run_durations
+             WHERE flow_name = ?1 ORDER BY recorded_at DESC LIMIT 200",
+        )
+        .map_err(|e| format!("Failed to prepare duration query: {}", e))?;
+    let history: Vec<(RunInputSize, u64)> = stmt
+        .query_map([&flow_name], |row| {
+            Ok((
+                RunInputSize {
+                    participant_count: row.get::<_, i64>(0)? as usize,
+                    file_count: row.get::<_, i64>(1)? as usize,
+                    input_bytes: row.get::<_, i64>(2)? as u64,
+                },
+                row.get::<_, i64>(3)? as u64,
+            ))
+        })
+        .map_err(|e| format!("Failed to query run durations: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read run durations: {}", e))?;
+
+    // Prefer runs within ±50% of every requested size; fall back to every run
+    // of the flow when there are too few comparable samples.
+    let has_target = participant_count.is_some() || file_count.is_some() || input_bytes.is_some();
+    let similar: Vec<u64> = if has_target {
+        history
+            .iter()
+            .filter(|(inputs, _)| {
+                within_half(
+                    inputs.participant_count as u64,
+                    participant_count.map(|c| c as u64),
+                ) && within_half(inputs.file_count as u64, file_count.map(|c| c as u64))
+                    && within_half(inputs.input_bytes, input_bytes)
+            })
+            .map(|(_, secs)| *secs)
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let similar_inputs_only = similar.len() >= 3;
+    let mut durations = if similar_inputs_only {
+        similar
+    } else {
+        history.iter().map(|(_, secs)| *secs).collect()
+    };
+    durations.sort_unstable();
+
+    let confidence = match durations.len() {
+        0 => "none",
+        1..=2 => "low",
+        3..=9 => "medium",
+        _ => "high",
+    };
+
+    Ok(RunDurationEstimate {
+        flow_name,
+        participant_count,
+        file_count,
+        input_bytes,
+        sample_size: durations.len(),
+        similar_inputs_only,
+        median_secs: percentile(&durations, 0.5),
+        p25_secs: percentile(&durations, 0.25),
+        p75_secs: percentile(&durations, 0.75),
+        p90_secs: percentile(&durations, 0.9),
+        confidence: confidence.to_string(),
+    })
+}
+
+// ============================================================================
+// Run disk space checks
+// ============================================================================
+
+/// How many recent successful runs of a flow are measured for the footprint estimate.
+const RUN_SPACE_HISTORY_RUNS: usize = 5;
+/// Without history, assume a run writes this many times its input size.
+const RUN_SPACE_INPUT_MULTIPLIER: u64 = 2;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RunVolumeSpace {
+    /// work | results | work+results
+    pub role: String,
+    pub path: String,
+    pub available_bytes: Option<u64>,
+    /// ok | low | insufficient | unknown
+    pub status: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RunSpaceCheck {
+    pub flow_id: i64,
+    pub input_bytes: u64,
+    /// Median on-disk size of recent successful runs of this flow
+    pub historical_bytes: Option<u64>,
+    pub history_samples: usize,
+    pub estimated_bytes: u64,
+    pub headroom_bytes: u64,
+    pub volumes: Vec<RunVolumeSpace>,
+    /// True when the run should not start (free space below the estimate)
+    pub blocked: bool,
+    pub warnings: Vec<String>,
+}
+
+pub(crate) fn path_size(path: &Path) -> u64 {
+    WalkDir::new(path)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| entry.metadata().ok())
+        .map(|meta| meta.len())
+        .sum()
+}
+
+/// On-disk size of finished runs by run id. Successful runs don't grow, so each
+/// is walked once per app session instead of on every run start.
+static RUN_FOOTPRINT_CACHE: Lazy<Mutex<HashMap<i64, u64>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// A past run whose size feeds the footprint estimate.
+pub(crate) struct RunSpaceHistoryEntry {
+    run_id: i64,
+    work_dir: PathBuf,
+    results_dir: PathBuf,
+}
+
+/// Recent successful runs of a flow. Cheap; call it under the DB lock and
+/// measure them with `check_run_space` after releasing it.
+pub(crate) fn run_space_history(db: &BioVaultDb, flow_id: i64) -> Vec<RunSpaceHistoryEntry> {
+    let mut runs: Vec<Run> = db
+        .list_flow_runs()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|run| run.flow_id == Some(flow_id) && run.status == "success")
+        .collect();
+    runs.sort_by(|a, b| b.id.cmp(&a.id));
+    runs.iter()
+        .map(|run| RunSpaceHistoryEntry {
+            run_id: run.id,
+            work_dir: PathBuf::from(&run.work_dir),
+            results_dir: run_results_dir(run),
+        })
+        .filter(|entry| entry.work_dir.exists() || entry.results_dir.exists())
+        .take(RUN_SPACE_HISTORY_RUNS)
+        .collect()
+}
+
+fn historical_run_footprints(history: &[RunSpaceHistoryEntry]) -> Vec<u64> {
+    let mut sizes: Vec<u64> = history
+        .iter()
+        .map(|entry| {
+            if let Some(size) = RUN_FOOTPRINT_CACHE
+                .lock()
+                .ok()
+                .and_then(|cache| cache.get(&entry.run_id).copied())
+            {
+                return size;
+            }
+            let mut size = path_size(&entry.work_dir);
+            if entry.results_dir != entry.work_dir {
+                size += path_size(&entry.results_dir);
+            }
+            if let Ok(mut cache) = RUN_FOOTPRINT_CACHE.lock() {
+                cache.insert(entry.run_id, size);
+            }
+            size
+        })
+        .collect();
+    sizes.sort_unstable();
+    sizes
+}
+
+fn byte_mb(bytes: u64) -> u64 {
+    bytes / (1024 * 1024)
+}
+
+/// Estimate how much a run will write and compare it against free space on the
+/// volumes holding its work and results directories. Walks the inputs and past
+/// runs, so don't call it while holding the DB lock.
+pub(crate) fn check_run_space(
+    history: &[RunSpaceHistoryEntry],
+    flow_id: i64,
+    input_paths: &[PathBuf],
+    work_dir: &Path,
+    results_dir: &Path,
+) -> Result<RunSpaceCheck, String> {
+    let settings = super::settings::get_settings()?;
+    let headroom_bytes = settings.run_space_headroom_mb * 1024 * 1024;
+
+    let mut seen = HashSet::new();
+    let input_bytes: u64 = input_paths
+        .iter()
+        .filter(|path| path.exists() && seen.insert((*path).clone()))
+        .map(|path| path_size(path))
+        .sum();
+
+    let history = historical_run_footprints(history);
+    let historical_bytes = percentile(&history, 0.5);
+    let estimated_bytes = historical_bytes
+        .unwrap_or(0)
+        .max(input_bytes * RUN_SPACE_INPUT_MULTIPLIER);
+
+    let mut targets = vec![("work", work_dir), ("results", results_dir)];
+    if work_dir == results_dir {
+        targets = vec![("work+results", work_dir)];
+    }
+
+    let mut blocked = false;
+    let mut warnings = Vec::new();
+    let volumes = targets
+        .into_iter()
+        .map(|(role, path)| {
+            let available_bytes = super::settings::available_disk_space(path);
+            let status = match available_bytes {
+                None => {
+                    warnings.push(format!(
+                        "Could not determine free space for {} directory {}; about {} MB needed",
+                        role,
+                        path.display(),
+                        byte_mb(estimated_bytes)
+                    ));
+                    "unknown"
+                }
+                Some(free) if free < estimated_bytes => {
+                    warnings.push(format!(
+                        "Not enough space for {} directory {}: {} MB free, about {} MB needed",
+                        role,
+                        path.display(),
+                        byte_mb(free),
+                        byte_mb(estimated_bytes)
+                    ));
+                    if settings.run_space_block_insufficient {
+                        blocked = true;
+                    }
+                    "insufficient"
+                }
+                Some(free) if free < estimated_bytes + headroom_bytes => {
+                    warnings.push(format!(
+                        "Low space for {} directory {}: {} MB free, about {} MB needed plus {} MB headroom",
+                        role,
+                        path.display(),
+                        byte_mb(free),
+                        byte_mb(estimated_bytes),
+                        byte_mb(headroom_bytes)
+                    ));
+                    "low"
+                }
+                Some(_) => "ok",
+            };
+            RunVolumeSpace {
+                role: role.to_string(),
+                path: path.to_string_lossy().to_string(),
+                available_bytes,
+                status: status.to_string(),
+            }
+        })
+        .collect();
+
+    Ok(RunSpaceCheck {
+        flow_id,
+        input_bytes,
+        historical_bytes,
+        history_samples: history.len(),
+        estimated_bytes,
+        headroom_bytes,
+        volumes,
+        blocked,
+        warnings,
+    })
+}
+
+fn run_config_input_paths(config_data: &serde_json::Value) -> Vec<PathBuf> {
+    config_data
+        .get("inputs")
+        .and_then(|inputs| inputs.as_object())
+        .map(|inputs| {
+            inputs
+                .values()
+                .filter_map(|value| value.as_str())
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(PathBuf::from)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn selection_file_paths(db: &BioVaultDb, file_ids: &[i64]) -> Vec<PathBuf> {
+    file_ids
+        .iter()
+        .filter_map(|id| {
+            db.connection()
+                .query_row("SELECT file_path FROM files WHERE id = ?1", [id], |row| {
+                    row.get::<_, String>(0)
+                })
+                .ok()
+        })
+        .map(PathBuf::from)
+        .collect()
+}
+
+/// Standalone disk space check for a saved run configuration, using the same
+/// estimate and thresholds as the pre-run check in `run_flow`.
+#[tauri::command]
+pub async fn check_run_space_requirements(
+    state: tauri::State<'_, AppState>,
+    config_id: i64,
+) -> Result<RunSpaceCheck, String> {
+    let (config, history) = {
+        let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+        let config = db
+            .get_flow_run_config(config_id)
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| format!("Run configuration {} not found", config_id))?;
+        let history = run_space_history(&db, config.flow_id);
+        (config, history)
+    };
+    let (work_root, results_root) = super::settings::resolve_run_roots()?;
+    check_run_space(
+        &history,
+        config.flow_id,
+        &run_config_input_paths(&config.config_data),
+        &work_root,
+        &results_root,
+    )
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RunOutputFileChange {
+    pub path: String,
+    /// text | binary
+    pub kind: String,
+    pub size_a: u64,
+    pub size_b: u64,
+    /// Text outputs only: rows that differ at the same position, plus rows
+    /// present in only one side.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rows_a: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rows_b: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rows_changed: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RunOutputDiff {
+    pub run_id_a: i64,
+    pub run_id_b: i64,
+    pub results_dir_a: String,
+    pub results_dir_b: String,
+    pub same_flow: bool,
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<RunOutputFileChange>,
+    pub unchanged_count: usize,
+}
+
+const TEXT_OUTPUT_EXTENSIONS: &[&str] = &["csv", "tsv", "json", "txt"];
+const RUN_BOOKKEEPING_FILES: &[&str] = &["flow.log", "flow.pid", "flow.state.json", ".flow.pause"];
+
+fn run_results_dir(run: &Run) -> PathBuf {
+    run.results_dir
+        .as_ref()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(&run.work_dir))
+}
+
+/// Relative paths of every output file, skipping Nextflow scratch and run bookkeeping.
+fn collect_output_files(root: &Path) -> HashMap<String, PathBuf> {
+    let mut files = HashMap::new();
+    let walker = WalkDir::new(root).into_iter().filter_entry(|entry| {
+        if entry.depth() == 0 {
+            return true;
+        }
+        let name = entry.file_name().to_string_lossy();
+        !(name.starts_with('.') || (entry.file_type().is_dir() && name == "work"))
+    });
+    for entry in walker.flatten() {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy();
+        if entry.depth() == 1 && RUN_BOOKKEEPING_FILES.contains(&name.as_ref()) {
+            continue;
+        }
+        if let Ok(rel) = entry.path().strip_prefix(root) {
+            let key = rel.to_string_lossy().replace('\\', "/");
+            files.insert(key, entry.path().to_path_buf());
+        }
+    }
+    files
+}
+
+fn hash_file_streaming(path: &Path) -> Result<String, String> {
+    use sha2::{Digest, Sha256};
+    use std::io::Read;
+
+    let mut file =
+        fs::File::open(path).map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    let mut hasher = Sha256::new();
+    let mut buf = [0u8; 64 * 1024];
+    loop {
+        let n = file
+            .read(&mut buf)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Walk both files line by line so memory stays flat regardless of size.
+fn compare_text_rows(a: &Path, b: &Path) -> Result<(u64, u64, u64), String> {
+    let open = |p: &Path| {
+        fs::File::open(p)
+            .map(BufReader::new)
+            .map_err(|e| format!("Failed to open {}: {}", p.display(), e))
+    };
+    let mut lines_a = open(a)?.lines();
+    let mut lines_b = open(b)?.lines();
+    let (mut rows_a, mut rows_b, mut changed) = (0u64, 0u64, 0u64);
+    loop {
+        let next_a = lines_a.next().transpose().map_err(|e| e.to_string())?;
+        let next_b = lines_b.next().transpose().map_err(|e| e.to_string())?;
+        match (next_a, next_b) {
+            (None, None) => break,
+            (Some(la), Some(lb)) => {
+                rows_a += 1;
+                rows_b += 1;
+                if la != lb {
+                    changed += 1;
+                }
+            }
+            (Some(_), None) => {
+                rows_a += 1;
+                changed += 1;
+            }
+            (None, Some(_)) => {
+                rows_b += 1;
+                changed += 1;
+            }
+        }
+    }
+    Ok((rows_a, rows_b, changed))
+}
+
+fn diff_output_dirs(
+    dir_a: &Path,
+    dir_b: &Path,
+) -> Result<(Vec<String>, Vec<String>, Vec<RunOutputFileChange>, usize), String> {
+    let files_a = collect_output_files(dir_a);
+    let files_b = collect_output_files(dir_b);
+
+    let mut added: Vec<String> = files_b
+        .keys()
+        .filter(|k| !files_a.contains_key(*k))
+        .cloned()
+        .collect();
+    let mut removed: Vec<String> = files_a
+        .keys()
+        .filter(|k| !files_b.contains_key(*k))
+        .cloned()
+        .collect();
+    added.sort();
+    removed.sort();
+
+    let mut common: Vec<&String> = files_a
+        .keys()
+        .filter(|k| files_b.contains_key(*k))
+        .collect();
+    common.sort();
+
+    let mut changed = Vec::new();
+    let mut unchanged_count = 0;
+    for rel in common {
+        let path_a = &files_a[rel];
+        let path_b = &files_b[rel];
+        let size_a = fs::metadata(path_a).map(|m| m.len()).unwrap_or(0);
+        let size_b = fs::metadata(path_b).map(|m| m.len()).unwrap_or(0);
+
+        if size_a == size_b && hash_file_streaming(path_a)? == hash_file_streaming(path_b)? {
+            unchanged_count += 1;
+            continue;
+        }
+
+        let is_text = Path::new(rel)
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|e| TEXT_OUTPUT_EXTENSIONS.contains(&e.to_ascii_lowercase().as_str()))
+            .unwrap_or(false);
+
+        let mut change = RunOutputFileChange {
+            path: rel.clone(),
+            kind: if is_text { "text" } else { "binary" }.to_string(),
+            size_a,
+            size_b,
+            rows_a: None,
+            rows_b: None,
+            rows_changed: None,
+        };
+        if is_text {
+            let (rows_a, rows_b, rows_changed) = compare_text_rows(path_a, path_b)?;
+            change.rows_a = Some(rows_a);
+            change.rows_b = Some(rows_b);
+            change.rows_changed = Some(rows_changed);
+        }
+        changed.push(change);
+    }
+
+    Ok((added, removed, changed, unchanged_count))
+}
+
+/// Compare the result directories of two runs file by file.
+#[tauri::command]
+pub async fn diff_run_outputs(
+    state: tauri::State<'_, AppState>,
+    run_id_a: i64,
+    run_id_b: i64,
+) -> Result<RunOutputDiff, String> {
+    let (run_a, run_b) = {
+        let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+        let load = |id: i64| {
+            db.get_flow_run(id)
+                .map_err(|e| e.to_string())?
+                .ok_or_else(|| format!("Flow run {} not found", id))
+        };
+        (load(run_id_a)?, load(run_id_b)?)
+    };
+
+    let dir_a = run_results_dir(&run_a);
+    let dir_b = run_results_dir(&run_b);
+    for dir in [&dir_a, &dir_b] {
+        if !dir.exists() {
+            return Err(format!("Results directory not found: {}", dir.display()));
+        }
+    }
+
+    let (dir_a_walk, dir_b_walk) = (dir_a.clone(), dir_b.clone());
+    let (added, removed, changed, unchanged_count) =
+        tauri::async_runtime::spawn_blocking(move || diff_output_dirs(&dir_a_walk, &dir_b_walk))
+            .await
+            .map_err(|e| format!("Output comparison failed: {}", e))??;
+
+    Ok(RunOutputDiff {
+        run_id_a,
+        run_id_b,
+        results_dir_a: dir_a.to_string_lossy().to_string(),
+        results_dir_b: dir_b.to_string_lossy().to_string(),
+        same_flow: run_a.flow_id.is_some() && run_a.flow_id == run_b.flow_id,
+        added,
+        removed,
+        changed,
+        unchanged_count,
+    })
+}
+
+// ============================================================================
+// Run input provenance
+// ============================================================================
+
+#[derive(Debug, Serialize)]
+pub struct RunInputSource {
+    pub participant_id: Option<String>,
+    pub local_path: String,
+    pub syft_url: Option<String>,
+    pub exists: bool,
+    pub sha256: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RunInputSources {
+    pub run_id: i64,
+    pub dataset_name: Option<String>,
+    pub dataset_owner: Option<String>,
+    pub data_type: Option<String>,
+    pub sources: Vec<RunInputSource>,
+}
+
+/// Local path -> syft URL for every dataset asset we know about, so private
+/// files (which live outside the datasite) can still be reverse-resolved.
+fn dataset_asset_url_index(db: &BioVaultDb) -> HashMap<String, String> {
+    let mut index = HashMap::new();
+    let Ok(rows) = biovault::data::list_datasets_with_assets(db) else {
+        return index;
+    };
+    for (ds, assets) in rows {
+        for asset in assets {
+            if let Some(private_url) = ds.private_url.as_ref() {
+                if let Some(path) = resolve_asset_path(db, &asset, "real") {
+                    index.insert(path, format!("{}#assets.{}", private_url, asset.asset_key));
+                }
+            }
+            if let Some(mock_url) = asset.mock_ref.as_ref().filter(|r| r.starts_with("syft://")) {
+                if let Some(path) = resolve_asset_path(db, &asset, "mock") {
+                    index.entry(path).or_insert_with(|| mock_url.clone());
+                }
+            }
+        }
+    }
+    index
+}
+
+fn local_path_to_syft_url(
+    data_dir: Option<&Path>,
+    asset_urls: &HashMap<String, String>,
+    path: &str,
+) -> Option<String> {
+    if let Some(url) = asset_urls.get(path) {
+        return Some(url.clone());
+    }
+    let rel = Path::new(path)
+        .strip_prefix(data_dir?.join("datasites"))
+        .ok()?;
+    let rel = rel.to_string_lossy().replace('\\', "/");
+    (!rel.is_empty()).then(|| format!("syft://{}", rel))
+}
+
+/// Which files (and whose data) fed a run, reconstructed from its stored
+/// `data_selection` metadata, with syft URLs and content hashes.
+#[tauri::command]
+pub async fn get_run_input_sources(
+    state: tauri::State<'_, AppState>,
+    run_id: i64,
+) -> Result<RunInputSources, String> {
+    let (mut result, catalog_hashes) = {
+        let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+        let run = db
+            .get_flow_run(run_id)
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| format!("Run {} not found", run_id))?;
+
+        let selection: serde_json::Value = run
+            .metadata
+            .as_deref()
+            .filter(|m| !m.trim().is_empty())
+            .map(serde_json::from_str::<serde_json::Value>)
+            .transpose()
+            .map_err(|e| format!("Invalid run metadata: {}", e))?
+            .and_then(|m| m.get("data_selection").cloned())
+            .unwrap_or(serde_json::Value::Null);
+
+        let str_field = |key: &str| {
+            selection
+                .get(key)
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+                .filter(|s| !s.trim().is_empty())
+        };
+        let list_field = |key: &str| -> Vec<String> {
+            selection
+                .get(key)
+                .and_then(|v| v.as_array())
+                .map(|items| {
+                    items
+                        .iter()
+                        .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        let dataset_name = str_field("dataset_name");
+        let data_type = str_field("dataset_data_type").or_else(|| str_field("data_type"));
+        let data_dir = biovault::config::Config::load()
+            .ok()
+            .and_then(|cfg| cfg.get_syftbox_data_dir().ok());
+
+        // (participant, path, url known from the selection itself)
+        let mut entries: Vec<(Option<String>, String, Option<String>)> = Vec::new();
+        let file_paths = list_field("file_paths");
+        if !file_paths.is_empty() {
+            let urls = list_field("urls");
+            let participant_ids = list_field("participant_ids");
+            let mut by_path: HashMap<String, (usize, String)> = HashMap::new();
+            if let Some(dir) = data_dir.as_ref() {
+                for (idx, url) in urls.iter().enumerate() {
+                    if let Ok(local) = biovault::data::resolve_syft_url(dir, url) {
+                        by_path.insert(local.to_string_lossy().to_string(), (idx, url.clone()));
+                    }
+                }
+            }
+            for path in file_paths {
+                let (participant, url) = match by_path.get(&path) {
+                    Some((idx, url)) => (
+                        participant_ids.get(*idx).filter(|p| !p.is_empty()).cloned(),
+                        Some(url.clone()),
+                    ),
+                    None => (None, None),
+                };
+                entries.push((participant, path, url));
+            }
+        } else if let Some(name) = dataset_name.as_ref() {
+            // Dataset-shaped selections only record the generated input, so
+            // re-derive the asset files from the dataset itself.
+            let asset_keys: HashSet<String> = list_field("asset_keys").into_iter().collect();
+            let data_type = data_type.clone().unwrap_or_else(|| "mock".to_string());
+            if let Some((_, assets)) = biovault::data::get_dataset_with_assets(&db, name)
+                .map_err(|e| format!("Failed to load dataset '{}': {}", name, e))?
+            {
+                for asset in &assets {
+                    if !asset_keys.is_empty() && !asset_keys.contains(&asset.asset_key) {
+                        continue;
+                    }
+                    if let Some(path) = resolve_asset_path(&db, asset, &data_type) {
+                        entries.push((Some(asset.asset_key.clone()), path, None));
+                    }
+                }
+            }
+        }
+
+        let asset_urls = dataset_asset_url_index(&db);
+        let mut catalog_hashes = Vec::with_capacity(entries.len());
+        let mut sources = Vec::with_capacity(entries.len());
+        for (participant, path, url) in entries {
+            let catalog: Option<(Option<String>, Option<String>)> = db
+                .conn
+                .query_row(
+                    "SELECT participant_id, file_hash FROM files WHERE file_path = ?1",
+                    [&path],
+                    |row| Ok((row.get(0)?, row.get(1)?)),
+                )
+                .ok();
+            let (catalog_participant, catalog_hash) = catalog.unwrap_or((None, None));
+            catalog_hashes
+                .push(catalog_hash.filter(|h| !h.is_empty() && !h.starts_with("pending_")));
+            let syft_url =
+                url.or_else(|| local_path_to_syft_url(data_dir.as_deref(), &asset_urls, &path));
+            sources.push(RunInputSource {
+                participant_id: participant.or(catalog_participant),
+                exists: Path::new(&path).is_file(),
+                local_path: path,
+                syft_url,
+                sha256: None,
+            });
+        }
+
+        (
+            RunInputSources {
+                run_id,
+                dataset_name,
+                dataset_owner: str_field("dataset_owner"),
+                data_type,
+                sources,
+            },
+            catalog_hashes,
+        )
+    };
+
+    // Hash anything the catalog doesn't know yet off the async runtime.
+    let mut sources = std::mem::take(&mut result.sources);
+    result.sources = tauri::async_runtime::spawn_blocking(move || {
+        for (source, catalog_hash) in sources.iter_mut().zip(catalog_hashes) {
+            source.sha256 = catalog_hash.or_else(|| {
+                source
+                    .exists
+                    .then(|| hash_file_streaming(Path::new(&source.local_path)).ok())
+                    .flatten()
+            });
+        }
+        sources
+    })
+    .await
+    .map_err(|e| format!("Failed to hash run inputs: {}", e))?;
+
+    Ok(result)
+}
+
+#[derive(Debug, Serialize)]
+pub struct DatasetRunReference {
+    pub run_id: i64,
+    pub flow_id: Option<i64>,
+    pub status: String,
+    pub created_at: String,
+    pub completed_at: Option<String>,
+    /// "dataset" when the run selected the dataset directly, "url" when it
+    /// picked individual files that belong to it.
+    pub match_kind: String,
+    pub matched_urls: Vec<String>,
+}
+
+/// Reverse lookup: every run whose `data_selection` referenced the dataset,
+/// either by name or through syft URLs that resolve into its assets.
+#[tauri::command]
+pub async fn get_runs_for_dataset(
+    state: tauri::State<'_, AppState>,
+    dataset_name: String,
+) -> Result<Vec<DatasetRunReference>, String> {
+    let dataset_name = dataset_name.trim().to_string();
+    if dataset_name.is_empty() {
+        return Err("Dataset name is required".to_string());
+    }
+
+    let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+    let (dataset, assets) = biovault::data::get_dataset_with_assets(&db, &dataset_name)
+        .map_err(|e| format!("Failed to load dataset '{}': {}", dataset_name, e))?
+        .ok_or_else(|| format!("Dataset '{}' not found", dataset_name))?;
+
+    // Everything a URL selection could point at: the asset URLs themselves
+    // and the local files they resolve to.
+    let mut dataset_urls: HashSet<String> = HashSet::new();
+    let mut dataset_paths: HashSet<String> = HashSet::new();
+    for asset in &assets {
+        if let Some(private_url) = dataset.private_url.as_ref() {
+            dataset_urls.insert(format!("{}#assets.{}", private_url, asset.asset_key));
+        }
+        if let Some(mock_url) = asset.mock_ref.as_ref().filter(|r| r.starts_with("syft://")) {
+            dataset_urls.insert(mock_url.clone());
+        }
+        for data_type in ["real", "mock"] {
+            if let Some(path) = resolve_asset_path(&db, asset, data_type) {
+                dataset_paths.insert(path);
+            }
+        }
+    }
+    let data_dir = biovault::config::Config::load()
+        .ok()
+        .and_then(|cfg| cfg.get_syftbox_data_dir().ok());
+
+    let runs = db.list_flow_runs().map_err(|e| e.to_string())?;
+    let mut references = Vec::new();
+    for run in runs {
+        let selection = match parse_flow_run_metadata(&run) {
+            Ok((_, Some(selection), _)) => selection,
+            _ => continue,
+        };
+
+        let direct = selection
+            .dataset_name
+            .as_deref()
+            .is_some_and(|name| name.trim() == dataset_name);
+        let matched_urls: Vec<String> = selection
+            .urls
+            .iter()
+            .filter(|url| {
+                dataset_urls.contains(url.as_str())
+                    || data_dir.as_ref().is_some_and(|dir| {
+                        biovault::data::resolve_syft_url(dir, url)
+                            .map(|local| {
+                                dataset_paths.contains(&local.to_string_lossy().to_string())
+                            })
+                            .unwrap_or(false)
+                    })
+            })
+            .cloned()
+            .collect();
+
+        if !direct && matched_urls.is_empty() {
+            continue;
+        }
+        references.push(DatasetRunReference {
+            run_id: run.id,
+            flow_id: run.flow_id,
+            status: run.status.clone(),
+            created_at: run.created_at.clone(),
+            completed_at: run.completed_at.clone(),
+            match_kind: if direct { "dataset" } else { "url" }.to_string(),
+            matched_urls,
+        });
+    }
+
+    references.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    Ok(references)
+}
@@ -1,7 +1,11 @@
-use crate::types::{JupyterResetResult, JupyterStatus, DEFAULT_JUPYTER_PYTHON};
+use crate::types::{
+    JupyterResetResult, JupyterServerInfo, JupyterStatus, JupyterStopResult, DEFAULT_JUPYTER_PYTHON,
+};
 use biovault::cli::commands::jupyter;
 use biovault::data::BioVaultDb;
-use std::path::Path;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 fn canonicalize_module_path(module_path: &str) -> String {
     Path::new(module_path)
@@ -108,3 +112,369 @@ pub async fn stop_jupyter(module_path: String) -> Result<JupyterStatus, String>
 pub fn get_jupyter_status(module_path: String) -> Result<JupyterStatus, String> {
     load_jupyter_status(&module_path)
 }
+
+#[derive(Deserialize)]
+struct JupyterApiStatus {
+    last_activity: Option<String>,
+    kernels: Option<u32>,
+}
+
+/// Ask a running server for its last activity via the Jupyter REST API.
+fn probe_jupyter_activity(port: i32, token: Option<&str>) -> Option<JupyterApiStatus> {
+    let client = reqwest::blocking::Client::builder()
+        .timeout(Duration::from_millis(800))
+        .build()
+        .ok()?;
+    let mut request = client.get(format!("http://127.0.0.1:{}/api/status", port));
+    if let Some(token) = token {
+        request = request.header("Authorization", format!("token {}", token));
+    }
+    let response = request.send().ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+    response.json().ok()
+}
+
+fn collect_jupyter_servers() -> Result<Vec<JupyterServerInfo>, String> {
+    let db = BioVaultDb::new().map_err(|e| format!("Failed to open BioVault database: {}", e))?;
+    let envs = db
+        .list_dev_envs()
+        .map_err(|e| format!("Failed to list Jupyter environments: {}", e))?;
+
+    let mut servers = Vec::new();
+    for env in envs {
+        if env.jupyter_pid.is_none() && env.jupyter_port.is_none() {
+            continue;
+        }
+        let session_id = super::sessions::session_id_for_workspace(&env.module_path);
+        let (running, port, url, token) = match session_id
+            .as_ref()
+            .and_then(|id| super::sessions::get_session_jupyter_status(id.clone()).ok())
+        {
+            Some(status) => (status.running, status.port, status.url, status.token),
+            None => {
+                let status = load_jupyter_status(&env.module_path)?;
+                (status.running, status.port, status.url, status.token)
+            }
+        };
+
+        let api = port
+            .filter(|_| running)
+            .and_then(|port| probe_jupyter_activity(port, token.as_deref()));
+        let last_activity = api.as_ref().and_then(|a| a.last_activity.clone());
+        let idle_seconds = last_activity
+            .as_deref()
+            .and_then(|ts| chrono::DateTime::parse_from_rfc3339(ts).ok())
+            .map(|ts| (chrono::Utc::now() - ts.with_timezone(&chrono::Utc)).num_seconds());
+
+        servers.push(JupyterServerInfo {
+            module_path: env.module_path.clone(),
+            session_id,
+            running,
+            pid: env.jupyter_pid,
+            port,
+            url,
+            token,
+            last_activity,
+            idle_seconds,
+            kernels: api.and_then(|a| a.kernels),
+        });
+    }
+    Ok(servers)
+}
+
+/// Every Jupyter server the app has launched, module and session alike.
+#[tauri::command]
+pub async fn list_jupyter_servers() -> Result<Vec<JupyterServerInfo>, String> {
+    tauri::async_runtime::spawn_blocking(collect_jupyter_servers)
+        .await
+        .map_err(|e| format!("Failed to list Jupyter servers (task join): {}", e))?
+}
+
+#[tauri::command]
+pub async fn stop_all_jupyter_servers() -> Result<Vec<JupyterStopResult>, String> {
+    let servers = list_jupyter_servers().await?;
+    let mut results = Vec::new();
+    for server in servers {
+        let module_path = server.module_path.clone();
+        let outcome = tauri::async_runtime::spawn_blocking(move || {
+            tauri::async_runtime::block_on(jupyter::stop(&module_path))
+        })
+        .await
+        .map_err(|e| format!("task join: {}", e))
+        .and_then(|r| r.map_err(|e| e.to_string()));
+        if let Err(err) = &outcome {
+            crate::desktop_log!(
+                "⚠️ Failed to stop Jupyter for {}: {}",
+                server.module_path,
+                err
+            );
+        }
+        results.push(JupyterStopResult {
+            module_path: server.module_path,
+            session_id: server.session_id,
+            stopped: outcome.is_ok(),
+            error: outcome.err(),
+        });
+    }
+    crate::desktop_log!(
+        "🛑 Stopped {} of {} Jupyter servers",
+        results.iter().filter(|r| r.stopped).count(),
+        results.len()
+    );
+    Ok(results)
+}
+
+/// Connection file Jupyter writes per server (`jpserver-<pid>.json`).
+#[derive(Deserialize)]
+struct JupyterRuntimeFile {
+    pid: i32,
+    port: i32,
+    url: Option<String>,
+    token: Option<String>,
+    root_dir: Option<String>,
+    notebook_dir: Option<String>,
+}
+
+fn jupyter_runtime_dirs(module_paths: &[String]) -> Vec<PathBuf> {
+    let mut dirs_list = Vec::new();
+    if let Ok(dir) = std::env::var("JUPYTER_RUNTIME_DIR") {
+        dirs_list.push(PathBuf::from(dir));
+    }
+    if let Some(data) = dirs::data_dir() {
+        dirs_list.push(data.join("jupyter").join("runtime"));
+    }
+    if let Some(home) = dirs::home_dir() {
+        dirs_list.push(home.join("Library").join("Jupyter").join("runtime"));
+        dirs_list.push(
+            home.join(".local")
+                .join("share")
+                .join("jupyter")
+                .join("runtime"),
+        );
+    }
+    // Servers started inside a module's virtualenv may keep their own runtime dir.
+    for module_path in module_paths {
+        dirs_list.push(
+            Path::new(module_path)
+                .join(".venv")
+                .join("share")
+                .join("jupyter")
+                .join("runtime"),
+        );
+    }
+    dirs_list.sort();
+    dirs_list.dedup();
+    dirs_list
+}
+
+/// Whether `pid`'s command line still mentions Jupyter. Runtime files outlive
+/// crashed servers, so a recorded pid may since have been reused.
+fn process_looks_like_jupyter(pid: i32) -> bool {
+    #[cfg(target_os = "windows")]
+    let output = {
+        let mut cmd = std::process::Command::new("powershell");
+        cmd.args([
+            "-NoProfile",
+            "-Command",
+            &format!(
+                "(Get-CimInstance Win32_Process -Filter 'ProcessId={}').CommandLine",
+                pid
+            ),
+        ]);
+        super::hide_console_window(&mut cmd);
+        cmd.output()
+    };
+    #[cfg(not(target_os = "windows"))]
+    let output = std::process::Command::new("ps")
+        .args(["-p", &pid.to_string(), "-o", "command="])
+        .output();
+
+    output
+        .map(|out| {
+            String::from_utf8_lossy(&out.stdout)
+                .to_ascii_lowercase()
+                .contains("jupyter")
+        })
+        .unwrap_or(false)
+}
+
+fn kill_jupyter_process(pid: i32) -> Result<(), String> {
+    // kill(0) / kill(-1) would signal our process group or every user process.
+    if pid <= 0 {
+        return Err(format!("Refusing to signal invalid pid {}", pid));
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        let mut cmd = std::process::Command::new("taskkill");
+        cmd.args(["/PID", &pid.to_string(), "/T", "/F"]);
+        super::hide_console_window(&mut cmd);
+        let status = cmd
+            .status()
+            .map_err(|e| format!("Failed to run taskkill: {}", e))?;
+        if !status.success() {
+            return Err(format!("taskkill exited with {}", status));
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    unsafe {
+        if libc::kill(pid, libc::SIGTERM) != 0 {
+            return Err(format!(
+                "Failed to signal pid {}: {}",
+                pid,
+                std::io::Error::last_os_error()
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Record (or clear) a server's details on its dev environment row.
+fn set_dev_env_jupyter(
+    db: &BioVaultDb,
+    module_path: &str,
+    server: Option<&JupyterRuntimeFile>,
+) -> Result<(), String> {
+    let updated = db
+        .connection()
+        .execute(
+            "UPDATE dev_envs SET jupyter_pid = ?1, jupyter_port = ?2, jupyter_url = ?3, jupyter_token = ?4
+             WHERE module_path = ?5",
+            rusqlite::params![
+                server.map(|s| s.pid),
+                server.map(|s| s.port),
+                server.and_then(|s| s.url.clone()),
+                server.and_then(|s| s.token.clone()),
+                module_path
+            ],
+        )
+        .map_err(|e| format!("Failed to update Jupyter environment: {}", e))?;
+    if updated == 0 {
+        return Err(format!(
+            "No Jupyter environment recorded for {}",
+            module_path
+        ));
+    }
+    Ok(())
+}
+
+/// Find Jupyter servers launched for one of our environments that the app no
+/// longer tracks (e.g. after a restart), by reading Jupyter's runtime files and
+/// probing each server's port. `action` "reattach" records a responding server
+/// on its environment again; "kill" terminates it. Without an action the
+/// servers are only reported.
+#[tauri::command]
+pub async fn discover_orphaned_jupyter(
+    action: Option<String>,
+) -> Result<Vec<OrphanedJupyterServer>, String> {
+    if let Some(action) = action.as_deref() {
+        if action != "reattach" && action != "kill" {
+            return Err(format!(
+                "Unknown action '{}'; expected 'reattach' or 'kill'",
+                action
+            ));
+        }
+    }
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let db =
+            BioVaultDb::new().map_err(|e| format!("Failed to open BioVault database: {}", e))?;
+        let envs = db
+            .list_dev_envs()
+            .map_err(|e| format!("Failed to list Jupyter environments: {}", e))?;
+        let module_paths: Vec<String> = envs.iter().map(|e| e.module_path.clone()).collect();
+
+        let mut found = Vec::new();
+        let mut seen_pids = std::collections::HashSet::new();
+        for dir in jupyter_runtime_dirs(&module_paths) {
+            let Ok(entries) = std::fs::read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let name = entry.file_name().to_string_lossy().to_string();
+                if !name.starts_with("jpserver-") || !name.ends_with(".json") {
+                    continue;
+                }
+                let Some(server) = std::fs::read_to_string(entry.path())
+                    .ok()
+                    .and_then(|c| serde_json::from_str::<JupyterRuntimeFile>(&c).ok())
+                else {
+                    continue;
+                };
+                if server.pid <= 0 || !seen_pids.insert(server.pid) {
+                    continue;
+                }
+                let Some(root_dir) = server
+                    .root_dir
+                    .clone()
+                    .or_else(|| server.notebook_dir.clone())
+                else {
+                    continue;
+                };
+                let root = canonicalize_module_path(&root_dir);
+                let Some(env) = envs.iter().find(|env| env.module_path == root) else {
+                    continue;
+                };
+                // Tracked servers aren't orphans.
+                if env.jupyter_pid == Some(server.pid) && env.jupyter_port == Some(server.port) {
+                    continue;
+                }
+                if !super::flows::is_pid_running(server.pid) {
+                    continue;
+                }
+                let responding =
+                    probe_jupyter_activity(server.port, server.token.as_deref()).is_some();
+
+                let mut orphan = OrphanedJupyterServer {
+                    pid: server.pid,
+                    port: server.port,
+                    url: server.url.clone(),
+                    root_dir,
+                    module_path: env.module_path.clone(),
+                    session_id: super::sessions::session_id_for_workspace(&env.module_path),
+                    responding,
+                    action: None,
+                    error: None,
+                };
+                let outcome = match action.as_deref() {
+                    Some("reattach") if !responding => {
+                        Err("Server is not responding; kill it instead".to_string())
+                    }
+                    Some("reattach") => set_dev_env_jupyter(&db, &env.module_path, Some(&server))
+                        .map(|_| Some("reattached")),
+                    Some("kill") if !responding && !process_looks_like_jupyter(server.pid) => {
+                        Err(format!(
+                            "pid {} no longer looks like a Jupyter server; not killing it",
+                            server.pid
+                        ))
+                    }
+                    Some("kill") => kill_jupyter_process(server.pid).and_then(|_| {
+                        if env.jupyter_pid.is_none() || env.jupyter_port == Some(server.port) {
+                            set_dev_env_jupyter(&db, &env.module_path, None)?;
+                        }
+                        Ok(Some("killed"))
+                    }),
+                    _ => Ok(None),
+                };
+                match outcome {
+                    Ok(done) => orphan.action = done.map(str::to_string),
+                    Err(e) => orphan.error = Some(e),
+                }
+                crate::desktop_log!(
+                    "🔎 Found untracked Jupyter server pid {} on port {} for {} ({})",
+                    orphan.pid,
+                    orphan.port,
+                    orphan.module_path,
+                    orphan.action.as_deref().unwrap_or("reported")
+                );
+                found.push(orphan);
+            }
+        }
+        Ok(found)
+    })
+    .await
+    .map_err(|e| format!("Failed to discover Jupyter servers (task join): {}", e))?
+}
@@ -147,3 +147,123 @@ pub fn get_desktop_log_dir() -> Result<String, String> {
         .ok_or_else(|| "Failed to determine desktop log directory".to_string())?;
     Ok(dir.to_string_lossy().to_string())
 }
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct LogFileInfo {
+    pub name: String,
+    pub path: String,
+    pub size_bytes: u64,
+    pub modified_at: Option<String>,
+    pub is_active: bool,
+}
+
+/// Files in the desktop log directory that belong to the desktop log: the
+/// active file itself plus rotated archives (`desktop.log.1`, `desktop.log.2.gz`, ...).
+fn desktop_log_files() -> Result<Vec<(PathBuf, bool)>, String> {
+    let log_path = logging::desktop_log_path();
+    let dir = log_path
+        .parent()
+        .ok_or_else(|| "Failed to determine desktop log directory".to_string())?;
+    let active_name = log_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let stem = log_path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir).map_err(|e| format!("Failed to read log directory: {}", e))? {
+        let Ok(entry) = entry else { continue };
+        let path = entry.path();
+        if !path.is_file() {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name == active_name {
+            files.push((path, true));
+        } else if !stem.is_empty()
+            && (name.starts_with(&format!("{}.", stem)) || name.starts_with(&format!("{}-", stem)))
+        {
+            files.push((path, false));
+        }
+    }
+    Ok(files)
+}
+
+/// List the active desktop log and any archives, newest first.
+#[tauri::command]
+pub fn list_log_files() -> Result<Vec<LogFileInfo>, String> {
+    let mut infos: Vec<LogFileInfo> = desktop_log_files()?
+        .into_iter()
+        .map(|(path, is_active)| {
+            let metadata = fs::metadata(&path).ok();
+            LogFileInfo {
+                name: path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_default(),
+                path: path.to_string_lossy().to_string(),
+                size_bytes: metadata.as_ref().map(|m| m.len()).unwrap_or(0),
+                modified_at: metadata
+                    .and_then(|m| m.modified().ok())
+                    .map(|t| chrono::DateTime::<chrono::Utc>::from(t).to_rfc3339()),
+                is_active,
+            }
+        })
+        .collect();
+
+    infos.sort_by(|a, b| {
+        b.is_active
+            .cmp(&a.is_active)
+            .then_with(|| b.modified_at.cmp(&a.modified_at))
+    });
+    Ok(infos)
+}
+
+/// Open one of the files returned by `list_log_files` with the system viewer.
+/// Only bare file names from the log directory are accepted.
+#[tauri::command]
+pub fn open_log_file(name: String) -> Result<(), String> {
+    let name = name.trim();
+    if name.is_empty() || name.contains('/') || name.contains('\\') || name.contains("..") {
+        return Err(format!("Invalid log file name: {}", name));
+    }
+    let path = desktop_log_files()?
+        .into_iter()
+        .map(|(path, _)| path)
+        .find(|path| path.file_name().map(|n| n == name).unwrap_or(false))
+        .ok_or_else(|| format!("Log file not found: {}", name))?;
+
+    #[cfg(target_os = "macos")]
+    {
+        std::process::Command::new("open")
+            .arg(&path)
+            .spawn()
+            .map_err(|e| format!("Failed to open log file: {}", e))?;
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        let mut cmd = std::process::Command::new("explorer");
+        cmd.arg(&path);
+        super::hide_console_window(&mut cmd);
+        cmd.spawn()
+            .map_err(|e| format!("Failed to open log file: {}", e))?;
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        std::process::Command::new("xdg-open")
+            .arg(&path)
+            .spawn()
+            .map_err(|e| format!("Failed to open log file: {}", e))?;
+    }
+
+    Ok(())
+}
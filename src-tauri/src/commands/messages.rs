@@ -1656,6 +1656,7 @@ pub fn send_flow_results(
                 "file_name": output.file_name,
                 "content_base64": base64_content,
                 "size_bytes": content.len(),
+                "sha256": attachment_sha256(&content),
                 "is_text": is_text,
             }));
         } else {
@@ -1707,6 +1708,125 @@ pub fn send_flow_results(
     Ok(updated)
 }
 
+/// Checksum recorded alongside each flow-result attachment.
+pub(crate) fn attachment_sha256(content: &[u8]) -> String {
+    hex::encode(Sha256::digest(content))
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AttachmentVerification {
+    pub message_id: String,
+    pub file_name: String,
+    /// "verified", "mismatch", "unverifiable" (no declared checksum) or
+    /// "unavailable" (checksum declared but the content could not be found).
+    pub status: String,
+    pub expected_sha256: Option<String>,
+    pub actual_sha256: Option<String>,
+    pub expected_size: Option<u64>,
+    pub actual_size: Option<u64>,
+    /// Where the content was read from: "inline" or "synced".
+    pub source: Option<String>,
+}
+
+/// Recompute the checksum of a flow-result attachment and compare it with the
+/// one declared by the sender. Inline content is decoded from the message; for
+/// manifest-only results the synced step output is hashed instead.
+#[tauri::command]
+pub fn verify_message_attachment(
+    message_id: String,
+    file_name: String,
+) -> Result<AttachmentVerification, String> {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+
+    let config = load_config()?;
+    let db_path = get_message_db_path(&config)
+        .map_err(|e| format!("Failed to locate message database: {}", e))?;
+    let db =
+        MessageDb::new(&db_path).map_err(|e| format!("Failed to open message database: {}", e))?;
+    let message = db
+        .get_message(&message_id)
+        .map_err(|e| format!("Failed to load message: {}", e))?
+        .ok_or_else(|| format!("Message {} not found", message_id))?;
+
+    let results = message
+        .metadata
+        .as_ref()
+        .and_then(|m| m.get("flow_results"))
+        .ok_or_else(|| "Message has no flow result attachments".to_string())?;
+    let entry = results
+        .get("files")
+        .and_then(|f| f.as_array())
+        .and_then(|files| {
+            files
+                .iter()
+                .find(|f| f.get("file_name").and_then(|n| n.as_str()) == Some(file_name.as_str()))
+        })
+        .ok_or_else(|| format!("Attachment '{}' not found in message", file_name))?;
+
+    let mut report = AttachmentVerification {
+        message_id,
+        file_name: file_name.clone(),
+        status: "unverifiable".to_string(),
+        expected_sha256: entry
+            .get("sha256")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_ascii_lowercase()),
+        actual_sha256: None,
+        expected_size: entry.get("size_bytes").and_then(|v| v.as_u64()),
+        actual_size: None,
+        source: None,
+    };
+    let Some(expected) = report.expected_sha256.clone() else {
+        return Ok(report);
+    };
+
+    let content = if let Some(encoded) = entry.get("content_base64").and_then(|v| v.as_str()) {
+        report.source = Some("inline".to_string());
+        match STANDARD.decode(encoded) {
+            Ok(bytes) => Some(bytes),
+            Err(_) => {
+                report.status = "mismatch".to_string();
+                return Ok(report);
+            }
+        }
+    } else {
+        let field = |key: &str| results.get(key).and_then(|v| v.as_str()).unwrap_or("");
+        let synced = crate::commands::multiparty::find_synced_step_output_file(
+            &config.email,
+            field("sender"),
+            field("flow_name"),
+            field("session_id"),
+            field("step_id"),
+            &file_name,
+        )
+        .and_then(|path| fs::read(path).ok());
+        if synced.is_some() {
+            report.source = Some("synced".to_string());
+        }
+        synced
+    };
+    let Some(content) = content else {
+        report.status = "unavailable".to_string();
+        return Ok(report);
+    };
+
+    let actual = attachment_sha256(&content);
+    report.actual_size = Some(content.len() as u64);
+    report.status = if actual == expected {
+        "verified".to_string()
+    } else {
+        crate::desktop_log!(
+            "⚠️ Attachment '{}' checksum mismatch (expected {}, got {})",
+            file_name,
+            expected,
+            actual
+        );
+        "mismatch".to_string()
+    };
+    report.actual_sha256 = Some(actual);
+    Ok(report)
+}
+
 /// Batched message refresh: sync + list threads in a single command
 /// This reduces the number of roundtrips from frontend by combining two common operations.
 #[tauri::command]
@@ -1857,3 +1977,168 @@ pub fn refresh_messages_batched(
         threads: summaries,
     })
 }
+
+// ============================================================================
+// Identity migration
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IdentityMigrationResult {
+    pub old_email: String,
+    pub new_email: String,
+    pub messages_from_updated: usize,
+    pub messages_to_updated: usize,
+    pub sessions_owner_updated: usize,
+    pub sessions_peer_updated: usize,
+    /// Multiparty sessions whose own participant entries were re-keyed
+    pub multiparty_sessions_updated: usize,
+    pub config_updated: bool,
+    pub settings_updated: bool,
+}
+
+/// Re-key local state from `old_email` to `new_email`.
+///
+/// Message sender/recipient columns and session owner/peer references are
+/// rewritten in one transaction (the message database is attached), then our
+/// own multiparty participant entries are re-keyed. config.yaml and
+/// settings.json are only written after that succeeds. Safe to re-run: a
+/// second pass finds nothing left to migrate and only re-asserts the config email.
+#[tauri::command]
+pub fn migrate_identity(
+    old_email: String,
+    new_email: String,
+) -> Result<IdentityMigrationResult, String> {
+    let old_email = old_email.trim().to_string();
+    let new_email = new_email.trim().to_string();
+    if old_email.is_empty() || new_email.is_empty() {
+        return Err("Both old and new email are required".to_string());
+    }
+    if old_email.eq_ignore_ascii_case(&new_email) {
+        return Err("Old and new email are the same".to_string());
+    }
+
+    crate::desktop_log!("🔁 Migrating identity {} -> {}", old_email, new_email);
+
+    let config = load_config()?;
+    let db_path = get_message_db_path(&config)
+        .map_err(|e| format!("Failed to locate message database: {}", e))?;
+
+    let (messages_from_updated, messages_to_updated, sessions_owner_updated, sessions_peer_updated) =
+        migrate_identity_rows(&db_path, &old_email, &new_email)?;
+
+    let multiparty_sessions_updated =
+        crate::commands::multiparty::migrate_own_participant_email(&old_email, &new_email)?;
+
+    let biovault_home = biovault::config::get_biovault_home()
+        .map_err(|e| format!("Failed to get BioVault home: {}", e))?;
+
+    let mut config = config;
+    let config_updated = config.email != new_email;
+    if config_updated {
+        config.email = new_email.clone();
+        config
+            .save(&biovault_home.join("config.yaml"))
+            .map_err(|e| format!("Failed to save config: {}", e))?;
+    }
+
+    let settings_path = biovault_home.join("database").join("settings.json");
+    let mut settings_updated = false;
+    if settings_path.exists() {
+        let content = fs::read_to_string(&settings_path)
+            .map_err(|e| format!("Failed to read settings: {}", e))?;
+        let mut settings: crate::types::Settings = serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse settings: {}", e))?;
+        if settings.email != new_email {
+            settings.email = new_email.clone();
+            let json = serde_json::to_string_pretty(&settings)
+                .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+            fs::write(&settings_path, json)
+                .map_err(|e| format!("Failed to write settings: {}", e))?;
+            settings_updated = true;
+        }
+    }
+
+    if let Err(err) = crate::commands::profiles::register_current_profile_email(&new_email) {
+        crate::desktop_log!("⚠️ Failed to refresh profile registration: {}", err);
+    }
+
+    crate::desktop_log!(
+        "✅ Identity migrated: {} sent, {} received, {} owned sessions, {} peer sessions, {} multiparty sessions",
+        messages_from_updated,
+        messages_to_updated,
+        sessions_owner_updated,
+        sessions_peer_updated,
+        multiparty_sessions_updated
+    );
+
+    Ok(IdentityMigrationResult {
+        old_email,
+        new_email,
+        messages_from_updated,
+        messages_to_updated,
+        sessions_owner_updated,
+        sessions_peer_updated,
+        multiparty_sessions_updated,
+        config_updated,
+        settings_updated,
+    })
+}
+
+/// Re-key messages and sessions in one transaction. Messages live in their own
+/// database file, so it is attached to the BioVault connection for the duration.
+fn migrate_identity_rows(
+    message_db_path: &Path,
+    old_email: &str,
+    new_email: &str,
+) -> Result<(usize, usize, usize, usize), String> {
+    let mut db =
+        biovault::data::BioVaultDb::new().map_err(|e| format!("Failed to open database: {}", e))?;
+    db.conn
+        .execute(
+            "ATTACH DATABASE ?1 AS msg",
+            rusqlite::params![message_db_path.to_string_lossy()],
+        )
+        .map_err(|e| format!("Failed to attach message database: {}", e))?;
+    let result = rekey_identity_rows(&mut db.conn, old_email, new_email);
+    let _ = db.conn.execute("DETACH DATABASE msg", []);
+    result
+}
+
+fn rekey_identity_rows(
+    conn: &mut rusqlite::Connection,
+    old_email: &str,
+    new_email: &str,
+) -> Result<(usize, usize, usize, usize), String> {
+    let tx = conn
+        .transaction()
+        .map_err(|e| format!("Failed to start identity migration: {}", e))?;
+    let from_updated = tx
+        .execute(
+            "UPDATE msg.messages SET from_address = ?2 WHERE from_address = ?1 COLLATE NOCASE",
+            rusqlite::params![old_email, new_email],
+        )
+        .map_err(|e| format!("Failed to migrate message senders: {}", e))?;
+    let to_updated = tx
+        .execute(
+            "UPDATE msg.messages SET to_address = ?2 WHERE to_address = ?1 COLLATE NOCASE",
+            rusqlite::params![old_email, new_email],
+        )
+        .map_err(|e| format!("Failed to migrate message recipients: {}", e))?;
+    let owner_updated = tx
+        .execute(
+            "UPDATE main.sessions SET owner = ?2, updated_at = CURRENT_TIMESTAMP
+             WHERE owner = ?1 COLLATE NOCASE",
+            rusqlite::params![old_email, new_email],
+        )
+        .map_err(|e| format!("Failed to migrate session owners: {}", e))?;
+    let peer_updated = tx
+        .execute(
+            "UPDATE main.sessions SET peer = ?2, updated_at = CURRENT_TIMESTAMP
+             WHERE peer = ?1 COLLATE NOCASE",
+            rusqlite::params![old_email, new_email],
+        )
+        .map_err(|e| format!("Failed to migrate session peers: {}", e))?;
+    tx.commit()
+        .map_err(|e| format!("Failed to commit identity migration: {}", e))?;
+    Ok((from_updated, to_updated, owner_updated, peer_updated))
+}
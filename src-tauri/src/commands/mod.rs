@@ -1,4 +1,5 @@
 pub mod agent_api;
+pub mod container_images;
 pub mod datasets;
 pub mod dependencies;
 pub mod files;
@@ -12,12 +13,15 @@ pub mod multiparty;
 pub mod notifications;
 pub mod participants;
 pub mod profiles;
+pub mod quiet_mode;
 pub mod runs;
+pub mod screenshot;
 pub mod sessions;
 pub mod settings;
 pub mod sql;
 pub mod syftbox;
 pub mod sync_tree;
+pub mod webhooks;
 
 /// Configure a Command to hide the console window on Windows.
 /// This prevents black CMD windows from flashing when spawning child processes.
@@ -1,4 +1,7 @@
-use crate::types::{AppState, Module, ModuleEditorLoadResponse, ModuleListEntry};
+use crate::types::{
+    AppState, Module, ModuleEditorLoadResponse, ModuleListEntry, ModuleReferenceIssue,
+    ModuleRunnableCheck, ModuleRunnableReport,
+};
 use biovault::data::{hash_file, ModuleMetadata, UpdateModuleParams};
 use biovault::module_spec::{self, InputSpec, ModuleSpec, OutputSpec, ParameterSpec};
 use biovault::module_spec::{ModuleAsset, ModuleFile};
@@ -6,6 +9,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::process::Command;
 use tauri::{AppHandle, Manager};
 
 #[derive(Deserialize)]
@@ -956,12 +960,397 @@ pub fn load_module_editor(
     let file_tree = biovault::data::build_module_file_tree(&path_buf)
         .map_err(|e| format!("Failed to build file tree: {}", e))?;
 
+    // Without a module.yaml the workflow is only our default guess, so don't flag it.
+    let reference_issues = if has_module_yaml {
+        check_module_references(&path_buf, &metadata)
+    } else {
+        Vec::new()
+    };
+    if !reference_issues.is_empty() {
+        crate::desktop_log!(
+            "⚠️ Module {} has {} missing file reference(s)",
+            path_buf.display(),
+            reference_issues.len()
+        );
+    }
+
     Ok(ModuleEditorLoadResponse {
         module_id: resolved_module_id,
         module_path: path_buf.to_string_lossy().to_string(),
         metadata,
         file_tree,
         has_module_yaml,
+        reference_issues,
+    })
+}
+
+/// Relative paths of all regular files in a module, skipping hidden and run directories.
+fn list_module_files(module_dir: &Path) -> Vec<String> {
+    const SKIP_DIRS: &[&str] = &["work", "results", "node_modules", "__pycache__"];
+    let mut out = Vec::new();
+    let mut stack = vec![module_dir.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.starts_with('.') {
+                continue;
+            }
+            let path = entry.path();
+            if path.is_dir() {
+                if !SKIP_DIRS.contains(&name.as_str()) {
+                    stack.push(path);
+                }
+            } else if let Ok(rel) = path.strip_prefix(module_dir) {
+                out.push(rel.to_string_lossy().replace('\\', "/"));
+            }
+        }
+    }
+    out.sort();
+    out
+}
+
+/// Suggest files that may be the renamed/moved target of a missing reference:
+/// same file name elsewhere first, otherwise same extension.
+fn find_reference_candidates(reference: &str, module_files: &[String]) -> Vec<String> {
+    let ref_path = Path::new(reference);
+    let file_name = ref_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string());
+    let same_name: Vec<String> = module_files
+        .iter()
+        .filter(|f| {
+            Path::new(f)
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                == file_name
+        })
+        .cloned()
+        .collect();
+    if !same_name.is_empty() {
+        return same_name;
+    }
+    let Some(ext) = ref_path.extension() else {
+        return Vec::new();
+    };
+    module_files
+        .iter()
+        .filter(|f| Path::new(f).extension() == Some(ext))
+        .cloned()
+        .collect()
+}
+
+fn check_module_references(
+    module_dir: &Path,
+    metadata: &ModuleMetadata,
+) -> Vec<ModuleReferenceIssue> {
+    let module_files = list_module_files(module_dir);
+    let mut issues = Vec::new();
+
+    let mut check = |kind: &str, reference: &str| {
+        let reference = reference.trim().replace('\\', "/");
+        // Glob patterns can't be checked against a single path.
+        if reference.is_empty() || reference.contains('*') {
+            return;
+        }
+        let expected = module_dir.join(&reference);
+        if expected.exists() {
+            return;
+        }
+        let found_paths = find_reference_candidates(&reference, &module_files);
+        let message = match found_paths.as_slice() {
+            [] => format!("{} '{}' not found in module", kind, reference),
+            [one] => format!(
+                "{} '{}' not found; did you mean '{}'?",
+                kind, reference, one
+            ),
+            many => format!(
+                "{} '{}' not found; {} similar files exist",
+                kind,
+                reference,
+                many.len()
+            ),
+        };
+        issues.push(ModuleReferenceIssue {
+            kind: kind.to_string(),
+            reference,
+            expected_path: expected.to_string_lossy().to_string(),
+            found_paths,
+            message,
+        });
+    };
+
+    check("workflow", &metadata.workflow);
+    for asset in &metadata.assets {
+        check("asset", asset);
+    }
+
+    issues
+}
+
+/// Check that the workflow entrypoint and assets listed in module.yaml exist.
+#[tauri::command]
+pub fn validate_module_references(
+    module_path: String,
+) -> Result<Vec<ModuleReferenceIssue>, String> {
+    let mut module_dir = PathBuf::from(&module_path);
+    if module_dir.is_file() {
+        module_dir = module_dir
+            .parent()
+            .ok_or_else(|| format!("Invalid module path (file with no parent): {}", module_path))?
+            .to_path_buf();
+    }
+    let metadata = biovault::data::load_module_metadata(&module_dir)
+        .map_err(|e| format!("Failed to read module.yaml: {}", e))?
+        .ok_or_else(|| format!("No module.yaml found in {}", module_dir.display()))?;
+    Ok(check_module_references(&module_dir, &metadata))
+}
+
+/// `spec.runner` kind and entrypoint from module.yaml. Syqure modules without
+/// an explicit entrypoint get the same default the multiparty runner uses.
+fn read_module_runner(module_dir: &Path) -> Result<(Option<String>, Option<String>), String> {
+    let yaml_path = ["module.yaml", "module.yml"]
+        .iter()
+        .map(|name| module_dir.join(name))
+        .find(|path| path.exists())
+        .ok_or_else(|| format!("No module.yaml found in {}", module_dir.display()))?;
+    let yaml = fs::read_to_string(&yaml_path).map_err(|e| {
+        format!(
+            "Failed to read module config {}: {}",
+            yaml_path.display(),
+            e
+        )
+    })?;
+    let parsed: serde_yaml::Value =
+        serde_yaml::from_str(&yaml).map_err(|e| format!("Invalid module yaml: {}", e))?;
+
+    let runner = parsed.get("spec").and_then(|v| v.get("runner"));
+    let field = |key: &str| {
+        runner
+            .and_then(|r| r.get(key))
+            .and_then(|v| v.as_str())
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+    };
+
+    let entrypoint = field("entrypoint");
+    let kind = field("kind")
+        .map(|k| k.to_ascii_lowercase())
+        .or_else(|| match field("template").as_deref() {
+            Some("dynamic-nextflow") => Some("nextflow".to_string()),
+            Some("shell") => Some("shell".to_string()),
+            _ => None,
+        })
+        .or_else(|| {
+            let ext = Path::new(entrypoint.as_deref()?).extension()?.to_str()?;
+            match ext {
+                "codon" => Some("syqure".to_string()),
+                "py" => Some("python".to_string()),
+                "sh" => Some("shell".to_string()),
+                "nf" => Some("nextflow".to_string()),
+                _ => None,
+            }
+        });
+    let entrypoint = match kind.as_deref() {
+        Some("syqure") => entrypoint.or_else(|| Some("smpc_aggregate.codon".to_string())),
+        _ => entrypoint,
+    };
+
+    Ok((kind, entrypoint))
+}
+
+#[cfg(target_os = "windows")]
+fn configure_child_process(cmd: &mut Command) {
+    use std::os::windows::process::CommandExt;
+    const CREATE_NO_WINDOW: u32 = 0x08000000;
+    cmd.creation_flags(CREATE_NO_WINDOW);
+}
+
+#[cfg(not(target_os = "windows"))]
+fn configure_child_process(_cmd: &mut Command) {}
+
+fn find_on_path(name: &str) -> Option<PathBuf> {
+    let exe = if cfg!(windows) {
+        format!("{}.exe", name)
+    } else {
+        name.to_string()
+    };
+    std::env::split_paths(&std::env::var_os("PATH")?)
+        .map(|dir| dir.join(&exe))
+        .find(|candidate| candidate.is_file())
+}
+
+/// Binary that launches the given runner kind, honouring configured and
+/// bundled paths before falling back to PATH.
+fn resolve_runner_runtime(kind: &str) -> Option<PathBuf> {
+    let cfg = biovault::config::Config::load().ok();
+    let configured = |name: &str| {
+        super::runs::resolve_binary_path(cfg.as_ref(), name)
+            .map(PathBuf::from)
+            .filter(|path| path.is_file())
+    };
+    match kind {
+        "syqure" => configured("syqure")
+            .or_else(|| find_on_path("syqure"))
+            .or_else(|| find_on_path("codon")),
+        "python" => find_on_path("python3").or_else(|| find_on_path("python")),
+        "shell" => find_on_path("bash").or_else(|| find_on_path("sh")),
+        "nextflow" => configured("nextflow").or_else(|| find_on_path("nextflow")),
+        _ => None,
+    }
+}
+
+fn syntax_check_command(kind: &str, runtime: &Path, entrypoint: &Path) -> Option<Command> {
+    let mut cmd = Command::new(runtime);
+    match kind {
+        "python" => {
+            cmd.args([
+                "-c",
+                "import ast, sys; ast.parse(open(sys.argv[1], encoding='utf-8').read(), sys.argv[1])",
+            ]);
+        }
+        "shell" => {
+            cmd.arg("-n");
+        }
+        _ => return None,
+    }
+    cmd.arg(entrypoint);
+    configure_child_process(&mut cmd);
+    Some(cmd)
+}
+
+/// Entrypoint and runtime checks for a module directory. With
+/// `include_runtime` false only the files themselves are inspected, which is
+/// what invitation preflight wants since other parties may run the step.
+pub(crate) fn module_runnable_checks(
+    module_dir: &Path,
+    include_runtime: bool,
+) -> Result<(Option<String>, Option<String>, Vec<ModuleRunnableCheck>), String> {
+    let (kind, entrypoint) = read_module_runner(module_dir)?;
+    let mut checks = Vec::new();
+
+    let Some(entrypoint_rel) = entrypoint.as_deref() else {
+        return Ok((kind, entrypoint, checks));
+    };
+    let entrypoint_path = module_dir.join(entrypoint_rel);
+    let entrypoint_display = Some(entrypoint_path.to_string_lossy().to_string());
+
+    let exists = entrypoint_path.is_file();
+    checks.push(ModuleRunnableCheck {
+        check: "entrypoint_exists".to_string(),
+        ok: exists,
+        path: entrypoint_display.clone(),
+        message: if exists {
+            format!("Entrypoint '{}' found", entrypoint_rel)
+        } else {
+            format!("Entrypoint '{}' not found in module", entrypoint_rel)
+        },
+    });
+    if !exists {
+        return Ok((kind, entrypoint, checks));
+    }
+
+    let size = fs::metadata(&entrypoint_path).map(|m| m.len()).unwrap_or(0);
+    checks.push(ModuleRunnableCheck {
+        check: "entrypoint_non_empty".to_string(),
+        ok: size > 0,
+        path: entrypoint_display.clone(),
+        message: if size > 0 {
+            format!("Entrypoint is {} bytes", size)
+        } else {
+            format!("Entrypoint '{}' is empty", entrypoint_rel)
+        },
+    });
+
+    let Some(kind_name) = kind.as_deref().filter(|_| include_runtime) else {
+        return Ok((kind, entrypoint, checks));
+    };
+    if !matches!(kind_name, "syqure" | "python" | "shell" | "nextflow") {
+        return Ok((kind, entrypoint, checks));
+    }
+
+    let runtime = resolve_runner_runtime(kind_name);
+    checks.push(ModuleRunnableCheck {
+        check: "runtime_available".to_string(),
+        ok: runtime.is_some(),
+        path: runtime.as_ref().map(|p| p.to_string_lossy().to_string()),
+        message: match runtime.as_ref() {
+            Some(path) => format!("{} runtime found at {}", kind_name, path.display()),
+            None => format!(
+                "No {} runtime found (configure it in Dependencies)",
+                kind_name
+            ),
+        },
+    });
+
+    if let Some(mut cmd) = runtime
+        .as_deref()
+        .and_then(|rt| syntax_check_command(kind_name, rt, &entrypoint_path))
+    {
+        let (ok, message) = match cmd.output() {
+            Ok(output) if output.status.success() => (true, "Syntax check passed".to_string()),
+            Ok(output) => {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                let detail = stderr
+                    .lines()
+                    .rev()
+                    .find(|line| !line.trim().is_empty())
+                    .unwrap_or("syntax check failed")
+                    .trim()
+                    .to_string();
+                (false, format!("Syntax error: {}", detail))
+            }
+            Err(e) => (false, format!("Failed to run syntax check: {}", e)),
+        };
+        checks.push(ModuleRunnableCheck {
+            check: "syntax".to_string(),
+            ok,
+            path: entrypoint_display,
+            message,
+        });
+    }
+
+    Ok((kind, entrypoint, checks))
+}
+
+/// Confirm a module's entrypoint is present, non-empty and launchable by its
+/// runner before a flow step tries to run it.
+#[tauri::command]
+pub async fn check_module_runnable(
+    state: tauri::State<'_, AppState>,
+    module_id: i64,
+) -> Result<ModuleRunnableReport, String> {
+    let module_path = {
+        let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+        db.get_module(&module_id.to_string())
+            .map_err(|e| format!("Failed to load module {}: {}", module_id, e))?
+            .ok_or_else(|| format!("Module {} not found", module_id))?
+            .module_path
+    };
+    let mut module_dir = PathBuf::from(&module_path);
+    if module_dir.is_file() {
+        module_dir = module_dir
+            .parent()
+            .ok_or_else(|| format!("Invalid module path (file with no parent): {}", module_path))?
+            .to_path_buf();
+    }
+
+    // Syntax checks spawn interpreters, so keep them off the async runtime.
+    let dir = module_dir.clone();
+    let (runner_kind, entrypoint, checks) =
+        tauri::async_runtime::spawn_blocking(move || module_runnable_checks(&dir, true))
+            .await
+            .map_err(|e| format!("Failed to check module: {}", e))??;
+
+    Ok(ModuleRunnableReport {
+        module_id,
+        module_dir: module_dir.to_string_lossy().to_string(),
+        runner_kind,
+        entrypoint,
+        runnable: checks.iter().all(|c| c.ok),
+        checks,
     })
 }
 
@@ -1300,3 +1689,249 @@ pub fn get_flow_template_catalog(app: AppHandle) -> Result<Vec<FlowTemplateCatal
 
     Ok(resolved)
 }
+
+#[derive(Debug, Serialize)]
+pub struct FlowExample {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub source: String,
+    /// Whether the spec was read from a local copy; remote-only entries carry
+    /// just the catalog description.
+    pub local: bool,
+    pub participant_count: Option<usize>,
+    pub roles: Vec<String>,
+    pub uses_mpc: bool,
+}
+
+fn describe_flow_example(entry: FlowTemplateCatalogEntry) -> FlowExample {
+    let mut example = FlowExample {
+        local: Path::new(&entry.source).is_file(),
+        id: entry.id,
+        name: entry.name,
+        description: entry.description,
+        source: entry.source,
+        participant_count: None,
+        roles: Vec::new(),
+        uses_mpc: false,
+    };
+    if !example.local {
+        return example;
+    }
+    let Some(doc) = fs::read_to_string(&example.source)
+        .ok()
+        .and_then(|content| serde_yaml::from_str::<serde_yaml::Value>(&content).ok())
+    else {
+        return example;
+    };
+
+    if example.description.trim().is_empty() {
+        if let Some(desc) = doc["metadata"]["description"].as_str() {
+            example.description = desc.trim().to_string();
+        }
+    }
+    let spec = &doc["spec"];
+    example.uses_mpc = !spec["mpc"].is_null();
+    if let Some(groups) = spec["datasites"]["groups"].as_mapping() {
+        example.roles = groups
+            .keys()
+            .filter_map(|k| k.as_str().map(|s| s.to_string()))
+            .collect();
+    }
+    // `datasites.all` is either a literal list or a binding like `inputs.datasites`.
+    let all = &spec["datasites"]["all"];
+    example.participant_count = match all {
+        serde_yaml::Value::Sequence(list) => Some(list.len()),
+        serde_yaml::Value::String(binding) => binding
+            .strip_prefix("inputs.")
+            .and_then(|input| spec["inputs"][input]["default"].as_sequence())
+            .map(|list| list.len()),
+        _ => None,
+    };
+    example
+}
+
+/// Example flows from the template catalog, with participant/role/MPC details
+/// read from the flow spec when a local copy is available.
+#[tauri::command]
+pub fn get_available_flow_examples(app: AppHandle) -> Result<Vec<FlowExample>, String> {
+    Ok(get_flow_template_catalog(app)?
+        .into_iter()
+        .map(describe_flow_example)
+        .collect())
+}
+
+/// Import an example flow (and its modules) by catalog id.
+#[tauri::command]
+pub async fn create_flow_from_example(
+    app: AppHandle,
+    name: String,
+    overwrite: Option<bool>,
+) -> Result<String, String> {
+    let entry = get_flow_template_catalog(app)?
+        .into_iter()
+        .find(|entry| entry.id == name || entry.name == name)
+        .ok_or_else(|| format!("Unknown flow example '{}'", name))?;
+
+    let mut source = entry.source;
+    if source.contains("github.com") && (source.contains("/blob/") || source.contains("/tree/")) {
+        source = source
+            .replacen("github.com", "raw.githubusercontent.com", 1)
+            .replacen("/blob/", "/", 1)
+            .replacen("/tree/", "/", 1);
+    }
+
+    crate::desktop_log!("🧪 Creating flow from example '{}' ({})", entry.id, source);
+    import_flow_with_deps(source, None, overwrite.unwrap_or(false)).await
+}
+
+#[derive(Debug, Serialize)]
+pub struct ModuleUsage {
+    pub flow_id: i64,
+    pub flow_name: String,
+    pub flow_path: String,
+    /// Steps whose `uses` resolves to the module.
+    pub step_ids: Vec<String>,
+}
+
+fn canonical_dir(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Resolve a step's `uses` the same way flow loading does: a key in
+/// `spec.modules` (local `source.path` or managed-module name), an inline
+/// `{ source: { path } }` ref, a relative/absolute path, or a bare module name.
+/// Returns the module name it refers to (when known) and its directory.
+fn resolve_step_module(
+    uses: &serde_yaml::Value,
+    modules: Option<&serde_yaml::Mapping>,
+    flow_dir: &Path,
+    modules_dir: Option<&Path>,
+) -> (Option<String>, Option<PathBuf>) {
+    let local_path = |def: &serde_yaml::Value| {
+        def.get("source")
+            .and_then(|s| s.get("path").or_else(|| s.get("subpath")))
+            .and_then(|p| p.as_str())
+            .map(|p| {
+                let candidate = Path::new(p);
+                if candidate.is_absolute() {
+                    candidate.to_path_buf()
+                } else {
+                    flow_dir.join(candidate)
+                }
+            })
+    };
+
+    if let Some(name) = uses.as_str() {
+        if let Some(def) = modules.and_then(|m| m.get(name)) {
+            let ref_name = def
+                .get("name")
+                .and_then(|n| n.as_str())
+                .unwrap_or(name)
+                .to_string();
+            let dir = local_path(def).or_else(|| modules_dir.map(|root| root.join(&ref_name)));
+            return (Some(ref_name), dir);
+        }
+        if name.contains("://") {
+            return (None, None);
+        }
+        if name.starts_with('.') || name.contains('/') || name.contains('\\') {
+            let candidate = Path::new(name);
+            let dir = if candidate.is_absolute() {
+                candidate.to_path_buf()
+            } else {
+                flow_dir.join(candidate)
+            };
+            return (None, Some(dir));
+        }
+        return (
+            Some(name.to_string()),
+            modules_dir.map(|root| root.join(name)),
+        );
+    }
+
+    let ref_name = uses
+        .get("name")
+        .and_then(|n| n.as_str())
+        .map(|s| s.to_string());
+    (ref_name, local_path(uses))
+}
+
+/// Flows (and the steps within them) that use a module, matched by resolved
+/// directory or by module name. Run this before editing or deleting a module.
+#[tauri::command]
+pub fn find_flows_using_module(
+    state: tauri::State<AppState>,
+    module_id_or_name: String,
+) -> Result<Vec<ModuleUsage>, String> {
+    let key = module_id_or_name.trim();
+    if key.is_empty() {
+        return Err("Module id or name is required".to_string());
+    }
+    let (module, flows) = {
+        let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+        let module = db
+            .get_module(key)
+            .map_err(|e| format!("Failed to load module {}: {}", key, e))?
+            .ok_or_else(|| format!("Module '{}' not found", key))?;
+        let flows = db
+            .list_flows()
+            .map_err(|e| format!("Failed to list flows: {}", e))?;
+        (module, flows)
+    };
+    let module_dir = canonical_dir(Path::new(&module.module_path));
+    let modules_dir = super::flows::get_modules_dir().ok();
+
+    let mut usages = Vec::new();
+    for flow in flows {
+        let flow_dir = PathBuf::from(&flow.flow_path);
+        let Some(doc) = fs::read_to_string(flow_dir.join(biovault::flow_spec::FLOW_YAML_FILE))
+            .ok()
+            .and_then(|raw| serde_yaml::from_str::<serde_yaml::Value>(&raw).ok())
+        else {
+            continue;
+        };
+        let spec = doc.get("spec").unwrap_or(&doc);
+        let modules = spec.get("modules").and_then(|m| m.as_mapping());
+        let Some(steps) = spec.get("steps").and_then(|s| s.as_sequence()) else {
+            continue;
+        };
+
+        let step_ids: Vec<String> = steps
+            .iter()
+            .enumerate()
+            .filter_map(|(index, step)| {
+                let uses = step.get("uses")?;
+                let (ref_name, dir) =
+                    resolve_step_module(uses, modules, &flow_dir, modules_dir.as_deref());
+                let by_path = dir.is_some_and(|d| canonical_dir(&d) == module_dir);
+                let by_name = ref_name.is_some_and(|n| n.eq_ignore_ascii_case(&module.name));
+                if !by_path && !by_name {
+                    return None;
+                }
+                Some(
+                    step.get("id")
+                        .and_then(|id| id.as_str())
+                        .map(|id| id.to_string())
+                        .unwrap_or_else(|| format!("step-{}", index + 1)),
+                )
+            })
+            .collect();
+
+        if !step_ids.is_empty() {
+            usages.push(ModuleUsage {
+                flow_id: flow.id,
+                flow_name: flow.name,
+                flow_path: flow.flow_path,
+                step_ids,
+            });
+        }
+    }
+
+    crate::desktop_log!(
+        "🔗 Module '{}' is used by {} flow(s)",
+        module.name,
+        usages.len()
+    );
+    Ok(usages)
+}
@@ -5,7 +5,7 @@ use biovault::subscriptions;
 use chrono::{TimeZone, Utc};
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
-use std::collections::{BTreeSet, HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::env;
 use std::fs;
 use std::hash::{Hash, Hasher};
@@ -19,6 +19,8 @@ use std::time::{Duration, SystemTime, UNIX_EPOCH};
 const SEQURE_COMMUNICATION_PORT_STRIDE: usize = 1000;
 const SEQURE_DATA_SHARING_PORT_OFFSET: usize = 10_000;
 const SEQURE_PORT_BASE_MIN: usize = 20_000;
+/// Seeds the built-in `generate` step when no explicit seed is passed.
+const MULTIPARTY_GENERATE_SEED_ENV: &str = "BV_MULTIPARTY_GENERATE_SEED";
 
 fn flow_spec_root(flow_spec: &serde_json::Value) -> &serde_json::Value {
     flow_spec.get("spec").unwrap_or(flow_spec)
@@ -1102,6 +1104,27 @@ fn validate_module_assets_exist(module_dir: &Path) -> Result<(), String> {
     }
 }
 
+fn validate_module_entrypoint(module_dir: &Path) -> Result<(), String> {
+    if !module_dir.join("module.yaml").exists() && !module_dir.join("module.yml").exists() {
+        return Ok(());
+    }
+    let (_, _, checks) = super::modules::module_runnable_checks(module_dir, false)?;
+    let failures: Vec<String> = checks
+        .into_iter()
+        .filter(|c| !c.ok)
+        .map(|c| c.message)
+        .collect();
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Module entrypoint not runnable in {}: {}",
+            module_dir.display(),
+            failures.join("; ")
+        ))
+    }
+}
+
 fn preflight_validate_flow_modules(
     flow_name: &str,
     flow_spec: &serde_json::Value,
@@ -1148,6 +1171,9 @@ fn preflight_validate_flow_modules(
         if let Err(err) = validate_module_assets_exist(&module_dir) {
             issues.push(format!("step '{}': {}", step_id, err));
         }
+        if let Err(err) = validate_module_entrypoint(&module_dir) {
+            issues.push(format!("step '{}': {}", step_id, err));
+        }
     }
 
     if issues.is_empty() {
@@ -2583,6 +2609,25 @@ fn find_participant_step_file(
     })
 }
 
+/// Locate a file `sender` shared from `step_id`, as synced to this viewer.
+pub(crate) fn find_synced_step_output_file(
+    viewer_email: &str,
+    sender: &str,
+    flow_name: &str,
+    session_id: &str,
+    step_id: &str,
+    file_name: &str,
+) -> Option<PathBuf> {
+    let biovault_home = biovault::config::get_biovault_home().ok()?;
+    participant_flow_dirs_for_viewer(&biovault_home, viewer_email, sender, flow_name, session_id)
+        .into_iter()
+        .find_map(|base| {
+            resolve_step_output_dir_by_id_any_number(&base, step_id)
+                .map(|dir| dir.join(file_name))
+                .filter(|path| path.is_file())
+        })
+}
+
 fn resolve_step_output_dir_by_id_any_number(base: &PathBuf, step_id: &str) -> Option<PathBuf> {
     let entries = fs::read_dir(base).ok()?;
     let mut best: Option<(usize, PathBuf)> = None;
@@ -2839,6 +2884,30 @@ pub struct MultipartyFlowState {
     pub flow_spec: Option<serde_json::Value>,
     #[serde(default)]
     pub syqure_port_base: Option<usize>,
+    /// Verbose `_mpc/file_transport.log` output for steps run in this session.
+    #[serde(default)]
+    pub mpc_verbose_logging: bool,
+    #[serde(default)]
+    pub auto_run_policy: AutoRunPolicy,
+    /// Cap on the total bytes this participant may share in the session.
+    #[serde(default)]
+    pub output_budget_bytes: Option<u64>,
+    #[serde(default)]
+    pub shared_output_bytes: u64,
+}
+
+/// Which Ready steps the session executes on its own.
+/// Per-step `auto_run` flags are honored under every policy.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AutoRunPolicy {
+    #[default]
+    Manual,
+    All,
+    /// Run steps automatically unless they share outputs with other participants.
+    NonSharingOnly,
+    /// Run everything before the first barrier step, then hand back control.
+    UntilBarrier,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -2884,6 +2953,12 @@ pub struct StepState {
     pub input_waiting_on: Vec<String>,
     #[serde(default)]
     pub input_waiting_reason: Option<String>,
+    /// Outputs were shared but the chat results message has not been posted yet.
+    #[serde(default)]
+    pub share_message_pending: bool,
+    /// Local module checkout used instead of the resolved module (for module authors)
+    #[serde(default)]
+    pub module_override: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
@@ -2945,23 +3020,167 @@ pub struct MultipartyPeerTelemetryDiagnostics {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
-struct HotlinkTelemetrySnapshot {
-    mode: String,
-    updated_ms: Option<u64>,
-    tx_packets: u64,
-    tx_bytes: u64,
-    tx_quic_packets: u64,
-    tx_ws_packets: u64,
-    tx_avg_send_ms: f64,
-    rx_packets: u64,
-    rx_bytes: u64,
-    rx_avg_write_ms: f64,
-    ws_fallbacks: u64,
+pub struct HotlinkTelemetrySnapshot {
+    pub mode: String,
+    pub updated_ms: Option<u64>,
+    pub tx_packets: u64,
+    pub tx_bytes: u64,
+    pub tx_quic_packets: u64,
+    pub tx_ws_packets: u64,
+    pub tx_avg_send_ms: f64,
+    pub rx_packets: u64,
+    pub rx_bytes: u64,
+    pub rx_avg_write_ms: f64,
+    pub ws_fallbacks: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HotlinkTelemetryEntry {
+    pub email: String,
+    pub is_local: bool,
+    pub path: String,
+    pub age_ms: Option<u64>,
+    pub stale: bool,
+    pub telemetry: HotlinkTelemetrySnapshot,
 }
 
 static FLOW_SESSIONS: Lazy<Mutex<HashMap<String, MultipartyFlowState>>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
 
+/// Sessions that are accepted or running locally; used to guard profile switches.
+pub(crate) fn active_multiparty_session_count() -> usize {
+    FLOW_SESSIONS
+        .lock()
+        .map(|sessions| {
+            sessions
+                .values()
+                .filter(|s| {
+                    matches!(
+                        s.status,
+                        FlowSessionStatus::Accepted | FlowSessionStatus::Running
+                    )
+                })
+                .count()
+        })
+        .unwrap_or(0)
+}
+
+/// Drop in-memory sessions, e.g. after switching to another profile.
+pub(crate) fn clear_flow_sessions() {
+    if let Ok(mut sessions) = FLOW_SESSIONS.lock() {
+        sessions.clear();
+    }
+}
+
+fn rewrite_own_email(
+    flow_state: &mut MultipartyFlowState,
+    old_email: &str,
+    new_email: &str,
+) -> bool {
+    let mut changed = false;
+    let mut swap = |value: &mut String| {
+        if value.eq_ignore_ascii_case(old_email) {
+            *value = new_email.to_string();
+            changed = true;
+        }
+    };
+    swap(&mut flow_state.my_email);
+    for participant in flow_state.participants.iter_mut() {
+        swap(&mut participant.email);
+    }
+    for step in flow_state.steps.iter_mut() {
+        step.target_emails.iter_mut().for_each(&mut swap);
+        step.share_to.iter_mut().for_each(&mut swap);
+        step.input_waiting_on.iter_mut().for_each(&mut swap);
+    }
+    changed
+}
+
+/// Re-key our own participant entries after an identity migration, both in
+/// memory and in every `multiparty.state.json` under the old or new datasite.
+/// Returns the number of sessions that changed.
+pub(crate) fn migrate_own_participant_email(
+    old_email: &str,
+    new_email: &str,
+) -> Result<usize, String> {
+    let mut migrated: HashSet<String> = HashSet::new();
+    {
+        let mut sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
+        for (session_id, flow_state) in sessions.iter_mut() {
+            if rewrite_own_email(flow_state, old_email, new_email) {
+                persist_multiparty_state(flow_state)?;
+                migrated.insert(session_id.clone());
+            }
+        }
+    }
+
+    let biovault_home = biovault::config::get_biovault_home()
+        .map_err(|e| format!("Failed to get BioVault home: {}", e))?;
+    for owner in [old_email, new_email] {
+        let flows_root = biovault_home
+            .join("datasites")
+            .join(owner)
+            .join("shared")
+            .join("flows");
+        if !flows_root.is_dir() {
+            continue;
+        }
+        for state_path in walkdir::WalkDir::new(&flows_root)
+            .min_depth(3)
+            .max_depth(3)
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter(|e| e.file_name() == "multiparty.state.json")
+            .map(|e| e.into_path())
+        {
+            let raw = fs::read_to_string(&state_path)
+                .map_err(|e| format!("Failed to read {}: {}", state_path.display(), e))?;
+            let mut flow_state: MultipartyFlowState = serde_json::from_str(&raw)
+                .map_err(|e| format!("Failed to parse {}: {}", state_path.display(), e))?;
+            if !rewrite_own_email(&mut flow_state, old_email, new_email) {
+                continue;
+            }
+            let state_json = serde_json::to_string_pretty(&flow_state)
+                .map_err(|e| format!("Failed to serialize state: {}", e))?;
+            fs::write(&state_path, state_json).map_err(|e| {
+                format!("Failed to write state file {}: {}", state_path.display(), e)
+            })?;
+            migrated.insert(flow_state.session_id);
+        }
+    }
+
+    Ok(migrated.len())
+}
+
+/// Execute a module step in this process. Party context goes through the
+/// task-local execution context and MPC verbosity through the run settings,
+/// never the process environment, so concurrent sessions can't see each other's.
+async fn execute_module_step(
+    dynamic_ctx: run_dynamic::DynamicExecutionContext,
+    module_dir: &Path,
+    args: Vec<String>,
+    output_dir: &Path,
+    mpc_verbose: bool,
+) -> Result<(), String> {
+    let settings = run_dynamic::RunSettings {
+        syqure_debug: mpc_verbose,
+        ..Default::default()
+    };
+    run_dynamic::with_execution_context(
+        dynamic_ctx,
+        run_dynamic::execute_dynamic(
+            &module_dir.to_string_lossy(),
+            args,
+            false,
+            false,
+            Some(output_dir.to_string_lossy().to_string()),
+            settings,
+        ),
+    )
+    .await
+    .map_err(|e| e.to_string())
+}
+
 /// Remove a multiparty session from in-memory cache so invitations can be re-accepted.
 /// Called when a flow run is deleted to allow "Join Flow" again from messages.
 pub fn clear_multiparty_session(session_id: &str) {
@@ -3491,7 +3710,79 @@ fn apply_terminal_run_update(app_state: &AppState, terminal_update: Option<(Stri
     };
     if let Ok(biovault_db) = app_state.biovault_db.lock() {
         let _ = biovault_db.update_flow_run_status(run_id, &status, true);
+        super::webhooks::notify_run_completion(&biovault_db, run_id, &status, None);
+    }
+}
+
+/// Role -> emails per flow name, kept locally so recurring collaborations can
+/// be set up in one click.
+type FlowRoleDefaults = BTreeMap<String, BTreeMap<String, Vec<String>>>;
+
+fn flow_role_defaults_path() -> Result<PathBuf, String> {
+    let biovault_home = biovault::config::get_biovault_home()
+        .map_err(|e| format!("Failed to get BioVault home: {}", e))?;
+    Ok(biovault_home
+        .join(".biovault")
+        .join("flow_role_defaults.json"))
+}
+
+fn load_flow_role_defaults() -> Result<FlowRoleDefaults, String> {
+    let path = flow_role_defaults_path()?;
+    if !path.exists() {
+        return Ok(FlowRoleDefaults::new());
     }
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read flow role defaults: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse flow role defaults: {}", e))
+}
+
+#[tauri::command]
+pub fn get_flow_role_defaults(flow_name: String) -> Result<BTreeMap<String, Vec<String>>, String> {
+    Ok(load_flow_role_defaults()?
+        .remove(&flow_name)
+        .unwrap_or_default())
+}
+
+/// Remember which emails fill each role of a flow. An empty map clears them.
+#[tauri::command]
+pub fn save_flow_role_defaults(
+    flow_name: String,
+    roles: BTreeMap<String, Vec<String>>,
+) -> Result<BTreeMap<String, Vec<String>>, String> {
+    let roles: BTreeMap<String, Vec<String>> = roles
+        .into_iter()
+        .map(|(role, emails)| {
+            let mut seen = HashSet::new();
+            let emails: Vec<String> = emails
+                .into_iter()
+                .map(|email| email.trim().to_string())
+                .filter(|email| !email.is_empty() && seen.insert(email.to_ascii_lowercase()))
+                .collect();
+            (role.trim().to_string(), emails)
+        })
+        .filter(|(role, emails)| !role.is_empty() && !emails.is_empty())
+        .collect();
+
+    let mut all = load_flow_role_defaults()?;
+    if roles.is_empty() {
+        all.remove(&flow_name);
+    } else {
+        all.insert(flow_name.clone(), roles.clone());
+    }
+    let path = flow_role_defaults_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create flow role defaults dir: {}", e))?;
+    }
+    let json = serde_json::to_string_pretty(&all)
+        .map_err(|e| format!("Failed to serialize flow role defaults: {}", e))?;
+    fs::write(&path, json).map_err(|e| format!("Failed to write flow role defaults: {}", e))?;
+    crate::desktop_log!(
+        "👥 Saved role defaults for flow '{}' ({} roles)",
+        flow_name,
+        roles.len()
+    );
+    Ok(roles)
 }
 
 #[tauri::command]
@@ -3502,6 +3793,27 @@ pub async fn send_flow_invitation(
     flow_spec: serde_json::Value,
     participant_roles: Vec<FlowParticipant>,
 ) -> Result<String, String> {
+    // No assignment given: fall back to the roles saved for this flow.
+    let participant_roles = if participant_roles.is_empty() {
+        let defaults = get_flow_role_defaults(flow_name.clone())?;
+        if defaults.is_empty() {
+            return Err(format!(
+                "No participants given and no role defaults saved for flow '{}'",
+                flow_name
+            ));
+        }
+        defaults
+            .into_iter()
+            .flat_map(|(role, emails)| {
+                emails.into_iter().map(move |email| FlowParticipant {
+                    email,
+                    role: role.clone(),
+                })
+            })
+            .collect()
+    } else {
+        participant_roles
+    };
     let session_id = uuid::Uuid::new_v4().to_string();
 
     let config =
@@ -3517,6 +3829,15 @@ pub async fn send_flow_invitation(
     preflight_validate_flow_modules(&flow_name, &flow_spec)?;
 
     let steps = parse_flow_steps(&flow_spec, &my_email, &participant_roles)?;
+    if let Ok(idle) = find_idle_participants(&flow_spec, &participant_roles) {
+        for p in idle {
+            crate::desktop_log!(
+                "⚠️ Inviting {} as '{}' but no step targets them",
+                p.email,
+                p.role
+            );
+        }
+    }
 
     // Set up work_dir for the proposer too (same as accept_flow_invitation)
     let work_dir = get_shared_flow_path(&flow_name, &session_id)?;
@@ -3528,6 +3849,7 @@ pub async fn send_flow_invitation(
 
     // Log "joined" event for the proposer
     append_progress_log(&progress_dir, "joined", None, &my_role);
+    write_platform_handshake(&progress_dir, &my_email);
 
     // Only coordination/progress data is globally shared.
     let all_participant_emails: Vec<String> =
@@ -3579,6 +3901,10 @@ pub async fn send_flow_invitation(
         input_overrides,
         flow_spec: Some(flow_spec.clone()),
         syqure_port_base,
+        mpc_verbose_logging: false,
+        auto_run_policy: AutoRunPolicy::Manual,
+        output_budget_bytes: None,
+        shared_output_bytes: 0,
     };
     let _ = persist_multiparty_state(&flow_state);
 
@@ -3754,6 +4080,10 @@ pub async fn accept_flow_invitation(
         input_overrides,
         flow_spec: Some(flow_spec.clone()),
         syqure_port_base,
+        mpc_verbose_logging: false,
+        auto_run_policy: AutoRunPolicy::Manual,
+        output_budget_bytes: None,
+        shared_output_bytes: 0,
     };
 
     // Save state to file for persistence
@@ -3762,6 +4092,7 @@ pub async fn accept_flow_invitation(
     // Log "joined" event to progress.json
     let progress_dir = get_progress_path(&work_dir);
     append_progress_log(&progress_dir, "joined", None, &flow_state.my_role);
+    write_platform_handshake(&progress_dir, &flow_state.my_email);
     write_progress_state(
         &progress_dir,
         &flow_state.my_role,
@@ -4365,11 +4696,58 @@ pub async fn get_participant_logs(session_id: String) -> Result<Vec<LogEntry>, S
     Ok(all_logs)
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParticipantJoinStatus {
+    pub email: String,
+    pub role: String,
+    pub is_me: bool,
+    pub joined: bool,
+    pub joined_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionJoinStatus {
+    pub session_id: String,
+    pub joined_count: usize,
+    pub total: usize,
+    pub waiting_on: Vec<String>,
+    pub participants: Vec<ParticipantJoinStatus>,
+}
+
+/// Earliest "joined" timestamp in a participant's synced progress logs.
+/// Returns (saw_any_event, joined_at) so later activity still counts as joined
+/// when the join event itself hasn't synced.
+fn read_participant_join_event(progress_dir: &Path) -> (bool, Option<String>) {
+    let mut saw_event = false;
+    let mut joined_at: Option<String> = None;
+    for path in [
+        progress_dir.join("log.jsonl"),
+        progress_dir.join("progress.json"),
+    ] {
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        for line in content.lines() {
+            let Ok(entry) = serde_json::from_str::<serde_json::Value>(line) else {
+                continue;
+            };
+            saw_event = true;
+            if entry.get("event").and_then(|e| e.as_str()) != Some("joined") {
+                continue;
+            }
+            if let Some(ts) = entry.get("timestamp").and_then(|t| t.as_str()) {
+                if joined_at.as_deref().map_or(true, |prev| ts < prev) {
+                    joined_at = Some(ts.to_string());
+                }
+            }
+        }
+    }
+    (saw_event, joined_at)
+}
+
+/// Which participants have joined the session, based on their synced `_progress` events.
 #[tauri::command]
-pub async fn get_multiparty_step_diagnostics(
-    session_id: String,
-    step_id: String,
-) -> Result<MultipartyStepDiagnostics, String> {
+pub async fn get_session_join_status(session_id: String) -> Result<SessionJoinStatus, String> {
     let (flow_name, my_email, participants) = {
         let sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
         let flow_state = sessions
@@ -4382,95 +4760,320 @@ pub async fn get_multiparty_step_diagnostics(
         )
     };
 
-    let now_ms = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_millis() as u64;
     let biovault_home = biovault::config::get_biovault_home()
         .map_err(|e| format!("Failed to get BioVault home: {}", e))?;
 
-    let mut channels = Vec::new();
-    for base in participant_flow_dirs_for_viewer(
-        &biovault_home,
-        &my_email,
-        &my_email,
-        &flow_name,
-        &session_id,
-    ) {
-        let mpc_dir = base.join("_mpc");
-        if !mpc_dir.exists() {
-            continue;
-        }
-        channels = collect_mpc_tcp_channel_diagnostics(&mpc_dir);
-        if !channels.is_empty() {
-            break;
+    let mut statuses = Vec::with_capacity(participants.len());
+    for participant in &participants {
+        let mut joined = false;
+        let mut joined_at: Option<String> = None;
+        for progress_dir in participant_flow_dirs_for_viewer(
+            &biovault_home,
+            &my_email,
+            &participant.email,
+            &flow_name,
+            &session_id,
+        )
+        .into_iter()
+        .map(|base| base.join("_progress"))
+        {
+            let (saw_event, ts) = read_participant_join_event(&progress_dir);
+            joined |= saw_event;
+            if let Some(ts) = ts {
+                if joined_at.as_deref().map_or(true, |prev| ts.as_str() < prev) {
+                    joined_at = Some(ts);
+                }
+            }
         }
+        statuses.push(ParticipantJoinStatus {
+            email: participant.email.clone(),
+            role: participant.role.clone(),
+            is_me: participant.email == my_email,
+            joined,
+            joined_at,
+        });
     }
 
-    let mut all_emails: BTreeSet<String> = participants
+    let waiting_on: Vec<String> = statuses
         .iter()
+        .filter(|p| !p.joined)
         .map(|p| p.email.clone())
-        .filter(|e| !e.trim().is_empty())
         .collect();
-    all_emails.insert(my_email.clone());
+    Ok(SessionJoinStatus {
+        session_id,
+        joined_count: statuses.len() - waiting_on.len(),
+        total: statuses.len(),
+        waiting_on,
+        participants: statuses,
+    })
+}
 
-    let mut peers = Vec::new();
-    for email in all_emails {
-        let mut peer = MultipartyPeerTelemetryDiagnostics {
-            email: email.clone(),
-            telemetry_present: false,
-            mode: "unknown".to_string(),
-            mode_short: "unknown".to_string(),
-            status: "pending".to_string(),
-            updated_ms: None,
-            age_ms: None,
-            tx_packets: 0,
-            tx_bytes: 0,
-            tx_quic_packets: 0,
-            tx_ws_packets: 0,
-            tx_avg_send_ms: 0.0,
-            rx_packets: 0,
-            rx_bytes: 0,
-            rx_avg_write_ms: 0.0,
-            ws_fallbacks: 0,
-        };
-        for path in hotlink_telemetry_candidates(&biovault_home, &email) {
-            if let Some(snapshot) = read_hotlink_telemetry(&path) {
-                peer.telemetry_present = true;
-                peer.mode = snapshot.mode.clone();
-                peer.mode_short = short_hotlink_mode(&snapshot.mode).to_string();
-                peer.updated_ms = snapshot.updated_ms;
-                peer.tx_packets = snapshot.tx_packets;
-                peer.tx_bytes = snapshot.tx_bytes;
-                peer.tx_quic_packets = snapshot.tx_quic_packets;
-                peer.tx_ws_packets = snapshot.tx_ws_packets;
-                peer.tx_avg_send_ms = snapshot.tx_avg_send_ms;
-                peer.rx_packets = snapshot.rx_packets;
-                peer.rx_bytes = snapshot.rx_bytes;
-                peer.rx_avg_write_ms = snapshot.rx_avg_write_ms;
-                peer.ws_fallbacks = snapshot.ws_fallbacks;
-                break;
-            }
-        }
-        peer.age_ms = peer
-            .updated_ms
-            .map(|updated| now_ms.saturating_sub(updated));
-        peer.status = if peer.telemetry_present {
-            if peer.age_ms.unwrap_or(0) <= 15_000 {
-                "connected".to_string()
-            } else {
-                "stale".to_string()
-            }
-        } else {
-            "pending".to_string()
-        };
-        peers.push(peer);
-    }
-    peers.sort_by(|a, b| a.email.cmp(&b.email));
+/// Handshake file each participant drops in their `_progress` dir on join.
+const PLATFORM_HANDSHAKE_FILE: &str = "platform.json";
 
-    Ok(MultipartyStepDiagnostics {
-        session_id,
-        step_id,
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlatformHandshake {
+    pub email: String,
+    pub os: String,
+    pub arch: String,
+    #[serde(default)]
+    pub app_version: Option<String>,
+    pub written_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParticipantPlatform {
+    pub email: String,
+    pub role: String,
+    pub is_me: bool,
+    /// None until the participant's handshake has synced.
+    pub os: Option<String>,
+    pub arch: Option<String>,
+    pub app_version: Option<String>,
+    pub reported_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionPlatformInfo {
+    pub session_id: String,
+    pub participants: Vec<ParticipantPlatform>,
+    /// More than one OS/arch combination among participants that reported.
+    pub heterogeneous: bool,
+    /// Informational preflight notes; none of these block the session.
+    pub notes: Vec<String>,
+}
+
+fn write_platform_handshake(progress_dir: &Path, email: &str) {
+    let handshake = PlatformHandshake {
+        email: email.to_string(),
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        app_version: Some(env!("CARGO_PKG_VERSION").to_string()),
+        written_at: Utc::now().to_rfc3339(),
+    };
+    if let Ok(json) = serde_json::to_string_pretty(&handshake) {
+        let _ = fs::write(progress_dir.join(PLATFORM_HANDSHAKE_FILE), json);
+    }
+}
+
+fn read_platform_handshake(progress_dir: &Path) -> Option<PlatformHandshake> {
+    let content = fs::read_to_string(progress_dir.join(PLATFORM_HANDSHAKE_FILE)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Whether the reported platforms differ, plus notes describing what is mixed
+/// or still unknown.
+fn platform_preflight_notes(participants: &[ParticipantPlatform]) -> (bool, Vec<String>) {
+    let mut platforms: BTreeMap<String, Vec<&str>> = BTreeMap::new();
+    for p in participants {
+        if let (Some(os), Some(arch)) = (&p.os, &p.arch) {
+            platforms
+                .entry(format!("{}/{}", os, arch))
+                .or_default()
+                .push(p.email.as_str());
+        }
+    }
+
+    let mut notes = Vec::new();
+    let heterogeneous = platforms.len() > 1;
+    if heterogeneous {
+        let summary: Vec<String> = platforms
+            .iter()
+            .map(|(platform, emails)| format!("{} ({})", platform, emails.join(", ")))
+            .collect();
+        notes.push(format!(
+            "Participants run on mixed platforms: {}. MPC transport can behave differently \
+             across platforms; if a secure step stalls, compare with a same-platform run.",
+            summary.join("; ")
+        ));
+    }
+
+    let unknown: Vec<&str> = participants
+        .iter()
+        .filter(|p| p.os.is_none() || p.arch.is_none())
+        .map(|p| p.email.as_str())
+        .collect();
+    if !unknown.is_empty() {
+        notes.push(format!(
+            "No platform info yet from {} (handshake not synced or older app version).",
+            unknown.join(", ")
+        ));
+    }
+
+    (heterogeneous, notes)
+}
+
+/// Each participant's OS/arch as exchanged through `_progress`, with a note when
+/// the session mixes platforms. Informational only; nothing here blocks a step.
+#[tauri::command]
+pub async fn get_session_platform_info(session_id: String) -> Result<SessionPlatformInfo, String> {
+    let (flow_name, my_email, participants) = {
+        let sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
+        let flow_state = sessions
+            .get(&session_id)
+            .ok_or_else(|| "Flow session not found".to_string())?;
+        (
+            flow_state.flow_name.clone(),
+            flow_state.my_email.clone(),
+            flow_state.participants.clone(),
+        )
+    };
+
+    // Sessions joined before the handshake existed publish it now.
+    if let Ok(work_dir) = get_shared_flow_path(&flow_name, &session_id) {
+        let progress_dir = get_progress_path(&work_dir);
+        if progress_dir.is_dir() && read_platform_handshake(&progress_dir).is_none() {
+            write_platform_handshake(&progress_dir, &my_email);
+        }
+    }
+
+    let biovault_home = biovault::config::get_biovault_home()
+        .map_err(|e| format!("Failed to get BioVault home: {}", e))?;
+
+    let mut platforms = Vec::with_capacity(participants.len());
+    for participant in &participants {
+        let handshake = participant_flow_dirs_for_viewer(
+            &biovault_home,
+            &my_email,
+            &participant.email,
+            &flow_name,
+            &session_id,
+        )
+        .into_iter()
+        .find_map(|base| read_platform_handshake(&base.join("_progress")));
+        platforms.push(ParticipantPlatform {
+            email: participant.email.clone(),
+            role: participant.role.clone(),
+            is_me: participant.email == my_email,
+            os: handshake.as_ref().map(|h| h.os.clone()),
+            arch: handshake.as_ref().map(|h| h.arch.clone()),
+            app_version: handshake.as_ref().and_then(|h| h.app_version.clone()),
+            reported_at: handshake.map(|h| h.written_at),
+        });
+    }
+
+    let (heterogeneous, notes) = platform_preflight_notes(&platforms);
+    if heterogeneous {
+        crate::desktop_log!(
+            "ℹ️ Session {} mixes participant platforms: {}",
+            session_id,
+            notes.first().map(String::as_str).unwrap_or_default()
+        );
+    }
+    Ok(SessionPlatformInfo {
+        session_id,
+        participants: platforms,
+        heterogeneous,
+        notes,
+    })
+}
+
+#[tauri::command]
+pub async fn get_multiparty_step_diagnostics(
+    session_id: String,
+    step_id: String,
+) -> Result<MultipartyStepDiagnostics, String> {
+    let (flow_name, my_email, participants) = {
+        let sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
+        let flow_state = sessions
+            .get(&session_id)
+            .ok_or_else(|| "Flow session not found".to_string())?;
+        (
+            flow_state.flow_name.clone(),
+            flow_state.my_email.clone(),
+            flow_state.participants.clone(),
+        )
+    };
+
+    let now_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+    let biovault_home = biovault::config::get_biovault_home()
+        .map_err(|e| format!("Failed to get BioVault home: {}", e))?;
+
+    let mut channels = Vec::new();
+    for base in participant_flow_dirs_for_viewer(
+        &biovault_home,
+        &my_email,
+        &my_email,
+        &flow_name,
+        &session_id,
+    ) {
+        let mpc_dir = base.join("_mpc");
+        if !mpc_dir.exists() {
+            continue;
+        }
+        channels = collect_mpc_tcp_channel_diagnostics(&mpc_dir);
+        if !channels.is_empty() {
+            break;
+        }
+    }
+
+    let mut all_emails: BTreeSet<String> = participants
+        .iter()
+        .map(|p| p.email.clone())
+        .filter(|e| !e.trim().is_empty())
+        .collect();
+    all_emails.insert(my_email.clone());
+
+    let mut peers = Vec::new();
+    for email in all_emails {
+        let mut peer = MultipartyPeerTelemetryDiagnostics {
+            email: email.clone(),
+            telemetry_present: false,
+            mode: "unknown".to_string(),
+            mode_short: "unknown".to_string(),
+            status: "pending".to_string(),
+            updated_ms: None,
+            age_ms: None,
+            tx_packets: 0,
+            tx_bytes: 0,
+            tx_quic_packets: 0,
+            tx_ws_packets: 0,
+            tx_avg_send_ms: 0.0,
+            rx_packets: 0,
+            rx_bytes: 0,
+            rx_avg_write_ms: 0.0,
+            ws_fallbacks: 0,
+        };
+        for path in hotlink_telemetry_candidates(&biovault_home, &email) {
+            if let Some(snapshot) = read_hotlink_telemetry(&path) {
+                peer.telemetry_present = true;
+                peer.mode = snapshot.mode.clone();
+                peer.mode_short = short_hotlink_mode(&snapshot.mode).to_string();
+                peer.updated_ms = snapshot.updated_ms;
+                peer.tx_packets = snapshot.tx_packets;
+                peer.tx_bytes = snapshot.tx_bytes;
+                peer.tx_quic_packets = snapshot.tx_quic_packets;
+                peer.tx_ws_packets = snapshot.tx_ws_packets;
+                peer.tx_avg_send_ms = snapshot.tx_avg_send_ms;
+                peer.rx_packets = snapshot.rx_packets;
+                peer.rx_bytes = snapshot.rx_bytes;
+                peer.rx_avg_write_ms = snapshot.rx_avg_write_ms;
+                peer.ws_fallbacks = snapshot.ws_fallbacks;
+                break;
+            }
+        }
+        peer.age_ms = peer
+            .updated_ms
+            .map(|updated| now_ms.saturating_sub(updated));
+        peer.status = if peer.telemetry_present {
+            if peer.age_ms.unwrap_or(0) <= 15_000 {
+                "connected".to_string()
+            } else {
+                "stale".to_string()
+            }
+        } else {
+            "pending".to_string()
+        };
+        peers.push(peer);
+    }
+    peers.sort_by(|a, b| a.email.cmp(&b.email));
+
+    Ok(MultipartyStepDiagnostics {
+        session_id,
+        step_id,
         flow_name,
         local_email: my_email,
         generated_at_ms: now_ms,
@@ -4479,6 +5082,61 @@ pub async fn get_multiparty_step_diagnostics(
     })
 }
 
+/// Raw hotlink telemetry for the local node and every synced peer datasite.
+#[tauri::command]
+pub async fn get_hotlink_telemetry() -> Result<Vec<HotlinkTelemetryEntry>, String> {
+    let my_email = get_owner_email()?;
+    let biovault_home = biovault::config::get_biovault_home()
+        .map_err(|e| format!("Failed to get BioVault home: {}", e))?;
+    let now_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+
+    let mut emails: BTreeSet<String> = BTreeSet::new();
+    emails.insert(my_email.clone());
+    let datasites_root = biovault_home.join("datasites");
+    for root in [
+        datasites_root.clone(),
+        datasites_root.join(&my_email).join("datasites"),
+    ] {
+        let Ok(entries) = fs::read_dir(&root) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            if !entry.path().is_dir() {
+                continue;
+            }
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.contains('@') {
+                emails.insert(name);
+            }
+        }
+    }
+
+    let mut entries = Vec::new();
+    for email in emails {
+        for path in hotlink_telemetry_candidates(&biovault_home, &email) {
+            if let Some(snapshot) = read_hotlink_telemetry(&path) {
+                let age_ms = snapshot
+                    .updated_ms
+                    .map(|updated| now_ms.saturating_sub(updated));
+                entries.push(HotlinkTelemetryEntry {
+                    is_local: email == my_email,
+                    email: email.clone(),
+                    path: path.to_string_lossy().to_string(),
+                    age_ms,
+                    stale: age_ms.map(|age| age > 15_000).unwrap_or(true),
+                    telemetry: snapshot,
+                });
+                break;
+            }
+        }
+    }
+
+    Ok(entries)
+}
+
 #[tauri::command]
 pub async fn get_multiparty_step_logs(
     state: tauri::State<'_, AppState>,
@@ -4728,44 +5386,417 @@ pub async fn get_multiparty_step_logs(
 
 #[tauri::command]
 pub async fn set_step_auto_run(
+    app: tauri::AppHandle,
     session_id: String,
     step_id: String,
     auto_run: bool,
 ) -> Result<(), String> {
+    {
+        let mut sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
+        let state = sessions
+            .get_mut(&session_id)
+            .ok_or_else(|| "Flow session not found".to_string())?;
+
+        let step = state
+            .steps
+            .iter_mut()
+            .find(|s| s.id == step_id)
+            .ok_or_else(|| "Step not found".to_string())?;
+
+        step.auto_run = auto_run;
+        let _ = persist_multiparty_state(state);
+    }
+
+    if auto_run {
+        spawn_auto_run_watcher(app, session_id);
+    }
+    Ok(())
+}
+
+/// Pin a step to a local module directory instead of the one the flow spec
+/// resolves to, so module authors can iterate without editing the flow.
+#[tauri::command]
+pub async fn override_step_module(
+    session_id: String,
+    step_id: String,
+    module_path: String,
+) -> Result<StepState, String> {
+    let module_dir = PathBuf::from(module_path.trim());
+    if !module_dir.is_dir() {
+        return Err(format!(
+            "Module directory not found: {}",
+            module_dir.display()
+        ));
+    }
+    let module_dir = module_dir
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve {}: {}", module_dir.display(), e))?;
+    validate_module_assets_exist(&module_dir)?;
+
     let mut sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
     let state = sessions
         .get_mut(&session_id)
         .ok_or_else(|| "Flow session not found".to_string())?;
-
     let step = state
         .steps
         .iter_mut()
         .find(|s| s.id == step_id)
         .ok_or_else(|| "Step not found".to_string())?;
+    if matches!(step.status, StepStatus::Running | StepStatus::Sharing) {
+        return Err(format!(
+            "Step '{}' is currently {:?}; wait for it to finish before overriding its module",
+            step_id, step.status
+        ));
+    }
 
-    step.auto_run = auto_run;
+    step.module_override = Some(module_dir.clone());
+    let updated = step.clone();
+    append_private_step_log(
+        &session_id,
+        &step_id,
+        &format!("module_override_set: {}", module_dir.display()),
+    );
     let _ = persist_multiparty_state(state);
-    Ok(())
+    Ok(updated)
 }
 
 #[tauri::command]
-pub async fn force_complete_flow_step(
-    state: tauri::State<'_, AppState>,
+pub async fn clear_step_module_override(
     session_id: String,
     step_id: String,
 ) -> Result<StepState, String> {
-    let (forced_step, terminal_update) = {
-        let mut sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
-        let flow_state = sessions
-            .get_mut(&session_id)
-            .ok_or_else(|| "Flow session not found".to_string())?;
-
-        let forced_status_label = {
-            let step = flow_state
-                .steps
-                .iter_mut()
-                .find(|s| s.id == step_id)
-                .ok_or_else(|| "Step not found".to_string())?;
+    let mut sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
+    let state = sessions
+        .get_mut(&session_id)
+        .ok_or_else(|| "Flow session not found".to_string())?;
+    let step = state
+        .steps
+        .iter_mut()
+        .find(|s| s.id == step_id)
+        .ok_or_else(|| "Step not found".to_string())?;
+
+    let previous = step.module_override.take();
+    let updated = step.clone();
+    if previous.is_some() {
+        append_private_step_log(&session_id, &step_id, "module_override_cleared");
+        let _ = persist_multiparty_state(state);
+    }
+    Ok(updated)
+}
+
+#[tauri::command]
+pub async fn get_session_mpc_verbose_logging(session_id: String) -> Result<bool, String> {
+    let sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
+    sessions
+        .get(&session_id)
+        .map(|state| state.mpc_verbose_logging)
+        .ok_or_else(|| "Flow session not found".to_string())
+}
+
+const AUTO_RUN_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Sessions with a running auto-run watcher.
+static AUTO_RUN_WATCHERS: Lazy<Mutex<HashSet<String>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// Whether `step` may run (and, for sharing steps, share) without user action.
+fn step_allowed_by_policy(flow_state: &MultipartyFlowState, step: &StepState) -> bool {
+    if step.auto_run {
+        return true;
+    }
+    match flow_state.auto_run_policy {
+        AutoRunPolicy::Manual => false,
+        AutoRunPolicy::All => true,
+        AutoRunPolicy::NonSharingOnly => !step.shares_output,
+        AutoRunPolicy::UntilBarrier => {
+            let step_idx = flow_state.steps.iter().position(|s| s.id == step.id);
+            let barrier_idx = flow_state.steps.iter().position(|s| s.is_barrier);
+            match (step_idx, barrier_idx) {
+                (Some(step_idx), Some(barrier_idx)) => step_idx < barrier_idx,
+                _ => true,
+            }
+        }
+    }
+}
+
+enum AutoRunAction {
+    Run(String),
+    Share(String),
+}
+
+fn next_auto_run_action(
+    flow_state: &MultipartyFlowState,
+    attempted: &HashSet<String>,
+) -> Option<AutoRunAction> {
+    flow_state.steps.iter().find_map(|step| {
+        if !step.my_action || !step_allowed_by_policy(flow_state, step) {
+            return None;
+        }
+        if step.status == StepStatus::Ready && !attempted.contains(&format!("run:{}", step.id)) {
+            return Some(AutoRunAction::Run(step.id.clone()));
+        }
+        if step.status == StepStatus::Completed
+            && step.shares_output
+            && !step.outputs_shared
+            && !attempted.contains(&format!("share:{}", step.id))
+        {
+            return Some(AutoRunAction::Share(step.id.clone()));
+        }
+        None
+    })
+}
+
+/// Poll the session and execute steps as they become Ready, per its policy.
+/// Exits once the policy is back to manual with nothing flagged, or the flow ends.
+fn spawn_auto_run_watcher(app: tauri::AppHandle, session_id: String) {
+    {
+        let Ok(mut watchers) = AUTO_RUN_WATCHERS.lock() else {
+            return;
+        };
+        if !watchers.insert(session_id.clone()) {
+            return;
+        }
+    }
+
+    tauri::async_runtime::spawn(async move {
+        use tauri::Manager;
+        let state = app.state::<AppState>();
+        // Each step is attempted at most once per watcher so failures don't loop.
+        let mut attempted: HashSet<String> = HashSet::new();
+        crate::desktop_log!("🤖 Auto-run watcher started for session {}", session_id);
+
+        loop {
+            let action = {
+                let Ok(mut sessions) = FLOW_SESSIONS.lock() else {
+                    break;
+                };
+                let Some(flow_state) = sessions.get_mut(&session_id) else {
+                    break;
+                };
+                if matches!(
+                    flow_state.status,
+                    FlowSessionStatus::Completed
+                        | FlowSessionStatus::Failed
+                        | FlowSessionStatus::Cancelled
+                ) {
+                    break;
+                }
+                if flow_state.auto_run_policy == AutoRunPolicy::Manual
+                    && !flow_state.steps.iter().any(|s| s.auto_run)
+                {
+                    break;
+                }
+                refresh_step_statuses(flow_state);
+                update_barrier_steps(flow_state);
+                next_auto_run_action(flow_state, &attempted)
+            };
+
+            match action {
+                Some(AutoRunAction::Run(step_id)) => {
+                    attempted.insert(format!("run:{}", step_id));
+                    append_private_step_log(&session_id, &step_id, "auto_run: executing");
+                    if let Err(err) = run_flow_step(
+                        app.clone(),
+                        state.clone(),
+                        session_id.clone(),
+                        step_id.clone(),
+                        None,
+                        None,
+                    )
+                    .await
+                    {
+                        crate::desktop_log!(
+                            "⚠️ Auto-run of step '{}' in session {} failed: {}",
+                            step_id,
+                            session_id,
+                            err
+                        );
+                    }
+                }
+                Some(AutoRunAction::Share(step_id)) => {
+                    attempted.insert(format!("share:{}", step_id));
+                    append_private_step_log(&session_id, &step_id, "auto_run: sharing");
+                    if let Err(err) =
+                        share_step_outputs(state.clone(), session_id.clone(), step_id.clone()).await
+                    {
+                        crate::desktop_log!(
+                            "⚠️ Auto-share of step '{}' in session {} failed: {}",
+                            step_id,
+                            session_id,
+                            err
+                        );
+                    }
+                }
+                None => tokio::time::sleep(AUTO_RUN_POLL_INTERVAL).await,
+            }
+        }
+
+        if let Ok(mut watchers) = AUTO_RUN_WATCHERS.lock() {
+            watchers.remove(&session_id);
+        }
+        crate::desktop_log!("🤖 Auto-run watcher stopped for session {}", session_id);
+    });
+}
+
+/// Restore persisted sessions that still want auto-run and re-arm their
+/// watchers, so the policy survives an app restart.
+pub(crate) fn resume_auto_run_watchers(app: tauri::AppHandle) {
+    let flows_root = match biovault::config::get_biovault_home()
+        .map_err(|e| format!("Failed to get BioVault home: {}", e))
+        .and_then(|home| Ok(home.join("datasites").join(get_owner_email()?)))
+    {
+        Ok(datasite) => datasite.join("shared").join("flows"),
+        Err(err) => {
+            crate::desktop_log!("⚠️ Failed to locate multiparty sessions: {}", err);
+            return;
+        }
+    };
+    if !flows_root.is_dir() {
+        return;
+    }
+
+    let session_ids: HashSet<String> = walkdir::WalkDir::new(&flows_root)
+        .min_depth(3)
+        .max_depth(3)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|e| e.file_name() == "multiparty.state.json")
+        .filter_map(|e| {
+            e.path()
+                .parent()
+                .and_then(|dir| dir.file_name())
+                .map(|name| name.to_string_lossy().to_string())
+        })
+        .collect();
+
+    for session_id in session_ids {
+        let flow_state = match load_multiparty_state_from_disk(&session_id) {
+            Ok(Some(flow_state)) => flow_state,
+            Ok(None) => continue,
+            Err(err) => {
+                crate::desktop_log!("⚠️ Failed to restore session {}: {}", session_id, err);
+                continue;
+            }
+        };
+        let wants_auto_run = flow_state.auto_run_policy != AutoRunPolicy::Manual
+            || flow_state.steps.iter().any(|s| s.auto_run);
+        let finished = matches!(
+            flow_state.status,
+            FlowSessionStatus::Completed | FlowSessionStatus::Failed | FlowSessionStatus::Cancelled
+        );
+        if !wants_auto_run || finished {
+            continue;
+        }
+        if let Ok(mut sessions) = FLOW_SESSIONS.lock() {
+            sessions.entry(session_id.clone()).or_insert(flow_state);
+        }
+        spawn_auto_run_watcher(app.clone(), session_id);
+    }
+}
+
+#[tauri::command]
+pub async fn set_flow_auto_run_policy(
+    app: tauri::AppHandle,
+    session_id: String,
+    policy: AutoRunPolicy,
+) -> Result<AutoRunPolicy, String> {
+    {
+        let mut sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
+        let state = sessions
+            .get_mut(&session_id)
+            .ok_or_else(|| "Flow session not found".to_string())?;
+        state.auto_run_policy = policy;
+        persist_multiparty_state(state)?;
+    }
+
+    if policy != AutoRunPolicy::Manual {
+        spawn_auto_run_watcher(app, session_id);
+    }
+    Ok(policy)
+}
+
+/// Toggle verbose MPC transport logging for steps run in this session from now on.
+#[tauri::command]
+pub async fn set_session_mpc_verbose_logging(
+    session_id: String,
+    enabled: bool,
+) -> Result<bool, String> {
+    let mut sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
+    let state = sessions
+        .get_mut(&session_id)
+        .ok_or_else(|| "Flow session not found".to_string())?;
+
+    state.mpc_verbose_logging = enabled;
+    persist_multiparty_state(state)?;
+    Ok(enabled)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionOutputBudget {
+    pub session_id: String,
+    pub max_bytes: Option<u64>,
+    pub shared_bytes: u64,
+    /// None when the session has no budget
+    pub remaining_bytes: Option<u64>,
+}
+
+/// Bytes a share of `output_dir` would publish; the permission file is ours.
+fn shared_output_size(output_dir: &Path) -> u64 {
+    walkdir::WalkDir::new(output_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file() && e.file_name() != "syft.pub.yaml")
+        .filter_map(|e| e.metadata().ok())
+        .map(|m| m.len())
+        .sum()
+}
+
+/// Cap the total bytes `share_step_outputs` may publish in this session
+/// (`None` removes the cap). Lowering it below what is already shared only
+/// blocks further shares.
+#[tauri::command]
+pub async fn set_session_output_budget(
+    session_id: String,
+    max_bytes: Option<u64>,
+) -> Result<SessionOutputBudget, String> {
+    let mut sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
+    let state = sessions
+        .get_mut(&session_id)
+        .ok_or_else(|| "Flow session not found".to_string())?;
+
+    state.output_budget_bytes = max_bytes;
+    persist_multiparty_state(state)?;
+    crate::desktop_log!(
+        "🔒 Session {} output budget set to {:?} bytes ({} already shared)",
+        session_id,
+        max_bytes,
+        state.shared_output_bytes
+    );
+    Ok(SessionOutputBudget {
+        session_id,
+        max_bytes,
+        shared_bytes: state.shared_output_bytes,
+        remaining_bytes: max_bytes.map(|max| max.saturating_sub(state.shared_output_bytes)),
+    })
+}
+
+#[tauri::command]
+pub async fn force_complete_flow_step(
+    state: tauri::State<'_, AppState>,
+    session_id: String,
+    step_id: String,
+) -> Result<StepState, String> {
+    let (forced_step, terminal_update) = {
+        let mut sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
+        let flow_state = sessions
+            .get_mut(&session_id)
+            .ok_or_else(|| "Flow session not found".to_string())?;
+
+        let forced_status_label = {
+            let step = flow_state
+                .steps
+                .iter_mut()
+                .find(|s| s.id == step_id)
+                .ok_or_else(|| "Step not found".to_string())?;
 
             if !step.my_action {
                 return Err("This step is not your action".to_string());
@@ -4984,12 +6015,187 @@ pub async fn republish_flow_step_state(
     Ok(republished_step)
 }
 
+fn generate_contribution_numbers(seed: Option<u64>) -> Vec<i32> {
+    match seed {
+        Some(seed) => {
+            use rand::{Rng, SeedableRng};
+            let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+            (0..5).map(|_| rng.gen::<i32>() % 100 + 1).collect()
+        }
+        None => (0..5).map(|_| rand::random::<i32>() % 100 + 1).collect(),
+    }
+}
+
+/// Lines kept per step so a console opened mid-run can catch up.
+const STEP_OUTPUT_BUFFER_LINES: usize = 500;
+const STEP_OUTPUT_POLL_MS: u64 = 250;
+
+static STEP_OUTPUT_BUFFERS: Lazy<
+    Mutex<HashMap<String, std::collections::VecDeque<StepOutputLine>>>,
+> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StepOutputLine {
+    pub session_id: String,
+    pub step_id: String,
+    /// Log file the line came from, relative to the step output dir.
+    pub source: String,
+    pub seq: u64,
+    pub line: String,
+}
+
+fn step_output_key(session_id: &str, step_id: &str) -> String {
+    format!("{}::{}", session_id, step_id)
+}
+
+fn is_step_log_file(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    name.ends_with(".log")
+        || name.ends_with(".out")
+        || name.ends_with(".err")
+        || name.contains("stdout")
+        || name.contains("stderr")
+}
+
+/// Tails the log files a module writes into its output dir, emits each new
+/// line as `multiparty:step-output` and keeps the latest ones for consoles
+/// that attach late. Dropping the stream stops the tailer after one last read
+/// and frees the buffer, whether the step succeeded or failed.
+struct StepOutputStream {
+    stop: std::sync::Arc<std::sync::atomic::AtomicBool>,
+}
+
+impl StepOutputStream {
+    fn start(app: tauri::AppHandle, session_id: &str, step_id: &str, output_dir: PathBuf) -> Self {
+        let stop = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let key = step_output_key(session_id, step_id);
+        if let Ok(mut buffers) = STEP_OUTPUT_BUFFERS.lock() {
+            buffers.insert(key.clone(), std::collections::VecDeque::new());
+        }
+
+        let session_id = session_id.to_string();
+        let step_id = step_id.to_string();
+        let stop_flag = stop.clone();
+        tauri::async_runtime::spawn(async move {
+            use tauri::Emitter;
+            let mut offsets: HashMap<PathBuf, u64> = HashMap::new();
+            let mut partial: HashMap<PathBuf, String> = HashMap::new();
+            let mut seq: u64 = 0;
+            loop {
+                let finished = stop_flag.load(std::sync::atomic::Ordering::SeqCst);
+                let files: Vec<PathBuf> = walkdir::WalkDir::new(&output_dir)
+                    .into_iter()
+                    .filter_map(|e| e.ok())
+                    .filter(|e| e.file_type().is_file())
+                    .filter(|e| is_step_log_file(&e.file_name().to_string_lossy()))
+                    .map(|e| e.into_path())
+                    .collect();
+                for path in files {
+                    let offset = offsets.entry(path.clone()).or_insert(0);
+                    let Some(chunk) = read_from_offset(&path, offset) else {
+                        continue;
+                    };
+                    let pending = partial.entry(path.clone()).or_default();
+                    pending.push_str(&chunk);
+                    let mut lines: Vec<String> = pending.split('\n').map(str::to_string).collect();
+                    // Keep the trailing fragment until its newline arrives (or the step ends).
+                    *pending = if finished {
+                        String::new()
+                    } else {
+                        lines.pop().unwrap_or_default()
+                    };
+                    let source = path
+                        .strip_prefix(&output_dir)
+                        .unwrap_or(&path)
+                        .to_string_lossy()
+                        .to_string();
+                    for line in lines {
+                        let line = line.trim_end_matches('\r');
+                        if line.is_empty() {
+                            continue;
+                        }
+                        seq += 1;
+                        let event = StepOutputLine {
+                            session_id: session_id.clone(),
+                            step_id: step_id.clone(),
+                            source: source.clone(),
+                            seq,
+                            line: line.to_string(),
+                        };
+                        if let Ok(mut buffers) = STEP_OUTPUT_BUFFERS.lock() {
+                            let buffer = buffers.entry(key.clone()).or_default();
+                            buffer.push_back(event.clone());
+                            while buffer.len() > STEP_OUTPUT_BUFFER_LINES {
+                                buffer.pop_front();
+                            }
+                        }
+                        let _ = app.emit("multiparty:step-output", &event);
+                    }
+                }
+                if finished {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_millis(STEP_OUTPUT_POLL_MS)).await;
+            }
+            if let Ok(mut buffers) = STEP_OUTPUT_BUFFERS.lock() {
+                buffers.remove(&key);
+            }
+            let _ = app.emit(
+                "multiparty:step-output-end",
+                serde_json::json!({ "session_id": session_id, "step_id": step_id }),
+            );
+        });
+        StepOutputStream { stop }
+    }
+}
+
+impl Drop for StepOutputStream {
+    fn drop(&mut self) {
+        self.stop.store(true, std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+/// Read whatever was appended to `path` since `offset`, advancing it. A file
+/// that shrank (truncated or rewritten) is read again from the start.
+fn read_from_offset(path: &Path, offset: &mut u64) -> Option<String> {
+    use std::io::{Read, Seek, SeekFrom};
+    let mut file = fs::File::open(path).ok()?;
+    let len = file.metadata().ok()?.len();
+    if len < *offset {
+        *offset = 0;
+    }
+    if len == *offset {
+        return None;
+    }
+    file.seek(SeekFrom::Start(*offset)).ok()?;
+    let mut buf = Vec::new();
+    file.take(len - *offset).read_to_end(&mut buf).ok()?;
+    *offset += buf.len() as u64;
+    Some(String::from_utf8_lossy(&buf).into_owned())
+}
+
+/// Buffered console output of a running step (the most recent lines only), for
+/// consoles that attach after the step started.
+#[tauri::command]
+pub async fn get_step_output(
+    session_id: String,
+    step_id: String,
+) -> Result<Vec<StepOutputLine>, String> {
+    let buffers = STEP_OUTPUT_BUFFERS.lock().map_err(|e| e.to_string())?;
+    Ok(buffers
+        .get(&step_output_key(&session_id, &step_id))
+        .map(|buffer| buffer.iter().cloned().collect())
+        .unwrap_or_default())
+}
+
 #[tauri::command]
 pub async fn run_flow_step(
+    app: tauri::AppHandle,
     state: tauri::State<'_, AppState>,
     session_id: String,
     step_id: String,
     force: Option<bool>,
+    seed: Option<u64>,
 ) -> Result<StepState, String> {
     let force_run = force.unwrap_or(false);
     let (
@@ -5002,6 +6208,7 @@ pub async fn run_flow_step(
         input_overrides,
         module_path,
         module_ref,
+        module_override,
         with_bindings,
         flow_spec,
         syqure_port_base,
@@ -5013,7 +6220,15 @@ pub async fn run_flow_step(
             .ok_or_else(|| "Flow session not found".to_string())?;
 
         // Get step info and check if it can run
-        let (step_deps, initial_step_status, is_my_action, module_path, module_ref, with_bindings) = {
+        let (
+            step_deps,
+            initial_step_status,
+            is_my_action,
+            module_path,
+            module_ref,
+            module_override,
+            with_bindings,
+        ) = {
             let step = flow_state
                 .steps
                 .iter()
@@ -5025,6 +6240,7 @@ pub async fn run_flow_step(
                 step.my_action,
                 step.module_path.clone(),
                 step.module_ref.clone(),
+                step.module_override.clone(),
                 step.with_bindings.clone(),
             )
         };
@@ -5370,6 +6586,7 @@ pub async fn run_flow_step(
             flow_state.input_overrides.clone(),
             module_path,
             module_ref,
+            module_override,
             with_bindings,
             flow_state.flow_spec.clone(),
             flow_state.syqure_port_base,
@@ -5396,14 +6613,23 @@ pub async fn run_flow_step(
             .map(|d| d.join("numbers.json"))
             .ok_or_else(|| "No output directory".to_string())?;
 
-        let numbers: Vec<i32> = (0..5).map(|_| rand::random::<i32>() % 100 + 1).collect();
+        // Seeded runs are reproducible so integration tests can assert exact sums.
+        let seed = seed.or_else(|| {
+            env::var(MULTIPARTY_GENERATE_SEED_ENV)
+                .ok()
+                .and_then(|v| v.trim().parse::<u64>().ok())
+        });
+        let numbers = generate_contribution_numbers(seed);
         let sum: i32 = numbers.iter().sum();
 
-        let result = serde_json::json!({
+        let mut result = serde_json::json!({
             "session_id": session_id,
             "numbers": numbers,
             "sum": sum
         });
+        if let Some(seed) = seed {
+            result["seed"] = serde_json::json!(seed);
+        }
 
         fs::write(&output_file, serde_json::to_string_pretty(&result).unwrap())
             .map_err(|e| format!("Failed to write output: {}", e))?;
@@ -5494,7 +6720,7 @@ pub async fn run_flow_step(
 
         fs::write(&output_file, serde_json::to_string_pretty(&result).unwrap())
             .map_err(|e| format!("Failed to write output: {}", e))?;
-    } else if module_ref.is_some() || module_path.is_some() {
+    } else if module_ref.is_some() || module_path.is_some() || module_override.is_some() {
         // ---- Generic module execution path (replaces all hardcoded step handlers) ----
         let output_dir = step_output_dir
             .as_ref()
@@ -5503,14 +6729,25 @@ pub async fn run_flow_step(
             .as_ref()
             .and_then(|fs| fs.get("flow_path"))
             .and_then(|v| v.as_str());
-        let module_dir =
-            resolve_module_directory(&flow_name, module_path.as_deref(), module_ref.as_deref(), source_flow_path)
+        let module_dir = match module_override {
+            Some(dir) => {
+                append_private_step_log(
+                    &session_id,
+                    &step_id,
+                    &format!("module_override: using {}", dir.display()),
+                );
+                dir
+            }
+            None => resolve_module_directory(&flow_name, module_path.as_deref(), module_ref.as_deref(), source_flow_path)
                 .ok_or_else(|| {
                     format!("Failed to resolve module directory for step '{}'. Searched flow_name='{}', module_path={:?}, module_ref={:?}, source_flow_path={:?}",
                         step_id, flow_name, module_path, module_ref, source_flow_path)
-                })?;
+                })?,
+        };
         validate_module_assets_exist(&module_dir)
             .map_err(|e| format!("Step '{}' failed preflight: {}", step_id, e))?;
+        validate_module_entrypoint(&module_dir)
+            .map_err(|e| format!("Step '{}' failed preflight: {}", step_id, e))?;
 
         let biovault_home = biovault::config::get_biovault_home()
             .map_err(|e| format!("Failed to get BioVault home: {}", e))?;
@@ -5627,24 +6864,32 @@ pub async fn run_flow_step(
             );
         }
 
+        let mpc_verbose = FLOW_SESSIONS
+            .lock()
+            .ok()
+            .and_then(|sessions| sessions.get(&session_id).map(|s| s.mpc_verbose_logging))
+            .unwrap_or(false);
+        if mpc_verbose {
+            append_private_step_log(&session_id, &step_id, "mpc_verbose_logging: enabled");
+        }
+        let output_stream =
+            StepOutputStream::start(app.clone(), &session_id, &step_id, output_dir.clone());
+
         eprintln!("[tauri-trace] run_flow_step calling execute_dynamic step={} party={}/{} pid={} thread={:?}",
             step_id, party_id_idx, party_emails.len(), std::process::id(), std::thread::current().id());
         // Important: pass party/session context through task-local scope.
         // Avoid reintroducing process-global env mutation here; concurrent
         // Tauri parties can race and produce non-deterministic Syqure wiring.
-        let run_result = run_dynamic::with_execution_context(
+        let run_result = execute_module_step(
             dynamic_ctx,
-            run_dynamic::execute_dynamic(
-                &module_dir.to_string_lossy(),
-                step_args,
-                false,
-                false,
-                Some(output_dir.to_string_lossy().to_string()),
-                run_dynamic::RunSettings::default(),
-            ),
+            &module_dir,
+            step_args,
+            &output_dir,
+            mpc_verbose,
         )
         .await
         .map_err(|e| format!("Step '{}' failed: {}", step_id, e));
+        drop(output_stream);
         eprintln!(
             "[tauri-trace] execute_dynamic returned step={} party={} result={:?}",
             step_id,
@@ -5803,11 +7048,24 @@ pub async fn share_step_outputs(
     session_id: String,
     step_id: String,
 ) -> Result<(), String> {
+    // Size the outputs before taking the session lock for the budget check.
+    let share_bytes = {
+        let sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
+        sessions
+            .get(&session_id)
+            .and_then(|s| s.steps.iter().find(|step| step.id == step_id))
+            .and_then(|step| step.output_dir.clone())
+    }
+    .map(|dir| shared_output_size(&dir))
+    .unwrap_or(0);
+
     let (output_dir, share_to_emails, my_email, thread_id, flow_name, step_name, participants) = {
         let mut sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
         let flow_state = sessions
             .get_mut(&session_id)
             .ok_or_else(|| "Flow session not found".to_string())?;
+        let budget = flow_state.output_budget_bytes;
+        let already_shared = flow_state.shared_output_bytes;
 
         let (output_dir, step_name, share_to) = {
             let step = flow_state
@@ -5824,6 +7082,25 @@ pub async fn share_step_outputs(
                 return Err("This step does not share outputs".to_string());
             }
 
+            if let Some(max_bytes) = budget {
+                if !step.outputs_shared && already_shared + share_bytes > max_bytes {
+                    let message = format!(
+                        "Sharing step '{}' ({} bytes) would exceed the session output budget: {} of {} bytes already shared, {} remaining",
+                        step_id,
+                        share_bytes,
+                        already_shared,
+                        max_bytes,
+                        max_bytes.saturating_sub(already_shared)
+                    );
+                    append_private_step_log(
+                        &session_id,
+                        &step_id,
+                        &format!("step_share_blocked: {}", message),
+                    );
+                    return Err(message);
+                }
+            }
+
             step.status = StepStatus::Sharing;
             append_private_step_log(&session_id, &step_id, "step_sharing_started");
 
@@ -5833,34 +7110,7 @@ pub async fn share_step_outputs(
                 step.share_to.clone(),
             )
         };
-        let (groups, default_to_actual) = flow_state
-            .flow_spec
-            .as_ref()
-            .map(|spec| build_group_map_from_participants(&flow_state.participants, spec))
-            .unwrap_or_default();
-        let datasites_order: Vec<String> = flow_state
-            .flow_spec
-            .as_ref()
-            .and_then(|spec| spec.get("inputs"))
-            .and_then(|i| i.get("datasites"))
-            .and_then(|d| d.get("default"))
-            .and_then(|arr| arr.as_array())
-            .map(|arr| {
-                arr.iter()
-                    .filter_map(|v| v.as_str().map(|s| s.to_string()))
-                    .collect::<Vec<String>>()
-            })
-            .unwrap_or_default()
-            .into_iter()
-            .map(|email| default_to_actual.get(&email).cloned().unwrap_or(email))
-            .collect::<Vec<String>>();
-        let share_to_emails = resolve_share_recipients(
-            &share_to,
-            &flow_state.participants,
-            &flow_state.my_email,
-            &datasites_order,
-            &groups,
-        );
+        let share_to_emails = step_share_recipients(flow_state, &share_to);
         let _ = persist_multiparty_state(flow_state);
 
         (
@@ -5877,7 +7127,23 @@ pub async fn share_step_outputs(
     let output_dir = output_dir.ok_or_else(|| "No output directory".to_string())?;
 
     // Create syft.pub.yaml in output directory to enable SyftBox sync
-    create_syft_pub_yaml(&output_dir, &my_email, &share_to_emails)?;
+    if let Err(err) = create_syft_pub_yaml(&output_dir, &my_email, &share_to_emails) {
+        // Put the step back to Completed so the share can be retried.
+        if let Ok(mut sessions) = FLOW_SESSIONS.lock() {
+            if let Some(flow_state) = sessions.get_mut(&session_id) {
+                if let Some(step) = flow_state.steps.iter_mut().find(|s| s.id == step_id) {
+                    step.status = StepStatus::Completed;
+                }
+                let _ = persist_multiparty_state(flow_state);
+            }
+        }
+        append_private_step_log(
+            &session_id,
+            &step_id,
+            &format!("step_share_failed: {}", err),
+        );
+        return Err(err);
+    }
 
     let terminal_update = {
         let mut sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
@@ -5891,9 +7157,14 @@ pub async fn share_step_outputs(
             .find(|s| s.id == step_id)
             .ok_or_else(|| "Step not found".to_string())?;
 
+        let first_share = !step.outputs_shared;
         step.status = StepStatus::Shared;
         step.outputs_shared = true;
+        step.share_message_pending = !thread_id.trim().is_empty();
         append_private_step_log(&session_id, &step_id, "step_shared");
+        if first_share {
+            flow_state.shared_output_bytes += share_bytes;
+        }
 
         // Save step status to shared _progress folder for cross-client syncing
         if let Some(ref work_dir) = flow_state.work_dir {
@@ -5947,15 +7218,282 @@ pub async fn share_step_outputs(
             &participants,
             true,
         )?;
+        clear_share_message_pending(&session_id, &step_id);
     }
 
     Ok(())
 }
 
-#[tauri::command]
-pub async fn get_step_output_files(
-    session_id: String,
-    step_id: String,
+fn clear_share_message_pending(session_id: &str, step_id: &str) {
+    if let Ok(mut sessions) = FLOW_SESSIONS.lock() {
+        if let Some(flow_state) = sessions.get_mut(session_id) {
+            if let Some(step) = flow_state.steps.iter_mut().find(|s| s.id == step_id) {
+                step.share_message_pending = false;
+            }
+            let _ = persist_multiparty_state(flow_state);
+        }
+    }
+}
+
+fn is_step_share_incomplete(step: &StepState) -> bool {
+    step.my_action
+        && step.shares_output
+        && ((step.status == StepStatus::Completed && !step.outputs_shared)
+            || (step.outputs_shared && step.share_message_pending))
+}
+
+/// Steps whose computation finished but whose outputs (or results message)
+/// never got shared, e.g. after a transient syft.pub.yaml or chat failure.
+#[tauri::command]
+pub async fn list_unshared_completed_steps(session_id: String) -> Result<Vec<StepState>, String> {
+    let sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
+    let flow_state = sessions
+        .get(&session_id)
+        .ok_or_else(|| "Flow session not found".to_string())?;
+    Ok(flow_state
+        .steps
+        .iter()
+        .filter(|step| is_step_share_incomplete(step))
+        .cloned()
+        .collect())
+}
+
+/// Re-attempt sharing a completed step without re-running it.
+#[tauri::command]
+pub async fn retry_share_step(
+    state: tauri::State<'_, AppState>,
+    session_id: String,
+    step_id: String,
+) -> Result<StepState, String> {
+    let (step, thread_id, flow_name, my_email, participants) = {
+        let sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
+        let flow_state = sessions
+            .get(&session_id)
+            .ok_or_else(|| "Flow session not found".to_string())?;
+        let step = flow_state
+            .steps
+            .iter()
+            .find(|s| s.id == step_id)
+            .cloned()
+            .ok_or_else(|| "Step not found".to_string())?;
+        (
+            step,
+            flow_state.thread_id.clone(),
+            flow_state.flow_name.clone(),
+            flow_state.my_email.clone(),
+            flow_state.participants.clone(),
+        )
+    };
+
+    if !is_step_share_incomplete(&step) {
+        return Err(format!("Step '{}' has no pending share to retry", step_id));
+    }
+
+    append_private_step_log(&session_id, &step_id, "step_share_retry");
+    if !step.outputs_shared {
+        share_step_outputs(state, session_id.clone(), step_id.clone()).await?;
+    } else {
+        // Permissions are already in place; only the chat message is missing.
+        let output_dir = step
+            .output_dir
+            .clone()
+            .ok_or_else(|| "No output directory".to_string())?;
+        publish_step_outputs_message(
+            &session_id,
+            &step_id,
+            &output_dir,
+            &thread_id,
+            &flow_name,
+            &my_email,
+            &step.name,
+            &participants,
+            true,
+        )?;
+        clear_share_message_pending(&session_id, &step_id);
+    }
+
+    let sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
+    sessions
+        .get(&session_id)
+        .and_then(|flow_state| flow_state.steps.iter().find(|s| s.id == step_id))
+        .cloned()
+        .ok_or_else(|| "Step not found".to_string())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SharingAuditFinding {
+    pub path: String,
+    /// "step", "mpc", "mpc_channel", "progress" or "other"
+    pub kind: String,
+    pub step_id: Option<String>,
+    pub allowed_readers: Vec<String>,
+    pub readers: Vec<String>,
+    pub offending_readers: Vec<String>,
+    /// A wildcard reader (`*`) makes the directory readable by anyone.
+    pub world_readable: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionSharingAudit {
+    pub session_id: String,
+    pub work_dir: String,
+    pub checked_files: usize,
+    pub findings: Vec<SharingAuditFinding>,
+}
+
+/// Every email (or wildcard) granted read or write access in a syft.pub.yaml.
+fn read_syft_pub_readers(perm_path: &Path) -> Result<Vec<String>, String> {
+    let contents = fs::read_to_string(perm_path)
+        .map_err(|e| format!("Failed to read {}: {}", perm_path.display(), e))?;
+    let doc: serde_json::Value = serde_yaml::from_str(&contents)
+        .map_err(|e| format!("Failed to parse {}: {}", perm_path.display(), e))?;
+    let mut readers: BTreeSet<String> = BTreeSet::new();
+    for rule in doc
+        .get("rules")
+        .and_then(|r| r.as_array())
+        .into_iter()
+        .flatten()
+    {
+        for access in ["read", "write"] {
+            for reader in rule
+                .get("access")
+                .and_then(|a| a.get(access))
+                .and_then(|r| r.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|r| r.as_str())
+            {
+                readers.insert(reader.trim().to_ascii_lowercase());
+            }
+        }
+    }
+    Ok(readers.into_iter().collect())
+}
+
+/// Compare every syft.pub.yaml under the session work dir with what the flow
+/// declares and report directories readable by anyone beyond that.
+#[tauri::command]
+pub async fn audit_session_sharing(session_id: String) -> Result<SessionSharingAudit, String> {
+    let (work_dir, my_email, participant_emails, step_allowed) = {
+        let sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
+        let flow_state = sessions
+            .get(&session_id)
+            .ok_or_else(|| "Flow session not found".to_string())?;
+        let work_dir = flow_state
+            .work_dir
+            .clone()
+            .ok_or_else(|| "No work directory".to_string())?;
+        let participant_emails: BTreeSet<String> = flow_state
+            .participants
+            .iter()
+            .map(|p| p.email.to_ascii_lowercase())
+            .collect();
+        // Keyed by the exact step directory names (`{n}-{id}` and the legacy
+        // padded `{nn}-{id}`) so ids sharing a suffix can't match each other.
+        let mut step_allowed: HashMap<String, (String, BTreeSet<String>)> = HashMap::new();
+        for (idx, step) in flow_state.steps.iter().enumerate() {
+            let recipients = if step.shares_output {
+                step_share_recipients(flow_state, &step.share_to)
+            } else {
+                Vec::new()
+            };
+            let allowed: BTreeSet<String> = recipients
+                .into_iter()
+                .map(|e| e.to_ascii_lowercase())
+                .collect();
+            for dir_name in [
+                format!("{}-{}", idx + 1, step.id),
+                format!("{:02}-{}", idx + 1, step.id),
+            ] {
+                step_allowed.insert(dir_name, (step.id.clone(), allowed.clone()));
+            }
+        }
+        (
+            work_dir,
+            flow_state.my_email.to_ascii_lowercase(),
+            participant_emails,
+            step_allowed,
+        )
+    };
+
+    let mut findings = Vec::new();
+    let mut checked_files = 0;
+    for entry in walkdir::WalkDir::new(&work_dir)
+        .into_iter()
+        .filter_entry(|e| e.depth() == 0 || e.file_name() != "work")
+        .flatten()
+    {
+        if !entry.file_type().is_file() || entry.file_name() != "syft.pub.yaml" {
+            continue;
+        }
+        let Some(dir) = entry.path().parent() else {
+            continue;
+        };
+        checked_files += 1;
+
+        let rel = dir.strip_prefix(&work_dir).unwrap_or(dir);
+        let components: Vec<String> = rel
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().to_string())
+            .collect();
+        let top = components.first().cloned().unwrap_or_default();
+
+        let (kind, step_id, mut allowed): (&str, Option<String>, BTreeSet<String>) =
+            if top == "_mpc" {
+                if components.len() > 1 {
+                    ("mpc_channel", None, participant_emails.clone())
+                } else {
+                    ("mpc", None, participant_emails.clone())
+                }
+            } else if top == "_progress" {
+                ("progress", None, participant_emails.clone())
+            } else if let Some((step_id, allowed)) = step_allowed.get(&top) {
+                ("step", Some(step_id.clone()), allowed.clone())
+            } else {
+                ("other", None, BTreeSet::new())
+            };
+        allowed.insert(my_email.clone());
+
+        let readers = match read_syft_pub_readers(entry.path()) {
+            Ok(readers) => readers,
+            Err(err) => {
+                crate::desktop_log!("⚠️ Sharing audit skipped {}: {}", dir.display(), err);
+                continue;
+            }
+        };
+        let world_readable = readers.iter().any(|r| r == "*" || r == "public");
+        let offending: Vec<String> = readers
+            .iter()
+            .filter(|r| !allowed.contains(*r))
+            .cloned()
+            .collect();
+        if offending.is_empty() && !world_readable {
+            continue;
+        }
+
+        findings.push(SharingAuditFinding {
+            path: dir.to_string_lossy().to_string(),
+            kind: kind.to_string(),
+            step_id,
+            allowed_readers: allowed.into_iter().collect(),
+            readers,
+            offending_readers: offending,
+            world_readable,
+        });
+    }
+
+    Ok(SessionSharingAudit {
+        session_id,
+        work_dir: work_dir.to_string_lossy().to_string(),
+        checked_files,
+        findings,
+    })
+}
+
+#[tauri::command]
+pub async fn get_step_output_files(
+    session_id: String,
+    step_id: String,
 ) -> Result<Vec<String>, String> {
     let sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
     let flow_state = sessions
@@ -6333,80 +7871,1776 @@ fn build_group_map_from_participants(
     (groups, default_to_actual)
 }
 
-fn format_default_mapping_diagnostics(
-    default_datasites: &[String],
+#[derive(Debug, Clone, Serialize)]
+pub struct GroupResolutionPreview {
+    pub groups: BTreeMap<String, Vec<String>>,
+    pub default_to_actual: BTreeMap<String, String>,
+    /// Default datasites from the spec that no participant was assigned to
+    pub unmapped_defaults: Vec<String>,
+    /// Groups declared under `datasites.groups` that resolved to nobody
+    pub empty_groups: Vec<String>,
+    /// Participants that only landed in the implicit `all` group
+    pub ungrouped_participants: Vec<String>,
+    /// Participants no non-barrier step targets; they would join and sit idle
+    pub idle_participants: Vec<IdleParticipant>,
+}
+
+fn preview_group_resolution_for(
     participants: &[FlowParticipant],
-    default_to_actual: &HashMap<String, String>,
-) -> String {
-    let participants_fmt = participants
-        .iter()
-        .map(|p| format!("{}({})", p.email, p.role))
-        .collect::<Vec<_>>()
-        .join(", ");
-    let defaults_fmt = if default_datasites.is_empty() {
-        "<none>".to_string()
-    } else {
-        default_datasites.join(", ")
-    };
-    let mut mappings = default_datasites
-        .iter()
-        .map(|d| {
-            let actual = default_to_actual
-                .get(d)
+    flow_spec: &serde_json::Value,
+) -> GroupResolutionPreview {
+    let (groups, default_to_actual) = build_group_map_from_participants(participants, flow_spec);
+    let spec_root = flow_spec_root(flow_spec);
+
+    let default_datasites: Vec<String> = spec_root
+        .get("inputs")
+        .and_then(|i| i.get("datasites"))
+        .and_then(|d| d.get("default"))
+        .and_then(|arr| arr.as_array())
+        .or_else(|| {
+            spec_root
+                .get("datasites")
+                .and_then(|d| d.get("all"))
+                .and_then(|arr| arr.as_array())
+        })
+        .or_else(|| spec_root.get("datasites").and_then(|d| d.as_array()))
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+    let unmapped_defaults = default_datasites
+        .into_iter()
+        .filter(|d| !default_to_actual.contains_key(d))
+        .collect();
+
+    let empty_groups = spec_root
+        .get("datasites")
+        .and_then(|d| d.get("groups"))
+        .and_then(|g| g.as_object())
+        .map(|spec_groups| {
+            spec_groups
+                .keys()
+                .filter(|name| groups.get(*name).map(|m| m.is_empty()).unwrap_or(true))
                 .cloned()
-                .unwrap_or_else(|| "<unmapped>".to_string());
-            format!("{} -> {}", d, actual)
+                .collect()
         })
-        .collect::<Vec<_>>();
-    mappings.sort();
-    format!(
-        "participants=[{}]; default_datasites=[{}]; default_to_actual={{ {} }}",
-        participants_fmt,
-        defaults_fmt,
-        mappings.join(", ")
-    )
-}
+        .unwrap_or_default();
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use serde_json::json;
+    let ungrouped_participants = participants
+        .iter()
+        .filter(|p| {
+            !groups
+                .iter()
+                .any(|(name, members)| name != "all" && members.contains(&p.email))
+        })
+        .map(|p| p.email.clone())
+        .collect();
 
-    #[test]
-    fn default_mapping_never_reuses_participant_for_multiple_default_slots() {
-        let participants = vec![
-            FlowParticipant {
-                email: "madhava@openmined.org".to_string(),
-                role: "clients".to_string(),
-            },
-            FlowParticipant {
-                email: "me@madhavajay.com".to_string(),
-                role: "clients".to_string(),
-            },
-            FlowParticipant {
-                email: "test@madhavajay.com".to_string(),
-                role: "aggregator".to_string(),
-            },
-        ];
+    GroupResolutionPreview {
+        groups: groups
+            .into_iter()
+            .map(|(name, mut members)| {
+                members.sort();
+                members.dedup();
+                (name, members)
+            })
+            .collect(),
+        default_to_actual: default_to_actual.into_iter().collect(),
+        unmapped_defaults,
+        empty_groups,
+        ungrouped_participants,
+        idle_participants: find_idle_participants(flow_spec, participants).unwrap_or_default(),
+    }
+}
 
-        let flow_spec = json!({
-            "spec": {
-                "datasites": ["aggregator@sandbox.local", "client1@sandbox.local", "client2@sandbox.local"]
-            }
-        });
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct IdleParticipant {
+    pub email: String,
+    pub role: String,
+}
 
-        let (_groups, default_to_actual) =
-            build_group_map_from_participants(&participants, &flow_spec);
+/// Participants whose role is never a target of a non-barrier step.
+fn find_idle_participants(
+    flow_spec: &serde_json::Value,
+    participants: &[FlowParticipant],
+) -> Result<Vec<IdleParticipant>, String> {
+    let my_email = participants
+        .first()
+        .map(|p| p.email.as_str())
+        .unwrap_or_default();
+    let steps = parse_flow_steps(flow_spec, my_email, participants)?;
+    let busy: HashSet<String> = steps
+        .iter()
+        .filter(|step| !step.is_barrier)
+        .flat_map(|step| step.target_emails.iter().map(|e| e.to_ascii_lowercase()))
+        .collect();
+    let mut seen = HashSet::new();
+    Ok(participants
+        .iter()
+        .filter(|p| !busy.contains(&p.email.to_ascii_lowercase()))
+        .filter(|p| seen.insert(p.email.to_ascii_lowercase()))
+        .map(|p| IdleParticipant {
+            email: p.email.clone(),
+            role: p.role.clone(),
+        })
+        .collect())
+}
 
-        // Aggregator must resolve to the aggregator participant.
-        assert_eq!(
-            default_to_actual.get("aggregator@sandbox.local"),
-            Some(&"test@madhavajay.com".to_string())
+/// Report invited participants who would have no step to run.
+#[tauri::command]
+pub fn check_idle_participants(
+    flow_spec: serde_json::Value,
+    participant_roles: Vec<FlowParticipant>,
+) -> Result<Vec<IdleParticipant>, String> {
+    let idle = find_idle_participants(&flow_spec, &participant_roles)?;
+    for p in &idle {
+        crate::desktop_log!(
+            "⚠️ Participant {} (role '{}') is not targeted by any step",
+            p.email,
+            p.role
         );
+    }
+    Ok(idle)
+}
 
-        // Client placeholders must resolve to two distinct client participants.
-        let c1 = default_to_actual
-            .get("client1@sandbox.local")
+/// Show how the flow's datasite groups and default datasites resolve for the
+/// proposed participants, before any invitation is sent.
+#[tauri::command]
+pub fn preview_group_resolution(
+    flow_spec: serde_json::Value,
+    participant_roles: Vec<FlowParticipant>,
+) -> Result<GroupResolutionPreview, String> {
+    if participant_roles.is_empty() {
+        return Err("At least one participant is required".to_string());
+    }
+    Ok(preview_group_resolution_for(&participant_roles, &flow_spec))
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct StepOutputCollision {
+    pub step_a: String,
+    pub step_b: String,
+    pub dir_a: String,
+    pub dir_b: String,
+    /// `duplicate_id`, `same_dir` (canonical/padded names coincide, incl. case-insensitive
+    /// filesystems) or `suffix_overlap` (one dir is also matched by the other step's id lookup)
+    pub reason: String,
+}
+
+fn find_step_output_collisions(flow_spec: &serde_json::Value) -> Vec<StepOutputCollision> {
+    let step_ids: Vec<String> = flow_spec_root(flow_spec)
+        .get("steps")
+        .and_then(|s| s.as_array())
+        .map(|steps| {
+            steps
+                .iter()
+                .filter_map(|s| s.get("id").and_then(|v| v.as_str()).map(|s| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Every on-disk name a step may end up using: canonical `N-id` and legacy padded `0N-id`.
+    let base = PathBuf::new();
+    let dir_names = |idx: usize, id: &str| -> Vec<String> {
+        let mut names = vec![
+            get_step_path(&base, idx + 1, id)
+                .to_string_lossy()
+                .to_string(),
+            get_padded_step_path(&base, idx + 1, id)
+                .to_string_lossy()
+                .to_string(),
+        ];
+        names.dedup();
+        names
+    };
+
+    let mut collisions = Vec::new();
+    for (i, id_a) in step_ids.iter().enumerate() {
+        for (j, id_b) in step_ids.iter().enumerate().skip(i + 1) {
+            let names_a = dir_names(i, id_a);
+            let names_b = dir_names(j, id_b);
+            let mut collision = |dir_a: &str, dir_b: &str, reason: &str| {
+                collisions.push(StepOutputCollision {
+                    step_a: id_a.clone(),
+                    step_b: id_b.clone(),
+                    dir_a: dir_a.to_string(),
+                    dir_b: dir_b.to_string(),
+                    reason: reason.to_string(),
+                });
+            };
+
+            if id_a == id_b {
+                collision(&names_a[0], &names_b[0], "duplicate_id");
+                continue;
+            }
+            if let Some((a, b)) = names_a.iter().find_map(|a| {
+                names_b
+                    .iter()
+                    .find(|b| a.eq_ignore_ascii_case(b))
+                    .map(|b| (a, b))
+            }) {
+                collision(a, b, "same_dir");
+                continue;
+            }
+            // list_step_dirs_for_id matches on the `-id` suffix, so `1-b` and `2-a-b` overlap.
+            let a_matched_by_b = names_a.iter().any(|n| n.ends_with(&format!("-{}", id_b)));
+            let b_matched_by_a = names_b.iter().any(|n| n.ends_with(&format!("-{}", id_a)));
+            if a_matched_by_b || b_matched_by_a {
+                collision(&names_a[0], &names_b[0], "suffix_overlap");
+            }
+        }
+    }
+    collisions
+}
+
+/// Report step pairs whose output directories would collide on disk.
+#[tauri::command]
+pub fn check_step_output_collisions(
+    flow_spec: serde_json::Value,
+) -> Result<Vec<StepOutputCollision>, String> {
+    let collisions = find_step_output_collisions(&flow_spec);
+    for c in &collisions {
+        crate::desktop_log!(
+            "⚠️ Step output collision ({}): {} [{}] vs {} [{}]",
+            c.reason,
+            c.step_a,
+            c.dir_a,
+            c.step_b,
+            c.dir_b
+        );
+    }
+    Ok(collisions)
+}
+
+/// Keys the local app adds to a stored flow spec that legitimately differ per participant.
+const LOCAL_FLOW_SPEC_KEYS: &[&str] = &["flow_path"];
+
+fn canonical_json(value: &serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => {
+            let sorted: BTreeMap<&String, serde_json::Value> =
+                map.iter().map(|(k, v)| (k, canonical_json(v))).collect();
+            serde_json::to_value(sorted).unwrap_or(serde_json::Value::Null)
+        }
+        serde_json::Value::Array(items) => {
+            serde_json::Value::Array(items.iter().map(canonical_json).collect())
+        }
+        other => other.clone(),
+    }
+}
+
+/// SHA-256 of a flow spec with sorted keys and local-only keys removed, so two
+/// participants holding the same spec always get the same digest.
+pub(crate) fn flow_spec_digest(flow_spec: &serde_json::Value) -> String {
+    use sha2::{Digest, Sha256};
+
+    let mut spec = flow_spec.clone();
+    if let Some(map) = spec.as_object_mut() {
+        for key in LOCAL_FLOW_SPEC_KEYS {
+            map.remove(*key);
+        }
+    }
+    let canonical = serde_json::to_string(&canonical_json(&spec)).unwrap_or_default();
+    hex::encode(Sha256::digest(canonical.as_bytes()))
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ParticipantSpecDigest {
+    pub email: String,
+    /// None when the participant's session state has not synced yet
+    pub digest: Option<String>,
+    pub source: Option<String>,
+    pub matches_local: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FlowSpecConsistencyReport {
+    pub session_id: String,
+    pub local_digest: Option<String>,
+    pub participants: Vec<ParticipantSpecDigest>,
+    pub consistent: bool,
+    pub divergent: Vec<String>,
+    pub unavailable: Vec<String>,
+}
+
+/// Compare the flow spec digest of this session against the specs stored in
+/// each peer's synced `multiparty.state.json`.
+#[tauri::command]
+pub async fn verify_flow_spec_consistency(
+    session_id: String,
+) -> Result<FlowSpecConsistencyReport, String> {
+    let flow_state = {
+        let sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
+        sessions.get(&session_id).cloned()
+    };
+    let flow_state = match flow_state {
+        Some(state) => state,
+        None => load_multiparty_state_from_disk(&session_id)?
+            .ok_or_else(|| "Flow session not found".to_string())?,
+    };
+
+    let local_digest = flow_state.flow_spec.as_ref().map(flow_spec_digest);
+    let biovault_home = biovault::config::get_biovault_home()
+        .map_err(|e| format!("Failed to get BioVault home: {}", e))?;
+
+    let mut participants = Vec::new();
+    let mut seen = HashSet::new();
+    for participant in &flow_state.participants {
+        if !seen.insert(participant.email.to_lowercase()) {
+            continue;
+        }
+        if participant.email.eq_ignore_ascii_case(&flow_state.my_email) {
+            participants.push(ParticipantSpecDigest {
+                email: participant.email.clone(),
+                digest: local_digest.clone(),
+                source: Some("local".to_string()),
+                matches_local: local_digest.as_ref().map(|_| true),
+            });
+            continue;
+        }
+
+        let mut entry = ParticipantSpecDigest {
+            email: participant.email.clone(),
+            digest: None,
+            source: None,
+            matches_local: None,
+        };
+        for base_dir in participant_flow_dirs_for_viewer(
+            &biovault_home,
+            &flow_state.my_email,
+            &participant.email,
+            &flow_state.flow_name,
+            &session_id,
+        ) {
+            let state_path = base_dir.join("multiparty.state.json");
+            let Ok(raw) = fs::read_to_string(&state_path) else {
+                continue;
+            };
+            let Some(spec) = serde_json::from_str::<serde_json::Value>(&raw)
+                .ok()
+                .and_then(|json| json.get("flow_spec").cloned())
+                .filter(|spec| !spec.is_null())
+            else {
+                continue;
+            };
+            let digest = flow_spec_digest(&spec);
+            entry.matches_local = local_digest.as_ref().map(|local| *local == digest);
+            entry.digest = Some(digest);
+            entry.source = Some(state_path.display().to_string());
+            break;
+        }
+        participants.push(entry);
+    }
+
+    let divergent: Vec<String> = participants
+        .iter()
+        .filter(|p| p.matches_local == Some(false))
+        .map(|p| p.email.clone())
+        .collect();
+    let unavailable: Vec<String> = participants
+        .iter()
+        .filter(|p| p.digest.is_none())
+        .map(|p| p.email.clone())
+        .collect();
+    if !divergent.is_empty() {
+        crate::desktop_log!(
+            "⚠️ Flow spec divergence in session {}: {}",
+            session_id,
+            divergent.join(", ")
+        );
+    }
+
+    Ok(FlowSpecConsistencyReport {
+        session_id,
+        consistent: divergent.is_empty(),
+        local_digest,
+        participants,
+        divergent,
+        unavailable,
+    })
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionLayoutEntry {
+    /// work_dir | state_file | progress | mpc | step_output | private_logs | peer
+    pub kind: String,
+    pub label: String,
+    pub path: String,
+    pub exists: bool,
+    /// Nearest existing directory, suitable for `open_path_in_file_manager`
+    pub open_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub step_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionLayout {
+    pub session_id: String,
+    pub flow_name: String,
+    pub work_dir: String,
+    pub entries: Vec<SessionLayoutEntry>,
+}
+
+fn layout_entry(kind: &str, label: String, path: &Path) -> SessionLayoutEntry {
+    let open_path = path
+        .ancestors()
+        .find(|p| p.is_dir())
+        .map(|p| p.to_string_lossy().to_string());
+    SessionLayoutEntry {
+        kind: kind.to_string(),
+        label,
+        path: path.to_string_lossy().to_string(),
+        exists: path.exists(),
+        open_path,
+        step_id: None,
+        email: None,
+    }
+}
+
+/// Map a session onto the concrete directories it uses: local work dir,
+/// progress/MPC dirs, each step's output dir and the synced peer dirs.
+/// Read-only: unlike the run path this never creates or merges directories.
+#[tauri::command]
+pub async fn describe_session_layout(session_id: String) -> Result<SessionLayout, String> {
+    let flow_state = {
+        let sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
+        sessions.get(&session_id).cloned()
+    };
+    let flow_state = match flow_state {
+        Some(state) => state,
+        None => load_multiparty_state_from_disk(&session_id)?
+            .ok_or_else(|| "Flow session not found".to_string())?,
+    };
+
+    let work_dir = match flow_state.work_dir.clone() {
+        Some(dir) => dir,
+        None => get_shared_flow_path(&flow_state.flow_name, &session_id)?,
+    };
+    let mut entries = vec![
+        layout_entry("work_dir", "Session work directory".to_string(), &work_dir),
+        layout_entry(
+            "state_file",
+            "Session state".to_string(),
+            &state_file_for_flow(&flow_state)?,
+        ),
+        layout_entry(
+            "progress",
+            "Progress logs".to_string(),
+            &get_progress_path(&work_dir),
+        ),
+        layout_entry("mpc", "MPC channels".to_string(), &work_dir.join("_mpc")),
+    ];
+
+    for (idx, step) in flow_state.steps.iter().enumerate() {
+        let canonical = get_step_path(&work_dir, idx + 1, &step.id);
+        let padded = get_padded_step_path(&work_dir, idx + 1, &step.id);
+        let dir = step
+            .output_dir
+            .clone()
+            .filter(|d| d.exists())
+            .or_else(|| Some(canonical.clone()).filter(|d| d.exists()))
+            .or_else(|| Some(padded).filter(|d| d.exists()))
+            .unwrap_or(canonical);
+        let mut entry = layout_entry(
+            "step_output",
+            format!("Step {} ({})", idx + 1, step.name),
+            &dir,
+        );
+        entry.step_id = Some(step.id.clone());
+        entries.push(entry);
+    }
+
+    let biovault_home = biovault::config::get_biovault_home()
+        .map_err(|e| format!("Failed to get BioVault home: {}", e))?;
+    let private_logs = biovault_home
+        .join(".biovault")
+        .join("multiparty_step_logs")
+        .join(&session_id);
+    entries.push(layout_entry(
+        "private_logs",
+        "Private step logs".to_string(),
+        &private_logs,
+    ));
+
+    let mut seen = HashSet::new();
+    for participant in &flow_state.participants {
+        if participant.email.eq_ignore_ascii_case(&flow_state.my_email)
+            || !seen.insert(participant.email.to_lowercase())
+        {
+            continue;
+        }
+        for dir in participant_flow_dirs_for_viewer(
+            &biovault_home,
+            &flow_state.my_email,
+            &participant.email,
+            &flow_state.flow_name,
+            &session_id,
+        ) {
+            let mut entry =
+                layout_entry("peer", format!("Synced from {}", participant.email), &dir);
+            entry.email = Some(participant.email.clone());
+            entries.push(entry);
+        }
+    }
+
+    Ok(SessionLayout {
+        session_id,
+        flow_name: flow_state.flow_name.clone(),
+        work_dir: work_dir.to_string_lossy().to_string(),
+        entries,
+    })
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ParticipantStepTiming {
+    pub participant: String,
+    pub role: String,
+    pub started_at: String,
+    pub ended_at: Option<String>,
+    /// Seconds from start to end, or elapsed so far while still running.
+    pub elapsed_seconds: i64,
+    /// "completed", "failed" or "running".
+    pub status: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StepTiming {
+    pub step_id: String,
+    pub step_name: Option<String>,
+    /// Earliest start to latest end across participants.
+    pub wall_clock_seconds: i64,
+    pub running: bool,
+    pub participants: Vec<ParticipantStepTiming>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionStepTimings {
+    pub session_id: String,
+    pub steps: Vec<StepTiming>,
+    pub bottleneck_step_id: Option<String>,
+    pub bottleneck_seconds: Option<i64>,
+    pub generated_at: String,
+}
+
+/// Pair each participant's latest `step_started` with the first
+/// `step_completed`/`step_failed` after it. Steps are returned in `step_order`
+/// first, then any others in the order they were first seen.
+fn compute_step_timings(
+    logs: &[LogEntry],
+    step_order: &[(String, String)],
+    now: chrono::DateTime<Utc>,
+) -> Vec<StepTiming> {
+    type Key = (String, String);
+    let parse = |ts: &str| {
+        chrono::DateTime::parse_from_rfc3339(ts)
+            .ok()
+            .map(|dt| dt.with_timezone(&Utc))
+    };
+
+    let mut sorted: Vec<(&LogEntry, chrono::DateTime<Utc>)> = logs
+        .iter()
+        .filter_map(|log| parse(&log.timestamp).map(|ts| (log, ts)))
+        .collect();
+    sorted.sort_by_key(|(_, ts)| *ts);
+
+    let mut spans: HashMap<
+        Key,
+        (
+            String,
+            chrono::DateTime<Utc>,
+            Option<(chrono::DateTime<Utc>, bool)>,
+        ),
+    > = HashMap::new();
+    let mut seen_steps: Vec<String> = Vec::new();
+    for (log, ts) in sorted {
+        let Some(step_id) = log.step_id.clone() else {
+            continue;
+        };
+        let key = (step_id.clone(), log.participant.to_lowercase());
+        match log.event.as_str() {
+            "step_started" => {
+                if !seen_steps.contains(&step_id) {
+                    seen_steps.push(step_id);
+                }
+                spans.insert(key, (log.role.clone(), ts, None));
+            }
+            "step_completed" | "step_failed" => {
+                if let Some(span) = spans.get_mut(&key) {
+                    if span.2.is_none() {
+                        span.2 = Some((ts, log.event == "step_failed"));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut ordered: Vec<String> = step_order
+        .iter()
+        .map(|(id, _)| id.clone())
+        .filter(|id| seen_steps.contains(id))
+        .collect();
+    ordered.extend(seen_steps.into_iter().filter(|id| !ordered.contains(id)));
+
+    ordered
+        .into_iter()
+        .map(|step_id| {
+            let mut participants: Vec<ParticipantStepTiming> = spans
+                .iter()
+                .filter(|((id, _), _)| *id == step_id)
+                .map(|((_, email), (role, start, end))| {
+                    let (end_ts, status) = match end {
+                        Some((ts, false)) => (Some(*ts), "completed"),
+                        Some((ts, true)) => (Some(*ts), "failed"),
+                        None => (None, "running"),
+                    };
+                    ParticipantStepTiming {
+                        participant: email.clone(),
+                        role: role.clone(),
+                        started_at: start.to_rfc3339(),
+                        ended_at: end_ts.map(|ts| ts.to_rfc3339()),
+                        elapsed_seconds: (end_ts.unwrap_or(now) - *start).num_seconds().max(0),
+                        status: status.to_string(),
+                    }
+                })
+                .collect();
+            participants.sort_by(|a, b| a.participant.cmp(&b.participant));
+
+            let first_start = spans
+                .iter()
+                .filter(|((id, _), _)| *id == step_id)
+                .map(|(_, (_, start, _))| *start)
+                .min()
+                .unwrap_or(now);
+            let running = participants.iter().any(|p| p.status == "running");
+            let last_end = if running {
+                now
+            } else {
+                spans
+                    .iter()
+                    .filter(|((id, _), _)| *id == step_id)
+                    .filter_map(|(_, (_, _, end))| end.map(|(ts, _)| ts))
+                    .max()
+                    .unwrap_or(now)
+            };
+
+            StepTiming {
+                step_name: step_order
+                    .iter()
+                    .find(|(id, _)| *id == step_id)
+                    .map(|(_, name)| name.clone()),
+                step_id,
+                wall_clock_seconds: (last_end - first_start).num_seconds().max(0),
+                running,
+                participants,
+            }
+        })
+        .collect()
+}
+
+/// Per-step start/end and elapsed time for every participant, derived from the
+/// synced `_progress` event logs, plus the step that took longest overall.
+#[tauri::command]
+pub async fn get_session_step_timings(session_id: String) -> Result<SessionStepTimings, String> {
+    let logs = get_participant_logs(session_id.clone()).await?;
+    let step_order: Vec<(String, String)> = {
+        let sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
+        sessions
+            .get(&session_id)
+            .map(|state| {
+                state
+                    .steps
+                    .iter()
+                    .map(|step| (step.id.clone(), step.name.clone()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    let now = Utc::now();
+    let steps = compute_step_timings(&logs, &step_order, now);
+    let bottleneck = steps
+        .iter()
+        .max_by_key(|step| step.wall_clock_seconds)
+        .filter(|step| step.wall_clock_seconds > 0);
+
+    Ok(SessionStepTimings {
+        session_id,
+        bottleneck_step_id: bottleneck.map(|step| step.step_id.clone()),
+        bottleneck_seconds: bottleneck.map(|step| step.wall_clock_seconds),
+        steps,
+        generated_at: now.to_rfc3339(),
+    })
+}
+
+/// Lines kept from each output stream captured during a replay.
+const REPLAY_LOG_TAIL_LINES: usize = 400;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StepReplayResult {
+    pub session_id: String,
+    pub step_id: String,
+    pub module_dir: String,
+    pub args: Vec<String>,
+    pub success: bool,
+    pub error: Option<String>,
+    pub duration_ms: u64,
+    /// Tail of the log files the replayed step wrote; `stderr` gathers the
+    /// `.err` / `*stderr*` files and `stdout` everything else.
+    pub stdout: String,
+    pub stderr: String,
+    /// Only set when `keep_scratch` was requested; otherwise the dir is removed.
+    pub scratch_dir: Option<String>,
+}
+
+/// Tail each log file a replay wrote into `scratch_dir`, split into the
+/// stdout-like and stderr-like files.
+fn collect_replay_output(scratch_dir: &Path) -> (String, String) {
+    let mut stdout = Vec::new();
+    let mut stderr = Vec::new();
+    let mut files: Vec<PathBuf> = walkdir::WalkDir::new(scratch_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter(|e| is_step_log_file(&e.file_name().to_string_lossy()))
+        .map(|e| e.into_path())
+        .collect();
+    files.sort();
+    for path in files {
+        let content = read_tail_lines(&path, REPLAY_LOG_TAIL_LINES).unwrap_or_default();
+        if content.is_empty() {
+            continue;
+        }
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_ascii_lowercase())
+            .unwrap_or_default();
+        let rel = path.strip_prefix(scratch_dir).unwrap_or(&path);
+        let section = format!("==> {} <==\n{}", rel.display(), content);
+        if name.ends_with(".err") || name.contains("stderr") {
+            stderr.push(section);
+        } else {
+            stdout.push(section);
+        }
+    }
+    (stdout.join("\n"), stderr.join("\n"))
+}
+
+/// Re-run a module step with the same resolved bindings, writing into a
+/// scratch directory instead of the real step dir. Session state, progress
+/// logs and shared outputs are left untouched.
+#[tauri::command]
+pub async fn replay_step(
+    session_id: String,
+    step_id: String,
+    keep_scratch: Option<bool>,
+) -> Result<StepReplayResult, String> {
+    let flow_state = {
+        let sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
+        sessions
+            .get(&session_id)
+            .cloned()
+            .ok_or_else(|| "Flow session not found".to_string())?
+    };
+    let step = flow_state
+        .steps
+        .iter()
+        .find(|s| s.id == step_id)
+        .ok_or_else(|| "Step not found".to_string())?;
+    if !step.my_action {
+        return Err("This step is not your action".to_string());
+    }
+    if step.module_ref.is_none() && step.module_path.is_none() && step.module_override.is_none() {
+        return Err(format!("Step '{}' does not run a module", step_id));
+    }
+    if step_id == "secure_aggregate" {
+        return Err("Secure aggregation needs live peers and cannot be replayed alone".to_string());
+    }
+    if matches!(step.status, StepStatus::Running | StepStatus::Sharing) {
+        return Err(format!("Step '{}' is currently {:?}", step_id, step.status));
+    }
+
+    let work_dir = flow_state
+        .work_dir
+        .clone()
+        .ok_or_else(|| "No work directory".to_string())?;
+    let flow_spec = flow_state
+        .flow_spec
+        .as_ref()
+        .ok_or_else(|| "Flow spec not stored in session state".to_string())?;
+    let source_flow_path = flow_spec.get("flow_path").and_then(|v| v.as_str());
+    let module_dir = match step.module_override.clone() {
+        Some(dir) => dir,
+        None => resolve_module_directory(
+            &flow_state.flow_name,
+            step.module_path.as_deref(),
+            step.module_ref.as_deref(),
+            source_flow_path,
+        )
+        .ok_or_else(|| format!("Failed to resolve module directory for step '{}'", step_id))?,
+    };
+    validate_module_assets_exist(&module_dir)
+        .map_err(|e| format!("Step '{}' failed preflight: {}", step_id, e))?;
+    validate_module_entrypoint(&module_dir)
+        .map_err(|e| format!("Step '{}' failed preflight: {}", step_id, e))?;
+
+    let biovault_home = biovault::config::get_biovault_home()
+        .map_err(|e| format!("Failed to get BioVault home: {}", e))?;
+    let step_numbers_by_id = flow_state
+        .steps
+        .iter()
+        .enumerate()
+        .map(|(i, s)| (s.id.clone(), i + 1))
+        .collect::<HashMap<_, _>>();
+    let step_args = resolve_with_bindings(
+        &step.with_bindings,
+        &flow_state.input_overrides,
+        flow_spec,
+        &flow_state.flow_name,
+        &session_id,
+        &flow_state.my_email,
+        &biovault_home,
+        &step_numbers_by_id,
+        &flow_state.steps,
+        &work_dir,
+        &flow_state.participants,
+    )?;
+
+    let scratch_dir = biovault_home
+        .join(".biovault")
+        .join("multiparty_replays")
+        .join(&session_id)
+        .join(format!("{}-{}", step_id, Utc::now().format("%Y%m%d%H%M%S")));
+    fs::create_dir_all(&scratch_dir)
+        .map_err(|e| format!("Failed to create replay directory: {}", e))?;
+
+    let (party_emails, _) = choose_syqure_party_order(
+        &flow_state.participants,
+        &flow_state.my_email,
+        &flow_state.input_overrides,
+        flow_spec,
+    );
+    let dynamic_ctx = run_dynamic::DynamicExecutionContext {
+        current_datasite: Some(flow_state.my_email.clone()),
+        datasites_override: Some(party_emails),
+        syftbox_data_dir: Some(biovault_home.to_string_lossy().to_string()),
+        run_id: Some(session_id.clone()),
+        flow_name: Some(flow_state.flow_name.clone()),
+        syqure_port_base: flow_state.syqure_port_base,
+        tauri_context: true,
+    };
+
+    crate::desktop_log!(
+        "🔁 Replaying step '{}' of session {} in {}",
+        step_id,
+        session_id,
+        scratch_dir.display()
+    );
+    let started = std::time::Instant::now();
+    let run_result = execute_module_step(
+        dynamic_ctx,
+        &module_dir,
+        step_args.clone(),
+        &scratch_dir,
+        flow_state.mpc_verbose_logging,
+    )
+    .await;
+    let duration_ms = started.elapsed().as_millis() as u64;
+    let (stdout, stderr) = collect_replay_output(&scratch_dir);
+
+    let keep = keep_scratch.unwrap_or(false);
+    if !keep {
+        let _ = fs::remove_dir_all(&scratch_dir);
+    }
+
+    Ok(StepReplayResult {
+        session_id,
+        step_id,
+        module_dir: module_dir.to_string_lossy().to_string(),
+        args: step_args,
+        success: run_result.is_ok(),
+        error: run_result.err(),
+        duration_ms,
+        stdout,
+        stderr,
+        scratch_dir: keep.then(|| scratch_dir.to_string_lossy().to_string()),
+    })
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionAuditExport {
+    pub session_id: String,
+    pub path: String,
+    pub records: usize,
+    /// Hash of the last record; anchoring this elsewhere pins the whole log.
+    pub head_hash: String,
+    pub flow_spec_digest: Option<String>,
+}
+
+/// Append `record` to a hash chain: each line carries the previous line's hash
+/// and its own SHA-256 over `prev_hash` + the record body, so editing,
+/// removing or reordering any line breaks every hash after it.
+fn chain_audit_record(
+    mut record: serde_json::Value,
+    seq: usize,
+    prev_hash: &str,
+) -> (serde_json::Value, String) {
+    use sha2::{Digest, Sha256};
+
+    if let Some(map) = record.as_object_mut() {
+        map.insert("seq".to_string(), serde_json::json!(seq));
+        map.insert("prev_hash".to_string(), serde_json::json!(prev_hash));
+    }
+    let body = serde_json::to_string(&canonical_json(&record)).unwrap_or_default();
+    let hash = hex::encode(Sha256::digest(format!("{}{}", prev_hash, body).as_bytes()));
+    if let Some(map) = record.as_object_mut() {
+        map.insert("hash".to_string(), serde_json::json!(hash));
+    }
+    (record, hash)
+}
+
+/// Files a step produced, as metadata only (name, size, checksum).
+fn audit_output_files(output_dir: &Path) -> Vec<serde_json::Value> {
+    let mut files: Vec<serde_json::Value> = walkdir::WalkDir::new(output_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter(|e| e.file_name() != "syft.pub.yaml")
+        .map(|e| {
+            let path = e.path();
+            let sha256 = fs::read(path)
+                .ok()
+                .map(|bytes| super::messages::attachment_sha256(&bytes));
+            serde_json::json!({
+                "path": path.strip_prefix(output_dir).unwrap_or(path).to_string_lossy(),
+                "size_bytes": e.metadata().ok().map(|m| m.len()),
+                "sha256": sha256,
+            })
+        })
+        .collect();
+    files.sort_by(|a, b| a["path"].as_str().cmp(&b["path"].as_str()));
+    files
+}
+
+/// Write a hash-chained JSONL audit trail of a session: participants, every
+/// progress event from every participant, and which outputs were shared with
+/// whom. File contents are never included, only names, sizes and checksums.
+#[tauri::command]
+pub async fn export_session_audit(
+    session_id: String,
+    dest_path: String,
+) -> Result<SessionAuditExport, String> {
+    let flow_state = {
+        let sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
+        sessions
+            .get(&session_id)
+            .cloned()
+            .ok_or_else(|| "Flow session not found".to_string())?
+    };
+    let dest = PathBuf::from(dest_path.trim());
+    if dest.as_os_str().is_empty() {
+        return Err("Destination path is required".to_string());
+    }
+    if let Some(parent) = dest.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+
+    let digest = flow_state.flow_spec.as_ref().map(flow_spec_digest);
+    let mut events = get_participant_logs(session_id.clone()).await?;
+    events.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+
+    let mut records = vec![serde_json::json!({
+        "type": "session",
+        "session_id": flow_state.session_id,
+        "flow_name": flow_state.flow_name,
+        "flow_spec_digest": digest,
+        "status": flow_state.status,
+        "exported_by": flow_state.my_email,
+        "exported_at": Utc::now().to_rfc3339(),
+        "participants": flow_state
+            .participants
+            .iter()
+            .map(|p| serde_json::json!({ "email": p.email, "role": p.role }))
+            .collect::<Vec<_>>(),
+    })];
+    records.extend(events.iter().map(|event| {
+        serde_json::json!({
+            "type": "event",
+            "timestamp": event.timestamp,
+            "participant": event.participant,
+            "role": event.role,
+            "event": event.event,
+            "step_id": event.step_id,
+            "message": event.message,
+        })
+    }));
+    for step in flow_state.steps.iter().filter(|s| s.shares_output) {
+        records.push(serde_json::json!({
+            "type": "share",
+            "step_id": step.id,
+            "shared": step.outputs_shared,
+            "from": flow_state.my_email,
+            "to": step_share_recipients(&flow_state, &step.share_to),
+            "files": step
+                .output_dir
+                .as_deref()
+                .map(audit_output_files)
+                .unwrap_or_default(),
+        }));
+    }
+
+    let mut lines = Vec::with_capacity(records.len());
+    let mut prev_hash = "0".repeat(64);
+    for (seq, record) in records.into_iter().enumerate() {
+        let (record, hash) = chain_audit_record(record, seq, &prev_hash);
+        lines.push(serde_json::to_string(&record).map_err(|e| e.to_string())?);
+        prev_hash = hash;
+    }
+    fs::write(&dest, format!("{}\n", lines.join("\n")))
+        .map_err(|e| format!("Failed to write audit log: {}", e))?;
+
+    crate::desktop_log!(
+        "🧾 Exported audit log for session {} ({} records) to {}",
+        session_id,
+        lines.len(),
+        dest.display()
+    );
+    Ok(SessionAuditExport {
+        session_id,
+        path: dest.to_string_lossy().to_string(),
+        records: lines.len(),
+        head_hash: prev_hash,
+        flow_spec_digest: digest,
+    })
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProgressMatrixExport {
+    pub session_id: String,
+    pub path: String,
+    pub participants: usize,
+    pub steps: usize,
+}
+
+/// Write a participant × step grid of the session's progress as CSV. Cells
+/// read `Status @ timestamp`; a final row counts completions per step.
+#[tauri::command]
+pub async fn export_progress_matrix(
+    session_id: String,
+    dest_path: String,
+) -> Result<ProgressMatrixExport, String> {
+    let step_ids: Vec<String> = {
+        let sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
+        sessions
+            .get(&session_id)
+            .ok_or_else(|| "Flow session not found".to_string())?
+            .steps
+            .iter()
+            .map(|s| s.id.clone())
+            .collect()
+    };
+    let dest = PathBuf::from(dest_path.trim());
+    if dest.as_os_str().is_empty() {
+        return Err("Destination path is required".to_string());
+    }
+    if let Some(parent) = dest.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+
+    let progress = get_all_participant_progress(session_id.clone()).await?;
+    let mut writer = csv::Writer::from_path(&dest)
+        .map_err(|e| format!("Failed to create {}: {}", dest.display(), e))?;
+
+    let mut header = vec!["participant".to_string(), "role".to_string()];
+    header.extend(step_ids.iter().cloned());
+    writer
+        .write_record(&header)
+        .map_err(|e| format!("Failed to write progress matrix: {}", e))?;
+
+    let mut completed = vec![0usize; step_ids.len()];
+    for participant in &progress {
+        let mut row = vec![participant.email.clone(), participant.role.clone()];
+        for (idx, step_id) in step_ids.iter().enumerate() {
+            let cell = match participant.steps.iter().find(|s| &s.step_id == step_id) {
+                Some(step) => {
+                    if step.status == "Completed" || step.status == "Shared" {
+                        completed[idx] += 1;
+                    }
+                    match Utc.timestamp_opt(step.timestamp, 0).single() {
+                        Some(ts) => format!("{} @ {}", step.status, ts.to_rfc3339()),
+                        None => step.status.clone(),
+                    }
+                }
+                None => String::new(),
+            };
+            row.push(cell);
+        }
+        writer
+            .write_record(&row)
+            .map_err(|e| format!("Failed to write progress matrix: {}", e))?;
+    }
+
+    let mut summary = vec!["completed".to_string(), String::new()];
+    summary.extend(
+        completed
+            .iter()
+            .map(|count| format!("{}/{}", count, progress.len())),
+    );
+    writer
+        .write_record(&summary)
+        .map_err(|e| format!("Failed to write progress matrix: {}", e))?;
+    writer
+        .flush()
+        .map_err(|e| format!("Failed to write progress matrix: {}", e))?;
+
+    crate::desktop_log!(
+        "📊 Exported progress matrix for session {} to {}",
+        session_id,
+        dest.display()
+    );
+    Ok(ProgressMatrixExport {
+        session_id,
+        path: dest.to_string_lossy().to_string(),
+        participants: progress.len(),
+        steps: step_ids.len(),
+    })
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ParticipantIdentityChange {
+    pub email: String,
+    pub role: String,
+    /// Datasite that now hosts this participant's copy of the session, if any.
+    pub suggested_email: Option<String>,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionIdentityReport {
+    pub session_id: String,
+    /// Participants whose datasite no longer hosts the session.
+    pub changes: Vec<ParticipantIdentityChange>,
+    /// Datasites hosting the session that aren't in the participant list.
+    pub unknown_hosts: Vec<String>,
+}
+
+/// Roles a session folder's `_progress` files were written under.
+fn progress_roles(flow_dir: &Path) -> BTreeSet<String> {
+    let progress_dir = flow_dir.join("_progress");
+    let mut roles = BTreeSet::new();
+    for name in ["log.jsonl", "progress.json"] {
+        if let Ok(content) = fs::read_to_string(progress_dir.join(name)) {
+            roles.extend(content.lines().filter_map(|line| {
+                serde_json::from_str::<serde_json::Value>(line)
+                    .ok()?
+                    .get("role")?
+                    .as_str()
+                    .map(|r| r.to_string())
+            }));
+        }
+    }
+    if let Ok(entries) = fs::read_dir(&progress_dir) {
+        for path in entries.flatten().map(|e| e.path()) {
+            if let Some(status) = fs::read_to_string(&path)
+                .ok()
+                .and_then(|c| serde_json::from_str::<SharedStepStatus>(&c).ok())
+            {
+                roles.insert(status.role);
+            }
+        }
+    }
+    roles.retain(|r| !r.trim().is_empty());
+    roles
+}
+
+/// Detect participants whose progress can no longer be found because they
+/// switched identity: their datasite stopped hosting the session while a
+/// datasite outside the participant list started hosting it.
+#[tauri::command]
+pub async fn get_session_identity_changes(
+    session_id: String,
+) -> Result<SessionIdentityReport, String> {
+    let (flow_name, my_email, participants) = {
+        let sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
+        let flow_state = sessions
+            .get(&session_id)
+            .ok_or_else(|| "Flow session not found".to_string())?;
+        (
+            flow_state.flow_name.clone(),
+            flow_state.my_email.clone(),
+            flow_state.participants.clone(),
+        )
+    };
+    let biovault_home = biovault::config::get_biovault_home()
+        .map_err(|e| format!("Failed to get BioVault home: {}", e))?;
+
+    let hosts_session = |email: &str| {
+        participant_flow_dirs_for_viewer(&biovault_home, &my_email, email, &flow_name, &session_id)
+            .into_iter()
+            .find(|dir| dir.exists())
+    };
+
+    let mut datasite_roots = vec![biovault_home.join("datasites")];
+    if let Some(sandbox_root) = find_sandbox_root(&biovault_home) {
+        datasite_roots.push(sandbox_root.join(&my_email).join("datasites"));
+    }
+    let mut unknown: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for root in datasite_roots {
+        let Ok(entries) = fs::read_dir(&root) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let email = entry.file_name().to_string_lossy().to_string();
+            if !email.contains('@')
+                || participants
+                    .iter()
+                    .any(|p| p.email.eq_ignore_ascii_case(&email))
+            {
+                continue;
+            }
+            let flow_dir = entry
+                .path()
+                .join("shared")
+                .join("flows")
+                .join(&flow_name)
+                .join(&session_id);
+            if flow_dir.exists() {
+                unknown
+                    .entry(email)
+                    .or_default()
+                    .extend(progress_roles(&flow_dir));
+            }
+        }
+    }
+
+    let mut changes = Vec::new();
+    for participant in &participants {
+        if participant.email.eq_ignore_ascii_case(&my_email)
+            || hosts_session(&participant.email).is_some()
+        {
+            continue;
+        }
+        // Prefer a host writing progress under the same role, then one sharing
+        // the old address's domain; with a single unknown host, take it.
+        let domain = participant.email.rsplit('@').next().unwrap_or_default();
+        let suggested = unknown
+            .iter()
+            .find(|(_, roles)| roles.contains(&participant.role))
+            .map(|(email, _)| (email.clone(), "writes progress under the same role"))
+            .or_else(|| {
+                unknown
+                    .keys()
+                    .find(|email| email.ends_with(&format!("@{}", domain)))
+                    .map(|email| (email.clone(), "shares the same email domain"))
+            })
+            .or_else(|| {
+                (unknown.len() == 1)
+                    .then(|| unknown.keys().next().cloned())
+                    .flatten()
+                    .map(|email| (email, "is the only unlisted datasite hosting the session"))
+            });
+
+        let reason = match &suggested {
+            Some((email, why)) => format!(
+                "{} no longer hosts this session; {} {}",
+                participant.email, email, why
+            ),
+            None => format!(
+                "{} does not host this session (not synced yet, or the identity changed)",
+                participant.email
+            ),
+        };
+        changes.push(ParticipantIdentityChange {
+            email: participant.email.clone(),
+            role: participant.role.clone(),
+            suggested_email: suggested.map(|(email, _)| email),
+            reason,
+        });
+    }
+
+    Ok(SessionIdentityReport {
+        session_id,
+        changes,
+        unknown_hosts: unknown.into_keys().collect(),
+    })
+}
+
+const REPRO_PARTIES: [&str; 2] = ["party1@repro.local", "party2@repro.local"];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MinimalRepro {
+    pub session_id: String,
+    pub flow_name: String,
+    /// Importable flow directory (flow.yaml plus stub modules).
+    pub directory: String,
+    pub flow_yaml_path: String,
+    pub parties: Vec<String>,
+    pub steps: usize,
+    pub flow_spec: serde_json::Value,
+}
+
+/// Rewrite `datasites[N]` references so indices past the last synthetic party
+/// point at it, matching how extra participants collapse onto party 2.
+fn clamp_datasite_indices(text: &str, max_index: usize) -> String {
+    const NEEDLE: &str = "datasites[";
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(pos) = rest.find(NEEDLE) {
+        let after = &rest[pos + NEEDLE.len()..];
+        let digits: String = after.chars().take_while(|c| c.is_ascii_digit()).collect();
+        out.push_str(&rest[..pos + NEEDLE.len()]);
+        match digits.parse::<usize>() {
+            Ok(index) => out.push_str(&index.min(max_index).to_string()),
+            Err(_) => out.push_str(&digits),
+        }
+        rest = &after[digits.len()..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn repro_module_name(uses: &str) -> String {
+    uses.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Keep step/input references (they carry the dependency structure) and
+/// replace literal values, which may point at real data, with placeholders.
+fn scrub_with_bindings(with: &mut serde_json::Value) {
+    let Some(bindings) = with.as_object_mut() else {
+        return;
+    };
+    for (key, value) in bindings.iter_mut() {
+        let is_reference = match value {
+            serde_json::Value::String(s) => s.starts_with("steps.") || s.starts_with("inputs."),
+            serde_json::Value::Object(obj) => obj.contains_key("from"),
+            _ => false,
+        };
+        if !is_reference {
+            *value = serde_json::Value::String(format!("synthetic-{}", key));
+        }
+    }
+}
+
+fn scrub_input_defaults(inputs: &mut serde_json::Value) {
+    let Some(inputs) = inputs.as_object_mut() else {
+        return;
+    };
+    for (name, input) in inputs.iter_mut() {
+        if name == "datasites" {
+            if let Some(obj) = input.as_object_mut() {
+                obj.insert("default".to_string(), serde_json::json!(REPRO_PARTIES));
+            }
+            continue;
+        }
+        let Some(default) = input.get_mut("default") else {
+            continue;
+        };
+        let keep = match default {
+            serde_json::Value::Bool(_) | serde_json::Value::Number(_) => true,
+            serde_json::Value::String(s) => s.parse::<f64>().is_ok(),
+            _ => false,
+        };
+        if !keep {
+            *default = serde_json::Value::String(format!("synthetic-{}", name));
+        }
+    }
+}
+
+/// Output names a step's module must produce: declared outputs of the
+/// original module, share sources, and outputs other steps reference.
+fn repro_module_outputs(
+    spec_root: &serde_json::Value,
+    step: &serde_json::Value,
+    step_id: &str,
+    original_module: Option<&serde_yaml::Value>,
+) -> BTreeSet<String> {
+    let mut outputs = BTreeSet::new();
+    if let Some(declared) = original_module
+        .and_then(|m| m.get("spec"))
+        .and_then(|s| s.get("outputs"))
+        .and_then(|o| o.as_sequence())
+    {
+        outputs.extend(
+            declared
+                .iter()
+                .filter_map(|o| o.get("name").and_then(|n| n.as_str()))
+                .map(str::to_string),
+        );
+    }
+    let share = step.get("share").and_then(|s| s.as_object());
+    if let Some(share) = share {
+        outputs.extend(
+            share
+                .values()
+                .filter_map(|s| s.get("source").and_then(|v| v.as_str()))
+                .map(str::to_string),
+        );
+    }
+
+    let prefix = format!("steps.{}.outputs.", step_id);
+    let text = spec_root.to_string();
+    let mut rest = text.as_str();
+    while let Some(pos) = rest.find(&prefix) {
+        rest = &rest[pos + prefix.len()..];
+        let name: String = rest
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric() || *c == '_' || *c == '-')
+            .collect();
+        if !name.is_empty() && !share.is_some_and(|s| s.contains_key(&name)) {
+            outputs.insert(name);
+        }
+    }
+    if outputs.is_empty() {
+        outputs.insert("result".to_string());
+    }
+    outputs
+}
+
+fn read_original_module(spec_root: &serde_json::Value, uses: &str) -> Option<serde_yaml::Value> {
+    let source_path = spec_root
+        .get("modules")
+        .and_then(|m| m.get(uses))
+        .and_then(|m| m.get("source"))
+        .and_then(|s| s.get("path"))
+        .and_then(|p| p.as_str())?;
+    resolve_module_directory_from_flow_spec(source_path)
+        .into_iter()
+        .find_map(|dir| {
+            let path = [dir.join("module.yaml"), dir.join("module.yml"), dir.clone()]
+                .into_iter()
+                .find(|p| p.is_file())?;
+            serde_yaml::from_str(&fs::read_to_string(path).ok()?).ok()
+        })
+}
+
+fn write_repro_module(
+    module_dir: &Path,
+    name: &str,
+    outputs: &BTreeSet<String>,
+    original_module: Option<&serde_yaml::Value>,
+) -> Result<(), String> {
+    fs::create_dir_all(module_dir)
+        .map_err(|e| format!("Failed to create {}: {}", module_dir.display(), e))?;
+
+    let mut runner = serde_json::json!({
+        "kind": "shell",
+        "template": "shell",
+        "entrypoint": "run.sh",
+    });
+    // Keep the MPC transport settings so the session sets up the same channels.
+    if let Some(syqure) = original_module
+        .and_then(|m| m.get("spec"))
+        .and_then(|s| s.get("runner"))
+        .and_then(|r| r.get("syqure"))
+        .and_then(|s| serde_json::to_value(s).ok())
+    {
+        runner["syqure"] = syqure;
+    }
+    let module = serde_json::json!({
+        "apiVersion": "syftbox.openmined.org/v1alpha1",
+        "kind": "Module",
+        "metadata": { "name": name, "version": "0.1.0" },
+        "spec": {
+            "runner": runner,
+            "outputs": outputs
+                .iter()
+                .map(|o| serde_json::json!({
+                    "name": o,
+                    "type": "File",
+                    "format": { "kind": "txt" },
+                    "path": format!("{}.txt", o),
+                }))
+                .collect::<Vec<_>>(),
+        },
+    });
+    let yaml = serde_yaml::to_string(&module)
+        .map_err(|e| format!("Failed to serialize module {}: {}", name, e))?;
+    fs::write(module_dir.join("module.yaml"), yaml)
+        .map_err(|e| format!("Failed to write module {}: {}", name, e))?;
+
+    let mut script = format!(
+        "#!/usr/bin/env bash\nset -euo pipefail\n# Synthetic stand-in for module '{}'.\n",
+        name
+    );
+    for output in outputs {
+        script.push_str(&format!(
+            "printf 'synthetic {} from %s\\n' \"${{BV_CURRENT_DATASITE:-unknown}}\" > \"${{BV_OUTPUT_{}:-{}.txt}}\"\n",
+            output,
+            output.to_ascii_uppercase().replace('-', "_"),
+            output
+        ));
+    }
+    let script_path = module_dir.join("run.sh");
+    fs::write(&script_path, script)
+        .map_err(|e| format!("Failed to write {}: {}", script_path.display(), e))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let _ = fs::set_permissions(&script_path, fs::Permissions::from_mode(0o755));
+    }
+    Ok(())
+}
+
+/// Build a two-party flow with the same step graph, targets, share topology
+/// and MPC transport as the session, but with synthetic parties, stub modules
+/// and no real inputs, so it can be handed to maintainers as a bug repro.
+#[tauri::command]
+pub async fn generate_minimal_repro(session_id: String) -> Result<MinimalRepro, String> {
+    let flow_state = {
+        let sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
+        sessions.get(&session_id).cloned()
+    };
+    let flow_state = match flow_state {
+        Some(state) => state,
+        None => load_multiparty_state_from_disk(&session_id)?
+            .ok_or_else(|| "Flow session not found".to_string())?,
+    };
+    let flow_spec = flow_state
+        .flow_spec
+        .clone()
+        .ok_or_else(|| "Session has no flow spec".to_string())?;
+    let original_root = flow_spec_root(&flow_spec).clone();
+
+    // Placeholder datasites keep their order; participants map through them.
+    let mut originals: Vec<String> = original_root
+        .get("inputs")
+        .and_then(|i| i.get("datasites"))
+        .and_then(|d| d.get("default"))
+        .and_then(|arr| arr.as_array())
+        .or_else(|| original_root.get("datasites").and_then(|d| d.as_array()))
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+    let (_groups, default_to_actual) =
+        build_group_map_from_participants(&flow_state.participants, &flow_spec);
+    let mut synthetic: HashMap<String, &str> = HashMap::new();
+    for (index, email) in originals.iter().enumerate() {
+        synthetic.insert(email.clone(), REPRO_PARTIES[index.min(1)]);
+    }
+    for (default, actual) in &default_to_actual {
+        if let Some(party) = synthetic.get(default).copied() {
+            synthetic.entry(actual.clone()).or_insert(party);
+        }
+    }
+    for participant in &flow_state.participants {
+        if !synthetic.contains_key(&participant.email) {
+            let party = REPRO_PARTIES[originals.len().min(1)];
+            synthetic.insert(participant.email.clone(), party);
+            originals.push(participant.email.clone());
+        }
+    }
+
+    let mut emails: Vec<&String> = synthetic.keys().collect();
+    emails.sort_by_key(|e| std::cmp::Reverse(e.len()));
+    let mut text = original_root.to_string();
+    for email in emails {
+        text = text.replace(email.as_str(), synthetic[email]);
+    }
+    let text = clamp_datasite_indices(&text, REPRO_PARTIES.len() - 1);
+    let mut spec_root: serde_json::Value =
+        serde_json::from_str(&text).map_err(|e| format!("Failed to rewrite flow spec: {}", e))?;
+
+    if let Some(datasites) = spec_root.get_mut("datasites") {
+        if datasites.is_array() {
+            *datasites = serde_json::json!(REPRO_PARTIES);
+        }
+    }
+    if let Some(inputs) = spec_root.get_mut("inputs") {
+        scrub_input_defaults(inputs);
+    }
+
+    let repro_name = format!("{}-repro", flow_state.flow_name);
+    let biovault_home = biovault::config::get_biovault_home()
+        .map_err(|e| format!("Failed to get BioVault home: {}", e))?;
+    let repro_dir = biovault_home
+        .join(".biovault")
+        .join("repros")
+        .join(format!("{}-{}", repro_name, session_id));
+    if repro_dir.exists() {
+        fs::remove_dir_all(&repro_dir)
+            .map_err(|e| format!("Failed to clear {}: {}", repro_dir.display(), e))?;
+    }
+
+    // Steps sharing a module get one stub producing all of their outputs.
+    let mut stubs: BTreeMap<String, (BTreeSet<String>, Option<serde_yaml::Value>)> =
+        BTreeMap::new();
+    let steps_snapshot = spec_root.clone();
+    let steps = spec_root
+        .get_mut("steps")
+        .and_then(|s| s.as_array_mut())
+        .ok_or_else(|| "Invalid flow spec: missing steps".to_string())?;
+    for step in steps.iter_mut() {
+        if let Some(with) = step.get_mut("with") {
+            scrub_with_bindings(with);
+        }
+        let Some(uses) = step
+            .get("uses")
+            .and_then(|u| u.as_str())
+            .map(str::to_string)
+        else {
+            continue;
+        };
+        let step_id = step
+            .get("id")
+            .and_then(|v| v.as_str())
+            .unwrap_or(&uses)
+            .to_string();
+        let name = repro_module_name(&uses);
+        let stub = stubs
+            .entry(name.clone())
+            .or_insert_with(|| (BTreeSet::new(), read_original_module(&original_root, &uses)));
+        let outputs = repro_module_outputs(&steps_snapshot, step, &step_id, stub.1.as_ref());
+        stub.0.extend(outputs);
+        step["uses"] = serde_json::Value::String(name);
+    }
+    let step_count = steps.len();
+
+    let mut modules = serde_json::Map::new();
+    for (name, (outputs, original_module)) in &stubs {
+        write_repro_module(
+            &repro_dir.join("modules").join(name),
+            name,
+            outputs,
+            original_module.as_ref(),
+        )?;
+        modules.insert(
+            name.clone(),
+            serde_json::json!({
+                "source": { "kind": "local", "path": format!("./modules/{}", name) },
+                "allow_dirty": true,
+            }),
+        );
+    }
+    spec_root["modules"] = serde_json::Value::Object(modules);
+
+    let flow_file = serde_json::json!({
+        "apiVersion": "syftbox.openmined.org/v1alpha1",
+        "kind": "Flow",
+        "metadata": {
+            "name": repro_name,
+            "version": "0.1.0",
+            "description": format!(
+                "Data-free reproduction of session {} ({})",
+                session_id, flow_state.flow_name
+            ),
+        },
+        "spec": spec_root,
+    });
+    let yaml = serde_yaml::to_string(&flow_file)
+        .map_err(|e| format!("Failed to serialize repro flow: {}", e))?;
+    fs::create_dir_all(&repro_dir)
+        .map_err(|e| format!("Failed to create {}: {}", repro_dir.display(), e))?;
+    let flow_yaml_path = repro_dir.join("flow.yaml");
+    fs::write(&flow_yaml_path, yaml)
+        .map_err(|e| format!("Failed to write {}: {}", flow_yaml_path.display(), e))?;
+
+    crate::desktop_log!(
+        "🧪 Generated minimal repro for session {} at {}",
+        session_id,
+        repro_dir.display()
+    );
+    Ok(MinimalRepro {
+        session_id,
+        flow_name: repro_name,
+        directory: repro_dir.to_string_lossy().to_string(),
+        flow_yaml_path: flow_yaml_path.to_string_lossy().to_string(),
+        parties: REPRO_PARTIES.iter().map(|p| p.to_string()).collect(),
+        steps: step_count,
+        flow_spec: flow_file,
+    })
+}
+
+fn format_default_mapping_diagnostics(
+    default_datasites: &[String],
+    participants: &[FlowParticipant],
+    default_to_actual: &HashMap<String, String>,
+) -> String {
+    let participants_fmt = participants
+        .iter()
+        .map(|p| format!("{}({})", p.email, p.role))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let defaults_fmt = if default_datasites.is_empty() {
+        "<none>".to_string()
+    } else {
+        default_datasites.join(", ")
+    };
+    let mut mappings = default_datasites
+        .iter()
+        .map(|d| {
+            let actual = default_to_actual
+                .get(d)
+                .cloned()
+                .unwrap_or_else(|| "<unmapped>".to_string());
+            format!("{} -> {}", d, actual)
+        })
+        .collect::<Vec<_>>();
+    mappings.sort();
+    format!(
+        "participants=[{}]; default_datasites=[{}]; default_to_actual={{ {} }}",
+        participants_fmt,
+        defaults_fmt,
+        mappings.join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn default_mapping_never_reuses_participant_for_multiple_default_slots() {
+        let participants = vec![
+            FlowParticipant {
+                email: "madhava@openmined.org".to_string(),
+                role: "clients".to_string(),
+            },
+            FlowParticipant {
+                email: "me@madhavajay.com".to_string(),
+                role: "clients".to_string(),
+            },
+            FlowParticipant {
+                email: "test@madhavajay.com".to_string(),
+                role: "aggregator".to_string(),
+            },
+        ];
+
+        let flow_spec = json!({
+            "spec": {
+                "datasites": ["aggregator@sandbox.local", "client1@sandbox.local", "client2@sandbox.local"]
+            }
+        });
+
+        let (_groups, default_to_actual) =
+            build_group_map_from_participants(&participants, &flow_spec);
+
+        // Aggregator must resolve to the aggregator participant.
+        assert_eq!(
+            default_to_actual.get("aggregator@sandbox.local"),
+            Some(&"test@madhavajay.com".to_string())
+        );
+
+        // Client placeholders must resolve to two distinct client participants.
+        let c1 = default_to_actual
+            .get("client1@sandbox.local")
             .cloned()
             .unwrap_or_default();
         let c2 = default_to_actual
@@ -6436,22 +9670,317 @@ mod tests {
             },
         ];
 
-        let flow_spec = json!({
-            "spec": {
-                "datasites": ["aggregator@sandbox.local", "client1@sandbox.local", "client2@sandbox.local"],
-                "steps": [{
-                    "id": "secure_aggregate",
-                    "runs_on": ["aggregator@sandbox.local", "client1@sandbox.local", "client2@sandbox.local"]
-                }]
-            }
+        let flow_spec = json!({
+            "spec": {
+                "datasites": ["aggregator@sandbox.local", "client1@sandbox.local", "client2@sandbox.local"],
+                "steps": [{
+                    "id": "secure_aggregate",
+                    "runs_on": ["aggregator@sandbox.local", "client1@sandbox.local", "client2@sandbox.local"]
+                }]
+            }
+        });
+
+        let err = parse_flow_steps(&flow_spec, "me@madhavajay.com", &participants)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Invalid multiparty participant mapping for step 'secure_aggregate'"));
+        assert!(err.contains("placeholder targets collapsed to duplicate participants"));
+        assert!(err.contains("default_to_actual"));
+    }
+
+    #[test]
+    fn repro_keeps_references_and_collapses_extra_datasites() {
+        assert_eq!(
+            clamp_datasite_indices("{datasites[0]},{datasites[2]},datasites[12]", 1),
+            "{datasites[0]},{datasites[1]},datasites[1]"
+        );
+
+        let mut with = json!({
+            "counts": "steps.count.outputs.counts",
+            "shared": { "from": "steps.tag.outputs.tagged_shared.manifest" },
+            "seed": "inputs.seed",
+            "genotype": "File(/home/alice/data/genome.txt)",
+        });
+        scrub_with_bindings(&mut with);
+        assert_eq!(with["counts"], "steps.count.outputs.counts");
+        assert_eq!(
+            with["shared"]["from"],
+            "steps.tag.outputs.tagged_shared.manifest"
+        );
+        assert_eq!(with["seed"], "inputs.seed");
+        assert_eq!(with["genotype"], "synthetic-genotype");
+
+        let spec = json!({
+            "steps": [
+                { "id": "tag", "share": { "tagged_shared": { "source": "tagged" } } },
+                { "id": "collect", "with": {
+                    "a": "steps.tag.outputs.extra",
+                    "b": "steps.tag.outputs.tagged_shared.manifest",
+                } },
+            ]
+        });
+        let outputs = repro_module_outputs(&spec, &spec["steps"][0], "tag", None);
+        assert_eq!(
+            outputs.into_iter().collect::<Vec<_>>(),
+            vec!["extra".to_string(), "tagged".to_string()]
+        );
+    }
+
+    #[test]
+    fn platform_notes_flag_mixed_platforms_without_blocking() {
+        let platform = |email: &str, os: Option<&str>, arch: Option<&str>| ParticipantPlatform {
+            email: email.to_string(),
+            role: "client".to_string(),
+            is_me: false,
+            os: os.map(str::to_string),
+            arch: arch.map(str::to_string),
+            app_version: None,
+            reported_at: None,
+        };
+
+        let same = vec![
+            platform("a@x.org", Some("macos"), Some("aarch64")),
+            platform("b@x.org", Some("macos"), Some("aarch64")),
+        ];
+        assert_eq!(platform_preflight_notes(&same), (false, Vec::new()));
+
+        let mixed = vec![
+            platform("a@x.org", Some("macos"), Some("aarch64")),
+            platform("b@x.org", Some("windows"), Some("x86_64")),
+            platform("c@x.org", None, None),
+        ];
+        let (heterogeneous, notes) = platform_preflight_notes(&mixed);
+        assert!(heterogeneous);
+        assert_eq!(notes.len(), 2);
+        assert!(notes[0].contains("macos/aarch64 (a@x.org); windows/x86_64 (b@x.org)"));
+        assert!(notes[1].contains("c@x.org"));
+    }
+
+    #[test]
+    fn seeded_generate_numbers_are_reproducible() {
+        let first = generate_contribution_numbers(Some(42));
+        assert_eq!(first.len(), 5);
+        assert_eq!(first, generate_contribution_numbers(Some(42)));
+        assert_ne!(first, generate_contribution_numbers(Some(43)));
+    }
+
+    #[test]
+    fn group_preview_reports_unmapped_defaults_and_empty_groups() {
+        let participants = vec![
+            FlowParticipant {
+                email: "agg@example.com".to_string(),
+                role: "aggregator".to_string(),
+            },
+            FlowParticipant {
+                email: "c1@example.com".to_string(),
+                role: "client1".to_string(),
+            },
+            FlowParticipant {
+                email: "c2@example.com".to_string(),
+                role: "client2".to_string(),
+            },
+        ];
+        let flow_spec = json!({
+            "spec": {
+                "datasites": {
+                    "all": [
+                        "aggregator@sandbox.local",
+                        "client1@sandbox.local",
+                        "client2@sandbox.local",
+                        "client3@sandbox.local"
+                    ],
+                    "groups": {
+                        "contributors": { "include": ["client1@sandbox.local", "client2@sandbox.local"] },
+                        "reviewers": { "include": ["reviewer"] }
+                    }
+                }
+            }
+        });
+
+        let preview = preview_group_resolution_for(&participants, &flow_spec);
+
+        assert_eq!(
+            preview.groups.get("contributors"),
+            Some(&vec![
+                "c1@example.com".to_string(),
+                "c2@example.com".to_string()
+            ])
+        );
+        assert_eq!(
+            preview.default_to_actual.get("aggregator@sandbox.local"),
+            Some(&"agg@example.com".to_string())
+        );
+        assert_eq!(preview.unmapped_defaults, vec!["client3@sandbox.local"]);
+        assert_eq!(preview.empty_groups, vec!["reviewers"]);
+        assert!(preview.ungrouped_participants.is_empty());
+    }
+
+    #[test]
+    fn idle_participants_ignore_barrier_only_roles() {
+        let participants = vec![
+            FlowParticipant {
+                email: "agg@example.com".to_string(),
+                role: "aggregator".to_string(),
+            },
+            FlowParticipant {
+                email: "c1@example.com".to_string(),
+                role: "client1".to_string(),
+            },
+            FlowParticipant {
+                email: "obs@example.com".to_string(),
+                role: "observer".to_string(),
+            },
+        ];
+        let flow_spec = json!({
+            "spec": {
+                "datasites": ["aggregator@sandbox.local", "client1@sandbox.local", "observer@sandbox.local"],
+                "steps": [
+                    { "id": "contribute", "runs_on": ["client1@sandbox.local"] },
+                    { "id": "wait", "barrier": { "wait_for": "contribute" }, "runs_on": ["observer@sandbox.local"] },
+                    { "id": "aggregate", "runs_on": ["aggregator@sandbox.local"] }
+                ]
+            }
+        });
+
+        let idle = find_idle_participants(&flow_spec, &participants).expect("parse steps");
+        assert_eq!(
+            idle,
+            vec![IdleParticipant {
+                email: "obs@example.com".to_string(),
+                role: "observer".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn flow_spec_digest_ignores_key_order_and_local_paths() {
+        let a = json!({
+            "flow_path": "/home/a/flows/demo",
+            "spec": { "steps": [{ "id": "s1", "uses": "m" }], "inputs": {} }
+        });
+        let b = json!({
+            "spec": { "inputs": {}, "steps": [{ "uses": "m", "id": "s1" }] },
+            "flow_path": "/home/b/flows/demo"
+        });
+        let c = json!({ "spec": { "steps": [{ "id": "s2", "uses": "m" }] } });
+        assert_eq!(flow_spec_digest(&a), flow_spec_digest(&b));
+        assert_ne!(flow_spec_digest(&a), flow_spec_digest(&c));
+    }
+
+    #[test]
+    fn step_output_collisions_flag_duplicates_and_suffix_overlap() {
+        let flow_spec = json!({
+            "spec": {
+                "steps": [
+                    { "id": "train" },
+                    { "id": "train" },
+                    { "id": "b" },
+                    { "id": "a-b" },
+                    { "id": "report" }
+                ]
+            }
+        });
+
+        let collisions = find_step_output_collisions(&flow_spec);
+        let reasons: Vec<(&str, &str, &str)> = collisions
+            .iter()
+            .map(|c| (c.step_a.as_str(), c.step_b.as_str(), c.reason.as_str()))
+            .collect();
+
+        assert_eq!(
+            reasons,
+            vec![
+                ("train", "train", "duplicate_id"),
+                ("b", "a-b", "suffix_overlap"),
+            ]
+        );
+    }
+
+    #[test]
+    fn step_timings_report_running_steps_and_bottleneck_order() {
+        let log = |participant: &str, event: &str, step: &str, ts: &str| LogEntry {
+            participant: participant.to_string(),
+            role: "clients".to_string(),
+            timestamp: ts.to_string(),
+            event: event.to_string(),
+            step_id: Some(step.to_string()),
+            message: None,
+        };
+        let logs = vec![
+            log("a@x.org", "step_started", "train", "2026-01-01T00:00:00Z"),
+            log("a@x.org", "step_completed", "train", "2026-01-01T00:05:00Z"),
+            log("b@x.org", "step_started", "train", "2026-01-01T00:01:00Z"),
+            log("b@x.org", "step_failed", "train", "2026-01-01T00:10:00Z"),
+            log(
+                "a@x.org",
+                "step_started",
+                "aggregate",
+                "2026-01-01T00:11:00Z",
+            ),
+        ];
+        let order = vec![
+            ("train".to_string(), "Train".to_string()),
+            ("aggregate".to_string(), "Aggregate".to_string()),
+        ];
+        let now = chrono::DateTime::parse_from_rfc3339("2026-01-01T00:12:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let steps = compute_step_timings(&logs, &order, now);
+        assert_eq!(steps.len(), 2);
+        assert_eq!(steps[0].step_id, "train");
+        assert_eq!(steps[0].wall_clock_seconds, 600);
+        assert_eq!(steps[0].participants[1].status, "failed");
+        assert!(!steps[0].running);
+        assert_eq!(steps[1].step_id, "aggregate");
+        assert!(steps[1].running);
+        assert_eq!(steps[1].participants[0].elapsed_seconds, 60);
+    }
+
+    #[test]
+    fn template_diff_flags_retargeted_steps_and_rebound_inputs() {
+        let template = json!({
+            "steps": [{
+                "id": "train",
+                "uses": "trainer",
+                "run": { "targets": "clients" },
+                "with": { "data": "inputs.samplesheet", "epochs": 5 }
+            }]
+        });
+        let invitation = json!({
+            "steps": [{
+                "id": "train",
+                "uses": "trainer",
+                "run": { "targets": "all" },
+                "with": { "data": "step.leak.outputs.path", "epochs": 5 }
+            }]
         });
 
-        let err = parse_flow_steps(&flow_spec, "me@madhavajay.com", &participants)
-            .unwrap_err()
-            .to_string();
-        assert!(err.contains("Invalid multiparty participant mapping for step 'secure_aggregate'"));
-        assert!(err.contains("placeholder targets collapsed to duplicate participants"));
-        assert!(err.contains("default_to_actual"));
+        let same = diff_invitation_against_template(
+            "s".to_string(),
+            "t".to_string(),
+            &template,
+            &template,
+        );
+        assert!(same.matches);
+
+        let diff = diff_invitation_against_template(
+            "s".to_string(),
+            "t".to_string(),
+            &invitation,
+            &template,
+        );
+        assert!(diff.security_relevant);
+        assert!(!diff.matches);
+        assert_eq!(diff.changed_targets.len(), 1);
+        assert_eq!(diff.changed_bindings.len(), 1);
+        assert_eq!(diff.changed_bindings[0].field, "with.data");
+
+        assert!(validate_template_name("gwas").is_ok());
+        assert!(validate_template_name("../secrets").is_err());
+        assert!(validate_template_name("a/b").is_err());
+        assert!(validate_template_name("a\\b").is_err());
+        assert!(validate_template_name("").is_err());
     }
 }
 
@@ -6524,6 +10053,39 @@ fn extract_share_to(step: &serde_json::Value) -> Vec<String> {
     share_to
 }
 
+/// Resolve a step's `share_to` targets to emails using the session's groups
+/// and datasite order.
+fn step_share_recipients(flow_state: &MultipartyFlowState, share_to: &[String]) -> Vec<String> {
+    let (groups, default_to_actual) = flow_state
+        .flow_spec
+        .as_ref()
+        .map(|spec| build_group_map_from_participants(&flow_state.participants, spec))
+        .unwrap_or_default();
+    let datasites_order: Vec<String> = flow_state
+        .flow_spec
+        .as_ref()
+        .and_then(|spec| spec.get("inputs"))
+        .and_then(|i| i.get("datasites"))
+        .and_then(|d| d.get("default"))
+        .and_then(|arr| arr.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                .collect::<Vec<String>>()
+        })
+        .unwrap_or_default()
+        .into_iter()
+        .map(|email| default_to_actual.get(&email).cloned().unwrap_or(email))
+        .collect::<Vec<String>>();
+    resolve_share_recipients(
+        share_to,
+        &flow_state.participants,
+        &flow_state.my_email,
+        &datasites_order,
+        &groups,
+    )
+}
+
 fn resolve_share_recipients(
     raw_targets: &[String],
     participants: &[FlowParticipant],
@@ -7066,6 +10628,8 @@ targets=[{}], unique_resolved={} of {}. {}",
             with_bindings,
             input_waiting_on: Vec::new(),
             input_waiting_reason: None,
+            share_message_pending: false,
+            module_override: None,
         });
     }
 
@@ -7118,6 +10682,7 @@ fn publish_step_outputs_message(
                     "file_name": file_name,
                     "content_base64": base64_content,
                     "size_bytes": content.len(),
+                    "sha256": crate::commands::messages::attachment_sha256(&content),
                     "is_text": is_text,
                 }));
             }
@@ -7143,17 +10708,14 @@ fn publish_step_outputs_message(
     group_participants.dedup();
 
     // Create message body
-    let body = format!(
-        "📊 Results from step '{}' are ready!\n\n{} file(s) attached. Click to download.",
-        step_name,
-        results_data.len()
-    );
+    let body = super::settings::render_results_message(step_name, results_data.len(), flow_name);
     let results_manifest: Vec<serde_json::Value> = results_data
         .iter()
         .map(|entry| {
             serde_json::json!({
                 "file_name": entry.get("file_name").cloned().unwrap_or(serde_json::Value::Null),
                 "size_bytes": entry.get("size_bytes").cloned().unwrap_or(serde_json::Value::Null),
+                "sha256": entry.get("sha256").cloned().unwrap_or(serde_json::Value::Null),
                 "is_text": entry.get("is_text").cloned().unwrap_or(serde_json::Value::Bool(false)),
             })
         })
@@ -7280,3 +10842,595 @@ pub async fn share_step_outputs_to_chat(
         !should_share_first,
     )
 }
+
+// ============================================================================
+// Invitation verification
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InvitationStepChange {
+    pub step_id: String,
+    pub field: String,
+    pub template_value: Option<String>,
+    pub invitation_value: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InvitationTemplateDiff {
+    pub session_id: String,
+    pub template_name: String,
+    pub matches: bool,
+    /// Any difference that could redirect data: new share recipients, extra
+    /// steps, swapped modules, retargeted steps or rebound inputs.
+    pub security_relevant: bool,
+    pub added_steps: Vec<String>,
+    pub removed_steps: Vec<String>,
+    pub changed_modules: Vec<InvitationStepChange>,
+    pub changed_targets: Vec<InvitationStepChange>,
+    /// `with` bindings that differ, one entry per binding (`field` is `with.<name>`)
+    pub changed_bindings: Vec<InvitationStepChange>,
+    pub added_share_recipients: Vec<InvitationStepChange>,
+    pub removed_share_recipients: Vec<InvitationStepChange>,
+}
+
+/// Find the flow spec attached to an invitation, preferring an accepted
+/// session's snapshot and falling back to the invitation message metadata.
+fn load_invitation_flow_spec(session_id: &str) -> Result<(String, serde_json::Value), String> {
+    {
+        let sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
+        if let Some(flow_state) = sessions.get(session_id) {
+            if let Some(spec) = flow_state.flow_spec.clone() {
+                return Ok((flow_state.flow_name.clone(), spec));
+            }
+        }
+    }
+    if let Some(restored) = load_multiparty_state_from_disk(session_id)? {
+        if let Some(spec) = restored.flow_spec {
+            return Ok((restored.flow_name, spec));
+        }
+    }
+
+    if let Some(invitation) = find_invitation_metadata(session_id)? {
+        let flow_name = invitation
+            .get("flow_name")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string();
+        if let Some(spec) = invitation.get("flow_spec").filter(|s| !s.is_null()) {
+            return Ok((flow_name, spec.clone()));
+        }
+    }
+
+    Err(format!(
+        "No flow invitation found for session '{}'",
+        session_id
+    ))
+}
+
+/// The `flow_invitation` metadata of the message that invited us to `session_id`.
+fn find_invitation_metadata(session_id: &str) -> Result<Option<serde_json::Value>, String> {
+    let config =
+        biovault::config::Config::load().map_err(|e| format!("Failed to load config: {}", e))?;
+    let db_path = biovault::cli::commands::messages::get_message_db_path(&config)
+        .map_err(|e| format!("Failed to locate message database: {}", e))?;
+    let db = biovault::messages::MessageDb::new(&db_path)
+        .map_err(|e| format!("Failed to open message database: {}", e))?;
+    let messages = db
+        .list_messages(None)
+        .map_err(|e| format!("Failed to list messages: {}", e))?;
+
+    Ok(messages.into_iter().find_map(|message| {
+        let invitation = message.metadata.as_ref()?.get("flow_invitation")?;
+        (invitation.get("session_id").and_then(|v| v.as_str()) == Some(session_id))
+            .then(|| invitation.clone())
+    }))
+}
+
+/// Template names are a single directory under `flows/`; anything that could
+/// step outside it is rejected.
+fn validate_template_name(template_name: &str) -> Result<(), String> {
+    let name = template_name.trim();
+    if name.is_empty()
+        || name.contains('/')
+        || name.contains('\\')
+        || name.contains("..")
+        || Path::new(name).is_absolute()
+    {
+        return Err(format!("Invalid template name '{}'", template_name));
+    }
+    Ok(())
+}
+
+fn load_trusted_flow_template(template_name: &str) -> Result<serde_json::Value, String> {
+    validate_template_name(template_name)?;
+    let biovault_home = biovault::config::get_biovault_home()
+        .map_err(|e| format!("Failed to get BioVault home: {}", e))?;
+    let yaml_path = biovault_home
+        .join("flows")
+        .join(template_name)
+        .join(biovault::flow_spec::FLOW_YAML_FILE);
+    let content = fs::read_to_string(&yaml_path).map_err(|e| {
+        format!(
+            "Failed to read trusted template {}: {}",
+            yaml_path.display(),
+            e
+        )
+    })?;
+    let yaml: serde_yaml::Value = serde_yaml::from_str(&content)
+        .map_err(|e| format!("Failed to parse trusted template: {}", e))?;
+    serde_json::to_value(yaml).map_err(|e| format!("Failed to convert trusted template: {}", e))
+}
+
+fn step_module_identity(spec_root: &serde_json::Value, step: &serde_json::Value) -> Option<String> {
+    let module_ref = step.get("uses").and_then(|v| v.as_str())?;
+    let source = spec_root
+        .get("modules")
+        .and_then(|m| m.get(module_ref))
+        .and_then(|m| m.get("source"));
+    let location = source
+        .and_then(|s| s.get("url").or_else(|| s.get("path")))
+        .and_then(|v| v.as_str());
+    Some(match location {
+        Some(loc) => format!("{} ({})", module_ref, loc),
+        None => module_ref.to_string(),
+    })
+}
+
+fn steps_by_id(spec_root: &serde_json::Value) -> Vec<(String, serde_json::Value)> {
+    spec_root
+        .get("steps")
+        .and_then(|s| s.as_array())
+        .map(|steps| {
+            steps
+                .iter()
+                .filter_map(|step| {
+                    step.get("id")
+                        .and_then(|v| v.as_str())
+                        .map(|id| (id.to_string(), step.clone()))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// A step's `with` bindings as name -> compact JSON, for comparison.
+fn step_bindings(step: &serde_json::Value) -> BTreeMap<String, String> {
+    step.get("with")
+        .and_then(|w| w.as_object())
+        .map(|with| {
+            with.iter()
+                .map(|(name, value)| {
+                    let value = match value {
+                        serde_json::Value::String(s) => s.clone(),
+                        other => other.to_string(),
+                    };
+                    (name.clone(), value)
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Compare a received invitation's flow spec with a locally trusted flow template.
+#[tauri::command]
+pub async fn verify_invitation_against_template(
+    session_id: String,
+    template_name: String,
+) -> Result<InvitationTemplateDiff, String> {
+    let (_, invitation_spec) = load_invitation_flow_spec(&session_id)?;
+    let template_spec = load_trusted_flow_template(&template_name)?;
+    let diff = diff_invitation_against_template(
+        session_id,
+        template_name,
+        &invitation_spec,
+        &template_spec,
+    );
+
+    if diff.security_relevant {
+        crate::desktop_log!(
+            "⚠️ Invitation {} deviates from trusted template '{}': {} new steps, {} module changes, {} target changes, {} binding changes, {} new share recipients",
+            diff.session_id,
+            diff.template_name,
+            diff.added_steps.len(),
+            diff.changed_modules.len(),
+            diff.changed_targets.len(),
+            diff.changed_bindings.len(),
+            diff.added_share_recipients.len()
+        );
+    }
+
+    Ok(diff)
+}
+
+fn diff_invitation_against_template(
+    session_id: String,
+    template_name: String,
+    invitation_spec: &serde_json::Value,
+    template_spec: &serde_json::Value,
+) -> InvitationTemplateDiff {
+    let invitation_root = flow_spec_root(invitation_spec);
+    let template_root = flow_spec_root(template_spec);
+    let invitation_steps = steps_by_id(invitation_root);
+    let template_steps: HashMap<String, serde_json::Value> =
+        steps_by_id(template_root).into_iter().collect();
+    let invitation_ids: HashSet<&String> = invitation_steps.iter().map(|(id, _)| id).collect();
+
+    let mut diff = InvitationTemplateDiff {
+        session_id,
+        template_name,
+        matches: true,
+        security_relevant: false,
+        added_steps: Vec::new(),
+        removed_steps: Vec::new(),
+        changed_modules: Vec::new(),
+        changed_targets: Vec::new(),
+        changed_bindings: Vec::new(),
+        added_share_recipients: Vec::new(),
+        removed_share_recipients: Vec::new(),
+    };
+
+    for (step_id, step) in &invitation_steps {
+        let Some(template_step) = template_steps.get(step_id) else {
+            diff.added_steps.push(step_id.clone());
+            continue;
+        };
+
+        let invitation_module = step_module_identity(invitation_root, step);
+        let template_module = step_module_identity(template_root, template_step);
+        if invitation_module != template_module {
+            diff.changed_modules.push(InvitationStepChange {
+                step_id: step_id.clone(),
+                field: "module".to_string(),
+                template_value: template_module,
+                invitation_value: invitation_module,
+            });
+        }
+
+        let invitation_targets = get_step_targets(step);
+        let template_targets = get_step_targets(template_step);
+        if invitation_targets != template_targets {
+            diff.changed_targets.push(InvitationStepChange {
+                step_id: step_id.clone(),
+                field: "targets".to_string(),
+                template_value: Some(template_targets.join(", ")),
+                invitation_value: Some(invitation_targets.join(", ")),
+            });
+        }
+
+        let invitation_bindings = step_bindings(step);
+        let template_bindings = step_bindings(template_step);
+        let binding_names: BTreeSet<&String> = invitation_bindings
+            .keys()
+            .chain(template_bindings.keys())
+            .collect();
+        for name in binding_names {
+            let template_value = template_bindings.get(name);
+            let invitation_value = invitation_bindings.get(name);
+            if template_value != invitation_value {
+                diff.changed_bindings.push(InvitationStepChange {
+                    step_id: step_id.clone(),
+                    field: format!("with.{}", name),
+                    template_value: template_value.cloned(),
+                    invitation_value: invitation_value.cloned(),
+                });
+            }
+        }
+
+        let invitation_share = extract_share_to(step);
+        let template_share = extract_share_to(template_step);
+        for recipient in invitation_share
+            .iter()
+            .filter(|r| !template_share.contains(r))
+        {
+            diff.added_share_recipients.push(InvitationStepChange {
+                step_id: step_id.clone(),
+                field: "share".to_string(),
+                template_value: None,
+                invitation_value: Some(recipient.clone()),
+            });
+        }
+        for recipient in template_share
+            .iter()
+            .filter(|r| !invitation_share.contains(r))
+        {
+            diff.removed_share_recipients.push(InvitationStepChange {
+                step_id: step_id.clone(),
+                field: "share".to_string(),
+                template_value: Some(recipient.clone()),
+                invitation_value: None,
+            });
+        }
+    }
+
+    let mut removed: Vec<String> = template_steps
+        .keys()
+        .filter(|id| !invitation_ids.contains(id))
+        .cloned()
+        .collect();
+    removed.sort();
+    diff.removed_steps = removed;
+
+    diff.security_relevant = !diff.added_steps.is_empty()
+        || !diff.changed_modules.is_empty()
+        || !diff.changed_targets.is_empty()
+        || !diff.changed_bindings.is_empty()
+        || !diff.added_share_recipients.is_empty();
+    diff.matches = !diff.security_relevant
+        && diff.removed_steps.is_empty()
+        && diff.removed_share_recipients.is_empty();
+    diff
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InvitationModule {
+    pub name: String,
+    /// "local", "remote" or "unknown"
+    pub source_kind: String,
+    pub location: Option<String>,
+    pub remote: bool,
+    pub used_by_steps: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InvitationShare {
+    pub step_id: String,
+    pub share_name: String,
+    pub source_output: Option<String>,
+    pub path: Option<String>,
+    pub readers: Vec<String>,
+    /// `readers` resolved to participant emails
+    pub recipients: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InvitationStepExecution {
+    pub step_id: String,
+    pub module: Option<String>,
+    pub targets: Vec<String>,
+    pub target_emails: Vec<String>,
+    /// Runs code on this machine if the invitation is accepted
+    pub runs_locally: bool,
+    pub remote_module: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InvitationInspection {
+    pub session_id: String,
+    pub flow_name: String,
+    pub proposed_by: Option<String>,
+    pub my_email: String,
+    pub participants: Vec<FlowParticipant>,
+    pub modules: Vec<InvitationModule>,
+    pub shares: Vec<InvitationShare>,
+    pub steps: Vec<InvitationStepExecution>,
+    pub has_remote_modules: bool,
+    pub warnings: Vec<String>,
+}
+
+fn classify_module_source(module_def: Option<&serde_json::Value>) -> (String, Option<String>) {
+    let Some(source) = module_def.and_then(|m| m.get("source")) else {
+        return ("unknown".to_string(), None);
+    };
+    let location = source
+        .get("url")
+        .or_else(|| source.get("path"))
+        .and_then(|v| v.as_str())
+        .map(str::to_string);
+    let kind = source
+        .get("kind")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let looks_remote = source.get("url").is_some()
+        || location.as_deref().is_some_and(|loc| {
+            loc.contains("://") || loc.starts_with("git@") || loc.starts_with("syft://")
+        });
+    let source_kind = if kind == "local" && !looks_remote {
+        "local"
+    } else if looks_remote || !kind.is_empty() {
+        "remote"
+    } else {
+        "local"
+    };
+    (source_kind.to_string(), location)
+}
+
+/// Describe what accepting an invitation would do, without accepting it: the
+/// modules that would run and where they come from, who receives each shared
+/// output, and which steps execute on this machine. Remote module sources
+/// are flagged in `warnings`.
+#[tauri::command]
+pub async fn inspect_invitation(session_id: String) -> Result<InvitationInspection, String> {
+    let (mut flow_name, flow_spec) = load_invitation_flow_spec(&session_id)?;
+    let invitation = find_invitation_metadata(&session_id).ok().flatten();
+    let proposed_by = invitation
+        .as_ref()
+        .and_then(|i| i.get("proposed_by"))
+        .and_then(|v| v.as_str())
+        .map(str::to_string);
+    let session_participants = {
+        let sessions = FLOW_SESSIONS.lock().map_err(|e| e.to_string())?;
+        sessions.get(&session_id).map(|s| s.participants.clone())
+    };
+    let participants: Vec<FlowParticipant> = match session_participants {
+        Some(participants) => participants,
+        None => invitation
+            .as_ref()
+            .and_then(|i| i.get("participants"))
+            .cloned()
+            .and_then(|p| serde_json::from_value(p).ok())
+            .unwrap_or_default(),
+    };
+    if flow_name.is_empty() {
+        if let Some(name) = invitation
+            .as_ref()
+            .and_then(|i| i.get("flow_name"))
+            .and_then(|v| v.as_str())
+        {
+            flow_name = name.to_string();
+        }
+    }
+    let my_email = get_owner_email()?;
+
+    let spec_root = flow_spec_root(&flow_spec);
+    let raw_steps = steps_by_id(spec_root);
+    let mut warnings = Vec::new();
+
+    let parsed_steps: HashMap<String, StepState> =
+        match parse_flow_steps(&flow_spec, &my_email, &participants) {
+            Ok(steps) => steps.into_iter().map(|s| (s.id.clone(), s)).collect(),
+            Err(err) => {
+                warnings.push(format!("Could not resolve step targets: {}", err));
+                HashMap::new()
+            }
+        };
+
+    let mut modules: BTreeMap<String, InvitationModule> = BTreeMap::new();
+    let mut steps = Vec::new();
+    for (step_id, step) in &raw_steps {
+        let module_name = step
+            .get("uses")
+            .and_then(|v| v.as_str())
+            .map(str::to_string);
+        let mut remote_module = false;
+        if let Some(name) = &module_name {
+            let entry = modules.entry(name.clone()).or_insert_with(|| {
+                let (source_kind, location) = classify_module_source(
+                    spec_root.get("modules").and_then(|m| m.get(name.as_str())),
+                );
+                InvitationModule {
+                    name: name.clone(),
+                    remote: source_kind == "remote",
+                    source_kind,
+                    location,
+                    used_by_steps: Vec::new(),
+                }
+            });
+            entry.used_by_steps.push(step_id.clone());
+            remote_module = entry.remote;
+        }
+
+        let targets = get_step_targets(step);
+        let (target_emails, runs_locally) = match parsed_steps.get(step_id) {
+            Some(parsed) => (parsed.target_emails.clone(), parsed.my_action),
+            None => (Vec::new(), targets.iter().any(|t| t == &my_email)),
+        };
+        if runs_locally && remote_module {
+            warnings.push(format!(
+                "Step '{}' runs remote module '{}' on this machine",
+                step_id,
+                module_name.as_deref().unwrap_or_default()
+            ));
+        }
+        steps.push(InvitationStepExecution {
+            step_id: step_id.clone(),
+            module: module_name,
+            targets,
+            target_emails,
+            runs_locally,
+            remote_module,
+        });
+    }
+
+    let (groups, default_to_actual) = build_group_map_from_participants(&participants, &flow_spec);
+    let datasites_order: Vec<String> = spec_root
+        .get("inputs")
+        .and_then(|i| i.get("datasites"))
+        .and_then(|d| d.get("default"))
+        .and_then(|arr| arr.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str())
+                .map(|email| {
+                    default_to_actual
+                        .get(email)
+                        .cloned()
+                        .unwrap_or_else(|| email.to_string())
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let known_emails: HashSet<&str> = participants.iter().map(|p| p.email.as_str()).collect();
+    let mut shares = Vec::new();
+    for (step_id, step) in &raw_steps {
+        let Some(share_block) = step.get("share").and_then(|s| s.as_object()) else {
+            continue;
+        };
+        for (share_name, share_def) in share_block {
+            let readers = extract_share_to(&serde_json::json!({
+                "share": { share_name.as_str(): share_def }
+            }));
+            let mut recipients = resolve_share_recipients(
+                &readers,
+                &participants,
+                &my_email,
+                &datasites_order,
+                &groups,
+            );
+            recipients.sort();
+            for outsider in recipients
+                .iter()
+                .filter(|r| !known_emails.contains(r.as_str()))
+            {
+                warnings.push(format!(
+                    "Step '{}' shares '{}' with {}, who is not a participant",
+                    step_id, share_name, outsider
+                ));
+            }
+            shares.push(InvitationShare {
+                step_id: step_id.clone(),
+                share_name: share_name.clone(),
+                source_output: share_def
+                    .get("source")
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string),
+                path: share_def
+                    .get("path")
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string),
+                readers,
+                recipients,
+            });
+        }
+    }
+
+    let modules: Vec<InvitationModule> = modules.into_values().collect();
+    let has_remote_modules = modules.iter().any(|m| m.remote);
+    // Remote module sources lead the warnings so they cannot be missed.
+    let mut remote_warnings: Vec<String> = modules
+        .iter()
+        .filter(|m| m.remote)
+        .map(|m| {
+            format!(
+                "Module '{}' is fetched from a remote source: {}",
+                m.name,
+                m.location.as_deref().unwrap_or("unspecified")
+            )
+        })
+        .collect();
+    remote_warnings.append(&mut warnings);
+    let mut warnings = remote_warnings;
+    if !participants.iter().any(|p| p.email == my_email) {
+        warnings.push("You are not listed as a participant in this invitation".to_string());
+    }
+
+    crate::desktop_log!(
+        "🔎 Inspected invitation {}: {} module(s), {} local step(s), {} warning(s)",
+        session_id,
+        modules.len(),
+        steps.iter().filter(|s| s.runs_locally).count(),
+        warnings.len()
+    );
+    Ok(InvitationInspection {
+        session_id,
+        flow_name,
+        proposed_by,
+        my_email,
+        participants,
+        modules,
+        shares,
+        steps,
+        has_remote_modules,
+        warnings,
+    })
+}
@@ -1,6 +1,233 @@
+use crate::types::AppState;
+use biovault::data::BioVaultDb;
+use rusqlite::params;
+use serde::Serialize;
+use std::collections::HashMap;
 use tauri::AppHandle;
 use tauri_plugin_notification::NotificationExt;
 
+/// Events that can raise a desktop notification, with their display labels.
+const NOTIFICATION_EVENTS: &[(&str, &str)] = &[
+    ("new_message", "New message"),
+    ("run_complete", "Run completed"),
+    ("run_failed", "Run failed"),
+    ("flow_invitation", "Flow invitation"),
+    ("step_shared", "Step outputs shared"),
+];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NotificationPreference {
+    pub event: String,
+    pub label: String,
+    pub enabled: bool,
+}
+
+/// Whether notifications for `event` are on. Unknown or unset events default to on.
+pub(crate) fn notification_event_enabled(event: &str) -> bool {
+    crate::commands::settings::get_settings()
+        .ok()
+        .and_then(|s| s.notification_preferences.get(event).copied())
+        .unwrap_or(true)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RunNotificationPreference {
+    pub run_id: i64,
+    pub enabled: bool,
+    /// False when the run follows the global default
+    pub overridden: bool,
+}
+
+fn ensure_run_notification_table(conn: &rusqlite::Connection) -> Result<(), String> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS run_notification_overrides (
+                run_id INTEGER PRIMARY KEY,
+                enabled INTEGER NOT NULL,
+                updated_at TEXT NOT NULL
+            )",
+        [],
+    )
+    .map_err(|e| format!("Failed to create run notification table: {}", e))?;
+    Ok(())
+}
+
+fn run_notification_preference(db: &BioVaultDb, run_id: i64) -> RunNotificationPreference {
+    let override_enabled: Option<bool> = ensure_run_notification_table(db.connection())
+        .ok()
+        .and_then(|_| {
+            db.connection()
+                .query_row(
+                    "SELECT enabled FROM run_notification_overrides WHERE run_id = ?1",
+                    params![run_id],
+                    |row| row.get::<_, i64>(0),
+                )
+                .ok()
+        })
+        .map(|v| v != 0);
+    let enabled = override_enabled.unwrap_or_else(run_notifications_default);
+    RunNotificationPreference {
+        run_id,
+        enabled,
+        overridden: override_enabled.is_some(),
+    }
+}
+
+fn run_notifications_default() -> bool {
+    crate::commands::settings::get_settings()
+        .map(|s| s.run_notifications_enabled)
+        .unwrap_or(false)
+}
+
+/// Effective notification preference for each of `run_ids`, in one query.
+pub(crate) fn run_notification_flags(
+    conn: &rusqlite::Connection,
+    run_ids: &[i64],
+) -> Result<HashMap<i64, bool>, String> {
+    ensure_run_notification_table(conn)?;
+    let mut stmt = conn
+        .prepare("SELECT run_id, enabled FROM run_notification_overrides")
+        .map_err(|e| format!("Failed to prepare run notification query: {}", e))?;
+    let overrides = stmt
+        .query_map([], |row| {
+            Ok((row.get::<_, i64>(0)?, row.get::<_, i64>(1)? != 0))
+        })
+        .map_err(|e| format!("Failed to query run notifications: {}", e))?
+        .collect::<Result<HashMap<_, _>, _>>()
+        .map_err(|e| format!("Failed to read run notifications: {}", e))?;
+    let default = run_notifications_default();
+    Ok(run_ids
+        .iter()
+        .map(|id| (*id, overrides.get(id).copied().unwrap_or(default)))
+        .collect())
+}
+
+/// Show a desktop notification for a finished run if its preference allows it.
+pub(crate) fn notify_run_finished(app: &AppHandle, db: &BioVaultDb, run_id: i64, status: &str) {
+    let event = if status == "success" {
+        "run_complete"
+    } else {
+        "run_failed"
+    };
+    if !notification_event_enabled(event) || !run_notification_preference(db, run_id).enabled {
+        return;
+    }
+    let flow_name = db
+        .get_flow_run(run_id)
+        .ok()
+        .flatten()
+        .and_then(|r| r.flow_id)
+        .and_then(|id| db.get_flow(id).ok().flatten())
+        .map(|flow| flow.name)
+        .unwrap_or_else(|| format!("Run {}", run_id));
+    let (title, body) = if status == "success" {
+        (
+            "Run completed",
+            format!("{} finished successfully", flow_name),
+        )
+    } else {
+        (
+            "Run failed",
+            format!("{} finished with status: {}", flow_name, status),
+        )
+    };
+    if let Err(e) = app.notification().builder().title(title).body(body).show() {
+        crate::desktop_log!("⚠️ Failed to show run notification: {}", e);
+    }
+}
+
+#[tauri::command]
+pub fn get_run_notification(
+    state: tauri::State<AppState>,
+    run_id: i64,
+) -> Result<RunNotificationPreference, String> {
+    let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+    Ok(run_notification_preference(&db, run_id))
+}
+
+/// Turn completion notifications on/off for one run; None reverts to the global default.
+#[tauri::command]
+pub fn set_run_notification(
+    state: tauri::State<AppState>,
+    run_id: i64,
+    enabled: Option<bool>,
+) -> Result<RunNotificationPreference, String> {
+    let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+    ensure_run_notification_table(db.connection())?;
+    match enabled {
+        Some(enabled) => {
+            db.connection()
+                .execute(
+                    "INSERT INTO run_notification_overrides (run_id, enabled, updated_at)
+                     VALUES (?1, ?2, ?3)
+                     ON CONFLICT(run_id) DO UPDATE SET enabled = excluded.enabled, updated_at = excluded.updated_at",
+                    params![run_id, enabled as i64, chrono::Utc::now().to_rfc3339()],
+                )
+                .map_err(|e| format!("Failed to save run notification preference: {}", e))?;
+        }
+        None => {
+            db.connection()
+                .execute(
+                    "DELETE FROM run_notification_overrides WHERE run_id = ?1",
+                    params![run_id],
+                )
+                .map_err(|e| format!("Failed to clear run notification preference: {}", e))?;
+        }
+    }
+    Ok(run_notification_preference(&db, run_id))
+}
+
+#[tauri::command]
+pub fn get_run_notifications_default() -> Result<bool, String> {
+    Ok(crate::commands::settings::get_settings()?.run_notifications_enabled)
+}
+
+#[tauri::command]
+pub fn set_run_notifications_default(enabled: bool) -> Result<bool, String> {
+    let mut settings = crate::commands::settings::get_settings()?;
+    settings.run_notifications_enabled = enabled;
+    crate::commands::settings::save_settings(settings)?;
+    Ok(enabled)
+}
+
+#[tauri::command]
+pub fn get_notification_preferences() -> Result<Vec<NotificationPreference>, String> {
+    let settings = crate::commands::settings::get_settings()?;
+    Ok(NOTIFICATION_EVENTS
+        .iter()
+        .map(|(event, label)| NotificationPreference {
+            event: event.to_string(),
+            label: label.to_string(),
+            enabled: settings
+                .notification_preferences
+                .get(*event)
+                .copied()
+                .unwrap_or(true),
+        })
+        .collect())
+}
+
+#[tauri::command]
+pub fn set_notification_preference(
+    event: String,
+    enabled: bool,
+) -> Result<Vec<NotificationPreference>, String> {
+    let event = event.trim().to_string();
+    if !NOTIFICATION_EVENTS.iter().any(|(name, _)| *name == event) {
+        return Err(format!("Unknown notification event: {}", event));
+    }
+    let mut settings = crate::commands::settings::get_settings()?;
+    settings
+        .notification_preferences
+        .insert(event.clone(), enabled);
+    crate::commands::settings::save_settings(settings)?;
+    crate::desktop_log!(
+        "🔔 {} notifications {}",
+        event,
+        if enabled { "enabled" } else { "disabled" }
+    );
+    get_notification_preferences()
+}
+
 #[tauri::command]
 pub fn test_notification(app: AppHandle) -> Result<(), String> {
     crate::desktop_log!("🔔 Test notification command called");
@@ -1,4 +1,5 @@
 use crate::types::{AppState, Participant};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 #[tauri::command]
 pub fn get_participants(state: tauri::State<AppState>) -> Result<Vec<Participant>, String> {
@@ -58,3 +59,303 @@ pub fn delete_participants_bulk(
     crate::desktop_log!("✅ Deleted {} participants", deleted);
     Ok(deleted)
 }
+
+/// One role a cohort flow expects (e.g. mother/father/child of a trio).
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CohortRoleRequirement {
+    pub role: String,
+    /// Explicit participant for this role; otherwise taken positionally from `participant_ids`
+    #[serde(default, alias = "participant_id")]
+    pub participant_id: Option<String>,
+    #[serde(default, alias = "data_type")]
+    pub data_type: Option<String>,
+    #[serde(default, alias = "grch_version")]
+    pub grch_version: Option<String>,
+    #[serde(default, alias = "expected_sex")]
+    pub expected_sex: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CohortRoleStatus {
+    pub role: String,
+    pub participant_id: Option<String>,
+    /// complete | missing_participant | missing_files | build_mismatch
+    pub status: String,
+    pub file_ids: Vec<i64>,
+    pub issues: Vec<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CohortCompletenessReport {
+    pub complete: bool,
+    pub roles: Vec<CohortRoleStatus>,
+}
+
+fn default_trio_roles() -> Vec<CohortRoleRequirement> {
+    [
+        ("mother", Some("Female")),
+        ("father", Some("Male")),
+        ("child", None),
+    ]
+    .into_iter()
+    .map(|(role, sex)| CohortRoleRequirement {
+        role: role.to_string(),
+        participant_id: None,
+        data_type: Some("Genotype".to_string()),
+        grch_version: None,
+        expected_sex: sex.map(|s| s.to_string()),
+    })
+    .collect()
+}
+
+#[tauri::command]
+pub fn check_cohort_completeness(
+    state: tauri::State<AppState>,
+    participant_ids: Vec<String>,
+    expected_roles: Option<Vec<CohortRoleRequirement>>,
+) -> Result<CohortCompletenessReport, String> {
+    let expected_roles = expected_roles
+        .filter(|roles| !roles.is_empty())
+        .unwrap_or_else(default_trio_roles);
+
+    let db = state.biovault_db.lock().unwrap();
+    let files = biovault::data::list_files(&db, None, None, false, None)
+        .map_err(|e| format!("Failed to list files: {}", e))?;
+
+    let roles: Vec<CohortRoleStatus> = expected_roles
+        .iter()
+        .enumerate()
+        .map(|(idx, requirement)| {
+            let participant_id = requirement
+                .participant_id
+                .clone()
+                .or_else(|| participant_ids.get(idx).cloned())
+                .filter(|id| !id.trim().is_empty());
+
+            let Some(participant_id) = participant_id else {
+                return CohortRoleStatus {
+                    role: requirement.role.clone(),
+                    participant_id: None,
+                    status: "missing_participant".to_string(),
+                    file_ids: Vec::new(),
+                    issues: vec![format!("No participant assigned to {}", requirement.role)],
+                };
+            };
+
+            let participant_files: Vec<_> = files
+                .iter()
+                .filter(|f| f.participant_id.as_deref() == Some(participant_id.as_str()))
+                .filter(|f| match requirement.data_type.as_deref() {
+                    Some(expected) => f
+                        .data_type
+                        .as_deref()
+                        .map(|dt| dt.eq_ignore_ascii_case(expected))
+                        .unwrap_or(false),
+                    None => true,
+                })
+                .collect();
+
+            let mut issues = Vec::new();
+            let data_label = requirement.data_type.as_deref().unwrap_or("data");
+            if participant_files.is_empty() {
+                issues.push(format!(
+                    "{} {} missing for {}",
+                    requirement.role, data_label, participant_id
+                ));
+                return CohortRoleStatus {
+                    role: requirement.role.clone(),
+                    participant_id: Some(participant_id),
+                    status: "missing_files".to_string(),
+                    file_ids: Vec::new(),
+                    issues,
+                };
+            }
+
+            let matching: Vec<_> = participant_files
+                .iter()
+                .filter(|f| match requirement.grch_version.as_deref() {
+                    Some(expected) => f
+                        .grch_version
+                        .as_deref()
+                        .map(|v| v.eq_ignore_ascii_case(expected))
+                        .unwrap_or(false),
+                    None => true,
+                })
+                .collect();
+
+            let status = if matching.is_empty() {
+                issues.push(format!(
+                    "{} {} for {} is not on build {}",
+                    requirement.role,
+                    data_label,
+                    participant_id,
+                    requirement.grch_version.as_deref().unwrap_or("?")
+                ));
+                "build_mismatch"
+            } else {
+                "complete"
+            };
+
+            if let Some(expected_sex) = requirement.expected_sex.as_deref() {
+                let conflicting = matching.iter().any(|f| {
+                    f.inferred_sex
+                        .as_deref()
+                        .map(|sex| !sex.eq_ignore_ascii_case(expected_sex))
+                        .unwrap_or(false)
+                });
+                if conflicting {
+                    issues.push(format!(
+                        "{} {} has inferred sex that differs from expected {}",
+                        requirement.role, participant_id, expected_sex
+                    ));
+                }
+            }
+
+            CohortRoleStatus {
+                role: requirement.role.clone(),
+                participant_id: Some(participant_id),
+                status: status.to_string(),
+                file_ids: matching.iter().map(|f| f.id).collect(),
+                issues,
+            }
+        })
+        .collect();
+
+    let complete = roles.iter().all(|r| r.status == "complete");
+    Ok(CohortCompletenessReport { complete, roles })
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ParticipantIdConflict {
+    pub participant_id: String,
+    /// existing | batch_merge | near_match
+    pub kind: String,
+    /// Files already stored under this participant ID
+    pub existing_file_count: i64,
+    pub proposed_paths: Vec<String>,
+    /// For near_match: the other IDs this one would easily be confused with
+    pub related_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ParticipantIdConflictReport {
+    pub has_conflicts: bool,
+    pub conflicting_ids: Vec<String>,
+    pub conflicts: Vec<ParticipantIdConflict>,
+}
+
+/// Case, punctuation and zero-padding insensitive form of a participant ID,
+/// so `P001`, `p-1` and `P1` all compare equal.
+fn normalized_participant_id(id: &str) -> String {
+    let mut out = String::new();
+    let mut digits = String::new();
+    let flush = |digits: &mut String, out: &mut String| {
+        if !digits.is_empty() {
+            let trimmed = digits.trim_start_matches('0');
+            out.push_str(if trimmed.is_empty() { "0" } else { trimmed });
+            digits.clear();
+        }
+    };
+    for ch in id.trim().chars() {
+        if ch.is_ascii_digit() {
+            digits.push(ch);
+        } else {
+            flush(&mut digits, &mut out);
+            if ch.is_alphanumeric() {
+                out.extend(ch.to_lowercase());
+            }
+        }
+    }
+    flush(&mut digits, &mut out);
+    out
+}
+
+/// Check proposed path → participant ID assignments before importing. Reports
+/// IDs that already hold files, IDs shared by several files in this batch, and
+/// IDs that only differ from another ID by case, punctuation or zero padding.
+#[tauri::command]
+pub fn check_participant_id_conflicts(
+    state: tauri::State<AppState>,
+    extractions: Vec<crate::types::SampleExtraction>,
+) -> Result<ParticipantIdConflictReport, String> {
+    let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+    let existing: HashMap<String, i64> = biovault::data::list_participants(&db)
+        .map_err(|e| format!("Failed to list participants: {}", e))?
+        .into_iter()
+        .map(|p| (p.participant_id, p.file_count))
+        .collect();
+    let existing_paths: HashMap<String, Option<String>> =
+        biovault::data::list_files(&db, None, None, false, None)
+            .map_err(|e| format!("Failed to list files: {}", e))?
+            .into_iter()
+            .map(|f| (f.file_path, f.participant_id))
+            .collect();
+    drop(db);
+
+    let mut proposed: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for extraction in extractions {
+        let id = extraction.participant_id.trim().to_string();
+        if id.is_empty() {
+            continue;
+        }
+        // Re-importing a file under the participant it already belongs to is a no-op.
+        if existing_paths.get(&extraction.path) == Some(&Some(id.clone())) {
+            continue;
+        }
+        proposed.entry(id).or_default().push(extraction.path);
+    }
+
+    let mut by_normalized: HashMap<String, BTreeSet<String>> = HashMap::new();
+    for id in proposed.keys().chain(existing.keys()) {
+        by_normalized
+            .entry(normalized_participant_id(id))
+            .or_default()
+            .insert(id.clone());
+    }
+
+    let mut conflicts = Vec::new();
+    for (id, paths) in &proposed {
+        let existing_file_count = existing.get(id).copied().unwrap_or(0);
+        let mut push = |kind: &str, related_ids: Vec<String>| {
+            conflicts.push(ParticipantIdConflict {
+                participant_id: id.clone(),
+                kind: kind.to_string(),
+                existing_file_count,
+                proposed_paths: paths.clone(),
+                related_ids,
+            });
+        };
+        if existing.contains_key(id) {
+            push("existing", Vec::new());
+        }
+        if paths.len() > 1 {
+            push("batch_merge", Vec::new());
+        }
+        let related: Vec<String> = by_normalized
+            .get(&normalized_participant_id(id))
+            .map(|ids| ids.iter().filter(|other| *other != id).cloned().collect())
+            .unwrap_or_default();
+        if !related.is_empty() {
+            push("near_match", related);
+        }
+    }
+
+    let conflicting_ids: Vec<String> = conflicts
+        .iter()
+        .map(|c| c.participant_id.clone())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    if !conflicting_ids.is_empty() {
+        crate::desktop_log!(
+            "⚠️ Import would touch {} conflicting participant ID(s)",
+            conflicting_ids.len()
+        );
+    }
+    Ok(ParticipantIdConflictReport {
+        has_conflicts: !conflicts.is_empty(),
+        conflicting_ids,
+        conflicts,
+    })
+}
@@ -84,6 +84,9 @@ struct ProfileStore {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct ProfileEntry {
     id: String,
+    /// Optional user-facing label, e.g. "work" or "research"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     email: Option<String>,
     biovault_home: String,
@@ -97,6 +100,7 @@ struct ProfileEntry {
 #[derive(Debug, Clone, Serialize)]
 pub struct ProfileSummary {
     pub id: String,
+    pub name: Option<String>,
     pub email: Option<String>,
     pub biovault_home: String,
     pub vault_path: String,
@@ -325,6 +329,7 @@ fn summarize_profile(entry: &ProfileEntry, current: Option<&str>) -> ProfileSumm
     };
     ProfileSummary {
         id: entry.id.clone(),
+        name: entry.name.clone(),
         email,
         biovault_home: entry.biovault_home.clone(),
         vault_path,
@@ -396,6 +401,7 @@ fn ensure_legacy_profile_migrated(mut store: ProfileStore) -> Result<ProfileStor
             let id = uuid::Uuid::new_v4().to_string();
             let entry = ProfileEntry {
                 id: id.clone(),
+                name: None,
                 email,
                 biovault_home: home_str,
                 created_at: now_rfc3339(),
@@ -424,6 +430,7 @@ fn ensure_legacy_profile_migrated(mut store: ProfileStore) -> Result<ProfileStor
     let id = uuid::Uuid::new_v4().to_string();
     let entry = ProfileEntry {
         id: id.clone(),
+        name: None,
         email,
         biovault_home: home_str,
         created_at: now_rfc3339(),
@@ -676,6 +683,7 @@ fn resolve_or_create_profile_for_home(
     let id = uuid::Uuid::new_v4().to_string();
     store.profiles.push(ProfileEntry {
         id: id.clone(),
+        name: None,
         email: None,
         biovault_home: home_str,
         created_at: now_rfc3339(),
@@ -1024,6 +1032,7 @@ pub fn profiles_switch_in_place(
     {
         let new_db = biovault::data::BioVaultDb::new()
             .map_err(|e| format!("Failed to initialize BioVault database: {}", e))?;
+        crate::commands::files::ensure_file_columns(&new_db);
         let mut shared_db = state
             .biovault_db
             .lock()
@@ -1133,6 +1142,7 @@ pub fn profiles_create_and_switch_in_place(
     {
         let new_db = biovault::data::BioVaultDb::new()
             .map_err(|e| format!("Failed to initialize BioVault database: {}", e))?;
+        crate::commands::files::ensure_file_columns(&new_db);
         let mut shared_db = state
             .biovault_db
             .lock()
@@ -1386,6 +1396,7 @@ pub fn profiles_create_and_switch(app: tauri::AppHandle) -> Result<(), String> {
 
     store.profiles.push(ProfileEntry {
         id: id.clone(),
+        name: None,
         email: None,
         biovault_home: home.to_string_lossy().to_string(),
         created_at: now_rfc3339(),
@@ -1462,6 +1473,7 @@ pub fn register_current_profile_email(email: &str) -> Result<(), String> {
         let id = uuid::Uuid::new_v4().to_string();
         store.profiles.push(ProfileEntry {
             id: id.clone(),
+            name: None,
             email: Some(email.trim().to_string()),
             biovault_home: home_str,
             created_at: now_rfc3339(),
@@ -1475,3 +1487,183 @@ pub fn register_current_profile_email(email: &str) -> Result<(), String> {
     save_store(&store)?;
     Ok(())
 }
+
+// ---------------------------------------------------------------------------
+// Named profiles
+// ---------------------------------------------------------------------------
+
+fn validate_profile_name(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Profile name is required".to_string());
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err("Profile names may only contain letters, numbers, '-' and '_'".to_string());
+    }
+    Ok(name.to_string())
+}
+
+fn find_profile_by_name(store: &ProfileStore, selector: &str) -> Option<ProfileEntry> {
+    store
+        .profiles
+        .iter()
+        .find(|p| {
+            p.name
+                .as_deref()
+                .is_some_and(|n| n.eq_ignore_ascii_case(selector.trim()))
+        })
+        .cloned()
+        .or_else(|| find_profile_by_id_or_email(store, selector.trim()))
+}
+
+fn current_profile_id(store: &ProfileStore) -> Option<String> {
+    env::var("BIOVAULT_PROFILE_ID")
+        .ok()
+        .map(|val| val.trim().to_string())
+        .filter(|val| store.profiles.iter().any(|p| p.id == *val))
+        .or_else(|| store.current_profile_id.clone())
+}
+
+#[tauri::command]
+pub fn list_profiles() -> Result<Vec<ProfileSummary>, String> {
+    if !profiles_enabled() {
+        return Err("Profiles are disabled".to_string());
+    }
+    let store = ensure_legacy_profile_migrated(load_store()?)?;
+    let current = current_profile_id(&store);
+    let mut profiles: Vec<ProfileSummary> = store
+        .profiles
+        .iter()
+        .map(|p| summarize_profile(p, current.as_deref()))
+        .collect();
+    profiles.sort_by(|a, b| b.last_used_at.cmp(&a.last_used_at));
+    Ok(profiles)
+}
+
+/// Create a named profile with its own home next to the default BioVault home
+/// (e.g. `~/Desktop/BioVault-research`). Does not switch to it.
+#[tauri::command]
+pub fn create_profile(name: String) -> Result<ProfileSummary, String> {
+    if !profiles_enabled() {
+        return Err("Profiles are disabled".to_string());
+    }
+    let name = validate_profile_name(&name)?;
+    let mut store = ensure_legacy_profile_migrated(load_store()?)?;
+    if store.profiles.iter().any(|p| {
+        p.name
+            .as_deref()
+            .is_some_and(|n| n.eq_ignore_ascii_case(&name))
+    }) {
+        return Err(format!("A profile named '{}' already exists", name));
+    }
+
+    let default_home = PathBuf::from(profiles_get_default_home()?);
+    let parent = default_home
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| PathBuf::from("."));
+    let home = parent.join(format!("BioVault-{}", name));
+    if !dir_is_empty(&home)? {
+        return Err(format!(
+            "{} already exists and is not empty",
+            home.to_string_lossy()
+        ));
+    }
+
+    let profile_id = resolve_or_create_profile_for_home(&mut store, &home)?;
+    if let Some(entry) = store.profiles.iter_mut().find(|p| p.id == profile_id) {
+        entry.name = Some(name.clone());
+        entry.last_used_at = None;
+    }
+    save_store(&store)?;
+
+    let entry = store
+        .profiles
+        .iter()
+        .find(|p| p.id == profile_id)
+        .ok_or_else(|| "Profile was not saved".to_string())?;
+    crate::desktop_log!("👤 Created profile '{}' at {}", name, home.display());
+    Ok(summarize_profile(
+        entry,
+        current_profile_id(&store).as_deref(),
+    ))
+}
+
+/// Switch to a profile by name (or id/email) without relaunching. Refuses while
+/// flow runs or multiparty sessions are active, since they hold the old DB/home.
+#[tauri::command]
+pub fn switch_profile(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, crate::AppState>,
+    name: String,
+) -> Result<ProfileSummary, String> {
+    if !profiles_enabled() {
+        return Err("Profiles are disabled".to_string());
+    }
+    let store = ensure_legacy_profile_migrated(load_store()?)?;
+    let entry =
+        find_profile_by_name(&store, &name).ok_or_else(|| format!("Unknown profile: {}", name))?;
+    if current_profile_id(&store).as_deref() == Some(entry.id.as_str()) {
+        return Ok(summarize_profile(&entry, Some(entry.id.as_str())));
+    }
+
+    let running_runs = {
+        let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+        db.list_flow_runs()
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .filter(|run| run.status == "running")
+            .count()
+    };
+    let running_sessions = crate::commands::multiparty::active_multiparty_session_count();
+    if running_runs > 0 || running_sessions > 0 {
+        return Err(format!(
+            "Cannot switch profiles while work is in progress ({} running flow run(s), {} active multiparty session(s))",
+            running_runs, running_sessions
+        ));
+    }
+
+    // The watcher is bound to the old profile's config; stop it before swapping homes.
+    if let Ok(mut slot) = state.message_watcher.lock() {
+        if let Some(mut handle) = slot.take() {
+            handle.stop();
+        }
+    }
+
+    let switched = profiles_switch_in_place(app.clone(), state.clone(), entry.id.clone());
+
+    // Restart on whichever profile is now active, so a failed switch keeps the
+    // old profile's watcher running.
+    match biovault::config::Config::load() {
+        Ok(cfg) => {
+            let emit_handle = app.clone();
+            match biovault::messages::watcher::start_message_rpc_watcher(cfg, move |ids| {
+                crate::emit_message_sync(&emit_handle, ids);
+            }) {
+                Ok(handle) => {
+                    if let Ok(mut slot) = state.message_watcher.lock() {
+                        *slot = Some(handle);
+                    }
+                }
+                Err(err) => crate::desktop_log!("⚠️ Failed to restart message watcher: {}", err),
+            }
+        }
+        Err(err) => crate::desktop_log!("⚠️ Failed to load config for watcher: {}", err),
+    }
+
+    switched?;
+    crate::commands::multiparty::clear_flow_sessions();
+    crate::commands::multiparty::resume_auto_run_watchers(app.clone());
+
+    let store = load_store()?;
+    let entry = store
+        .profiles
+        .iter()
+        .find(|p| p.id == entry.id)
+        .cloned()
+        .unwrap_or(entry);
+    Ok(summarize_profile(&entry, Some(entry.id.as_str())))
+}
@@ -0,0 +1,199 @@
+use crate::types::AppState;
+use biovault::messages::watcher::start_message_rpc_watcher;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use tauri::Manager;
+
+/// What quiet mode actually paused, so exiting only resumes those things.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct QuietModeStatus {
+    pub active: bool,
+    pub entered_at: Option<String>,
+    pub queue_paused: bool,
+    pub message_watcher_stopped: bool,
+    /// Runs quiet mode paused. Kept after exiting if they couldn't be resumed
+    /// (e.g. no main window), so the next exit retries them.
+    pub paused_flow_run_ids: Vec<i64>,
+}
+
+static QUIET_MODE: Lazy<Mutex<QuietModeStatus>> =
+    Lazy::new(|| Mutex::new(QuietModeStatus::default()));
+
+/// Set while entering or exiting, so overlapping calls can't both pause (or
+/// both resume) the same things.
+static QUIET_MODE_TRANSITION: AtomicBool = AtomicBool::new(false);
+
+struct TransitionGuard;
+
+impl TransitionGuard {
+    fn acquire() -> Result<Self, String> {
+        QUIET_MODE_TRANSITION
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .map(|_| TransitionGuard)
+            .map_err(|_| "Quiet mode is already changing".to_string())
+    }
+}
+
+impl Drop for TransitionGuard {
+    fn drop(&mut self) {
+        QUIET_MODE_TRANSITION.store(false, Ordering::SeqCst);
+    }
+}
+
+#[tauri::command]
+pub fn get_quiet_mode_status() -> Result<QuietModeStatus, String> {
+    QUIET_MODE
+        .lock()
+        .map(|status| status.clone())
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn enter_quiet_mode(
+    state: tauri::State<'_, AppState>,
+    pause_flows: Option<bool>,
+) -> Result<QuietModeStatus, String> {
+    let _transition = TransitionGuard::acquire()?;
+    let left_paused = {
+        let status = QUIET_MODE.lock().map_err(|e| e.to_string())?;
+        if status.active {
+            return Ok(status.clone());
+        }
+        status.paused_flow_run_ids.clone()
+    };
+
+    crate::desktop_log!("🤫 Entering quiet mode");
+    let mut status = QuietModeStatus {
+        active: true,
+        entered_at: Some(chrono::Utc::now().to_rfc3339()),
+        paused_flow_run_ids: left_paused,
+        ..Default::default()
+    };
+
+    if !state.queue_processor_paused.load(Ordering::SeqCst) {
+        crate::commands::files::pause_queue_processor(state.clone())?;
+        status.queue_paused = true;
+    }
+
+    if let Ok(mut slot) = state.message_watcher.lock() {
+        if let Some(mut handle) = slot.take() {
+            handle.stop();
+            status.message_watcher_stopped = true;
+        }
+    }
+
+    if pause_flows.unwrap_or(false) {
+        let running: Vec<i64> = {
+            let biovault_db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+            biovault_db
+                .list_flow_runs()
+                .map_err(|e| e.to_string())?
+                .into_iter()
+                .filter(|run| run.status == "running")
+                .map(|run| run.id)
+                .collect()
+        };
+        for run_id in running {
+            match crate::commands::flows::pause_flow_run(state.clone(), run_id).await {
+                Ok(()) if !status.paused_flow_run_ids.contains(&run_id) => {
+                    status.paused_flow_run_ids.push(run_id)
+                }
+                Ok(()) => {}
+                Err(err) => {
+                    crate::desktop_log!("⚠️ Quiet mode could not pause run {}: {}", run_id, err)
+                }
+            }
+        }
+    }
+
+    crate::desktop_log!(
+        "🤫 Quiet mode active (queue paused: {}, watcher stopped: {}, flows paused: {})",
+        status.queue_paused,
+        status.message_watcher_stopped,
+        status.paused_flow_run_ids.len()
+    );
+
+    let mut slot = QUIET_MODE.lock().map_err(|e| e.to_string())?;
+    *slot = status.clone();
+    Ok(status)
+}
+
+#[tauri::command]
+pub async fn exit_quiet_mode(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<QuietModeStatus, String> {
+    let _transition = TransitionGuard::acquire()?;
+    let previous = {
+        let slot = QUIET_MODE.lock().map_err(|e| e.to_string())?;
+        if !slot.active && slot.paused_flow_run_ids.is_empty() {
+            return Ok(slot.clone());
+        }
+        slot.clone()
+    };
+
+    crate::desktop_log!("🔔 Exiting quiet mode");
+
+    if previous.queue_paused {
+        state.queue_processor_paused.store(false, Ordering::SeqCst);
+    }
+
+    if previous.message_watcher_stopped {
+        match biovault::config::Config::load() {
+            Ok(cfg) => {
+                let emit_handle = app.clone();
+                match start_message_rpc_watcher(cfg, move |ids| {
+                    crate::emit_message_sync(&emit_handle, ids);
+                }) {
+                    Ok(handle) => {
+                        if let Ok(mut slot) = state.message_watcher.lock() {
+                            *slot = Some(handle);
+                        }
+                    }
+                    Err(err) => {
+                        crate::desktop_log!("⚠️ Failed to restart message watcher: {}", err)
+                    }
+                }
+            }
+            Err(err) => crate::desktop_log!("⚠️ Failed to load config for watcher: {}", err),
+        }
+    }
+
+    let mut left_paused = Vec::new();
+    if !previous.paused_flow_run_ids.is_empty() {
+        match app.get_webview_window("main") {
+            Some(window) => {
+                for run_id in &previous.paused_flow_run_ids {
+                    if let Err(err) = crate::commands::flows::resume_flow_run(
+                        state.clone(),
+                        window.clone(),
+                        *run_id,
+                        None,
+                        None,
+                    )
+                    .await
+                    {
+                        crate::desktop_log!("⚠️ Failed to resume run {}: {}", run_id, err);
+                        left_paused.push(*run_id);
+                    }
+                }
+            }
+            None => {
+                crate::desktop_log!(
+                    "⚠️ No main window; paused runs stay tracked for the next exit"
+                );
+                left_paused = previous.paused_flow_run_ids.clone();
+            }
+        }
+    }
+
+    let status = QuietModeStatus {
+        paused_flow_run_ids: left_paused,
+        ..Default::default()
+    };
+    let mut slot = QUIET_MODE.lock().map_err(|e| e.to_string())?;
+    *slot = status.clone();
+    Ok(status)
+}
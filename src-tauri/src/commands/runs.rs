@@ -1,8 +1,10 @@
-use crate::types::{AppState, FileRecord, Participant, Run, RunStartResult};
+use crate::types::{
+    AppState, FileRecord, FlowRunRecord, Participant, Run, RunLabelCount, RunStartResult,
+};
 use biovault::cli::commands::run::{execute as run_execute, RunParams};
 use biovault::config::Config;
 use rusqlite::params;
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, HashMap};
 use std::env;
 use std::fs::{self};
 use std::io::{BufRead, BufReader, Write};
@@ -80,8 +82,11 @@ pub fn start_analysis(
             row_count: f.row_count,
             chromosome_count: f.chromosome_count,
             inferred_sex: f.inferred_sex,
+            inferred_sex_confidence: None,
+            inferred_sex_low_confidence: None,
             status: f.status,
             processing_error: f.processing_error,
+            import_origin: None,
             created_at: f.created_at,
             updated_at: f.updated_at,
         })
@@ -318,27 +323,25 @@ pub async fn execute_analysis(
         env_lines.push("  Preferred binary paths: <config missing>".to_string());
     }
 
-    if let Some(augmented_path) = build_augmented_path(config.as_ref()) {
-        env::set_var("PATH", &augmented_path);
+    let overrides = child_env_overrides(&biovault_home, config.as_ref());
+
+    if let Some(augmented_path) = &overrides.path {
+        env::set_var("PATH", augmented_path);
         env_lines.push(format!("  PATH (augmented) = {}", augmented_path));
     } else {
         env_lines.push("  PATH (augmented) = <unchanged>".to_string());
     }
 
-    let mut java_home_set = false;
-    let mut java_cmd_set = false;
-    if let Some(java_bin) = resolve_binary_path(config.as_ref(), "java") {
+    if let Some(java_bin) = &overrides.java_cmd {
         env_lines.push(format!("  java binary = {}", java_bin));
-        env::set_var("JAVA_CMD", &java_bin);
+        env::set_var("JAVA_CMD", java_bin);
         env_lines.push(format!("  JAVA_CMD set from java binary = {}", java_bin));
-        java_cmd_set = true;
-        if let Some(java_home) = derive_java_home(&java_bin) {
-            env::set_var("JAVA_HOME", &java_home);
+        if let Some(java_home) = &overrides.java_home {
+            env::set_var("JAVA_HOME", java_home);
             env_lines.push(format!(
                 "  JAVA_HOME derived from java binary = {}",
                 java_home
             ));
-            java_home_set = true;
         } else {
             env_lines.push(format!(
                 "  WARNING: Could not derive JAVA_HOME from java binary: {}",
@@ -347,7 +350,7 @@ pub async fn execute_analysis(
         }
     }
 
-    if !java_home_set {
+    if overrides.java_home.is_none() {
         if let Some(ref existing) = original_java_home {
             env_lines.push(format!(
                 "  JAVA_HOME retained (pre-existing) = {}",
@@ -356,18 +359,17 @@ pub async fn execute_analysis(
         }
     }
 
-    if !java_cmd_set {
+    if overrides.java_cmd.is_none() {
         match original_java_cmd.as_deref() {
+            Some(existing) if !overrides.clear_java_cmd => {
+                env_lines.push(format!("  JAVA_CMD retained (pre-existing) = {}", existing));
+            }
             Some(existing) => {
-                if std::path::Path::new(existing).exists() {
-                    env_lines.push(format!("  JAVA_CMD retained (pre-existing) = {}", existing));
-                } else {
-                    env::remove_var("JAVA_CMD");
-                    env_lines.push(format!(
-                        "  JAVA_CMD removed (pre-existing path missing) = {}",
-                        existing
-                    ));
-                }
+                env::remove_var("JAVA_CMD");
+                env_lines.push(format!(
+                    "  JAVA_CMD removed (pre-existing path missing) = {}",
+                    existing
+                ));
             }
             None => {
                 env::remove_var("JAVA_CMD");
@@ -376,15 +378,17 @@ pub async fn execute_analysis(
         }
     }
 
-    let nxf_home_path = biovault_home.join("data").join("nextflow");
-    match fs::create_dir_all(&nxf_home_path) {
+    match fs::create_dir_all(&overrides.nxf_home) {
         Ok(_) => {
-            env::set_var("NXF_HOME", &nxf_home_path);
-            env_lines.push(format!("  NXF_HOME = {}", nxf_home_path.to_string_lossy()));
+            env::set_var("NXF_HOME", &overrides.nxf_home);
+            env_lines.push(format!(
+                "  NXF_HOME = {}",
+                overrides.nxf_home.to_string_lossy()
+            ));
         }
         Err(err) => env_lines.push(format!(
             "  WARNING: Failed to prepare NXF_HOME at {}: {}",
-            nxf_home_path.to_string_lossy(),
+            overrides.nxf_home.to_string_lossy(),
             err
         )),
     }
@@ -465,21 +469,74 @@ pub async fn execute_analysis(
     }
 }
 
-#[tauri::command]
-pub fn get_runs(state: tauri::State<AppState>) -> Result<Vec<Run>, String> {
-    let biovault_db = state.biovault_db.lock().unwrap();
-    let mut stmt = biovault_db.conn
-        .prepare(
-            "SELECT r.id, r.step_id, p.name, r.work_dir, r.participant_count, r.status, r.created_at
-             FROM runs r
-             JOIN modules p ON r.step_id = p.id
-             WHERE r.step_id IS NOT NULL
-             ORDER BY r.created_at DESC"
-        )
+const RUN_SELECT_SQL: &str =
+    "SELECT r.id, r.step_id, p.name, r.work_dir, r.participant_count, r.status, r.created_at
+     FROM runs r
+     JOIN modules p ON r.step_id = p.id";
+
+/// Labels belong to flow runs (the runs the UI lists), keyed by flow run id.
+pub(crate) fn ensure_flow_run_labels_table(conn: &rusqlite::Connection) -> Result<(), String> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS flow_run_labels (
+            run_id INTEGER NOT NULL,
+            label TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            PRIMARY KEY (run_id, label)
+        )",
+        [],
+    )
+    .map_err(|e| format!("Failed to create run labels table: {}", e))?;
+    Ok(())
+}
+
+/// Labels for every labeled flow run, in one query.
+pub(crate) fn load_flow_run_labels(
+    conn: &rusqlite::Connection,
+) -> Result<HashMap<i64, Vec<String>>, String> {
+    ensure_flow_run_labels_table(conn)?;
+    let mut stmt = conn
+        .prepare("SELECT run_id, label FROM flow_run_labels ORDER BY run_id, label")
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+        })
         .map_err(|e| e.to_string())?;
+    let mut labels: HashMap<i64, Vec<String>> = HashMap::new();
+    for row in rows {
+        let (run_id, label) = row.map_err(|e| e.to_string())?;
+        labels.entry(run_id).or_default().push(label);
+    }
+    Ok(labels)
+}
+
+/// Attach labels and the effective notification preference to flow runs.
+pub(crate) fn with_flow_run_labels(
+    conn: &rusqlite::Connection,
+    runs: Vec<biovault::data::Run>,
+) -> Result<Vec<FlowRunRecord>, String> {
+    let mut labels = load_flow_run_labels(conn)?;
+    let run_ids: Vec<i64> = runs.iter().map(|run| run.id).collect();
+    let notifications = super::notifications::run_notification_flags(conn, &run_ids)?;
+    Ok(runs
+        .into_iter()
+        .map(|run| FlowRunRecord {
+            labels: labels.remove(&run.id).unwrap_or_default(),
+            notifications_enabled: notifications.get(&run.id).copied().unwrap_or(false),
+            run,
+        })
+        .collect())
+}
+
+fn query_runs(
+    conn: &rusqlite::Connection,
+    sql: &str,
+    args: &[&dyn rusqlite::ToSql],
+) -> Result<Vec<Run>, String> {
+    let mut stmt = conn.prepare(sql).map_err(|e| e.to_string())?;
 
     let runs = stmt
-        .query_map([], |row| {
+        .query_map(args, |row| {
             Ok(Run {
                 id: row.get(0)?,
                 module_id: row.get(1)?,
@@ -498,31 +555,134 @@ pub fn get_runs(state: tauri::State<AppState>) -> Result<Vec<Run>, String> {
 }
 
 #[tauri::command]
-pub fn delete_run(state: tauri::State<AppState>, run_id: i64) -> Result<(), String> {
+pub fn get_runs(state: tauri::State<AppState>) -> Result<Vec<Run>, String> {
     let biovault_db = state.biovault_db.lock().unwrap();
+    let sql = format!(
+        "{} WHERE r.step_id IS NOT NULL ORDER BY r.created_at DESC",
+        RUN_SELECT_SQL
+    );
+    query_runs(&biovault_db.conn, &sql, &[])
+}
 
-    let work_dir: String = biovault_db
+/// Replace the labels on a flow run. Labels are trimmed, de-duplicated and free-form.
+#[tauri::command]
+pub fn set_run_labels(
+    state: tauri::State<AppState>,
+    run_id: i64,
+    labels: Vec<String>,
+) -> Result<Vec<String>, String> {
+    let labels: BTreeSet<String> = labels
+        .into_iter()
+        .map(|label| label.trim().to_string())
+        .filter(|label| !label.is_empty())
+        .collect();
+
+    let mut biovault_db = state.biovault_db.lock().unwrap();
+    ensure_flow_run_labels_table(&biovault_db.conn)?;
+
+    if biovault_db
+        .get_flow_run(run_id)
+        .map_err(|e| e.to_string())?
+        .is_none()
+    {
+        return Err(format!("Run {} not found", run_id));
+    }
+
+    let now = chrono::Utc::now().to_rfc3339();
+    let tx = biovault_db
         .conn
-        .query_row(
-            "SELECT work_dir FROM runs WHERE id = ?1",
-            params![run_id],
-            |row| row.get(0),
+        .transaction()
+        .map_err(|e| format!("Failed to start transaction: {}", e))?;
+    tx.execute(
+        "DELETE FROM flow_run_labels WHERE run_id = ?1",
+        params![run_id],
+    )
+    .map_err(|e| e.to_string())?;
+    for label in &labels {
+        tx.execute(
+            "INSERT INTO flow_run_labels (run_id, label, created_at) VALUES (?1, ?2, ?3)",
+            params![run_id, label, now],
         )
         .map_err(|e| e.to_string())?;
+    }
+    tx.commit()
+        .map_err(|e| format!("Failed to save run labels: {}", e))?;
+
+    Ok(labels.into_iter().collect())
+}
 
-    biovault_db
+/// Flow runs carrying `label`, newest first.
+#[tauri::command]
+pub fn get_runs_by_label(
+    state: tauri::State<AppState>,
+    label: String,
+) -> Result<Vec<FlowRunRecord>, String> {
+    let biovault_db = state.biovault_db.lock().unwrap();
+    let label = label.trim().to_string();
+    let mut runs = with_flow_run_labels(
+        &biovault_db.conn,
+        biovault_db.list_flow_runs().map_err(|e| e.to_string())?,
+    )?;
+    runs.retain(|r| r.labels.contains(&label));
+    runs.sort_by(|a, b| b.run.id.cmp(&a.run.id));
+    Ok(runs)
+}
+
+/// Label usage counts for building the runs filter sidebar.
+#[tauri::command]
+pub fn get_run_label_counts(state: tauri::State<AppState>) -> Result<Vec<RunLabelCount>, String> {
+    let biovault_db = state.biovault_db.lock().unwrap();
+    ensure_flow_run_labels_table(&biovault_db.conn)?;
+    let mut stmt = biovault_db
         .conn
-        .execute(
-            "DELETE FROM run_participants WHERE run_id = ?1",
+        .prepare(
+            "SELECT label, COUNT(*) FROM flow_run_labels
+             GROUP BY label
+             ORDER BY COUNT(*) DESC, label ASC",
+        )
+        .map_err(|e| e.to_string())?;
+
+    let counts = stmt
+        .query_map([], |row| {
+            Ok(RunLabelCount {
+                label: row.get(0)?,
+                count: row.get(1)?,
+            })
+        })
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+
+    Ok(counts)
+}
+
+/// Remove a run and its participant rows; returns the run's work dir.
+fn delete_run_rows(conn: &rusqlite::Connection, run_id: i64) -> Result<String, String> {
+    let work_dir: String = conn
+        .query_row(
+            "SELECT work_dir FROM runs WHERE id = ?1",
             params![run_id],
+            |row| row.get(0),
         )
         .map_err(|e| e.to_string())?;
 
-    biovault_db
-        .conn
-        .execute("DELETE FROM runs WHERE id = ?1", params![run_id])
+    conn.execute(
+        "DELETE FROM run_participants WHERE run_id = ?1",
+        params![run_id],
+    )
+    .map_err(|e| e.to_string())?;
+
+    conn.execute("DELETE FROM runs WHERE id = ?1", params![run_id])
         .map_err(|e| e.to_string())?;
 
+    Ok(work_dir)
+}
+
+#[tauri::command]
+pub fn delete_run(state: tauri::State<AppState>, run_id: i64) -> Result<(), String> {
+    let biovault_db = state.biovault_db.lock().unwrap();
+    let work_dir = delete_run_rows(&biovault_db.conn, run_id)?;
+
     if Path::new(&work_dir).exists() {
         let _ = fs::remove_dir_all(&work_dir);
     }
@@ -530,6 +690,137 @@ pub fn delete_run(state: tauri::State<AppState>, run_id: i64) -> Result<(), Stri
     Ok(())
 }
 
+/// Only runs in one of these states are eligible for bulk cleanup.
+const FINISHED_RUN_STATUSES: &[&str] = &["success", "failed", "completed", "cancelled", "error"];
+/// Sweeps larger than this emit `runs:cleanup-progress` events.
+const RUN_CLEANUP_PROGRESS_THRESHOLD: usize = 50;
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RunCleanupResult {
+    pub cutoff: String,
+    pub deleted_runs: usize,
+    pub skipped_labeled: usize,
+    pub deleted_work_dirs: usize,
+    pub bytes_reclaimed: u64,
+    pub errors: Vec<String>,
+}
+
+fn parse_run_timestamp(value: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&chrono::Utc))
+        .ok()
+        .or_else(|| {
+            chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S")
+                .ok()
+                .map(|dt| dt.and_utc())
+        })
+}
+
+/// Delete finished flow runs created more than `days` ago. Running or queued
+/// runs are never touched; labeled runs are kept unless `keep_labeled` is false.
+#[tauri::command]
+pub async fn delete_runs_older_than(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+    days: u32,
+    keep_labeled: Option<bool>,
+    delete_work_dirs: Option<bool>,
+) -> Result<RunCleanupResult, String> {
+    if days == 0 {
+        return Err("days must be at least 1".to_string());
+    }
+    let keep_labeled = keep_labeled.unwrap_or(true);
+    let delete_work_dirs = delete_work_dirs.unwrap_or(true);
+    let cutoff = chrono::Utc::now() - chrono::Duration::days(days as i64);
+
+    let mut result = RunCleanupResult {
+        cutoff: cutoff.to_rfc3339(),
+        deleted_runs: 0,
+        skipped_labeled: 0,
+        deleted_work_dirs: 0,
+        bytes_reclaimed: 0,
+        errors: Vec::new(),
+    };
+
+    // Only rows change under the lock; directories are removed after it is released.
+    let mut dirs_to_remove = Vec::new();
+    {
+        let biovault_db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+        let candidates: Vec<FlowRunRecord> = with_flow_run_labels(
+            &biovault_db.conn,
+            biovault_db.list_flow_runs().map_err(|e| e.to_string())?,
+        )?
+        .into_iter()
+        .filter(|r| FINISHED_RUN_STATUSES.contains(&r.run.status.to_lowercase().as_str()))
+        .filter(|r| {
+            parse_run_timestamp(&r.run.created_at)
+                .map(|created| created < cutoff)
+                .unwrap_or(false)
+        })
+        .collect();
+
+        for record in candidates {
+            if keep_labeled && !record.labels.is_empty() {
+                result.skipped_labeled += 1;
+                continue;
+            }
+            match super::flows::delete_flow_run_rows(&biovault_db, record.run.id) {
+                Ok(dirs) => {
+                    result.deleted_runs += 1;
+                    dirs_to_remove.extend(dirs);
+                }
+                Err(e) => result
+                    .errors
+                    .push(format!("Failed to delete run {}: {}", record.run.id, e)),
+            }
+        }
+    }
+
+    if delete_work_dirs {
+        // Sizing and removing run dirs can take a while; keep it off the async runtime.
+        let (removed, bytes, errors) = tauri::async_runtime::spawn_blocking(move || {
+            let dirs_to_remove: Vec<PathBuf> =
+                dirs_to_remove.into_iter().filter(|d| d.exists()).collect();
+            let total = dirs_to_remove.len();
+            let report_progress = total > RUN_CLEANUP_PROGRESS_THRESHOLD;
+            let mut removed: usize = 0;
+            let mut bytes: u64 = 0;
+            let mut errors = Vec::new();
+            for (idx, dir) in dirs_to_remove.into_iter().enumerate() {
+                let size = super::flows::path_size(&dir);
+                match fs::remove_dir_all(&dir) {
+                    Ok(()) => {
+                        removed += 1;
+                        bytes += size;
+                    }
+                    Err(e) => errors.push(format!("Failed to remove {}: {}", dir.display(), e)),
+                }
+                if report_progress && ((idx + 1) % 25 == 0 || idx + 1 == total) {
+                    let _ = app.emit(
+                        "runs:cleanup-progress",
+                        serde_json::json!({ "processed": idx + 1, "total": total }),
+                    );
+                }
+            }
+            (removed, bytes, errors)
+        })
+        .await
+        .map_err(|e| format!("Failed to remove run directories: {}", e))?;
+        result.deleted_work_dirs = removed;
+        result.bytes_reclaimed = bytes;
+        result.errors.extend(errors);
+    }
+
+    crate::desktop_log!(
+        "🧹 Deleted {} flow run(s) older than {} day(s) ({} labeled kept, {} MB reclaimed)",
+        result.deleted_runs,
+        days,
+        result.skipped_labeled,
+        result.bytes_reclaimed / (1024 * 1024)
+    );
+    Ok(result)
+}
+
 #[tauri::command]
 pub fn get_run_logs(state: tauri::State<AppState>, run_id: i64) -> Result<String, String> {
     // Default: return last 500 lines for fast initial load
@@ -615,7 +906,7 @@ fn bundled_env_var(name: &str) -> Option<&'static str> {
     }
 }
 
-fn resolve_binary_path(cfg: Option<&Config>, name: &str) -> Option<String> {
+pub(crate) fn resolve_binary_path(cfg: Option<&Config>, name: &str) -> Option<String> {
     if let Some(cfg) = cfg {
         if let Some(path) = cfg.get_binary_path(name) {
             if !path.is_empty() {
@@ -691,6 +982,128 @@ fn derive_java_home(java_bin: &str) -> Option<String> {
     None
 }
 
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ChildProcessEnvVar {
+    pub name: String,
+    pub value: Option<String>,
+    /// "process" when inherited as-is, "spawn" when the run path overrides it
+    pub source: String,
+    pub redacted: bool,
+}
+
+const CHILD_ENV_PREFIXES: &[&str] = &["BIOVAULT_", "SYFTBOX_", "SEQURE_", "NXF_", "DOCKER_"];
+const CHILD_ENV_NAMES: &[&str] = &["PATH", "JAVA_HOME", "JAVA_CMD", "HOME", "TMPDIR"];
+const SECRET_MARKERS: &[&str] = &[
+    "TOKEN",
+    "SECRET",
+    "PASSWORD",
+    "PASSWD",
+    "CREDENTIAL",
+    "API_KEY",
+    "AUTH",
+];
+
+fn is_secret_env_name(name: &str) -> bool {
+    let upper = name.to_ascii_uppercase();
+    upper.ends_with("_KEY")
+        || upper.ends_with("KEY_ID")
+        || SECRET_MARKERS.iter().any(|marker| upper.contains(marker))
+}
+
+/// Env overrides for Nextflow/module child processes. `execute_analysis`
+/// applies them and `get_child_process_environment` reports them, so the two
+/// can't disagree about what a run sees.
+struct ChildEnvOverrides {
+    /// PATH with the configured binaries' directories added, if any were
+    path: Option<String>,
+    /// Configured java binary, exported as JAVA_CMD
+    java_cmd: Option<String>,
+    java_home: Option<String>,
+    /// No java is configured and the inherited JAVA_CMD is unset or points
+    /// at a missing file, so it is removed
+    clear_java_cmd: bool,
+    nxf_home: PathBuf,
+}
+
+fn child_env_overrides(biovault_home: &Path, config: Option<&Config>) -> ChildEnvOverrides {
+    let java_cmd = resolve_binary_path(config, "java");
+    let java_home = java_cmd.as_deref().and_then(derive_java_home);
+    let clear_java_cmd = java_cmd.is_none()
+        && env::var("JAVA_CMD")
+            .map(|existing| !Path::new(&existing).exists())
+            .unwrap_or(true);
+    ChildEnvOverrides {
+        path: build_augmented_path(config),
+        java_cmd,
+        java_home,
+        clear_java_cmd,
+        nxf_home: biovault_home.join("data").join("nextflow"),
+    }
+}
+
+/// Report the environment that Nextflow/module child processes inherit, mirroring
+/// the overrides applied in `execute_analysis` without mutating this process.
+#[tauri::command]
+pub fn get_child_process_environment() -> Result<Vec<ChildProcessEnvVar>, String> {
+    let mut resolved: std::collections::BTreeMap<String, (Option<String>, &'static str)> =
+        std::collections::BTreeMap::new();
+
+    for (name, value) in env::vars() {
+        let relevant = CHILD_ENV_NAMES.contains(&name.as_str())
+            || CHILD_ENV_PREFIXES
+                .iter()
+                .any(|prefix| name.starts_with(prefix));
+        if relevant {
+            resolved.insert(name, (Some(value), "process"));
+        }
+    }
+
+    let biovault_home = biovault::config::get_biovault_home()
+        .map_err(|e| format!("Failed to get BioVault home: {}", e))?;
+    resolved.insert(
+        "BIOVAULT_HOME".to_string(),
+        (Some(biovault_home.to_string_lossy().to_string()), "spawn"),
+    );
+
+    let config = biovault::config::get_config().ok();
+    let overrides = child_env_overrides(&biovault_home, config.as_ref());
+    if let Some(augmented_path) = overrides.path {
+        resolved.insert("PATH".to_string(), (Some(augmented_path), "spawn"));
+    }
+    if let Some(java_home) = overrides.java_home {
+        resolved.insert("JAVA_HOME".to_string(), (Some(java_home), "spawn"));
+    }
+    if let Some(java_bin) = overrides.java_cmd {
+        resolved.insert("JAVA_CMD".to_string(), (Some(java_bin), "spawn"));
+    } else if overrides.clear_java_cmd {
+        resolved.insert("JAVA_CMD".to_string(), (None, "spawn"));
+    }
+    resolved.insert(
+        "NXF_HOME".to_string(),
+        (
+            Some(overrides.nxf_home.to_string_lossy().to_string()),
+            "spawn",
+        ),
+    );
+
+    Ok(resolved
+        .into_iter()
+        .map(|(name, (value, source))| {
+            let redacted = value.is_some() && is_secret_env_name(&name);
+            ChildProcessEnvVar {
+                value: if redacted {
+                    Some("<redacted>".to_string())
+                } else {
+                    value
+                },
+                name,
+                source: source.to_string(),
+                redacted,
+            }
+        })
+        .collect())
+}
+
 fn append_run_log_lines(
     log_file: &mut fs::File,
     window: &tauri::Window,
@@ -0,0 +1,164 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tauri::Manager;
+
+fn run_capture(mut cmd: Command, tool: &str) -> Result<(), String> {
+    super::hide_console_window(&mut cmd);
+    let output = cmd
+        .output()
+        .map_err(|e| format!("Failed to run {}: {}", tool, e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "{} failed: {}",
+            tool,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
+
+/// Platform window id of the main window, as the capture tool expects it.
+#[cfg(target_os = "macos")]
+fn native_window_id(window: &tauri::WebviewWindow) -> Result<String, String> {
+    use objc2::msg_send;
+    use objc2::runtime::AnyObject;
+
+    let ns_window = window
+        .ns_window()
+        .map_err(|e| format!("Failed to get native window: {}", e))?
+        as *mut AnyObject;
+    if ns_window.is_null() {
+        return Err("Native window is not available".to_string());
+    }
+    // SAFETY: tauri hands out a live NSWindow pointer for the window's lifetime.
+    let number: isize = unsafe { msg_send![&*ns_window, windowNumber] };
+    Ok(number.to_string())
+}
+
+#[cfg(target_os = "windows")]
+fn native_window_id(window: &tauri::WebviewWindow) -> Result<String, String> {
+    let hwnd = window
+        .hwnd()
+        .map_err(|e| format!("Failed to get native window: {}", e))?;
+    Ok((hwnd.0 as isize).to_string())
+}
+
+#[cfg(target_os = "linux")]
+fn native_window_id(window: &tauri::WebviewWindow) -> Result<String, String> {
+    use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+
+    let handle = window
+        .window_handle()
+        .map_err(|e| format!("Failed to get native window: {}", e))?;
+    match handle.as_raw() {
+        RawWindowHandle::Xlib(h) => Ok(h.window.to_string()),
+        RawWindowHandle::Xcb(h) => Ok(h.window.get().to_string()),
+        RawWindowHandle::Wayland(_) => Err(
+            "Window screenshots are not available on Wayland; use your desktop's screenshot tool"
+                .to_string(),
+        ),
+        _ => Err("Unsupported window system for screenshots".to_string()),
+    }
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+fn native_window_id(_window: &tauri::WebviewWindow) -> Result<String, String> {
+    Err("Window screenshots are not supported on this platform".to_string())
+}
+
+#[cfg(target_os = "macos")]
+fn capture_window(window_id: &str, dest: &Path) -> Result<(), String> {
+    // -l captures the window's own contents, even if other windows overlap it.
+    let mut cmd = Command::new("screencapture");
+    cmd.args(["-x", "-o", "-t", "png"])
+        .arg(format!("-l{}", window_id))
+        .arg(dest);
+    run_capture(cmd, "screencapture")
+}
+
+#[cfg(target_os = "windows")]
+fn capture_window(window_id: &str, dest: &Path) -> Result<(), String> {
+    // PrintWindow asks the window to render itself, so overlapping windows never
+    // end up in the image. PW_RENDERFULLCONTENT (2) is needed for WebView2.
+    let script = format!(
+        "Add-Type -AssemblyName System.Drawing; \
+         Add-Type -TypeDefinition 'using System; using System.Runtime.InteropServices; \
+         public struct BvRect {{ public int Left, Top, Right, Bottom; }} \
+         public static class BvWin {{ \
+         [DllImport(\"user32.dll\")] public static extern bool GetWindowRect(IntPtr h, out BvRect r); \
+         [DllImport(\"user32.dll\")] public static extern bool PrintWindow(IntPtr h, IntPtr dc, uint f); }}'; \
+         $h = [IntPtr]{hwnd}; $r = New-Object BvRect; \
+         if (-not [BvWin]::GetWindowRect($h, [ref]$r)) {{ throw 'GetWindowRect failed' }}; \
+         $bmp = New-Object System.Drawing.Bitmap -ArgumentList ($r.Right - $r.Left), ($r.Bottom - $r.Top); \
+         $g = [System.Drawing.Graphics]::FromImage($bmp); $dc = $g.GetHdc(); \
+         $ok = [BvWin]::PrintWindow($h, $dc, 2); $g.ReleaseHdc($dc); \
+         if (-not $ok) {{ throw 'PrintWindow failed' }}; \
+         $bmp.Save('{dest}', [System.Drawing.Imaging.ImageFormat]::Png); \
+         $g.Dispose(); $bmp.Dispose()",
+        hwnd = window_id,
+        dest = dest.to_string_lossy().replace('\'', "''"),
+    );
+    let mut cmd = Command::new("powershell");
+    cmd.args(["-NoProfile", "-NonInteractive", "-Command", &script]);
+    run_capture(cmd, "powershell")
+}
+
+#[cfg(target_os = "linux")]
+fn capture_window(window_id: &str, dest: &Path) -> Result<(), String> {
+    // ImageMagick reads the X11 window itself rather than a region of the root window.
+    let mut cmd = Command::new("import");
+    cmd.args(["-silent", "-window", window_id]).arg(dest);
+    run_capture(cmd, "import")
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+fn capture_window(_window_id: &str, _dest: &Path) -> Result<(), String> {
+    Err("Window screenshots are not supported on this platform".to_string())
+}
+
+/// Capture the main window's own contents (never the screen around or over it)
+/// to a PNG.
+/// Must be triggered by the user: `user_approved` has to be true and this
+/// command is deliberately not exposed over the WebSocket bridge.
+#[tauri::command]
+pub async fn capture_window_screenshot(
+    app: tauri::AppHandle,
+    dest_path: String,
+    user_approved: bool,
+) -> Result<String, String> {
+    if !user_approved {
+        return Err("Screenshots require explicit user approval".to_string());
+    }
+
+    let mut dest = PathBuf::from(dest_path.trim());
+    if dest.as_os_str().is_empty() {
+        return Err("Destination path is required".to_string());
+    }
+    if dest.extension().and_then(|e| e.to_str()) != Some("png") {
+        dest.set_extension("png");
+    }
+    if let Some(parent) = dest.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+
+    let window = app
+        .get_webview_window("main")
+        .ok_or_else(|| "Main window not found".to_string())?;
+    if window.is_minimized().unwrap_or(false) {
+        return Err("Restore the window before taking a screenshot".to_string());
+    }
+    let window_id = native_window_id(&window)?;
+
+    let capture_dest = dest.clone();
+    tauri::async_runtime::spawn_blocking(move || capture_window(&window_id, &capture_dest))
+        .await
+        .map_err(|e| format!("Screenshot task failed: {}", e))??;
+
+    if !dest.exists() {
+        return Err("Screenshot tool did not produce an image".to_string());
+    }
+
+    crate::desktop_log!("📸 Saved window screenshot to {}", dest.display());
+    Ok(dest.to_string_lossy().to_string())
+}
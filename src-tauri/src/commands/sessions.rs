@@ -65,6 +65,18 @@ fn get_private_session_path(session_id: &str) -> std::path::PathBuf {
     get_private_sessions_dir().join(session_id)
 }
 
+/// Session id owning a Jupyter workspace path, if it lives under the
+/// private sessions root.
+pub(crate) fn session_id_for_workspace(workspace: &str) -> Option<String> {
+    let root = get_private_sessions_dir();
+    let root = root.canonicalize().unwrap_or(root);
+    let workspace = std::path::Path::new(workspace);
+    let rel = workspace.strip_prefix(&root).ok()?;
+    let mut components = rel.components();
+    let session_id = components.next()?.as_os_str().to_string_lossy().to_string();
+    components.next().is_none().then_some(session_id)
+}
+
 fn ensure_private_session_dir(session_id: &str) -> Result<std::path::PathBuf, String> {
     let private_root = get_private_session_path(session_id);
     fs::create_dir_all(&private_root)
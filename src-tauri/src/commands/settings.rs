@@ -248,6 +248,7 @@ fn reset_all_data_impl(state: &AppState, preserve_keys: bool) -> Result<(), Stri
     {
         let new_db = biovault::data::BioVaultDb::new()
             .map_err(|e| format!("Failed to initialize BioVault database: {}", e))?;
+        crate::commands::files::ensure_file_columns(&new_db);
         let mut shared_db = state
             .biovault_db
             .lock()
@@ -279,11 +280,100 @@ pub fn get_database_path() -> Result<String, String> {
         .to_string())
 }
 
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DatabaseSchemaVersion {
+    pub db_version: i64,
+    /// From the embedded CLI's schema.sql at build time; None if it wasn't found
+    pub expected_version: Option<i64>,
+    /// None when the expected version is unknown and nothing could be compared
+    pub compatible: Option<bool>,
+    /// The database was written by a newer BioVault than this app embeds
+    pub db_is_newer: bool,
+    pub message: Option<String>,
+}
+
+fn check_database_schema_version(state: &AppState) -> Result<DatabaseSchemaVersion, String> {
+    let db_version: i64 = {
+        let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+        db.connection()
+            .query_row("PRAGMA user_version", [], |row| row.get(0))
+            .map_err(|e| format!("Failed to read schema version: {}", e))?
+    };
+    let Some(expected_version) = env!("BIOVAULT_SCHEMA_VERSION").parse::<i64>().ok() else {
+        crate::desktop_log!(
+            "⚠️ Expected schema version unknown (schema.sql not found at build time); DB is v{}",
+            db_version
+        );
+        return Ok(DatabaseSchemaVersion {
+            db_version,
+            expected_version: None,
+            compatible: None,
+            db_is_newer: false,
+            message: None,
+        });
+    };
+
+    let compatible = db_version == expected_version;
+    let db_is_newer = db_version > expected_version;
+    let message = if compatible {
+        None
+    } else if db_is_newer {
+        Some(format!(
+            "Database schema v{} is newer than this app supports (v{}). Update BioVault Desktop before continuing.",
+            db_version, expected_version
+        ))
+    } else {
+        Some(format!(
+            "Database schema v{} is older than expected (v{}). Restart the app to let BioVault migrate it.",
+            db_version, expected_version
+        ))
+    };
+    if let Some(msg) = &message {
+        crate::desktop_log!("⚠️ {}", msg);
+    }
+
+    Ok(DatabaseSchemaVersion {
+        db_version,
+        expected_version: Some(expected_version),
+        compatible: Some(compatible),
+        db_is_newer,
+        message,
+    })
+}
+
+/// Check the schema version once at startup and emit `database:schema-mismatch`
+/// so the UI can warn before anything touches the database.
+pub(crate) fn emit_schema_mismatch_warning(app: &tauri::AppHandle) {
+    use tauri::{Emitter, Manager};
+    let state = app.state::<AppState>();
+    if let Ok(version) = check_database_schema_version(state.inner()) {
+        if version.compatible == Some(false) {
+            let _ = app.emit("database:schema-mismatch", &version);
+        }
+    }
+}
+
+/// Compare the schema version stamped in the BioVault DB (`PRAGMA user_version`,
+/// set by the CLI's schema.sql) with the one in the schema.sql this app was built with.
+#[tauri::command]
+pub fn get_database_schema_version(
+    state: tauri::State<AppState>,
+) -> Result<DatabaseSchemaVersion, String> {
+    check_database_schema_version(state.inner())
+}
+
 #[tauri::command]
 pub fn get_app_version() -> String {
     env!("CARGO_PKG_VERSION").to_string()
 }
 
+/// Version of the biovault CLI library compiled into this build, which can
+/// differ from a standalone `bv` on PATH.
+#[tauri::command]
+pub fn get_embedded_cli_version() -> String {
+    env!("BIOVAULT_CLI_VERSION").to_string()
+}
+
 #[tauri::command]
 pub fn check_is_onboarded() -> Result<bool, String> {
     let biovault_home = biovault::config::get_biovault_home()
@@ -392,6 +482,109 @@ pub(crate) fn private_key_is_readable_for_home_and_email(
     }
 }
 
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct StartupConfigProblem {
+    /// Stable identifier the UI can switch on (`home_unresolved`, `home_not_writable`,
+    /// `config_missing`, `config_unreadable`, `email_missing`).
+    pub code: String,
+    pub message: String,
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct StartupConfigReport {
+    pub ok: bool,
+    pub home: Option<String>,
+    pub config_path: Option<String>,
+    pub email: Option<String>,
+    pub problems: Vec<StartupConfigProblem>,
+}
+
+fn startup_problem(code: &str, message: String, path: Option<&Path>) -> StartupConfigProblem {
+    StartupConfigProblem {
+        code: code.to_string(),
+        message,
+        path: path.map(|p| p.to_string_lossy().to_string()),
+    }
+}
+
+/// Cheap, non-panicking checks that must pass before the BioVault DB is opened.
+/// Safe to call from `run()` before any state exists.
+pub(crate) fn startup_config_report() -> StartupConfigReport {
+    let mut report = StartupConfigReport {
+        ok: false,
+        home: None,
+        config_path: None,
+        email: None,
+        problems: Vec::new(),
+    };
+
+    let home = match biovault::config::get_biovault_home() {
+        Ok(home) => home,
+        Err(e) => {
+            report.problems.push(startup_problem(
+                "home_unresolved",
+                format!("Failed to resolve BioVault home: {}", e),
+                None,
+            ));
+            return report;
+        }
+    };
+    report.home = Some(home.to_string_lossy().to_string());
+
+    let probe = home.join(format!(".bv-write-probe-{}", std::process::id()));
+    let writable = fs::create_dir_all(&home).and_then(|_| fs::write(&probe, b"ok"));
+    match writable {
+        Ok(()) => {
+            let _ = fs::remove_file(&probe);
+        }
+        Err(e) => report.problems.push(startup_problem(
+            "home_not_writable",
+            format!("BioVault home is not writable: {}", e),
+            Some(&home),
+        )),
+    }
+
+    let config_path = home.join("config.yaml");
+    report.config_path = Some(config_path.to_string_lossy().to_string());
+    if !config_path.exists() {
+        report.problems.push(startup_problem(
+            "config_missing",
+            "No config.yaml yet; complete onboarding to create one".to_string(),
+            Some(&config_path),
+        ));
+    } else {
+        match biovault::config::Config::load() {
+            Ok(config) => {
+                let email = config.email.trim();
+                if email.is_empty() || email == PLACEHOLDER_EMAIL {
+                    report.problems.push(startup_problem(
+                        "email_missing",
+                        "config.yaml has no email; complete onboarding to set one".to_string(),
+                        Some(&config_path),
+                    ));
+                } else {
+                    report.email = Some(email.to_string());
+                }
+            }
+            Err(e) => report.problems.push(startup_problem(
+                "config_unreadable",
+                format!("Failed to load config.yaml: {}", e),
+                Some(&config_path),
+            )),
+        }
+    }
+
+    report.ok = report.problems.is_empty();
+    report
+}
+
+/// Validate home/config/email so the UI can show a setup screen instead of failing later.
+#[tauri::command]
+pub fn validate_startup_config() -> Result<StartupConfigReport, String> {
+    Ok(startup_config_report())
+}
+
 #[tauri::command]
 pub fn reset_all_data(state: tauri::State<AppState>) -> Result<(), String> {
     reset_all_data_impl(&state, true)
@@ -1144,9 +1337,566 @@ pub fn set_autostart_enabled(app: tauri::AppHandle, enabled: bool) -> Result<(),
     }
 }
 
+/// Minimum free space required on a work/results root before runs are placed there.
+const MIN_RUN_ROOT_FREE_BYTES: u64 = 1024 * 1024 * 1024;
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RunRootStatus {
+    pub path: String,
+    pub custom: bool,
+    pub writable: bool,
+    pub available_bytes: Option<u64>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RunDirectoryRoots {
+    pub work: RunRootStatus,
+    pub results: RunRootStatus,
+}
+
+/// Free bytes on the volume holding `path` (or its nearest existing ancestor).
+#[cfg(unix)]
+#[allow(clippy::unnecessary_cast)]
+pub(crate) fn available_disk_space(path: &Path) -> Option<u64> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let existing = path.ancestors().find(|p| p.exists())?;
+    let c_path = CString::new(existing.as_os_str().as_bytes()).ok()?;
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    if unsafe { libc::statvfs(c_path.as_ptr(), &mut stat) } != 0 {
+        return None;
+    }
+    Some(stat.f_bavail as u64 * stat.f_frsize as u64)
+}
+
+#[cfg(not(unix))]
+pub(crate) fn available_disk_space(_path: &Path) -> Option<u64> {
+    None
+}
+
+fn default_runs_root() -> Result<PathBuf, String> {
+    let home = biovault::config::get_biovault_home()
+        .map_err(|e| format!("Failed to get BioVault home: {}", e))?;
+    Ok(home.join("runs"))
+}
+
+fn check_run_root(path: &Path, custom: bool) -> RunRootStatus {
+    let mut status = RunRootStatus {
+        path: path.to_string_lossy().to_string(),
+        custom,
+        writable: false,
+        available_bytes: None,
+        error: None,
+    };
+
+    if let Err(e) = fs::create_dir_all(path) {
+        status.error = Some(format!("Failed to create directory: {}", e));
+        return status;
+    }
+    let probe = path.join(format!(".bv-write-probe-{}", std::process::id()));
+    match fs::write(&probe, b"ok") {
+        Ok(()) => {
+            let _ = fs::remove_file(&probe);
+            status.writable = true;
+        }
+        Err(e) => {
+            status.error = Some(format!("Directory is not writable: {}", e));
+            return status;
+        }
+    }
+
+    status.available_bytes = available_disk_space(path);
+    if let Some(free) = status.available_bytes {
+        if free < MIN_RUN_ROOT_FREE_BYTES {
+            status.error = Some(format!(
+                "Only {} MB free (need at least {} MB)",
+                free / (1024 * 1024),
+                MIN_RUN_ROOT_FREE_BYTES / (1024 * 1024)
+            ));
+        }
+    }
+    status
+}
+
+fn configured_root(value: &Option<String>) -> Option<PathBuf> {
+    value
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Effective (work_root, results_root) for new flow runs. Both default to
+/// `BIOVAULT_HOME/runs`; a configured root that is unusable is an error rather
+/// than a silent fallback, so runs never land somewhere unexpected.
+pub(crate) fn resolve_run_roots() -> Result<(PathBuf, PathBuf), String> {
+    let settings = get_settings()?;
+    let default_root = default_runs_root()?;
+    let resolve = |label: &str, value: &Option<String>| -> Result<PathBuf, String> {
+        let Some(root) = configured_root(value) else {
+            return Ok(default_root.clone());
+        };
+        if let Some(err) = check_run_root(&root, true).error {
+            return Err(format!(
+                "Configured {} directory {} is unusable: {}",
+                label,
+                root.display(),
+                err
+            ));
+        }
+        Ok(root)
+    };
+    Ok((
+        resolve("work", &settings.work_dir_root)?,
+        resolve("results", &settings.results_dir_root)?,
+    ))
+}
+
+#[tauri::command]
+pub fn get_run_directory_roots() -> Result<RunDirectoryRoots, String> {
+    let settings = get_settings()?;
+    let default_root = default_runs_root()?;
+    let work = configured_root(&settings.work_dir_root);
+    let results = configured_root(&settings.results_dir_root);
+    Ok(RunDirectoryRoots {
+        work: check_run_root(work.as_deref().unwrap_or(&default_root), work.is_some()),
+        results: check_run_root(
+            results.as_deref().unwrap_or(&default_root),
+            results.is_some(),
+        ),
+    })
+}
+
+/// Set where new flow runs put Nextflow work files and results. Empty or None
+/// resets a root to the default. Both roots are validated before saving.
+#[tauri::command]
+pub fn set_run_directory_roots(
+    work_dir_root: Option<String>,
+    results_dir_root: Option<String>,
+) -> Result<RunDirectoryRoots, String> {
+    let work = configured_root(&work_dir_root);
+    let results = configured_root(&results_dir_root);
+    for (label, root) in [("Work", &work), ("Results", &results)] {
+        if let Some(root) = root {
+            if !root.is_absolute() {
+                return Err(format!("{} directory must be an absolute path", label));
+            }
+            if let Some(err) = check_run_root(root, true).error {
+                return Err(format!("{} directory {}: {}", label, root.display(), err));
+            }
+        }
+    }
+
+    let mut settings = get_settings()?;
+    settings.work_dir_root = work.map(|p| p.to_string_lossy().to_string());
+    settings.results_dir_root = results.map(|p| p.to_string_lossy().to_string());
+    save_settings(settings)?;
+    crate::desktop_log!("📂 Updated run directory roots");
+    get_run_directory_roots()
+}
+
+/// Settings read straight from settings.json at launch, before the rest of
+/// the app is up. Missing or unreadable settings fall back to defaults.
+fn load_launch_settings(biovault_home: &Path) -> Settings {
+    fs::read_to_string(biovault_home.join("database").join("settings.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<Settings>(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Whether the queue processor starts running; the default is "start paused".
+pub(crate) fn load_queue_autostart(biovault_home: &Path) -> bool {
+    load_launch_settings(biovault_home).queue_autostart
+}
+
+pub(crate) fn load_stale_processing_minutes(biovault_home: &Path) -> u64 {
+    load_launch_settings(biovault_home).stale_processing_minutes
+}
+
+const MAX_QUEUE_WORKERS: usize = 8;
+
+/// Worker count for the import queue: the requested value (env or settings)
+/// or half the CPUs, clamped to 1..=MAX_QUEUE_WORKERS.
+fn clamp_queue_workers(requested: Option<u32>, cpus: usize) -> usize {
+    requested
+        .map(|n| n as usize)
+        .unwrap_or(cpus / 2)
+        .clamp(1, MAX_QUEUE_WORKERS)
+}
+
+/// `BIOVAULT_QUEUE_WORKERS` wins over the saved `queue_workers` setting.
+pub(crate) fn load_queue_workers(biovault_home: &Path) -> usize {
+    let requested = std::env::var("BIOVAULT_QUEUE_WORKERS")
+        .ok()
+        .and_then(|v| v.trim().parse::<u32>().ok())
+        .or_else(|| load_launch_settings(biovault_home).queue_workers);
+    let cpus = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1);
+    clamp_queue_workers(requested, cpus)
+}
+
+#[tauri::command]
+pub fn get_queue_autostart() -> Result<bool, String> {
+    Ok(get_settings()?.queue_autostart)
+}
+
+/// Whether the queue processor runs at launch. Takes effect on next start;
+/// use pause/resume to change the running processor.
+#[tauri::command]
+pub fn set_queue_autostart(enabled: bool) -> Result<bool, String> {
+    let mut settings = get_settings()?;
+    settings.queue_autostart = enabled;
+    save_settings(settings)?;
+    crate::desktop_log!(
+        "⏯️ Queue processor will {} at launch",
+        if enabled {
+            "start running"
+        } else {
+            "start paused"
+        }
+    );
+    Ok(enabled)
+}
+
+/// Bounds for `default_max_forks`; higher values are effectively unlimited.
+pub const MIN_DEFAULT_MAX_FORKS: u32 = 1;
+pub const MAX_DEFAULT_MAX_FORKS: u32 = 64;
+
+#[tauri::command]
+pub fn get_default_max_forks() -> Result<Option<u32>, String> {
+    Ok(get_settings()?.default_max_forks)
+}
+
+/// Set the maxForks applied to runs that don't specify one. Pass None to
+/// clear it; other values are clamped to a sane range.
+#[tauri::command]
+pub fn set_default_max_forks(max_forks: Option<u32>) -> Result<Option<u32>, String> {
+    let max_forks = max_forks.map(|v| v.clamp(MIN_DEFAULT_MAX_FORKS, MAX_DEFAULT_MAX_FORKS));
+    let mut settings = get_settings()?;
+    settings.default_max_forks = max_forks;
+    save_settings(settings)?;
+    match max_forks {
+        Some(value) => crate::desktop_log!("🧵 Default Nextflow maxForks set to {}", value),
+        None => crate::desktop_log!("🧵 Default Nextflow maxForks cleared"),
+    }
+    Ok(max_forks)
+}
+
+pub const DEFAULT_RESULTS_MESSAGE_TEMPLATE: &str =
+    "📊 Results from step '{step_name}' are ready!\n\n{file_count} file(s) attached. Click to download.";
+const RESULTS_MESSAGE_PLACEHOLDERS: &[&str] = &["step_name", "file_count", "flow_name"];
+
+/// Check that every `{...}` in a results template is a known placeholder.
+fn validate_results_message_template(template: &str) -> Result<(), String> {
+    if template.trim().is_empty() {
+        return Err("Template must not be empty".to_string());
+    }
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let after = &rest[start + 1..];
+        let end = after
+            .find('}')
+            .ok_or_else(|| "Unclosed '{' in template".to_string())?;
+        let name = &after[..end];
+        if !RESULTS_MESSAGE_PLACEHOLDERS.contains(&name) {
+            return Err(format!(
+                "Unknown placeholder '{{{}}}'; use {}",
+                name,
+                RESULTS_MESSAGE_PLACEHOLDERS
+                    .iter()
+                    .map(|p| format!("{{{}}}", p))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+        rest = &after[end + 1..];
+    }
+    if rest.contains('}') {
+        return Err("Unmatched '}' in template".to_string());
+    }
+    Ok(())
+}
+
+/// Body of a shared-results message, using the saved template when set.
+pub(crate) fn render_results_message(
+    step_name: &str,
+    file_count: usize,
+    flow_name: &str,
+) -> String {
+    let template = get_settings()
+        .ok()
+        .and_then(|s| s.results_message_template)
+        .filter(|t| validate_results_message_template(t).is_ok())
+        .unwrap_or_else(|| DEFAULT_RESULTS_MESSAGE_TEMPLATE.to_string());
+    // Single pass so a step or flow name containing `{...}` isn't expanded again.
+    let mut body = String::with_capacity(template.len());
+    let mut rest = template.as_str();
+    while let Some(start) = rest.find('{') {
+        body.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let end = after.find('}').unwrap_or(0);
+        match &after[..end] {
+            "step_name" => body.push_str(step_name),
+            "file_count" => body.push_str(&file_count.to_string()),
+            "flow_name" => body.push_str(flow_name),
+            other => {
+                body.push('{');
+                body.push_str(other);
+                body.push('}');
+            }
+        }
+        rest = &after[end + 1..];
+    }
+    body.push_str(rest);
+    body
+}
+
+#[tauri::command]
+pub fn get_results_message_template() -> Result<String, String> {
+    Ok(get_settings()?
+        .results_message_template
+        .unwrap_or_else(|| DEFAULT_RESULTS_MESSAGE_TEMPLATE.to_string()))
+}
+
+/// Save the wording for shared-results messages. Supports `{step_name}`,
+/// `{file_count}` and `{flow_name}`; None restores the default.
+#[tauri::command]
+pub fn set_results_message_template(template: Option<String>) -> Result<String, String> {
+    let template = template.filter(|t| !t.trim().is_empty());
+    if let Some(template) = &template {
+        validate_results_message_template(template)?;
+    }
+    let mut settings = get_settings()?;
+    settings.results_message_template = template;
+    save_settings(settings)?;
+    crate::desktop_log!("💬 Updated results message template");
+    get_results_message_template()
+}
+
+const SETTINGS_PROFILE_VERSION: u32 = 1;
+
+/// Settings that identify this install or grant access; never exported or imported.
+/// Webhook URLs often embed a secret token in the path or query.
+const SETTINGS_PROFILE_EXCLUDED: &[&str] = &[
+    "email",
+    "ai_api_token",
+    "agent_bridge_token",
+    "run_completion_webhook_url",
+];
+
+/// Binary paths: a missing file on the importing machine is a warning, not a failure.
+const SETTINGS_PROFILE_BINARY_KEYS: &[&str] =
+    &["docker_path", "java_path", "syftbox_path", "biovault_path"];
+
+const SETTINGS_PROFILE_DIR_KEYS: &[&str] = &["work_dir_root", "results_dir_root"];
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct SettingsProfileFile {
+    version: u32,
+    exported_at: String,
+    settings: serde_json::Map<String, Value>,
+    /// Custom file type detection rules (the analyzer's detection config).
+    #[serde(default)]
+    data_types: Vec<SettingsProfileDataType>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct SettingsProfileDataType {
+    name: String,
+    rules: super::files::DataTypeDetectionRules,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SkippedSetting {
+    pub key: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct SettingsProfileImportResult {
+    pub applied: Vec<String>,
+    pub unchanged: Vec<String>,
+    pub skipped: Vec<SkippedSetting>,
+    pub warnings: Vec<String>,
+}
+
+/// Write the current settings and custom data type detection rules to a portable
+/// JSON profile (secrets and identity excluded). The container runtime and log
+/// rotation are not settings in this app (the runtime comes from
+/// `BIOVAULT_CONTAINER_RUNTIME` or is detected per run, and logs are not
+/// rotated), so a profile has nothing to carry for them.
+#[tauri::command]
+pub fn export_settings_profile(
+    state: tauri::State<AppState>,
+    dest_path: String,
+) -> Result<String, String> {
+    let settings = get_settings()?;
+    let mut map = match serde_json::to_value(&settings)
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?
+    {
+        Value::Object(map) => map,
+        _ => return Err("Failed to serialize settings".to_string()),
+    };
+    for key in SETTINGS_PROFILE_EXCLUDED {
+        map.remove(*key);
+    }
+    // get_settings decorates env-provided paths for display; don't export that.
+    if env::var_os("BIOVAULT_PATH").is_some() {
+        map.remove("biovault_path");
+    }
+
+    let data_types = {
+        let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+        super::files::load_custom_data_types(&db)?
+    };
+
+    let profile = SettingsProfileFile {
+        version: SETTINGS_PROFILE_VERSION,
+        exported_at: chrono::Utc::now().to_rfc3339(),
+        settings: map,
+        data_types: data_types
+            .into_iter()
+            .map(|t| SettingsProfileDataType {
+                name: t.name,
+                rules: t.rules,
+            })
+            .collect(),
+    };
+    let dest = PathBuf::from(dest_path.trim());
+    if let Some(parent) = dest.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    let json = serde_json::to_string_pretty(&profile)
+        .map_err(|e| format!("Failed to serialize settings profile: {}", e))?;
+    fs::write(&dest, json).map_err(|e| format!("Failed to write settings profile: {}", e))?;
+
+    crate::desktop_log!("⚙️ Exported settings profile to {}", dest.display());
+    Ok(dest.to_string_lossy().to_string())
+}
+
+/// Apply a profile written by `export_settings_profile`. Each key is applied on its
+/// own so one bad or unknown value doesn't block the rest.
+#[tauri::command]
+pub fn import_settings_profile(
+    state: tauri::State<AppState>,
+    path: String,
+) -> Result<SettingsProfileImportResult, String> {
+    let content =
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read settings profile: {}", e))?;
+    let profile: SettingsProfileFile = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse settings profile: {}", e))?;
+    if profile.version > SETTINGS_PROFILE_VERSION {
+        return Err(format!(
+            "Settings profile version {} is newer than this app supports ({})",
+            profile.version, SETTINGS_PROFILE_VERSION
+        ));
+    }
+
+    let current = serde_json::to_value(get_settings()?)
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+    let mut merged = current.clone();
+    let mut result = SettingsProfileImportResult::default();
+
+    let mut keys: Vec<&String> = profile.settings.keys().collect();
+    keys.sort();
+    for key in keys {
+        let value = &profile.settings[key];
+        let skip = |reason: &str| SkippedSetting {
+            key: key.clone(),
+            reason: reason.to_string(),
+        };
+        if SETTINGS_PROFILE_EXCLUDED.contains(&key.as_str()) {
+            result
+                .skipped
+                .push(skip("not imported (identity or secret)"));
+            continue;
+        }
+        if current.get(key).unwrap_or(&Value::Null) == value {
+            result.unchanged.push(key.clone());
+            continue;
+        }
+
+        // Round-trip through Settings to reject unknown keys and wrongly typed values.
+        let mut candidate = merged.clone();
+        candidate[key.as_str()] = value.clone();
+        let round_trip = serde_json::from_value::<Settings>(candidate.clone())
+            .ok()
+            .and_then(|s| serde_json::to_value(s).ok());
+        let Some(round_trip) = round_trip else {
+            result.skipped.push(skip("invalid value"));
+            continue;
+        };
+        if round_trip.get(key).unwrap_or(&Value::Null) != value {
+            result.skipped.push(skip("unknown setting"));
+            continue;
+        }
+
+        if let Some(path) = value.as_str().filter(|p| !p.trim().is_empty()) {
+            let is_binary = SETTINGS_PROFILE_BINARY_KEYS.contains(&key.as_str());
+            let is_dir = SETTINGS_PROFILE_DIR_KEYS.contains(&key.as_str());
+            // Bare command names like "bv" are resolved via PATH at runtime.
+            let looks_like_path = path.contains('/') || path.contains('\\');
+            if ((is_binary && looks_like_path) || is_dir) && !Path::new(path).exists() {
+                result
+                    .warnings
+                    .push(format!("{}: {} does not exist on this machine", key, path));
+            }
+        }
+
+        merged = candidate;
+        result.applied.push(key.clone());
+    }
+
+    if !result.applied.is_empty() {
+        let settings: Settings = serde_json::from_value(merged)
+            .map_err(|e| format!("Failed to parse merged settings: {}", e))?;
+        save_settings(settings)?;
+    }
+
+    if !profile.data_types.is_empty() {
+        let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+        let existing: std::collections::HashMap<String, Value> =
+            super::files::load_custom_data_types(&db)?
+                .into_iter()
+                .map(|t| {
+                    let rules = serde_json::to_value(&t.rules).unwrap_or(Value::Null);
+                    (t.name.to_lowercase(), rules)
+                })
+                .collect();
+        for data_type in profile.data_types {
+            let key = format!("data_type:{}", data_type.name);
+            let rules = serde_json::to_value(&data_type.rules).unwrap_or(Value::Null);
+            if existing.get(&data_type.name.trim().to_lowercase()) == Some(&rules) {
+                result.unchanged.push(key);
+                continue;
+            }
+            match super::files::save_custom_data_type(&db, &data_type.name, data_type.rules) {
+                Ok(_) => result.applied.push(key),
+                Err(e) => result.skipped.push(SkippedSetting { key, reason: e }),
+            }
+        }
+    }
+
+    crate::desktop_log!(
+        "⚙️ Imported settings profile {}: {} applied, {} skipped, {} warnings",
+        path,
+        result.applied.len(),
+        result.skipped.len(),
+        result.warnings.len()
+    );
+    Ok(result)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::private_key_is_readable_for_home_and_email;
+    use super::{
+        check_run_root, clamp_queue_workers, private_key_is_readable_for_home_and_email,
+        validate_results_message_template, DEFAULT_RESULTS_MESSAGE_TEMPLATE,
+    };
     use std::fs;
     use tempfile::TempDir;
 
@@ -1184,4 +1934,36 @@ mod tests {
             .expect("valid check should not error");
         assert!(valid);
     }
+
+    #[test]
+    fn run_root_check_creates_missing_dir_and_reports_writable() {
+        let temp = TempDir::new().expect("temp dir");
+        let root = temp.path().join("scratch").join("runs");
+
+        let status = check_run_root(&root, true);
+        assert!(root.is_dir());
+        assert!(status.writable);
+        assert!(status.custom);
+        assert!(fs::read_dir(&root).expect("read root").next().is_none());
+    }
+
+    #[test]
+    fn results_message_template_rejects_unknown_placeholders() {
+        assert!(validate_results_message_template(DEFAULT_RESULTS_MESSAGE_TEMPLATE).is_ok());
+        assert!(validate_results_message_template("{flow_name}: {file_count} files").is_ok());
+        assert!(validate_results_message_template("{step}").is_err());
+        assert!(validate_results_message_template("broken {step_name").is_err());
+        assert!(validate_results_message_template("stray }").is_err());
+        assert!(validate_results_message_template("   ").is_err());
+    }
+
+    #[test]
+    fn queue_workers_default_to_half_the_cpus_and_stay_in_range() {
+        assert_eq!(clamp_queue_workers(None, 8), 4);
+        assert_eq!(clamp_queue_workers(None, 1), 1);
+        assert_eq!(clamp_queue_workers(None, 64), 8);
+        assert_eq!(clamp_queue_workers(Some(0), 8), 1);
+        assert_eq!(clamp_queue_workers(Some(3), 2), 3);
+        assert_eq!(clamp_queue_workers(Some(32), 2), 8);
+    }
 }
@@ -20,8 +20,18 @@ static LAST_SUBSCRIPTION_404_LOG: AtomicU64 = AtomicU64::new(0);
 static LAST_QUEUE_POLL_LOG: AtomicU64 = AtomicU64::new(0);
 static LAST_CONTROL_PLANE_OK_LOG: AtomicU64 = AtomicU64::new(0);
 static LAST_KNOWN_WS_CONNECTED: AtomicBool = AtomicBool::new(false);
+static LAST_UPLOAD_BYTES_SAMPLE: Mutex<Option<(u64, Instant)>> = Mutex::new(None);
+/// Upload cap in kbps (0 = unlimited), mirrored from settings for the pacer.
+static UPLOAD_BANDWIDTH_LIMIT_KBPS: AtomicU64 = AtomicU64::new(0);
+/// Uploads the bandwidth pacer paused and still has to resume.
+static PACED_UPLOADS: once_cell::sync::Lazy<Mutex<std::collections::HashSet<String>>> =
+    once_cell::sync::Lazy::new(|| Mutex::new(std::collections::HashSet::new()));
 static CONTROL_PLANE_LOG: once_cell::sync::Lazy<Mutex<Vec<ControlPlaneLogEntry>>> =
     once_cell::sync::Lazy::new(|| Mutex::new(Vec::new()));
+/// Last reported `uploaded_bytes` per upload id, so progress events only fire on change.
+static LAST_UPLOAD_FILE_PROGRESS: once_cell::sync::Lazy<
+    Mutex<std::collections::HashMap<String, i64>>,
+> = once_cell::sync::Lazy::new(|| Mutex::new(std::collections::HashMap::new()));
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ControlPlaneLogEntry {
@@ -562,6 +572,8 @@ pub struct SyftBoxRuntimeUploads {
 
 #[derive(Debug, Clone, Serialize)]
 pub struct SyftBoxDiagnostics {
+    pub app_version: String,
+    pub cli_version: String,
     pub running: bool,
     pub mode: String,
     pub backend: String,
@@ -636,6 +648,49 @@ struct PeerLinkFrame {
     payload_len: usize,
 }
 
+const HOTLINK_TRANSPORT_MODES: &[&str] = &["quic-only", "quic-pref", "ws-only"];
+/// Mode the SyftBox client uses when nothing is configured.
+const DEFAULT_HOTLINK_TRANSPORT_MODE: &str = "quic-pref";
+const HOTLINK_QUIC_ENV: &str = "SYFTBOX_HOTLINK_QUIC";
+const HOTLINK_QUIC_ONLY_ENV: &str = "SYFTBOX_HOTLINK_QUIC_ONLY";
+
+/// Whether the QUIC switches were already set when the app started (dev and
+/// test scripts pin them). Forced by `capture_hotlink_env_preset` at the top
+/// of `run()`, before any defaults are written.
+static HOTLINK_ENV_PRESET: once_cell::sync::Lazy<bool> = once_cell::sync::Lazy::new(|| {
+    std::env::var_os(HOTLINK_QUIC_ENV).is_some()
+        || std::env::var_os(HOTLINK_QUIC_ONLY_ENV).is_some()
+});
+
+pub(crate) fn capture_hotlink_env_preset() {
+    once_cell::sync::Lazy::force(&HOTLINK_ENV_PRESET);
+}
+
+/// Map an explicitly chosen hotlink transport mode onto the SyftBox client env
+/// switches. Without a choice, or when the environment already pins them,
+/// nothing is changed.
+fn apply_hotlink_transport_mode_env(mode: Option<&str>) {
+    let Some(mode) = mode else {
+        return;
+    };
+    if *HOTLINK_ENV_PRESET {
+        crate::desktop_log!(
+            "ℹ️ {} / {} set in the environment; ignoring hotlink transport mode '{}'",
+            HOTLINK_QUIC_ENV,
+            HOTLINK_QUIC_ONLY_ENV,
+            mode
+        );
+        return;
+    }
+    let (quic, quic_only) = match mode {
+        "quic-only" => ("1", "1"),
+        "ws-only" => ("0", "0"),
+        _ => ("1", "0"),
+    };
+    std::env::set_var(HOTLINK_QUIC_ENV, quic);
+    std::env::set_var(HOTLINK_QUIC_ONLY_ENV, quic_only);
+}
+
 fn set_default_env_var_if_unset(key: &str, value: &str) {
     if std::env::var_os(key).is_none() {
         std::env::set_var(key, value);
@@ -660,6 +715,15 @@ fn apply_syftbox_fast_mode_defaults() {
     set_default_env_var_if_unset("SYFTBOX_HOTLINK_DEBUG", "0");
 }
 
+/// Env the SyftBox client reads at start: fast-mode defaults plus the
+/// configured hotlink transport mode. Every path that starts the client runs this.
+fn prepare_syftbox_client_env() {
+    apply_syftbox_fast_mode_defaults();
+    if let Ok(settings) = crate::commands::settings::get_settings() {
+        apply_hotlink_transport_mode_env(settings.hotlink_transport_mode.as_deref());
+    }
+}
+
 fn resolve_turn_target(server_url: &str) -> Result<(String, u16, String), String> {
     let trimmed = server_url.trim();
     if trimmed.is_empty() {
@@ -1909,7 +1973,7 @@ pub async fn syftbox_submit_otp(
             // Restart the local SyftBox daemon so it picks up fresh auth tokens.
             let restart_runtime = runtime.clone();
             tauri::async_runtime::spawn_blocking(move || {
-                apply_syftbox_fast_mode_defaults();
+                prepare_syftbox_client_env();
                 if let Err(e) = syftctl::stop_syftbox(&restart_runtime) {
                     crate::desktop_log!("ℹ️ Failed to stop SyftBox after auth: {}", e);
                 }
@@ -1948,6 +2012,251 @@ pub fn check_syftbox_auth() -> Result<bool, String> {
     Ok(is_authenticated)
 }
 
+/// Warn this long before the access token expires.
+const ACCESS_TOKEN_REFRESH_WINDOW_SECS: i64 = 10 * 60;
+/// Warn this long before the refresh token expires (re-login needed after that).
+const REFRESH_TOKEN_WARNING_WINDOW_SECS: i64 = 3 * 24 * 60 * 60;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SyftBoxTokenExpiry {
+    pub present: bool,
+    pub expires_at: Option<String>,
+    /// Negative once expired; None when the token carries no `exp` claim
+    pub expires_in_secs: Option<i64>,
+    pub expired: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SyftBoxTokenStatus {
+    pub access_token: SyftBoxTokenExpiry,
+    pub refresh_token: SyftBoxTokenExpiry,
+    /// Access token expires within the refresh window (or already has)
+    pub refresh_imminent: bool,
+    /// Refresh token is missing or expired; only a new OTP sign-in helps
+    pub reauth_required: bool,
+    pub warning: Option<String>,
+}
+
+/// Tokens the SyftBox client is using. The daemon rewrites config.json when it
+/// refreshes, so those values win over the copies in BioVault's config.
+fn current_syftbox_tokens() -> (Option<String>, Option<String>) {
+    let mut access = None;
+    let mut refresh = None;
+    if let Ok(runtime) = load_runtime_config() {
+        if let Ok(raw) = fs::read_to_string(&runtime.config_path) {
+            if let Ok(val) = serde_json::from_str::<Value>(&raw) {
+                let field = |key: &str| {
+                    val.get(key)
+                        .and_then(|v| v.as_str())
+                        .map(|t| t.trim().to_string())
+                        .filter(|t| !t.is_empty())
+                };
+                access = field("client_token");
+                refresh = field("refresh_token");
+            }
+        }
+    }
+    if let Some(creds) = biovault::config::Config::load()
+        .ok()
+        .and_then(|cfg| cfg.syftbox_credentials)
+    {
+        access = access.or(creds.access_token.filter(|t| !t.trim().is_empty()));
+        refresh = refresh.or(creds.refresh_token.filter(|t| !t.trim().is_empty()));
+    }
+    (access, refresh)
+}
+
+/// `exp` claim of a JWT, read without verifying the signature.
+fn jwt_expiry(token: &str) -> Option<i64> {
+    use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+    let payload = token.split('.').nth(1)?;
+    let bytes = URL_SAFE_NO_PAD.decode(payload.trim_end_matches('=')).ok()?;
+    let claims: Value = serde_json::from_slice(&bytes).ok()?;
+    claims.get("exp").and_then(|v| v.as_i64())
+}
+
+fn token_expiry(token: Option<&str>, now: i64) -> SyftBoxTokenExpiry {
+    let exp = token.and_then(jwt_expiry);
+    SyftBoxTokenExpiry {
+        present: token.is_some(),
+        expires_at: exp
+            .and_then(|ts| DateTime::<Utc>::from_timestamp(ts, 0))
+            .map(|dt| dt.to_rfc3339()),
+        expires_in_secs: exp.map(|ts| ts - now),
+        expired: exp.is_some_and(|ts| ts <= now),
+    }
+}
+
+pub(crate) fn syftbox_token_status() -> SyftBoxTokenStatus {
+    let (access, refresh) = current_syftbox_tokens();
+    let now = Utc::now().timestamp();
+    let access_token = token_expiry(access.as_deref(), now);
+    let refresh_token = token_expiry(refresh.as_deref(), now);
+
+    let refresh_imminent = !access_token.present
+        || access_token
+            .expires_in_secs
+            .is_some_and(|secs| secs <= ACCESS_TOKEN_REFRESH_WINDOW_SECS);
+    let reauth_required = !refresh_token.present || refresh_token.expired;
+
+    let warning = if reauth_required {
+        Some("SyftBox sign-in has expired; sync is stopped until you sign in again.".to_string())
+    } else if let Some(secs) = refresh_token
+        .expires_in_secs
+        .filter(|secs| *secs <= REFRESH_TOKEN_WARNING_WINDOW_SECS)
+    {
+        Some(format!(
+            "SyftBox sign-in expires in {}h; sign in again to keep syncing.",
+            secs / 3600
+        ))
+    } else if access_token.expired {
+        Some("SyftBox access token has expired and has not been refreshed yet.".to_string())
+    } else {
+        None
+    };
+
+    SyftBoxTokenStatus {
+        access_token,
+        refresh_token,
+        refresh_imminent,
+        reauth_required,
+        warning,
+    }
+}
+
+/// Expiry of the SyftBox access and refresh tokens. Never returns the tokens.
+#[tauri::command]
+pub fn get_syftbox_token_status() -> Result<SyftBoxTokenStatus, String> {
+    let status = syftbox_token_status();
+    if let Some(warning) = &status.warning {
+        crate::desktop_log!("⚠️ {}", warning);
+    }
+    Ok(status)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SyftBoxTokenRefreshResult {
+    pub refreshed: bool,
+    /// The refresh token was rejected or expired; route the user to OTP sign-in
+    pub reauth_required: bool,
+    pub error: Option<String>,
+    pub status: SyftBoxTokenStatus,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SyftBoxRefreshResponse {
+    access_token: String,
+    refresh_token: Option<String>,
+}
+
+/// Store refreshed tokens in BioVault's config and the client's config.json.
+fn persist_refreshed_tokens(access_token: &str, refresh_token: &str) -> Result<(), String> {
+    let mut cfg =
+        biovault::config::Config::load().map_err(|e| format!("Failed to load config: {}", e))?;
+    let creds = cfg
+        .syftbox_credentials
+        .get_or_insert_with(biovault::config::SyftboxCredentials::default);
+    creds.access_token = Some(access_token.to_string());
+    creds.refresh_token = Some(refresh_token.to_string());
+    let config_path = biovault::config::Config::get_config_path().map_err(|e| e.to_string())?;
+    cfg.save(config_path)
+        .map_err(|e| format!("Failed to save config: {}", e))?;
+
+    if let Ok(runtime) = load_runtime_config() {
+        if let Ok(raw) = fs::read_to_string(&runtime.config_path) {
+            if let Ok(mut val) = serde_json::from_str::<Value>(&raw) {
+                val["client_token"] = json!(access_token);
+                val["refresh_token"] = json!(refresh_token);
+                fs::write(
+                    &runtime.config_path,
+                    serde_json::to_string_pretty(&val).map_err(|e| e.to_string())?,
+                )
+                .map_err(|e| format!("Failed to update SyftBox config.json: {}", e))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn request_token_refresh(
+    refresh_token: &str,
+) -> Result<SyftBoxRefreshResponse, (bool, String)> {
+    let server_url = biovault::config::Config::load()
+        .ok()
+        .and_then(|cfg| cfg.syftbox_credentials)
+        .and_then(|creds| creds.server_url)
+        .filter(|url| !url.trim().is_empty())
+        .unwrap_or_else(|| crate::types::DEFAULT_SYFTBOX_SERVER_URL.to_string());
+    let url = format!("{}/auth/refresh", server_url.trim_end_matches('/'));
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(15))
+        .build()
+        .map_err(|e| (false, format!("Failed to build HTTP client: {}", e)))?;
+    let resp = client
+        .post(&url)
+        .json(&json!({ "refreshToken": refresh_token }))
+        .send()
+        .await
+        .map_err(|e| (false, format!("Failed to reach {}: {}", url, e)))?;
+    let code = resp.status();
+    if code.as_u16() == 401 || code.as_u16() == 403 {
+        return Err((true, "SyftBox rejected the refresh token".to_string()));
+    }
+    if !code.is_success() {
+        return Err((false, format!("Token refresh failed: HTTP {}", code)));
+    }
+    resp.json()
+        .await
+        .map_err(|e| (false, format!("Failed to decode refresh response: {}", e)))
+}
+
+/// Renew the SyftBox access token now using the stored refresh token, e.g.
+/// before leaving a long run unattended. Emits `syftbox:auth-status`.
+#[tauri::command]
+pub async fn refresh_syftbox_token(app: AppHandle) -> Result<SyftBoxTokenRefreshResult, String> {
+    use tauri::Emitter;
+
+    let before = syftbox_token_status();
+    let (_, refresh_token) = current_syftbox_tokens();
+    let outcome = match refresh_token.filter(|_| !before.reauth_required) {
+        None => Err((
+            true,
+            "SyftBox sign-in has expired; sign in again".to_string(),
+        )),
+        Some(refresh_token) => match request_token_refresh(&refresh_token).await {
+            Ok(tokens) => {
+                let new_refresh = tokens.refresh_token.unwrap_or(refresh_token);
+                persist_refreshed_tokens(&tokens.access_token, &new_refresh).map_err(|e| (false, e))
+            }
+            Err(err) => Err(err),
+        },
+    };
+
+    let result = match outcome {
+        Ok(()) => {
+            crate::desktop_log!("🔑 SyftBox token refreshed");
+            SyftBoxTokenRefreshResult {
+                refreshed: true,
+                reauth_required: false,
+                error: None,
+                status: syftbox_token_status(),
+            }
+        }
+        Err((reauth_required, error)) => {
+            crate::desktop_log!("⚠️ SyftBox token refresh failed: {}", error);
+            SyftBoxTokenRefreshResult {
+                refreshed: false,
+                reauth_required,
+                error: Some(error),
+                status: syftbox_token_status(),
+            }
+        }
+    };
+    let _ = app.emit("syftbox:auth-status", &result);
+    Ok(result)
+}
+
 #[tauri::command]
 pub fn get_syftbox_config_info() -> Result<SyftBoxConfigInfo, String> {
     crate::desktop_log!("🔍 get_syftbox_config_info called");
@@ -2055,6 +2364,201 @@ pub fn get_syftbox_config_info() -> Result<SyftBoxConfigInfo, String> {
     })
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct SyftBoxDataDirCandidate {
+    pub path: String,
+    /// current | env | biovault_config | syftbox_config | standalone_config | default | home
+    pub source: String,
+    pub exists: bool,
+    pub has_datasites: bool,
+    /// The signed-in email has a datasite directory here
+    pub has_own_datasite: bool,
+    pub valid: bool,
+    pub current: bool,
+}
+
+fn data_dir_from_config_file(path: &Path) -> Option<String> {
+    let raw = fs::read_to_string(path).ok()?;
+    let val: Value = serde_json::from_str(&raw).ok()?;
+    val.get("data_dir")
+        .and_then(|v| v.as_str())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+fn inspect_syftbox_data_dir(path: &Path, email: Option<&str>) -> (bool, bool, bool) {
+    let exists = path.is_dir();
+    let datasites = path.join("datasites");
+    let has_datasites = datasites.is_dir();
+    let has_own_datasite = email
+        .filter(|e| !e.trim().is_empty())
+        .map(|e| datasites.join(e).is_dir())
+        .unwrap_or(false);
+    (exists, has_datasites, has_own_datasite)
+}
+
+/// A SyftBox data dir holds a `datasites/` tree; when we know the user's email
+/// their own datasite should be in it too.
+fn validate_syftbox_data_dir(path: &Path, email: Option<&str>) -> Result<(), String> {
+    let (exists, has_datasites, has_own_datasite) = inspect_syftbox_data_dir(path, email);
+    if !exists {
+        return Err(format!("Directory does not exist: {}", path.display()));
+    }
+    if !has_datasites {
+        return Err(format!(
+            "{} does not look like a SyftBox data directory (no datasites/ folder)",
+            path.display()
+        ));
+    }
+    if let Some(email) = email.filter(|e| !e.trim().is_empty()) {
+        if !has_own_datasite {
+            return Err(format!("{} has no datasite for {}", path.display(), email));
+        }
+    }
+    Ok(())
+}
+
+fn syftbox_identity_email(cfg: &biovault::config::Config) -> Option<String> {
+    cfg.syftbox_credentials
+        .as_ref()
+        .and_then(|c| c.email.clone())
+        .filter(|e| !e.trim().is_empty())
+        .or_else(|| Some(cfg.email.clone()).filter(|e| !e.trim().is_empty()))
+}
+
+/// Probe the places a SyftBox data directory usually lives and report which
+/// ones look valid, current resolution first.
+#[tauri::command]
+pub fn detect_syftbox_data_dir() -> Result<Vec<SyftBoxDataDirCandidate>, String> {
+    let config = biovault::config::Config::load().ok();
+    let email = config.as_ref().and_then(syftbox_identity_email);
+    let current = config
+        .as_ref()
+        .and_then(|cfg| cfg.get_syftbox_data_dir().ok())
+        .map(|p| p.to_string_lossy().to_string());
+
+    let mut probes: Vec<(String, String)> = Vec::new();
+    if let Some(dir) = current.clone() {
+        probes.push((dir, "current".to_string()));
+    }
+    if let Ok(dir) = env::var("SYFTBOX_DATA_DIR") {
+        probes.push((dir, "env".to_string()));
+    }
+    if let Some(dir) = config
+        .as_ref()
+        .and_then(|cfg| cfg.syftbox_credentials.as_ref())
+        .and_then(|c| c.data_dir.clone())
+    {
+        probes.push((dir, "biovault_config".to_string()));
+    }
+    if let Some(dir) = config
+        .as_ref()
+        .and_then(|cfg| cfg.get_syftbox_config_path().ok())
+        .and_then(|p| data_dir_from_config_file(&p))
+    {
+        probes.push((dir, "syftbox_config".to_string()));
+    }
+    if let Some(home) = dirs::home_dir() {
+        for standalone in [
+            home.join(".syftbox").join("config.json"),
+            home.join(".config").join("syftbox").join("config.json"),
+        ] {
+            if let Some(dir) = data_dir_from_config_file(&standalone) {
+                probes.push((dir, "standalone_config".to_string()));
+            }
+        }
+    }
+    if let Ok(dir) = biovault::config::Config::default_syftbox_data_dir() {
+        probes.push((dir.to_string_lossy().to_string(), "default".to_string()));
+    }
+    if let Some(home) = dirs::home_dir() {
+        probes.push((
+            home.join("SyftBox").to_string_lossy().to_string(),
+            "home".to_string(),
+        ));
+        if let Some(desktop) = dirs::desktop_dir() {
+            probes.push((
+                desktop.join("SyftBox").to_string_lossy().to_string(),
+                "home".to_string(),
+            ));
+        }
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    let candidates = probes
+        .into_iter()
+        .filter(|(path, _)| !path.trim().is_empty() && seen.insert(path.clone()))
+        .map(|(path, source)| {
+            let (exists, has_datasites, has_own_datasite) =
+                inspect_syftbox_data_dir(Path::new(&path), email.as_deref());
+            SyftBoxDataDirCandidate {
+                valid: exists && has_datasites && (email.is_none() || has_own_datasite),
+                current: current.as_deref() == Some(path.as_str()),
+                path,
+                source,
+                exists,
+                has_datasites,
+                has_own_datasite,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    crate::desktop_log!(
+        "🔍 SyftBox data dir probe: {} candidate(s), {} valid",
+        candidates.len(),
+        candidates.iter().filter(|c| c.valid).count()
+    );
+    Ok(candidates)
+}
+
+/// Pin the SyftBox data directory in the BioVault config (and the managed
+/// SyftBox config.json) after checking it looks like a SyftBox data dir.
+/// A running SyftBox client must be restarted to pick up the change.
+#[tauri::command]
+pub fn set_syftbox_data_dir(path: String) -> Result<SyftBoxDataDirCandidate, String> {
+    let mut cfg =
+        biovault::config::Config::load().map_err(|e| format!("Failed to load config: {}", e))?;
+    let email = syftbox_identity_email(&cfg);
+    let dir = Path::new(path.trim())
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve {}: {}", path.trim(), e))?;
+    validate_syftbox_data_dir(&dir, email.as_deref())?;
+    let dir_str = dir.to_string_lossy().to_string();
+
+    let mut creds = cfg.syftbox_credentials.clone().unwrap_or_default();
+    creds.data_dir = Some(dir_str.clone());
+    cfg.syftbox_credentials = Some(creds);
+    let config_path = biovault::config::Config::get_config_path().map_err(|e| e.to_string())?;
+    cfg.save(config_path)
+        .map_err(|e| format!("Failed to save config: {}", e))?;
+
+    if let Ok(syftbox_config_path) = cfg.get_syftbox_config_path() {
+        if let Ok(raw) = fs::read_to_string(&syftbox_config_path) {
+            if let Ok(mut val) = serde_json::from_str::<Value>(&raw) {
+                val["data_dir"] = json!(dir_str);
+                let pretty = serde_json::to_string_pretty(&val).map_err(|e| e.to_string())?;
+                fs::write(&syftbox_config_path, pretty)
+                    .map_err(|e| format!("Failed to update SyftBox config: {}", e))?;
+            }
+        }
+    }
+    // Resolution in this process prefers the env var, so keep it in step.
+    env::set_var("SYFTBOX_DATA_DIR", &dir_str);
+
+    crate::desktop_log!("📁 SyftBox data dir pinned to {}", dir_str);
+    let (exists, has_datasites, has_own_datasite) =
+        inspect_syftbox_data_dir(&dir, email.as_deref());
+    Ok(SyftBoxDataDirCandidate {
+        path: dir_str,
+        source: "biovault_config".to_string(),
+        exists,
+        has_datasites,
+        has_own_datasite,
+        valid: true,
+        current: true,
+    })
+}
+
 #[tauri::command]
 pub fn get_syftbox_state() -> Result<SyftBoxState, String> {
     let (running, mode, mut log_path, error, pid, client_url, tx_bytes, rx_bytes) =
@@ -2163,7 +2667,7 @@ fn get_tx_rx_bytes(client_url: &Option<String>) -> (u64, u64) {
 
 #[tauri::command]
 pub fn start_syftbox_client() -> Result<SyftBoxState, String> {
-    apply_syftbox_fast_mode_defaults();
+    prepare_syftbox_client_env();
 
     let runtime = load_runtime_config()?;
     ensure_syftbox_config(&runtime)?;
@@ -2391,6 +2895,8 @@ pub fn get_syftbox_diagnostics() -> Result<SyftBoxDiagnostics, String> {
         .unwrap_or_default();
 
     Ok(SyftBoxDiagnostics {
+        app_version: crate::commands::settings::get_app_version(),
+        cli_version: crate::commands::settings::get_embedded_cli_version(),
         running,
         mode,
         backend: syftbox_backend_label(),
@@ -2596,13 +3102,21 @@ pub async fn syftbox_subscriptions_discovery() -> Result<Vec<SyftBoxDiscoveryFil
 
 #[tauri::command]
 pub async fn syftbox_upload_action(id: String, action: String) -> Result<(), String> {
+    // An explicit action from the user takes the upload out of the pacer's hands.
+    if let Ok(mut paced) = PACED_UPLOADS.lock() {
+        paced.remove(&id);
+    }
+    send_upload_action(&id, &action).await
+}
+
+async fn send_upload_action(id: &str, action: &str) -> Result<(), String> {
     let cfg = load_syftbox_client_config()?;
     let client = reqwest::Client::builder()
         .timeout(Duration::from_secs(10))
         .build()
         .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
 
-    let (path, method) = match action.as_str() {
+    let (path, method) = match action {
         "pause" => (format!("/v1/uploads/{}/pause", id), "POST"),
         "resume" => (format!("/v1/uploads/{}/resume", id), "POST"),
         "restart" => (format!("/v1/uploads/{}/restart", id), "POST"),
@@ -2616,7 +3130,7 @@ pub async fn syftbox_upload_action(id: String, action: String) -> Result<(), Str
         path.trim_start_matches('/')
     );
 
-    let request = match action.as_str() {
+    let request = match action {
         "cancel" => client.delete(&url),
         _ => client.post(&url),
     }
@@ -2642,6 +3156,444 @@ pub async fn syftbox_upload_action(id: String, action: String) -> Result<(), Str
     }
 }
 
+const ACTIVE_UPLOAD_STATES: &[&str] = &["pending", "uploading", "paused", "retrying"];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ActiveUpload {
+    pub id: String,
+    pub key: String,
+    pub file_path: String,
+    pub state: String,
+    pub bytes_transferred: i64,
+    pub total_bytes: i64,
+    pub percent: f64,
+    pub error: Option<String>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<SyftBoxUploadInfo> for ActiveUpload {
+    fn from(upload: SyftBoxUploadInfo) -> Self {
+        let percent = if upload.size > 0 {
+            (upload.uploaded_bytes as f64 / upload.size as f64 * 100.0).clamp(0.0, 100.0)
+        } else {
+            upload.progress
+        };
+        ActiveUpload {
+            file_path: upload
+                .local_path
+                .clone()
+                .filter(|p| !p.is_empty())
+                .unwrap_or_else(|| upload.key.clone()),
+            id: upload.id,
+            key: upload.key,
+            state: upload.state,
+            bytes_transferred: upload.uploaded_bytes,
+            total_bytes: upload.size,
+            percent: (percent * 10.0).round() / 10.0,
+            error: upload.error,
+            updated_at: upload.updated_at,
+        }
+    }
+}
+
+async fn fetch_active_uploads() -> Result<Vec<ActiveUpload>, String> {
+    let cfg = load_syftbox_client_config()?;
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+    let mut list =
+        cp_get::<SyftBoxUploadList>(&client, &cfg.client_url, "/v1/uploads/", &cfg.client_token)
+            .await?;
+    normalize_uploads(&mut list.uploads);
+
+    Ok(list
+        .uploads
+        .into_iter()
+        .filter(|u| ACTIVE_UPLOAD_STATES.contains(&u.state.to_lowercase().as_str()))
+        .map(ActiveUpload::from)
+        .collect())
+}
+
+/// Emit `syftbox:upload-file-progress` for every upload that moved since the last look.
+fn emit_upload_file_progress(app: &AppHandle, active: &[ActiveUpload]) {
+    use tauri::Emitter;
+
+    if let Ok(mut last) = LAST_UPLOAD_FILE_PROGRESS.lock() {
+        for upload in active {
+            if last.get(&upload.id) != Some(&upload.bytes_transferred) {
+                let _ = app.emit("syftbox:upload-file-progress", upload);
+            }
+        }
+        *last = active
+            .iter()
+            .map(|u| (u.id.clone(), u.bytes_transferred))
+            .collect();
+    }
+}
+
+/// Poll interval while uploads are in flight, and while waiting for one to start.
+const UPLOAD_PROGRESS_ACTIVE_POLL: Duration = Duration::from_secs(1);
+const UPLOAD_PROGRESS_IDLE_POLL: Duration = Duration::from_secs(5);
+
+static UPLOAD_PROGRESS_POLLER: AtomicBool = AtomicBool::new(false);
+
+/// Longest single pause the pacer imposes, so a burst can't stall uploads for long.
+const UPLOAD_PACING_MAX_PAUSE: Duration = Duration::from_secs(30);
+
+/// Enforces the upload bandwidth cap. The SyftBox client has no rate limit of
+/// its own, so when uploads ran faster than the cap since the last sample they
+/// are paused long enough to bring the average back down to it, then resumed.
+#[derive(Default)]
+struct UploadPacer {
+    last_bytes: std::collections::HashMap<String, i64>,
+    last_at: Option<Instant>,
+    paused_until: Option<Instant>,
+}
+
+impl UploadPacer {
+    async fn tick(&mut self, active: &[ActiveUpload]) {
+        let now = Instant::now();
+        let limit_kbps = UPLOAD_BANDWIDTH_LIMIT_KBPS.load(Ordering::SeqCst);
+
+        if let Some(until) = self.paused_until {
+            if limit_kbps != 0 && now < until {
+                return;
+            }
+            self.paused_until = None;
+            self.resume_paced().await;
+        }
+
+        let sent: i64 = active
+            .iter()
+            .map(|u| {
+                let prev = self
+                    .last_bytes
+                    .get(&u.id)
+                    .copied()
+                    .unwrap_or(u.bytes_transferred);
+                (u.bytes_transferred - prev).max(0)
+            })
+            .sum();
+        let elapsed = self.last_at.map(|at| now.duration_since(at).as_secs_f64());
+        self.last_bytes = active
+            .iter()
+            .map(|u| (u.id.clone(), u.bytes_transferred))
+            .collect();
+        self.last_at = Some(now);
+
+        let Some(elapsed) = elapsed.filter(|e| *e > 0.0) else {
+            return;
+        };
+        if limit_kbps == 0 || sent == 0 {
+            return;
+        }
+        let rate_kbps = sent as f64 * 8.0 / 1000.0 / elapsed;
+        if rate_kbps <= limit_kbps as f64 {
+            return;
+        }
+
+        let pause = Duration::from_secs_f64(elapsed * (rate_kbps / limit_kbps as f64 - 1.0))
+            .min(UPLOAD_PACING_MAX_PAUSE);
+        let mut paused = Vec::new();
+        for upload in active
+            .iter()
+            .filter(|u| u.state.eq_ignore_ascii_case("uploading"))
+        {
+            if send_upload_action(&upload.id, "pause").await.is_ok() {
+                paused.push(upload.id.clone());
+            }
+        }
+        if paused.is_empty() {
+            return;
+        }
+        if let Ok(mut paced) = PACED_UPLOADS.lock() {
+            paced.extend(paused);
+        }
+        self.paused_until = Some(now + pause);
+    }
+
+    async fn resume_paced(&mut self) {
+        let ids: Vec<String> = match PACED_UPLOADS.lock() {
+            Ok(mut paced) => paced.drain().collect(),
+            Err(_) => return,
+        };
+        for id in ids {
+            let _ = send_upload_action(&id, "resume").await;
+        }
+        // Bytes sent while paused don't exist; start the next window fresh.
+        self.last_at = None;
+    }
+}
+
+/// Background loop that emits per-file upload progress as uploads advance and
+/// paces them to the bandwidth cap, polling fast only while some are active.
+/// Started once at app setup.
+pub(crate) fn spawn_upload_progress_poller(app: AppHandle) {
+    if UPLOAD_PROGRESS_POLLER.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    if let Ok(settings) = crate::commands::settings::get_settings() {
+        UPLOAD_BANDWIDTH_LIMIT_KBPS
+            .store(settings.syftbox_upload_bandwidth_limit, Ordering::SeqCst);
+    }
+    tauri::async_runtime::spawn(async move {
+        let mut pacer = UploadPacer::default();
+        loop {
+            let mut interval = UPLOAD_PROGRESS_IDLE_POLL;
+            if SYFTBOX_RUNNING.load(Ordering::SeqCst) {
+                if let Ok(active) = fetch_active_uploads().await {
+                    emit_upload_file_progress(&app, &active);
+                    pacer.tick(&active).await;
+                    if !active.is_empty() {
+                        interval = UPLOAD_PROGRESS_ACTIVE_POLL;
+                    }
+                }
+            }
+            tokio::time::sleep(interval).await;
+        }
+    });
+}
+
+/// In-flight uploads from the SyftBox client with per-file byte progress.
+/// Progress events come from the background poller; this is a snapshot.
+#[tauri::command]
+pub async fn list_active_uploads() -> Result<Vec<ActiveUpload>, String> {
+    fetch_active_uploads().await
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UploadCancelResult {
+    pub cancelled: bool,
+    pub id: Option<String>,
+    pub file_path: Option<String>,
+    pub bytes_transferred: i64,
+}
+
+/// Abort one in-flight upload, matched by upload id, key or local path.
+/// Only the client's upload session is dropped; the file stays in place so the
+/// next sync pass can queue it again.
+#[tauri::command]
+pub async fn cancel_upload(
+    app: AppHandle,
+    file_path_or_id: String,
+) -> Result<UploadCancelResult, String> {
+    use tauri::Emitter;
+
+    let target = file_path_or_id.trim();
+    if target.is_empty() {
+        return Err("Upload id or file path is required".to_string());
+    }
+    let active = fetch_active_uploads().await?;
+    let Some(upload) = active.into_iter().find(|u| {
+        u.id == target || u.key == target || Path::new(&u.file_path) == Path::new(target)
+    }) else {
+        return Ok(UploadCancelResult {
+            cancelled: false,
+            id: None,
+            file_path: None,
+            bytes_transferred: 0,
+        });
+    };
+
+    syftbox_upload_action(upload.id.clone(), "cancel".to_string()).await?;
+    if let Ok(mut last) = LAST_UPLOAD_FILE_PROGRESS.lock() {
+        last.remove(&upload.id);
+    }
+
+    crate::desktop_log!(
+        "🛑 Cancelled SyftBox upload {} ({})",
+        upload.id,
+        upload.file_path
+    );
+    let result = UploadCancelResult {
+        cancelled: true,
+        id: Some(upload.id),
+        file_path: Some(upload.file_path),
+        bytes_transferred: upload.bytes_transferred,
+    };
+    let _ = app.emit("syftbox:upload-cancelled", &result);
+    Ok(result)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SyftBoxBandwidthStatus {
+    /// Configured cap in kbps (0 = unlimited)
+    pub limit_kbps: u64,
+    pub unlimited: bool,
+    /// Always false: the SyftBox client has no upload rate limit API, so the
+    /// desktop paces uploads itself by pausing and resuming them
+    pub applied_to_client: bool,
+    pub active_uploads: usize,
+    /// Uploads currently held back by the pacer
+    pub paced_uploads: usize,
+    /// Observed upload rate since the previous sample, if one exists
+    pub current_upload_kbps: Option<f64>,
+    pub error: Option<String>,
+}
+
+async fn collect_bandwidth_status(limit_kbps: u64) -> SyftBoxBandwidthStatus {
+    let mut status = SyftBoxBandwidthStatus {
+        limit_kbps,
+        unlimited: limit_kbps == 0,
+        applied_to_client: false,
+        active_uploads: 0,
+        paced_uploads: PACED_UPLOADS.lock().map(|p| p.len()).unwrap_or(0),
+        current_upload_kbps: None,
+        error: None,
+    };
+
+    let cfg = match load_syftbox_client_config() {
+        Ok(cfg) => cfg,
+        Err(err) => {
+            status.error = Some(err);
+            return status;
+        }
+    };
+    let client = match reqwest::Client::builder()
+        .timeout(Duration::from_secs(5))
+        .build()
+    {
+        Ok(c) => c,
+        Err(e) => {
+            status.error = Some(format!("Failed to create HTTP client: {}", e));
+            return status;
+        }
+    };
+
+    if let Ok(list) =
+        cp_get::<SyftBoxUploadList>(&client, &cfg.client_url, "/v1/uploads/", &cfg.client_token)
+            .await
+    {
+        status.active_uploads = list
+            .uploads
+            .iter()
+            .filter(|u| {
+                let state = u.state.to_lowercase();
+                state == "uploading" || state == "pending"
+            })
+            .count();
+    }
+
+    match cp_get::<SyftBoxStatus>(&client, &cfg.client_url, "/v1/status", &cfg.client_token).await {
+        Ok(s) => {
+            let runtime = s.runtime.unwrap_or_default();
+            let ws = runtime.websocket.unwrap_or_default();
+            let http = runtime.http.unwrap_or_default();
+            let sent = ws.bytes_sent_total.unwrap_or(0) + http.bytes_sent_total.unwrap_or(0);
+            let now = Instant::now();
+            if let Ok(mut last) = LAST_UPLOAD_BYTES_SAMPLE.lock() {
+                if let Some((prev_bytes, prev_at)) = *last {
+                    let elapsed = now.duration_since(prev_at).as_secs_f64();
+                    if elapsed > 0.0 && sent >= prev_bytes {
+                        let kbps = (sent - prev_bytes) as f64 * 8.0 / 1000.0 / elapsed;
+                        status.current_upload_kbps = Some((kbps * 10.0).round() / 10.0);
+                    }
+                }
+                *last = Some((sent, now));
+            }
+        }
+        Err(e) => status.error = Some(e),
+    }
+
+    status
+}
+
+/// Persist an upload bandwidth cap (kbps, 0 = unlimited). The upload poller
+/// enforces it from the next sample on; the status shows the observed upload
+/// rate next to it.
+#[tauri::command]
+pub async fn set_syftbox_bandwidth_limit(kbps: u64) -> Result<SyftBoxBandwidthStatus, String> {
+    let mut settings = crate::commands::settings::get_settings()?;
+    settings.syftbox_upload_bandwidth_limit = kbps;
+    crate::commands::settings::save_settings(settings)?;
+    UPLOAD_BANDWIDTH_LIMIT_KBPS.store(kbps, Ordering::SeqCst);
+
+    crate::desktop_log!(
+        "📶 SyftBox upload bandwidth limit set to {}",
+        if kbps == 0 {
+            "unlimited".to_string()
+        } else {
+            format!("{} kbps", kbps)
+        }
+    );
+
+    Ok(collect_bandwidth_status(kbps).await)
+}
+
+#[tauri::command]
+pub async fn get_syftbox_bandwidth_status() -> Result<SyftBoxBandwidthStatus, String> {
+    let settings = crate::commands::settings::get_settings()?;
+    Ok(collect_bandwidth_status(settings.syftbox_upload_bandwidth_limit).await)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HotlinkTransportModeStatus {
+    pub mode: String,
+    /// False while the client default is in use (no mode chosen in settings)
+    pub explicit: bool,
+    /// True when SYFTBOX_HOTLINK_QUIC(_ONLY) from the environment take precedence
+    pub env_override: bool,
+    pub available_modes: Vec<String>,
+    /// True when the running SyftBox client was restarted to apply the mode
+    pub client_restarted: bool,
+}
+
+#[tauri::command]
+pub fn get_hotlink_transport_mode() -> Result<HotlinkTransportModeStatus, String> {
+    let settings = crate::commands::settings::get_settings()?;
+    Ok(HotlinkTransportModeStatus {
+        explicit: settings.hotlink_transport_mode.is_some(),
+        mode: settings
+            .hotlink_transport_mode
+            .unwrap_or_else(|| DEFAULT_HOTLINK_TRANSPORT_MODE.to_string()),
+        env_override: *HOTLINK_ENV_PRESET,
+        available_modes: HOTLINK_TRANSPORT_MODES
+            .iter()
+            .map(|m| m.to_string())
+            .collect(),
+        client_restarted: false,
+    })
+}
+
+/// Persist the hotlink transport preference and restart a running client so it takes effect.
+#[tauri::command]
+pub fn set_hotlink_transport_mode(mode: String) -> Result<HotlinkTransportModeStatus, String> {
+    let mode = mode.trim().to_lowercase();
+    if !HOTLINK_TRANSPORT_MODES.contains(&mode.as_str()) {
+        return Err(format!(
+            "Unsupported hotlink transport mode '{}'. Expected one of: {}",
+            mode,
+            HOTLINK_TRANSPORT_MODES.join(", ")
+        ));
+    }
+
+    let mut settings = crate::commands::settings::get_settings()?;
+    let changed = settings.hotlink_transport_mode.as_deref() != Some(mode.as_str());
+    settings.hotlink_transport_mode = Some(mode.clone());
+    crate::commands::settings::save_settings(settings)?;
+    apply_hotlink_transport_mode_env(Some(&mode));
+    crate::desktop_log!("🔀 Hotlink transport mode set to {}", mode);
+
+    let mut client_restarted = false;
+    if changed && !*HOTLINK_ENV_PRESET && SYFTBOX_RUNNING.load(Ordering::SeqCst) {
+        crate::desktop_log!("🔄 Restarting SyftBox to apply hotlink transport mode");
+        start_syftbox_client()?;
+        client_restarted = true;
+    }
+
+    Ok(HotlinkTransportModeStatus {
+        mode,
+        explicit: true,
+        env_override: *HOTLINK_ENV_PRESET,
+        available_modes: HOTLINK_TRANSPORT_MODES
+            .iter()
+            .map(|m| m.to_string())
+            .collect(),
+        client_restarted,
+    })
+}
+
 #[tauri::command]
 pub async fn trigger_syftbox_sync() -> Result<(), String> {
     let cfg = load_syftbox_client_config()?;
@@ -1,6 +1,6 @@
 use crate::types::{
-    SharedWithMeItem, SyftPubInfo, SyftPubPermission, SyncIgnorePatterns, SyncTreeDetails,
-    SyncTreeNode,
+    SharedWithMeItem, SyftPubInfo, SyftPubPermission, SyncConflict, SyncConflictVersion,
+    SyncIgnorePatterns, SyncTreeDetails, SyncTreeNode,
 };
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
@@ -848,3 +848,172 @@ fn count_files_recursive(path: &Path) -> u32 {
     }
     count
 }
+
+/// Name segments SyftBox inserts when it splits off a copy it couldn't merge,
+/// e.g. `flow.syftconflict.yaml` or `flow.syftrejected.20250101120000.yaml`.
+/// Plain words like `conflict` are not markers; users name files that way.
+const SYNC_CONFLICT_MARKERS: &[&str] = &["syftconflict", "syftrejected"];
+
+/// Split a marked file name into (marker kind, original name).
+fn parse_conflict_name(name: &str) -> Option<(String, String)> {
+    let segments: Vec<&str> = name.split('.').collect();
+    let marker_idx = segments
+        .iter()
+        .enumerate()
+        .skip(1)
+        .find(|(_, seg)| SYNC_CONFLICT_MARKERS.contains(&seg.to_ascii_lowercase().as_str()))
+        .map(|(idx, _)| idx)?;
+    let kind = if segments[marker_idx]
+        .to_ascii_lowercase()
+        .ends_with("rejected")
+    {
+        "rejected"
+    } else {
+        "conflict"
+    };
+    let mut kept: Vec<&str> = segments[..marker_idx].to_vec();
+    let mut rest = &segments[marker_idx + 1..];
+    // Drop a timestamp segment following the marker.
+    if let Some(first) = rest.first() {
+        if !first.is_empty()
+            && first
+                .chars()
+                .all(|c| c.is_ascii_digit() || matches!(c, '-' | '_' | 'T' | 'Z'))
+            && first.chars().any(|c| c.is_ascii_digit())
+        {
+            rest = &rest[1..];
+        }
+    }
+    kept.extend_from_slice(rest);
+    Some((kind.to_string(), kept.join(".")))
+}
+
+fn file_sha256(path: &Path) -> Option<String> {
+    use sha2::{Digest, Sha256};
+    let mut file = fs::File::open(path).ok()?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher).ok()?;
+    Some(hex::encode(hasher.finalize()))
+}
+
+fn conflict_version(path: &Path, datasites: &Path) -> SyncConflictVersion {
+    SyncConflictVersion {
+        path: path
+            .strip_prefix(datasites)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .to_string(),
+        exists: path.is_file(),
+        size: file_size(path),
+        modified: file_modified_time(path),
+        sha256: file_sha256(path),
+    }
+}
+
+/// The user's own datasite; conflicts are only listed and resolved there.
+fn get_own_datasite_path() -> Result<(PathBuf, PathBuf), String> {
+    let runtime = load_runtime_config()?;
+    let email = runtime.email.trim();
+    if email.is_empty() {
+        return Err("SyftBox email is not configured".to_string());
+    }
+    let datasites = PathBuf::from(&runtime.data_dir).join("datasites");
+    let own = datasites.join(email);
+    Ok((datasites, own))
+}
+
+/// Find the conflict/rejected copies SyftBox left in the user's datasite,
+/// with both versions' metadata so the user can pick one.
+#[tauri::command]
+pub async fn list_sync_conflicts() -> Result<Vec<SyncConflict>, String> {
+    let (datasites, own_datasite) = get_own_datasite_path()?;
+    if !own_datasite.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut conflicts = Vec::new();
+    for entry in walkdir::WalkDir::new(&own_datasite)
+        .into_iter()
+        .filter_entry(|e| e.depth() == 0 || !e.file_name().to_string_lossy().starts_with('.'))
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file())
+    {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let Some((kind, original_name)) = parse_conflict_name(&name) else {
+            continue;
+        };
+        let original = entry.path().with_file_name(&original_name);
+        let local = conflict_version(entry.path(), &datasites);
+        conflicts.push(SyncConflict {
+            path: local.path.clone(),
+            original_path: original
+                .strip_prefix(&datasites)
+                .unwrap_or(&original)
+                .to_string_lossy()
+                .to_string(),
+            kind,
+            local,
+            remote: conflict_version(&original, &datasites),
+        });
+    }
+    conflicts.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(conflicts)
+}
+
+/// Resolve a conflict copy: "local" moves it over the original, "remote"
+/// discards it and keeps what SyftBox synced.
+#[tauri::command]
+pub async fn resolve_sync_conflict(path: String, choice: String) -> Result<(), String> {
+    let (datasites, own_datasite) = get_own_datasite_path()?;
+    let candidate = PathBuf::from(&path);
+    let conflict_path = if candidate.is_absolute() {
+        candidate
+    } else {
+        datasites.join(candidate)
+    };
+    let conflict_path = conflict_path
+        .canonicalize()
+        .map_err(|e| format!("Failed to find conflict file {}: {}", path, e))?;
+    let own_datasite = own_datasite
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve your datasite path: {}", e))?;
+    if !conflict_path.starts_with(&own_datasite) || !conflict_path.is_file() {
+        return Err(format!("{} is not a file in your datasite", path));
+    }
+
+    let name = conflict_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let (_, original_name) = parse_conflict_name(&name)
+        .ok_or_else(|| format!("{} is not a sync conflict copy", path))?;
+    let original = conflict_path.with_file_name(&original_name);
+
+    match choice.as_str() {
+        "local" => {
+            fs::rename(&conflict_path, &original).map_err(|e| {
+                format!(
+                    "Failed to restore local version to {}: {}",
+                    original.display(),
+                    e
+                )
+            })?;
+        }
+        "remote" => {
+            fs::remove_file(&conflict_path)
+                .map_err(|e| format!("Failed to remove conflict copy: {}", e))?;
+        }
+        other => {
+            return Err(format!(
+                "Unknown choice '{}'; expected 'local' or 'remote'",
+                other
+            ))
+        }
+    }
+    crate::desktop_log!(
+        "🔀 Resolved sync conflict for {} (kept {})",
+        original.display(),
+        choice
+    );
+    Ok(())
+}
@@ -0,0 +1,252 @@
+use crate::types::AppState;
+use biovault::data::BioVaultDb;
+use rusqlite::params;
+use serde::Serialize;
+use serde_json::json;
+use std::time::Duration;
+
+const WEBHOOK_TIMEOUT_SECS: u64 = 10;
+const WEBHOOK_MAX_ATTEMPTS: u32 = 3;
+const WEBHOOK_INITIAL_BACKOFF_MS: u64 = 1000;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookDeliveryResult {
+    pub url: String,
+    pub delivered: bool,
+    pub attempts: u32,
+    pub status_code: Option<u16>,
+    pub error: Option<String>,
+}
+
+fn normalize_webhook_url(url: Option<String>) -> Result<Option<String>, String> {
+    let Some(url) = url.map(|u| u.trim().to_string()).filter(|u| !u.is_empty()) else {
+        return Ok(None);
+    };
+    let parsed = reqwest::Url::parse(&url).map_err(|e| format!("Invalid webhook URL: {}", e))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err("Webhook URL must use http or https".to_string());
+    }
+    Ok(Some(url))
+}
+
+/// Scheme and host of a webhook URL, for logs. Paths and query strings often
+/// carry tokens, so the full URL is never logged.
+fn redact_webhook_url(url: &str) -> String {
+    match reqwest::Url::parse(url) {
+        Ok(parsed) => match (parsed.host_str(), parsed.port()) {
+            (Some(host), Some(port)) => format!("{}://{}:{}", parsed.scheme(), host, port),
+            (Some(host), None) => format!("{}://{}", parsed.scheme(), host),
+            _ => format!("{}://<redacted>", parsed.scheme()),
+        },
+        Err(_) => "<invalid url>".to_string(),
+    }
+}
+
+pub(crate) fn ensure_webhook_override_table(db: &BioVaultDb) -> Result<(), String> {
+    db.connection()
+        .execute(
+            "CREATE TABLE IF NOT EXISTS run_webhook_overrides (
+                run_id INTEGER PRIMARY KEY,
+                url TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .map_err(|e| format!("Failed to create webhook override table: {}", e))?;
+    Ok(())
+}
+
+fn resolve_webhook_url(db: &BioVaultDb, run_id: i64) -> Option<String> {
+    if ensure_webhook_override_table(db).is_ok() {
+        let override_url: Option<String> = db
+            .connection()
+            .query_row(
+                "SELECT url FROM run_webhook_overrides WHERE run_id = ?1",
+                params![run_id],
+                |row| row.get(0),
+            )
+            .ok();
+        if override_url.is_some() {
+            return override_url;
+        }
+    }
+    crate::commands::settings::get_settings()
+        .ok()
+        .and_then(|s| s.run_completion_webhook_url)
+        .filter(|u| !u.trim().is_empty())
+}
+
+async fn post_with_retry(url: &str, payload: &serde_json::Value) -> WebhookDeliveryResult {
+    let mut result = WebhookDeliveryResult {
+        url: url.to_string(),
+        delivered: false,
+        attempts: 0,
+        status_code: None,
+        error: None,
+    };
+
+    let client = match reqwest::Client::builder()
+        .timeout(Duration::from_secs(WEBHOOK_TIMEOUT_SECS))
+        .build()
+    {
+        Ok(client) => client,
+        Err(e) => {
+            result.error = Some(format!("Failed to create HTTP client: {}", e));
+            return result;
+        }
+    };
+
+    let mut backoff = Duration::from_millis(WEBHOOK_INITIAL_BACKOFF_MS);
+    while result.attempts < WEBHOOK_MAX_ATTEMPTS {
+        result.attempts += 1;
+        match client.post(url).json(payload).send().await {
+            Ok(resp) => {
+                let status = resp.status();
+                result.status_code = Some(status.as_u16());
+                if status.is_success() {
+                    result.delivered = true;
+                    result.error = None;
+                    return result;
+                }
+                result.error = Some(format!("Webhook returned HTTP {}", status));
+                // Client errors won't succeed on retry.
+                if status.is_client_error() && status.as_u16() != 429 {
+                    return result;
+                }
+            }
+            Err(e) => {
+                result.error = Some(format!("Webhook request failed: {}", e.without_url()));
+            }
+        }
+        if result.attempts < WEBHOOK_MAX_ATTEMPTS {
+            tokio::time::sleep(backoff).await;
+            backoff *= 2;
+        }
+    }
+    result
+}
+
+/// Fire the run-completion webhook (if one is configured) in the background.
+/// Never blocks the caller; delivery failures are only logged.
+pub(crate) fn notify_run_completion(
+    db: &BioVaultDb,
+    run_id: i64,
+    status: &str,
+    duration_secs: Option<u64>,
+) {
+    let Some(url) = resolve_webhook_url(db, run_id) else {
+        return;
+    };
+    let run = db.get_flow_run(run_id).ok().flatten();
+    let flow_name = run
+        .as_ref()
+        .and_then(|r| r.flow_id)
+        .and_then(|id| db.get_flow(id).ok().flatten())
+        .map(|flow| flow.name);
+    let results_path = run
+        .as_ref()
+        .map(|r| r.results_dir.clone().unwrap_or_else(|| r.work_dir.clone()));
+
+    let payload = json!({
+        "event": "run.completed",
+        "run_id": run_id,
+        "flow": flow_name,
+        "status": status,
+        "duration_secs": duration_secs,
+        "results_path": results_path,
+        "completed_at": chrono::Utc::now().to_rfc3339(),
+    });
+
+    tauri::async_runtime::spawn(async move {
+        let result = post_with_retry(&url, &payload).await;
+        let target = redact_webhook_url(&url);
+        if result.delivered {
+            crate::desktop_log!("🪝 Run {} webhook delivered to {}", run_id, target);
+        } else {
+            crate::desktop_log!(
+                "⚠️ Run {} webhook to {} failed after {} attempt(s): {}",
+                run_id,
+                target,
+                result.attempts,
+                result.error.unwrap_or_default()
+            );
+        }
+    });
+}
+
+#[tauri::command]
+pub fn get_run_completion_webhook() -> Result<Option<String>, String> {
+    Ok(crate::commands::settings::get_settings()?.run_completion_webhook_url)
+}
+
+/// Set (or clear with an empty/None url) the global run-completion webhook.
+#[tauri::command]
+pub fn set_run_completion_webhook(url: Option<String>) -> Result<Option<String>, String> {
+    let url = normalize_webhook_url(url)?;
+    let mut settings = crate::commands::settings::get_settings()?;
+    settings.run_completion_webhook_url = url.clone();
+    crate::commands::settings::save_settings(settings)?;
+    crate::desktop_log!(
+        "🪝 Run completion webhook {}",
+        if url.is_some() {
+            "configured"
+        } else {
+            "cleared"
+        }
+    );
+    Ok(url)
+}
+
+/// Override the webhook for a single run; None removes the override.
+#[tauri::command]
+pub fn set_run_webhook_override(
+    state: tauri::State<AppState>,
+    run_id: i64,
+    url: Option<String>,
+) -> Result<Option<String>, String> {
+    let url = normalize_webhook_url(url)?;
+    let db = state.biovault_db.lock().map_err(|e| e.to_string())?;
+    ensure_webhook_override_table(&db)?;
+    match &url {
+        Some(url) => {
+            db.connection()
+                .execute(
+                    "INSERT INTO run_webhook_overrides (run_id, url, updated_at)
+                     VALUES (?1, ?2, ?3)
+                     ON CONFLICT(run_id) DO UPDATE SET url = excluded.url, updated_at = excluded.updated_at",
+                    params![run_id, url, chrono::Utc::now().to_rfc3339()],
+                )
+                .map_err(|e| format!("Failed to save webhook override: {}", e))?;
+        }
+        None => {
+            db.connection()
+                .execute(
+                    "DELETE FROM run_webhook_overrides WHERE run_id = ?1",
+                    params![run_id],
+                )
+                .map_err(|e| format!("Failed to clear webhook override: {}", e))?;
+        }
+    }
+    Ok(url)
+}
+
+/// Send a sample payload to `url` (or the configured webhook) and report the outcome.
+#[tauri::command]
+pub async fn test_webhook(url: Option<String>) -> Result<WebhookDeliveryResult, String> {
+    let url = match normalize_webhook_url(url)? {
+        Some(url) => url,
+        None => crate::commands::settings::get_settings()?
+            .run_completion_webhook_url
+            .ok_or_else(|| "No webhook URL configured".to_string())?,
+    };
+    let payload = json!({
+        "event": "webhook.test",
+        "run_id": null,
+        "flow": null,
+        "status": "test",
+        "duration_secs": null,
+        "results_path": null,
+        "completed_at": chrono::Utc::now().to_rfc3339(),
+    });
+    Ok(post_with_retry(&url, &payload).await)
+}
@@ -1,10 +1,11 @@
 use rusqlite::Connection;
+use std::collections::VecDeque;
 use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 use tauri::{
     image::Image,
     menu::{CheckMenuItemBuilder, MenuBuilder, MenuItemBuilder},
@@ -30,10 +31,11 @@ mod telemetry {
 mod types;
 
 // Import types from types module
-use types::AppState;
+use types::{AppState, QueueMetricsBuffer, QueueSample};
 
 // Import all command functions from command modules
 use commands::agent_api::*;
+use commands::container_images::*;
 use commands::datasets::*;
 use commands::dependencies::*;
 use commands::files::*;
@@ -46,11 +48,14 @@ use commands::modules::*;
 use commands::notifications::*;
 use commands::participants::*;
 use commands::profiles::*;
+use commands::quiet_mode::*;
 use commands::runs::*;
+use commands::screenshot::*;
 use commands::sessions::*;
 use commands::settings::*;
 use commands::sql::*;
 use commands::syftbox::*;
+use commands::webhooks::*;
 
 // BioVault CLI library imports
 use biovault::data::BioVaultDb;
@@ -137,222 +142,250 @@ fn backup_biovault_db(db_path: &Path) -> Result<Vec<PathBuf>, String> {
     Ok(backups)
 }
 
+enum QueueFileOutcome {
+    /// Deleted by clear queue, or no longer pending (claimed by another worker)
+    Skipped,
+    Processed,
+    Failed,
+    /// Pause was requested; the file went back to "pending"
+    Paused,
+}
+
+fn requeue_paused_file(biovault_db: &Mutex<BioVaultDb>, file_id: i64) -> QueueFileOutcome {
+    if let Ok(db) = biovault_db.lock() {
+        let _ = biovault::data::update_file_status(&db, file_id, "pending", None);
+    }
+    QueueFileOutcome::Paused
+}
+
+/// Hash and analyze one queued file. The DB lock is only held to claim the
+/// row and to write results back, never during hashing or analysis.
+fn process_queued_file(
+    file: &commands::files::PendingQueueFile,
+    custom_types: &[commands::files::CustomDataType],
+    paused_flag: &AtomicBool,
+    biovault_db: &Mutex<BioVaultDb>,
+    metrics: &Mutex<VecDeque<QueueSample>>,
+) -> QueueFileOutcome {
+    // Lock briefly to mark as processing
+    // Also check if file still exists (might have been deleted by clear queue)
+    let marked = match biovault_db.lock() {
+        Ok(db) => {
+            let file_exists: Result<bool, _> = db.connection().query_row(
+                "SELECT COUNT(*) FROM files WHERE id = ?1 AND status = 'pending'",
+                [file.id],
+                |row| Ok(row.get::<_, i64>(0)? > 0),
+            );
+
+            if let Ok(true) = file_exists {
+                biovault::data::update_file_status(&db, file.id, "processing", None).is_ok()
+            } else {
+                false // File doesn't exist or not pending anymore
+            }
+        }
+        Err(_) => false,
+    };
+
+    if !marked {
+        return QueueFileOutcome::Skipped;
+    }
+
+    // Check pause flag before starting expensive operations
+    if paused_flag.load(Ordering::SeqCst) {
+        return requeue_paused_file(biovault_db, file.id);
+    }
+
+    // Process file WITHOUT holding lock (expensive I/O operations)
+    let bytes = std::fs::metadata(&file.file_path)
+        .map(|m| m.len())
+        .unwrap_or(0);
+    let hash_started = Instant::now();
+    let hash_result = biovault::data::hash_file(&file.file_path);
+    let hash_ms = hash_started.elapsed().as_millis() as u64;
+
+    // Check pause flag again after hashing
+    if paused_flag.load(Ordering::SeqCst) {
+        return requeue_paused_file(biovault_db, file.id);
+    }
+
+    let hash = match hash_result {
+        Ok(hash) => hash,
+        Err(e) => {
+            // Lock briefly to mark error
+            // First check if file still exists (might have been deleted by clear queue)
+            let error_msg = format!("{}", e);
+            if let Ok(db) = biovault_db.lock() {
+                let file_exists: Result<bool, _> = db.connection().query_row(
+                    "SELECT COUNT(*) FROM files WHERE id = ?1",
+                    [file.id],
+                    |row| Ok(row.get::<_, i64>(0)? > 0),
+                );
+
+                if let Ok(true) = file_exists {
+                    let _ =
+                        biovault::data::update_file_status(&db, file.id, "error", Some(&error_msg));
+                    commands::files::record_queue_sample(
+                        metrics,
+                        QueueSample {
+                            finished_at: Instant::now(),
+                            hash_ms,
+                            analyze_ms: None,
+                            bytes,
+                            failed: true,
+                        },
+                    );
+                    return QueueFileOutcome::Failed;
+                }
+            }
+            return QueueFileOutcome::Skipped;
+        }
+    };
+
+    // Detect and analyze file WITHOUT holding lock
+    let mut metadata = None;
+    let mut sex_confidence = None;
+    let mut analyze_ms = None;
+    let mut is_genotype = file.data_type.as_deref() == Some("Genotype");
+    if file.data_type.as_deref() == Some("Unknown") || file.data_type.is_none() {
+        // Detect file type first
+        match commands::files::detect_file_metadata(custom_types, &file.file_path) {
+            Ok(detected) if detected.data_type == "Genotype" => is_genotype = true,
+            Ok(detected) => metadata = Some(detected),
+            Err(_) => {}
+        }
+    }
+    if is_genotype {
+        // Check pause flag before expensive analysis
+        if paused_flag.load(Ordering::SeqCst) {
+            return requeue_paused_file(biovault_db, file.id);
+        }
+        let analyze_started = Instant::now();
+        if let Ok((analysis, confidence)) =
+            commands::files::analyze_genotype_file_with_sex_confidence(&file.file_path)
+        {
+            metadata = Some(analysis);
+            sex_confidence = confidence;
+        }
+        analyze_ms = Some(analyze_started.elapsed().as_millis() as u64);
+    }
+
+    // Final pause check before updating database
+    if paused_flag.load(Ordering::SeqCst) {
+        return requeue_paused_file(biovault_db, file.id);
+    }
+
+    // Lock briefly to update DB with results
+    // First check if file still exists (might have been deleted by clear queue)
+    let Ok(db) = biovault_db.lock() else {
+        return QueueFileOutcome::Skipped;
+    };
+    let file_exists: Result<bool, _> = db.connection().query_row(
+        "SELECT COUNT(*) FROM files WHERE id = ?1",
+        [file.id],
+        |row| Ok(row.get::<_, i64>(0)? > 0),
+    );
+    if !matches!(file_exists, Ok(true)) {
+        // Deleted meanwhile (e.g., by clear queue) - no error needed
+        return QueueFileOutcome::Skipped;
+    }
+
+    let outcome = if let Err(e) =
+        biovault::data::update_file_from_queue(&db, file.id, &hash, metadata.as_ref())
+    {
+        let _ = biovault::data::update_file_status(&db, file.id, "error", Some(&format!("{}", e)));
+        QueueFileOutcome::Failed
+    } else {
+        if let Some(confidence) = &sex_confidence {
+            let _ = commands::files::save_sex_confidence(&db, file.id, confidence);
+        }
+        QueueFileOutcome::Processed
+    };
+    drop(db);
+
+    commands::files::record_queue_sample(
+        metrics,
+        QueueSample {
+            finished_at: Instant::now(),
+            hash_ms,
+            analyze_ms,
+            bytes,
+            failed: matches!(outcome, QueueFileOutcome::Failed),
+        },
+    );
+    outcome
+}
+
 fn spawn_queue_processor(
     paused_flag: Arc<AtomicBool>,
     biovault_db_for_processor: Arc<Mutex<BioVaultDb>>,
+    queue_metrics: QueueMetricsBuffer,
+    workers: usize,
 ) {
+    let workers = workers.max(1);
+    crate::desktop_log!("🧵 Queue processor using {} worker(s)", workers);
+
     std::thread::spawn(move || {
         loop {
+            let mut made_progress = false;
+
             // Check if paused
             if !paused_flag.load(Ordering::SeqCst) {
                 // Get pending files - lock only briefly
                 let pending_files = {
                     match biovault_db_for_processor.lock() {
-                        Ok(db) => biovault::data::get_pending_files(&db, 10).ok(),
+                        Ok(db) => commands::files::get_prioritized_pending_files(&db, workers * 10)
+                            .ok()
+                            .map(|files| {
+                                let custom_types = commands::files::load_custom_data_types(&db)
+                                    .unwrap_or_default();
+                                (files, custom_types)
+                            }),
                         Err(_) => None,
                     }
                     // Lock is released here automatically
                 };
 
-                if let Some(files) = pending_files {
+                if let Some((files, custom_types)) = pending_files {
                     if !files.is_empty() {
-                        let mut processed = 0;
-                        let mut errors = 0;
-
-                        for file in &files {
-                            // Lock briefly to mark as processing
-                            // Also check if file still exists (might have been deleted by clear queue)
-                            let marked = {
-                                match biovault_db_for_processor.lock() {
-                                    Ok(db) => {
-                                        // Check if file still exists first
-                                        let file_exists: Result<bool, _> = db.connection().query_row(
-                                            "SELECT COUNT(*) FROM files WHERE id = ?1 AND status = 'pending'",
-                                            [file.id],
-                                            |row| Ok(row.get::<_, i64>(0)? > 0),
-                                        );
-
-                                        if let Ok(true) = file_exists {
-                                            biovault::data::update_file_status(
-                                                &db,
-                                                file.id,
-                                                "processing",
-                                                None,
-                                            )
-                                            .is_ok()
-                                        } else {
-                                            false // File doesn't exist or not pending anymore
-                                        }
-                                    }
-                                    Err(_) => false,
-                                }
-                            };
-
-                            if !marked {
-                                continue;
-                            }
-
-                            // Check pause flag before starting expensive operations
-                            if paused_flag.load(Ordering::SeqCst) {
-                                // Paused - reset this file back to pending and break
-                                if let Ok(db) = biovault_db_for_processor.lock() {
-                                    let _ = biovault::data::update_file_status(
-                                        &db, file.id, "pending", None,
-                                    );
-                                }
-                                break; // Break out of file processing loop
-                            }
-
-                            // Process file WITHOUT holding lock (expensive I/O operations)
-                            let hash_result = biovault::data::hash_file(&file.file_path);
-
-                            // Check pause flag again after hashing
-                            if paused_flag.load(Ordering::SeqCst) {
-                                // Paused during processing - reset back to pending
-                                if let Ok(db) = biovault_db_for_processor.lock() {
-                                    let _ = biovault::data::update_file_status(
-                                        &db, file.id, "pending", None,
-                                    );
-                                }
-                                break;
-                            }
-
-                            match hash_result {
-                                Ok(hash) => {
-                                    // Check pause flag before metadata operations
-                                    if paused_flag.load(Ordering::SeqCst) {
-                                        if let Ok(db) = biovault_db_for_processor.lock() {
-                                            let _ = biovault::data::update_file_status(
-                                                &db, file.id, "pending", None,
-                                            );
-                                        }
-                                        break;
-                                    }
-
-                                    // Detect and analyze file WITHOUT holding lock
-                                    let metadata = if file.data_type.as_deref() == Some("Unknown")
-                                        || file.data_type.is_none()
-                                    {
-                                        // Detect file type first
-                                        if let Ok(detected) =
-                                            biovault::data::detect_genotype_metadata(
-                                                &file.file_path,
-                                            )
-                                        {
-                                            if detected.data_type == "Genotype" {
-                                                // Check pause flag before expensive analysis
-                                                if paused_flag.load(Ordering::SeqCst) {
-                                                    if let Ok(db) = biovault_db_for_processor.lock()
-                                                    {
-                                                        let _ = biovault::data::update_file_status(
-                                                            &db, file.id, "pending", None,
-                                                        );
-                                                    }
-                                                    break;
-                                                }
-                                                // It's a genotype - analyze it fully
-                                                biovault::data::analyze_genotype_file(
-                                                    &file.file_path,
-                                                )
-                                                .ok()
-                                            } else {
-                                                Some(detected)
-                                            }
-                                        } else {
-                                            None
-                                        }
-                                    } else if file.data_type.as_deref() == Some("Genotype") {
-                                        // Check pause flag before expensive analysis
-                                        if paused_flag.load(Ordering::SeqCst) {
-                                            if let Ok(db) = biovault_db_for_processor.lock() {
-                                                let _ = biovault::data::update_file_status(
-                                                    &db, file.id, "pending", None,
-                                                );
-                                            }
+                        let next = AtomicUsize::new(0);
+                        let processed = AtomicUsize::new(0);
+                        let errors = AtomicUsize::new(0);
+
+                        // Workers pull from the batch in priority order and
+                        // re-check the pause flag before every file.
+                        std::thread::scope(|scope| {
+                            for _ in 0..workers.min(files.len()) {
+                                scope.spawn(|| {
+                                    while !paused_flag.load(Ordering::SeqCst) {
+                                        let Some(file) =
+                                            files.get(next.fetch_add(1, Ordering::SeqCst))
+                                        else {
                                             break;
-                                        }
-                                        // Already known to be genotype - analyze it
-                                        biovault::data::analyze_genotype_file(&file.file_path).ok()
-                                    } else {
-                                        None
-                                    };
-
-                                    // Final pause check before updating database
-                                    if paused_flag.load(Ordering::SeqCst) {
-                                        if let Ok(db) = biovault_db_for_processor.lock() {
-                                            let _ = biovault::data::update_file_status(
-                                                &db, file.id, "pending", None,
-                                            );
-                                        }
-                                        break;
-                                    }
-
-                                    // Lock briefly to update DB with results
-                                    // First check if file still exists (might have been deleted by clear queue)
-                                    match biovault_db_for_processor.lock() {
-                                        Ok(db) => {
-                                            // Check if file still exists before updating
-                                            let file_exists: Result<bool, _> =
-                                                db.connection().query_row(
-                                                    "SELECT COUNT(*) FROM files WHERE id = ?1",
-                                                    [file.id],
-                                                    |row| Ok(row.get::<_, i64>(0)? > 0),
-                                                );
-
-                                            if let Ok(true) = file_exists {
-                                                if let Err(e) =
-                                                    biovault::data::update_file_from_queue(
-                                                        &db,
-                                                        file.id,
-                                                        &hash,
-                                                        metadata.as_ref(),
-                                                    )
-                                                {
-                                                    let _ = biovault::data::update_file_status(
-                                                        &db,
-                                                        file.id,
-                                                        "error",
-                                                        Some(&format!("{}", e)),
-                                                    );
-                                                    errors += 1;
-                                                } else {
-                                                    processed += 1;
-                                                }
+                                        };
+                                        match process_queued_file(
+                                            file,
+                                            &custom_types,
+                                            &paused_flag,
+                                            &biovault_db_for_processor,
+                                            &queue_metrics,
+                                        ) {
+                                            QueueFileOutcome::Processed => {
+                                                processed.fetch_add(1, Ordering::SeqCst);
                                             }
-                                            // If file doesn't exist anymore, it was deleted (e.g., by clear queue)
-                                            // Just skip it - no error needed
-                                        }
-                                        Err(_) => continue,
-                                    }
-                                }
-                                Err(e) => {
-                                    // Lock briefly to mark error
-                                    // First check if file still exists (might have been deleted by clear queue)
-                                    let error_msg = format!("{}", e);
-                                    if let Ok(db) = biovault_db_for_processor.lock() {
-                                        // Check if file still exists before updating
-                                        let file_exists: Result<bool, _> =
-                                            db.connection().query_row(
-                                                "SELECT COUNT(*) FROM files WHERE id = ?1",
-                                                [file.id],
-                                                |row| Ok(row.get::<_, i64>(0)? > 0),
-                                            );
-
-                                        if let Ok(true) = file_exists {
-                                            let _ = biovault::data::update_file_status(
-                                                &db,
-                                                file.id,
-                                                "error",
-                                                Some(&error_msg),
-                                            );
-                                            errors += 1;
+                                            QueueFileOutcome::Failed => {
+                                                errors.fetch_add(1, Ordering::SeqCst);
+                                            }
+                                            QueueFileOutcome::Paused => break,
+                                            QueueFileOutcome::Skipped => {}
                                         }
-                                        // If file doesn't exist anymore, it was deleted (e.g., by clear queue)
-                                        // Just skip it - no error needed
                                     }
-                                }
+                                });
                             }
-                        }
+                        });
+
+                        let processed = processed.into_inner();
+                        let errors = errors.into_inner();
+                        made_progress = processed + errors > 0;
 
                         // Only log if files were actually processed
                         if processed > 0 {
@@ -366,8 +399,11 @@ fn spawn_queue_processor(
                 }
             }
 
-            // Wait 2 seconds before next check
-            std::thread::sleep(std::time::Duration::from_secs(2));
+            // Go straight to the next batch while there is backlog; otherwise
+            // wait 2 seconds before the next check
+            if !made_progress {
+                std::thread::sleep(std::time::Duration::from_secs(2));
+            }
         }
     });
 }
@@ -413,7 +449,7 @@ fn find_bundled_binary(resource_dir: &Path, name: &str) -> Option<PathBuf> {
 }
 
 #[cfg(not(target_os = "windows"))]
-fn expose_bundled_binaries(app: &tauri::App) {
+pub(crate) fn expose_bundled_binaries(app: &AppHandle) {
     let platform = format!("{}-{}", std::env::consts::OS, std::env::consts::ARCH);
     crate::desktop_log!("🔍 Exposing bundled binaries for platform: {}", platform);
 
@@ -699,7 +735,7 @@ fn expose_bundled_binaries(app: &tauri::App) {
 }
 
 #[cfg(target_os = "windows")]
-fn expose_bundled_binaries(app: &tauri::App) {
+pub(crate) fn expose_bundled_binaries(app: &AppHandle) {
     let platform = format!("{}-{}", std::env::consts::OS, std::env::consts::ARCH);
     crate::desktop_log!("🔍 Exposing bundled binaries for platform: {}", platform);
 
@@ -1017,6 +1053,10 @@ fn maybe_write_spawn_probe(args: &[String]) -> bool {
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    // Record whether the hotlink QUIC switches came from the environment
+    // before anything below writes defaults for them.
+    commands::syftbox::capture_hotlink_env_preset();
+
     let args: Vec<String> = std::env::args().collect();
 
     if std::env::var("BV_SYFTBOX_BACKEND").is_err() {
@@ -1115,12 +1155,19 @@ pub fn run() {
     // Initialize OpenTelemetry if OTEL_EXPORTER_OTLP_ENDPOINT is set
     telemetry::init();
 
+    // Check config/home/email before any DB is opened so problems are logged, not panics.
+    if !profile_picker_mode {
+        let report = commands::settings::startup_config_report();
+        for problem in &report.problems {
+            crate::desktop_log!("⚠️ Startup config [{}]: {}", problem.code, problem.message);
+        }
+    }
+
     // Get the actual biovault_home_dir that was used (for window title / DB paths).
     let (biovault_home_dir, home_display) = if profile_picker_mode {
         (PathBuf::from(""), "profile picker".to_string())
     } else {
-        let biovault_home_dir =
-            biovault::config::get_biovault_home().expect("Failed to get BioVault home directory");
+        let biovault_home_dir = resolve_biovault_home_path();
         let home_display = biovault_home_dir.to_string_lossy().to_string();
         crate::desktop_log!("📂 BioVault home resolved to {}", home_display);
         (biovault_home_dir, home_display)
@@ -1130,6 +1177,7 @@ pub fn run() {
     } else {
         Some(biovault_home_dir.join("biovault.db"))
     };
+    let queue_workers = commands::settings::load_queue_workers(&biovault_home_dir);
 
     let mut db_init_error: Option<String> = None;
     let biovault_db = if profile_picker_mode {
@@ -1140,7 +1188,10 @@ pub fn run() {
         // Initialize shared BioVaultDb (handles files/participants)
         // This automatically creates the directory via get_biovault_home() if needed
         match BioVaultDb::new() {
-            Ok(db) => db,
+            Ok(db) => {
+                commands::files::ensure_file_columns(&db);
+                db
+            }
             Err(err) => {
                 let message = format!("{}", err);
                 crate::desktop_log!("❌ Failed to initialize BioVault DB: {}", message);
@@ -1204,8 +1255,13 @@ pub fn run() {
         crate::desktop_log!("🗃️ BioVault DB path: {}", db_path.display());
         let conn = Connection::open(&db_path).expect("Could not open database");
         init_db(&conn).expect("Could not initialize database");
-        // Queue processor starts paused by default; UI can resume explicitly.
-        (conn, Arc::new(AtomicBool::new(true)))
+        // Queue processor starts paused unless the user opted into autostart;
+        // UI can resume explicitly.
+        let autostart = commands::settings::load_queue_autostart(&biovault_home_dir);
+        if autostart {
+            crate::desktop_log!("▶️ Queue autostart enabled; processor starts running");
+        }
+        (conn, Arc::new(AtomicBool::new(!autostart)))
     };
 
     let app_state = AppState {
@@ -1213,13 +1269,31 @@ pub fn run() {
         biovault_db: Arc::new(Mutex::new(biovault_db)),
         queue_processor_paused: queue_processor_paused.clone(),
         message_watcher: Mutex::new(None),
+        queue_metrics: Arc::new(Mutex::new(VecDeque::new())),
     };
 
     // Spawn background queue processor (using library)
     if !profile_picker_mode && db_init_error.is_none() {
+        let stale_minutes = commands::settings::load_stale_processing_minutes(&biovault_home_dir);
+        match app_state.biovault_db.lock() {
+            Ok(db) => match reset_stale_processing_files(&db, stale_minutes) {
+                Ok(0) => {}
+                Ok(count) => crate::desktop_log!(
+                    "♻️ Reset {} file(s) stuck in processing back to pending",
+                    count
+                ),
+                Err(e) => crate::desktop_log!("⚠️ {}", e),
+            },
+            Err(e) => crate::desktop_log!("⚠️ Failed to lock DB for queue reconciliation: {}", e),
+        }
         let paused_flag = queue_processor_paused.clone();
         let biovault_db_for_processor = app_state.biovault_db.clone();
-        spawn_queue_processor(paused_flag, biovault_db_for_processor);
+        spawn_queue_processor(
+            paused_flag,
+            biovault_db_for_processor,
+            app_state.queue_metrics.clone(),
+            queue_workers,
+        );
     }
 
     crate::desktop_log!("Setup: building Tauri app");
@@ -1250,6 +1324,7 @@ pub fn run() {
                 let app_handle = app.handle().clone();
                 let paused_flag = app_handle.state::<AppState>().queue_processor_paused.clone();
                 let biovault_db_handle = app_handle.state::<AppState>().biovault_db.clone();
+                let queue_metrics_handle = app_handle.state::<AppState>().queue_metrics.clone();
 
                 std::thread::spawn(move || {
                     let display_path = db_path.to_string_lossy().to_string();
@@ -1317,6 +1392,7 @@ pub fn run() {
                         }
                     };
 
+                    commands::files::ensure_file_columns(&new_db);
                     if let Ok(mut db_lock) = biovault_db_handle.lock() {
                         *db_lock = new_db;
                     }
@@ -1359,7 +1435,12 @@ pub fn run() {
                     }
 
                     paused_flag.store(false, Ordering::SeqCst);
-                    spawn_queue_processor(paused_flag.clone(), biovault_db_handle.clone());
+                    spawn_queue_processor(
+                        paused_flag.clone(),
+                        biovault_db_handle.clone(),
+                        queue_metrics_handle.clone(),
+                        queue_workers,
+                    );
                 });
             }
             if std::env::var("BV_WS_BRIDGE_PROBE").is_ok() {
@@ -1375,7 +1456,7 @@ pub fn run() {
             }
             // Surface bundled binaries (java/nextflow/uv) to the environment so dependency
             // checks and runtime execution prefer the packaged versions.
-            expose_bundled_binaries(app);
+            expose_bundled_binaries(app.handle());
             crate::desktop_log!("Setup: bundled binaries exposed");
 
             // Ensure bundled SyftBox binary is exposed if not already provided
@@ -1576,6 +1657,15 @@ pub fn run() {
                 }
             });
 
+            // Warn about a schema mismatch, re-arm watchers saved by a previous session
+            // and start upload progress polling
+            if !profile_picker_mode {
+                commands::settings::emit_schema_mismatch_warning(app.handle());
+                commands::files::resume_import_watchers(app.handle().clone());
+                commands::multiparty::resume_auto_run_watchers(app.handle().clone());
+                commands::syftbox::spawn_upload_progress_poller(app.handle().clone());
+            }
+
             // Handle deep link URLs (biovault://...)
             #[cfg(desktop)]
             {
@@ -1610,20 +1700,47 @@ pub fn run() {
             is_directory,
             import_files,
             import_files_with_metadata,
+            preview_import,
+            watch_import_folder,
+            unwatch_import_folder,
+            list_watched_folders,
+            get_import_conflicts,
+            import_genotype_zip,
             import_files_pending,
             process_queue,
             pause_queue_processor,
             resume_queue_processor,
             get_queue_processor_status,
+            get_queue_metrics,
+            get_queue_throughput_stats,
             get_queue_info,
             clear_pending_queue,
+            list_queued_files,
+            cancel_queued_files,
+            set_file_priority,
+            prioritize_files,
             get_files,
+            get_files_page,
+            get_files_by_origin,
             delete_file,
             delete_files_bulk,
+            update_files_metadata_bulk,
+            check_files_exist,
+            verify_files,
+            prune_missing_files,
+            snapshot_library,
+            get_sex_inference_confidence,
+            list_library_snapshots,
+            diff_library_snapshots,
+            delete_library_snapshot,
+            register_data_type,
+            list_data_types,
+            remove_data_type,
             update_file_reference,
             get_file_reference,
             detect_file_types,
             analyze_file_types,
+            reanalyze_file,
             fetch_sample_data,
             fetch_sample_data_with_progress,
             check_sample_downloaded,
@@ -1634,7 +1751,12 @@ pub fn run() {
             list_datasets_with_assets,
             upsert_dataset_manifest,
             delete_dataset,
+            find_duplicate_datasets,
+            audit_dataset_links,
+            repair_dataset_links,
+            merge_datasets,
             publish_dataset,
+            publish_datasets_bulk,
             unpublish_dataset,
             save_dataset_with_files,
             is_dataset_published,
@@ -1648,6 +1770,8 @@ pub fn run() {
             get_participants,
             delete_participant,
             delete_participants_bulk,
+            check_cohort_completeness,
+            check_participant_id_conflicts,
             // Messages commands
             list_message_threads,
             get_thread_messages,
@@ -1656,6 +1780,7 @@ pub fn run() {
             mark_thread_as_read,
             delete_thread,
             delete_message,
+            migrate_identity,
             // Failed messages commands
             list_failed_messages,
             count_failed_messages,
@@ -1668,6 +1793,7 @@ pub fn run() {
             list_results_tree,
             import_flow_results,
             send_flow_results,
+            verify_message_attachment,
             // Modules commands
             import_module,
             import_module_from_folder,
@@ -1675,11 +1801,14 @@ pub fn run() {
             import_flow_from_request,
             get_modules,
             delete_module,
+            find_flows_using_module,
             delete_module_folder,
             create_module,
             get_available_module_examples,
             get_default_module_path,
             load_module_editor,
+            validate_module_references,
+            check_module_runnable,
             save_module_editor,
             preview_module_spec,
             get_module_spec_digest,
@@ -1689,10 +1818,15 @@ pub fn run() {
             get_common_formats,
             get_local_flow_templates,
             get_flow_template_catalog,
+            get_available_flow_examples,
+            create_flow_from_example,
             // Jupyter commands
             launch_jupyter,
             stop_jupyter,
             get_jupyter_status,
+            list_jupyter_servers,
+            stop_all_jupyter_servers,
+            discover_orphaned_jupyter,
             reset_jupyter,
             // Runs commands
             start_analysis,
@@ -1701,12 +1835,18 @@ pub fn run() {
             get_run_logs,
             get_run_logs_tail,
             get_run_logs_full,
+            get_child_process_environment,
             delete_run,
+            delete_runs_older_than,
+            set_run_labels,
+            get_runs_by_label,
+            get_run_label_counts,
             // Flow commands
             get_flows,
             get_runs_base_dir,
             create_flow,
             import_flow_from_json,
+            import_flow_from_git,
             load_flow_editor,
             save_flow_editor,
             delete_flow,
@@ -1715,7 +1855,18 @@ pub fn run() {
             list_run_configs,
             get_run_config,
             delete_run_config,
+            estimate_run_duration,
+            check_run_space_requirements,
+            diff_run_outputs,
+            get_run_input_sources,
+            get_runs_for_dataset,
+            get_run_image_status,
+            prefetch_run_images,
             run_flow,
+            preview_run_inputs,
+            describe_flow_inputs,
+            get_flow_spec_digest,
+            check_dataset_shape_compatibility,
             flow_request_sync_status,
             get_flow_runs,
             get_flow_run_logs,
@@ -1741,21 +1892,55 @@ pub fn run() {
             // Settings commands
             get_settings,
             save_settings,
+            get_run_directory_roots,
+            set_run_directory_roots,
+            get_queue_autostart,
+            set_queue_autostart,
+            get_default_max_forks,
+            set_default_max_forks,
+            get_results_message_template,
+            set_results_message_template,
+            export_settings_profile,
+            import_settings_profile,
             get_agent_api_commands,
             restart_agent_bridge,
+            agent_list_actions,
+            agent_execute,
             get_app_version,
+            get_embedded_cli_version,
             open_folder,
             save_file_bytes,
             open_in_vscode,
             show_in_folder,
             get_config_path,
             get_database_path,
+            get_database_schema_version,
             check_is_onboarded,
+            validate_startup_config,
             complete_onboarding,
             reset_all_data,
             reset_everything,
             get_autostart_enabled,
             set_autostart_enabled,
+            // Quiet mode commands
+            enter_quiet_mode,
+            exit_quiet_mode,
+            get_quiet_mode_status,
+            // Run completion webhooks
+            get_run_completion_webhook,
+            set_run_completion_webhook,
+            set_run_webhook_override,
+            test_webhook,
+            // Run completion notifications
+            get_run_notification,
+            set_run_notification,
+            get_run_notifications_default,
+            set_run_notifications_default,
+            // Notification preferences
+            get_notification_preferences,
+            set_notification_preference,
+            // Bug report screenshots (UI only, not exposed over the WS bridge)
+            capture_window_screenshot,
             // Profiles
             profiles_get_boot_state,
             profiles_get_default_home,
@@ -1770,6 +1955,9 @@ pub fn run() {
             profiles_move_home,
             profiles_delete_profile,
             profiles_create_and_switch,
+            list_profiles,
+            create_profile,
+            switch_profile,
             // Key management
             key_check_vault_debug,
             key_get_status,
@@ -1798,12 +1986,16 @@ pub fn run() {
             get_desktop_log_text,
             clear_desktop_log,
             get_desktop_log_dir,
+            list_log_files,
+            open_log_file,
             // Dependencies commands
             check_dependencies,
             check_single_dependency,
+            check_java_compatibility,
             get_saved_dependency_states,
             save_custom_path,
             update_saved_dependency_states,
+            refresh_bundled_binaries,
             check_brew_installed,
             install_brew,
             check_command_line_tools_installed,
@@ -1819,6 +2011,10 @@ pub fn run() {
             get_default_syftbox_server_url,
             check_syftbox_auth,
             get_syftbox_config_info,
+            get_syftbox_token_status,
+            refresh_syftbox_token,
+            detect_syftbox_data_dir,
+            set_syftbox_data_dir,
             get_syftbox_state,
             start_syftbox_client,
             stop_syftbox_client,
@@ -1828,6 +2024,12 @@ pub fn run() {
             syftbox_subscriptions_discovery,
             syftbox_queue_status,
             syftbox_upload_action,
+            list_active_uploads,
+            cancel_upload,
+            set_syftbox_bandwidth_limit,
+            get_syftbox_bandwidth_status,
+            get_hotlink_transport_mode,
+            set_hotlink_transport_mode,
             trigger_syftbox_sync,
             open_path_in_file_manager,
             test_notification,
@@ -1843,6 +2045,8 @@ pub fn run() {
             commands::sync_tree::sync_tree_subscribe,
             commands::sync_tree::sync_tree_unsubscribe,
             commands::sync_tree::sync_tree_set_subscription,
+            commands::sync_tree::list_sync_conflicts,
+            commands::sync_tree::resolve_sync_conflict,
             // Sessions commands
             get_sessions,
             list_sessions,
@@ -1870,19 +2074,47 @@ pub fn run() {
             list_session_datasets,
             // Multiparty flow commands
             commands::multiparty::send_flow_invitation,
+            commands::multiparty::get_flow_role_defaults,
+            commands::multiparty::save_flow_role_defaults,
+            commands::multiparty::preview_group_resolution,
+            commands::multiparty::check_idle_participants,
+            commands::multiparty::check_step_output_collisions,
             commands::multiparty::accept_flow_invitation,
+            commands::multiparty::verify_invitation_against_template,
+            commands::multiparty::inspect_invitation,
             commands::multiparty::get_multiparty_flow_state,
             commands::multiparty::get_all_participant_progress,
             commands::multiparty::get_multiparty_participant_datasite_path,
             commands::multiparty::get_participant_logs,
+            commands::multiparty::get_session_join_status,
+            commands::multiparty::get_session_platform_info,
             commands::multiparty::get_multiparty_step_diagnostics,
+            commands::multiparty::get_hotlink_telemetry,
             commands::multiparty::set_step_auto_run,
+            commands::multiparty::override_step_module,
+            commands::multiparty::clear_step_module_override,
+            commands::multiparty::verify_flow_spec_consistency,
+            commands::multiparty::describe_session_layout,
+            commands::multiparty::get_session_step_timings,
+            commands::multiparty::replay_step,
+            commands::multiparty::export_session_audit,
+            commands::multiparty::export_progress_matrix,
+            commands::multiparty::get_session_identity_changes,
+            commands::multiparty::generate_minimal_repro,
+            commands::multiparty::set_flow_auto_run_policy,
+            commands::multiparty::get_session_mpc_verbose_logging,
+            commands::multiparty::set_session_mpc_verbose_logging,
+            commands::multiparty::set_session_output_budget,
             commands::multiparty::force_complete_flow_step,
             commands::multiparty::republish_flow_step_state,
             commands::multiparty::run_flow_step,
+            commands::multiparty::get_step_output,
             commands::multiparty::share_step_outputs,
             commands::multiparty::share_step_outputs_to_chat,
+            commands::multiparty::list_unshared_completed_steps,
+            commands::multiparty::retry_share_step,
             commands::multiparty::get_step_output_files,
+            commands::multiparty::audit_session_sharing,
             commands::multiparty::get_multiparty_step_logs,
             commands::multiparty::receive_flow_step_outputs,
         ])
@@ -3,8 +3,10 @@ use biovault::defaults::SYFTBOX_DEFAULT_SERVER_URL;
 use biovault::messages::MessageRpcWatcherHandle;
 use rusqlite::Connection;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
 use std::sync::atomic::AtomicBool;
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
 use biovault::data::BioVaultDb;
 
@@ -17,6 +19,59 @@ pub struct AppState {
     pub biovault_db: Arc<Mutex<BioVaultDb>>,
     pub queue_processor_paused: Arc<AtomicBool>,
     pub message_watcher: Mutex<Option<MessageRpcWatcherHandle>>,
+    pub queue_metrics: QueueMetricsBuffer,
+}
+
+/// Recent per-file queue timings, newest last, capped at QUEUE_METRICS_CAPACITY.
+pub type QueueMetricsBuffer = Arc<Mutex<VecDeque<QueueSample>>>;
+
+pub const QUEUE_METRICS_CAPACITY: usize = 500;
+
+#[derive(Clone, Debug)]
+pub struct QueueSample {
+    pub finished_at: Instant,
+    pub hash_ms: u64,
+    /// None when the file was not a genotype and skipped analysis
+    pub analyze_ms: Option<u64>,
+    /// On-disk size of the file, for bytes/second throughput
+    pub bytes: u64,
+    pub failed: bool,
+}
+
+#[derive(Serialize, Debug)]
+pub struct QueueMetrics {
+    pub processed_last_minute: usize,
+    pub avg_hash_ms: Option<f64>,
+    pub avg_analyze_ms: Option<f64>,
+    /// Share of sampled files that ended in "error", 0.0-1.0
+    pub error_rate: f64,
+    pub sample_count: usize,
+    pub pending_count: i64,
+    /// Pending files divided by last-minute throughput; None while idle
+    pub eta_seconds: Option<u64>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct QueueThroughputWindow {
+    pub window_seconds: u64,
+    /// Seconds the sample buffer actually covers; below window_seconds when
+    /// the buffer wrapped or the queue only started recently
+    pub covered_seconds: f64,
+    pub files: usize,
+    pub bytes: u64,
+    pub files_per_second: f64,
+    pub bytes_per_second: f64,
+    pub avg_hash_ms: Option<f64>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct QueueThroughputStats {
+    pub windows: Vec<QueueThroughputWindow>,
+    pub avg_hash_ms: Option<f64>,
+    /// Hashing bandwidth (bytes hashed per second of hashing time), a disk-speed hint
+    pub hash_bytes_per_second: Option<f64>,
+    pub configured_workers: usize,
+    pub sample_count: usize,
 }
 
 // Settings
@@ -47,6 +102,50 @@ pub struct Settings {
     /// Blocked agent bridge commands
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub agent_bridge_blocklist: Vec<String>,
+    /// SyftBox upload bandwidth cap in kbps (0 = unlimited); enforced by pausing uploads that run over it
+    #[serde(default)]
+    pub syftbox_upload_bandwidth_limit: u64,
+    /// Hotlink transport preference: quic-only, quic-pref or ws-only.
+    /// None leaves the SyftBox client's own defaults (and any env overrides) alone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hotlink_transport_mode: Option<String>,
+    /// URL that receives a JSON POST when a run finishes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_completion_webhook_url: Option<String>,
+    /// Root for flow run Nextflow work dirs (default: BIOVAULT_HOME/runs)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub work_dir_root: Option<String>,
+    /// Root for flow run results dirs (default: BIOVAULT_HOME/runs)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub results_dir_root: Option<String>,
+    /// Show a desktop notification when a run finishes unless the run overrides it
+    #[serde(default)]
+    pub run_notifications_enabled: bool,
+    /// Free space (MB) to keep beyond a run's estimated footprint before warning
+    #[serde(default = "default_run_space_headroom_mb")]
+    pub run_space_headroom_mb: u64,
+    /// Refuse to start a run when free space is below its estimated footprint
+    /// (off by default: the estimate is a guess, so it only warns)
+    #[serde(default)]
+    pub run_space_block_insufficient: bool,
+    /// Per-event desktop notification toggles; events not listed are enabled
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub notification_preferences: BTreeMap<String, bool>,
+    /// Start processing queued imports at launch instead of waiting for a manual resume
+    #[serde(default)]
+    pub queue_autostart: bool,
+    /// Files left in "processing" longer than this are requeued at launch
+    #[serde(default = "default_stale_processing_minutes")]
+    pub stale_processing_minutes: u64,
+    /// Import queue worker threads; None picks half the CPUs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub queue_workers: Option<u32>,
+    /// Nextflow maxForks for runs that don't set their own; None leaves it unlimited
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_max_forks: Option<u32>,
+    /// Body for shared-results chat messages; None uses the built-in wording
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub results_message_template: Option<String>,
 }
 
 fn default_agent_bridge_enabled() -> bool {
@@ -61,6 +160,14 @@ fn default_agent_bridge_http_port() -> u16 {
     3334
 }
 
+fn default_run_space_headroom_mb() -> u64 {
+    1024
+}
+
+fn default_stale_processing_minutes() -> u64 {
+    5
+}
+
 impl Default for Settings {
     fn default() -> Self {
         Settings {
@@ -78,6 +185,20 @@ impl Default for Settings {
             agent_bridge_http_port: default_agent_bridge_http_port(),
             agent_bridge_token: None,
             agent_bridge_blocklist: Vec::new(),
+            syftbox_upload_bandwidth_limit: 0,
+            hotlink_transport_mode: None,
+            run_completion_webhook_url: None,
+            work_dir_root: None,
+            results_dir_root: None,
+            run_notifications_enabled: false,
+            run_space_headroom_mb: default_run_space_headroom_mb(),
+            run_space_block_insufficient: false,
+            notification_preferences: BTreeMap::new(),
+            queue_autostart: false,
+            stale_processing_minutes: default_stale_processing_minutes(),
+            queue_workers: None,
+            default_max_forks: None,
+            results_message_template: None,
         }
     }
 }
@@ -155,9 +276,17 @@ pub struct FileRecord {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub inferred_sex: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub inferred_sex_confidence: Option<f64>,
+    /// Set when the sex call is weakly supported and should be verified by hand.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inferred_sex_low_confidence: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub processing_error: Option<String>,
+    /// Folder the import scan started from, for re-syncing from the source.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub import_origin: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -196,6 +325,39 @@ pub struct ModuleEditorLoadResponse {
     pub metadata: ModuleMetadata,
     pub file_tree: Vec<ModuleFileNode>,
     pub has_module_yaml: bool,
+    pub reference_issues: Vec<ModuleReferenceIssue>,
+}
+
+/// A file referenced from module.yaml that is missing on disk.
+#[derive(Serialize, Clone, Debug)]
+pub struct ModuleReferenceIssue {
+    /// "workflow" or "asset"
+    pub kind: String,
+    pub reference: String,
+    pub expected_path: String,
+    /// Files in the module that look like the intended target (e.g. after a rename).
+    pub found_paths: Vec<String>,
+    pub message: String,
+}
+
+/// One preflight check on a module's entrypoint or runtime.
+#[derive(Serialize, Clone, Debug)]
+pub struct ModuleRunnableCheck {
+    /// "entrypoint_exists", "entrypoint_non_empty", "runtime_available" or "syntax"
+    pub check: String,
+    pub ok: bool,
+    pub path: Option<String>,
+    pub message: String,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct ModuleRunnableReport {
+    pub module_id: i64,
+    pub module_dir: String,
+    pub runner_kind: Option<String>,
+    pub entrypoint: Option<String>,
+    pub runnable: bool,
+    pub checks: Vec<ModuleRunnableCheck>,
 }
 
 // Run Types
@@ -210,6 +372,22 @@ pub struct Run {
     pub created_at: String,
 }
 
+/// A flow run as listed in the UI, with the desktop-side data kept next to it.
+#[derive(Serialize)]
+pub struct FlowRunRecord {
+    #[serde(flatten)]
+    pub run: biovault::data::Run,
+    pub labels: Vec<String>,
+    /// Whether finishing this run raises a desktop notification (override or global default)
+    pub notifications_enabled: bool,
+}
+
+#[derive(Serialize)]
+pub struct RunLabelCount {
+    pub label: String,
+    pub count: i64,
+}
+
 #[derive(Serialize)]
 pub struct RunStartResult {
     pub run_id: i64,
@@ -283,6 +461,53 @@ pub struct JupyterStatus {
     pub token: Option<String>,
 }
 
+#[derive(Serialize, Clone)]
+pub struct JupyterServerInfo {
+    pub module_path: String,
+    /// Set for servers launched from a session workspace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    pub running: bool,
+    pub pid: Option<i32>,
+    pub port: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    /// Reported by the server's /api/status; None when it doesn't answer
+    pub last_activity: Option<String>,
+    pub idle_seconds: Option<i64>,
+    pub kernels: Option<u32>,
+}
+
+#[derive(Serialize)]
+pub struct OrphanedJupyterServer {
+    pub pid: i32,
+    pub port: i32,
+    pub url: Option<String>,
+    pub root_dir: String,
+    /// The dev environment the server was launched for
+    pub module_path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    /// Answered /api/status on its port
+    pub responding: bool,
+    /// "reattached", "killed", or None when only reported
+    pub action: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct JupyterStopResult {
+    pub module_path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    pub stopped: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
 #[derive(Serialize)]
 pub struct JupyterResetResult {
     pub status: JupyterStatus,
@@ -426,6 +651,29 @@ pub struct SharedWithMeItem {
     pub is_subscribed: bool,
 }
 
+#[derive(Serialize, Clone)]
+pub struct SyncConflictVersion {
+    pub path: String,
+    pub exists: bool,
+    pub size: Option<u64>,
+    pub modified: Option<String>,
+    pub sha256: Option<String>,
+}
+
+#[derive(Serialize, Clone)]
+pub struct SyncConflict {
+    /// Path of the marked copy, relative to the datasites root
+    pub path: String,
+    /// Path the copy was split from, relative to the datasites root
+    pub original_path: String,
+    /// "conflict" or "rejected"
+    pub kind: String,
+    /// The edit made on this machine (the marked copy)
+    pub local: SyncConflictVersion,
+    /// What SyftBox kept at the original path
+    pub remote: SyncConflictVersion,
+}
+
 // Log Types
 #[derive(Serialize, Deserialize, Clone)]
 pub struct LogEntry {
@@ -239,24 +239,42 @@ fn get_commands_list() -> serde_json::Value {
         cmd("agent_api_get_schema", "agent_api", true),
         cmd("agent_api_list_commands", "agent_api", true),
         cmd("get_agent_api_commands", "agent_api", true),
+        cmd("agent_list_actions", "agent_api", true),
+        cmd_long("agent_execute", "agent_api", false),
         cmd("agent_api_events_info", "agent_api", true),
         // App Status
         cmd("get_app_version", "app_status", true),
+        cmd("get_embedded_cli_version", "app_status", true),
         cmd("is_dev_mode", "app_status", true),
         cmd("get_dev_mode_info", "app_status", true),
         cmd("get_env_var", "app_status", true),
         cmd("get_config_path", "app_status", true),
         cmd("get_database_path", "app_status", true),
+        cmd("get_database_schema_version", "app_status", true),
         cmd("get_settings", "settings", true),
         cmd("save_settings", "settings", false),
+        cmd("get_run_directory_roots", "settings", true),
+        cmd("set_run_directory_roots", "settings", false),
+        cmd("get_queue_autostart", "settings", true),
+        cmd("set_queue_autostart", "settings", false),
+        cmd("get_default_max_forks", "settings", true),
+        cmd("set_default_max_forks", "settings", false),
+        cmd("get_results_message_template", "settings", true),
+        cmd("set_results_message_template", "settings", false),
+        cmd("export_settings_profile", "settings", false),
+        cmd("import_settings_profile", "settings", false),
         cmd("set_autostart_enabled", "settings", false),
         cmd("get_autostart_enabled", "app_status", true),
+        cmd_async("enter_quiet_mode", "app_status", false),
+        cmd_async("exit_quiet_mode", "app_status", false),
+        cmd("get_quiet_mode_status", "app_status", true),
         // UI Control
         cmd("ui_navigate", "ui", false),
         cmd("ui_flow_import_options", "ui", false),
         cmd("ui_flow_import_from_path", "ui", false),
         // Onboarding
         cmd("check_is_onboarded", "onboarding", true),
+        cmd("validate_startup_config", "onboarding", true),
         cmd_async("complete_onboarding", "onboarding", false),
         // Profiles
         cmd("profiles_get_boot_state", "profiles", true),
@@ -272,11 +290,16 @@ fn get_commands_list() -> serde_json::Value {
         cmd("profiles_move_home", "profiles", false),
         cmd("profiles_delete_profile", "profiles", false),
         cmd("profiles_create_and_switch", "profiles", false),
+        cmd("list_profiles", "profiles", true),
+        cmd("create_profile", "profiles", false),
+        cmd("switch_profile", "profiles", false),
         // Dependencies
         cmd_async("check_dependencies", "dependencies", true),
         cmd_async("check_single_dependency", "dependencies", true),
+        cmd_async("check_java_compatibility", "dependencies", true),
         cmd_long("install_dependencies", "dependencies", false),
         cmd("update_saved_dependency_states", "dependencies", false),
+        cmd("refresh_bundled_binaries", "dependencies", false),
         cmd("get_saved_dependency_states", "dependencies", true),
         cmd_async("check_docker_running", "dependencies", true),
         cmd_long("install_dependency", "dependencies", false),
@@ -290,6 +313,10 @@ fn get_commands_list() -> serde_json::Value {
         cmd("start_syftbox_client", "syftbox", false),
         cmd("stop_syftbox_client", "syftbox", false),
         cmd("get_syftbox_config_info", "syftbox", true),
+        cmd("get_syftbox_token_status", "syftbox", true),
+        cmd_async("refresh_syftbox_token", "syftbox", false),
+        cmd("detect_syftbox_data_dir", "syftbox", true),
+        cmd("set_syftbox_data_dir", "syftbox", false),
         cmd("get_default_syftbox_server_url", "syftbox", true),
         cmd("is_dev_syftbox_enabled", "syftbox", true),
         cmd_async("check_dev_syftbox_server", "syftbox", true),
@@ -300,6 +327,12 @@ fn get_commands_list() -> serde_json::Value {
         cmd("test_peer_link", "syftbox", false),
         cmd_async("syftbox_subscriptions_discovery", "syftbox", true),
         cmd_long("syftbox_upload_action", "syftbox", false),
+        cmd_async("list_active_uploads", "syftbox", true),
+        cmd_async("cancel_upload", "syftbox", false),
+        cmd_async("set_syftbox_bandwidth_limit", "syftbox", false),
+        cmd_async("get_syftbox_bandwidth_status", "syftbox", true),
+        cmd("get_hotlink_transport_mode", "syftbox", true),
+        cmd("set_hotlink_transport_mode", "syftbox", false),
         cmd_async("syftbox_request_otp", "syftbox", false),
         cmd_async("syftbox_submit_otp", "syftbox", false),
         // Sync Tree
@@ -313,6 +346,8 @@ fn get_commands_list() -> serde_json::Value {
         cmd_async("sync_tree_subscribe", "sync_tree", false),
         cmd_async("sync_tree_unsubscribe", "sync_tree", false),
         cmd_async("sync_tree_set_subscription", "sync_tree", false),
+        cmd_async("list_sync_conflicts", "sync_tree", true),
+        cmd_async("resolve_sync_conflict", "sync_tree", false),
         // Keys
         cmd("key_get_status", "keys", true),
         cmd("key_list_contacts", "keys", true),
@@ -337,11 +372,13 @@ fn get_commands_list() -> serde_json::Value {
         cmd("send_message", "messages", false),
         cmd("mark_thread_as_read", "messages", false),
         cmd("delete_message", "messages", false),
+        cmd("verify_message_attachment", "messages", true),
         cmd("delete_thread", "messages", false),
         cmd("count_failed_messages", "messages", true),
         cmd("list_failed_messages", "messages", true),
         cmd("dismiss_failed_message", "messages", false),
         cmd("delete_failed_message", "messages", false),
+        cmd("migrate_identity", "messages", false),
         // Modules
         cmd("get_modules", "modules", true),
         cmd("get_available_module_examples", "modules", true),
@@ -350,8 +387,11 @@ fn get_commands_list() -> serde_json::Value {
         cmd("import_module", "modules", false),
         cmd("import_module_from_folder", "modules", false),
         cmd("delete_module", "modules", false),
+        cmd("find_flows_using_module", "modules", true),
         cmd("delete_module_folder", "modules", false),
         cmd("load_module_editor", "modules", true),
+        cmd("validate_module_references", "modules", true),
+        cmd_async("check_module_runnable", "modules", true),
         cmd("save_module_editor", "modules", false),
         cmd("preview_module_spec", "modules", true),
         cmd("get_module_spec_digest", "modules", true),
@@ -361,6 +401,8 @@ fn get_commands_list() -> serde_json::Value {
         cmd("get_common_formats", "modules", true),
         cmd("get_local_flow_templates", "modules", true),
         cmd("get_flow_template_catalog", "modules", true),
+        cmd("get_available_flow_examples", "modules", true),
+        cmd_long("create_flow_from_example", "modules", false),
         // Flows
         cmd_async("get_flows", "flows", true),
         cmd_async("create_flow", "flows", false),
@@ -368,8 +410,13 @@ fn get_commands_list() -> serde_json::Value {
         cmd_async("import_flow_from_message", "flows", false),
         cmd_async("import_flow_from_request", "flows", false),
         cmd_async("import_flow_from_json", "flows", false),
+        cmd_async("import_flow_from_git", "flows", false),
         cmd_long("import_flow_with_deps", "flows", false),
         cmd_long("run_flow", "flows", false),
+        cmd_async("preview_run_inputs", "flows", true),
+        cmd_async("describe_flow_inputs", "flows", true),
+        cmd("get_flow_spec_digest", "flows", true),
+        cmd_async("check_dataset_shape_compatibility", "flows", true),
         cmd_async("get_flow_runs", "flows", true),
         cmd_async("get_runs_base_dir", "flows", true),
         cmd_async("load_flow_editor", "flows", true),
@@ -382,6 +429,23 @@ fn get_commands_list() -> serde_json::Value {
         cmd_async("list_run_configs", "flows", true),
         cmd_async("get_run_config", "flows", true),
         cmd_async("delete_run_config", "flows", false),
+        cmd("estimate_run_duration", "flows", true),
+        cmd_async("check_run_space_requirements", "flows", true),
+        cmd_async("diff_run_outputs", "flows", true),
+        cmd_async("get_run_input_sources", "flows", true),
+        cmd_async("get_runs_for_dataset", "flows", true),
+        cmd_async("get_run_image_status", "flows", true),
+        cmd_long("prefetch_run_images", "flows", false),
+        cmd("get_run_completion_webhook", "flows", true),
+        cmd("set_run_completion_webhook", "flows", false),
+        cmd("set_run_webhook_override", "flows", false),
+        cmd_async("test_webhook", "flows", true),
+        cmd("get_run_notification", "flows", true),
+        cmd("set_run_notification", "flows", false),
+        cmd("get_run_notifications_default", "flows", true),
+        cmd("set_run_notifications_default", "flows", false),
+        cmd("get_notification_preferences", "settings", true),
+        cmd("set_notification_preference", "settings", false),
         cmd("send_flow_request", "flows", false),
         cmd("send_flow_request_results", "flows", false),
         cmd("send_flow_results", "flows", false),
@@ -394,7 +458,12 @@ fn get_commands_list() -> serde_json::Value {
         cmd("upsert_dataset_manifest", "datasets", false),
         cmd("is_dataset_published", "datasets", true),
         cmd("delete_dataset", "datasets", false),
+        cmd("find_duplicate_datasets", "datasets", true),
+        cmd("audit_dataset_links", "datasets", true),
+        cmd("repair_dataset_links", "datasets", false),
+        cmd("merge_datasets", "datasets", false),
         cmd_async("publish_dataset", "datasets", false),
+        cmd_long("publish_datasets_bulk", "datasets", false),
         cmd("unpublish_dataset", "datasets", false),
         cmd("get_datasets_folder_path", "datasets", true),
         cmd("resolve_dataset_path", "datasets", true),
@@ -404,7 +473,9 @@ fn get_commands_list() -> serde_json::Value {
         cmd("unsubscribe_dataset", "datasets", false),
         // Files
         cmd("get_files", "files", true),
+        cmd("get_files_page", "files", true),
         cmd("list_files", "files", true),
+        cmd("get_files_by_origin", "files", true),
         cmd("get_participants", "participants", true),
         cmd("get_extensions", "files", true),
         cmd("search_txt_files", "files", true),
@@ -414,28 +485,61 @@ fn get_commands_list() -> serde_json::Value {
         cmd("extract_ids_for_files", "files", true),
         cmd_async("detect_file_types", "files", true),
         cmd_async("analyze_file_types", "files", true),
+        cmd("reanalyze_file", "files", false),
         cmd_async("fetch_sample_data", "files", false),
         cmd_async("fetch_sample_data_with_progress", "files", false),
         cmd_async("import_files_pending", "files", false),
         cmd_async("import_files", "files", false),
         cmd_async("import_files_with_metadata", "files", false),
+        cmd_async("preview_import", "files", true),
+        cmd("watch_import_folder", "files", false),
+        cmd("unwatch_import_folder", "files", false),
+        cmd("list_watched_folders", "files", true),
+        cmd("get_import_conflicts", "files", true),
+        cmd_long("import_genotype_zip", "files", false),
         cmd("is_directory", "files", true),
         cmd("delete_file", "files", false),
         cmd("delete_files_bulk", "files", false),
+        cmd("update_files_metadata_bulk", "files", false),
+        cmd_async("check_files_exist", "files", true),
+        cmd_async("verify_files", "files", true),
+        cmd("prune_missing_files", "files", false),
+        cmd("snapshot_library", "files", false),
+        cmd("get_sex_inference_confidence", "files", false),
+        cmd("list_library_snapshots", "files", true),
+        cmd("diff_library_snapshots", "files", true),
+        cmd("delete_library_snapshot", "files", false),
+        cmd("register_data_type", "files", false),
+        cmd("list_data_types", "files", true),
+        cmd("remove_data_type", "files", false),
         cmd_async("process_queue", "files", false),
         cmd("pause_queue_processor", "files", false),
         cmd("resume_queue_processor", "files", false),
         cmd("clear_pending_queue", "files", false),
+        cmd("list_queued_files", "files", true),
+        cmd("cancel_queued_files", "files", false),
+        cmd("set_file_priority", "files", false),
+        cmd("prioritize_files", "files", false),
         cmd("open_folder", "files", false),
         // Participants
         cmd("delete_participant", "participants", false),
         cmd("delete_participants_bulk", "participants", false),
+        cmd("check_cohort_completeness", "participants", true),
+        cmd("check_participant_id_conflicts", "participants", true),
         // Runs
         cmd("get_runs", "runs", true),
         cmd("delete_run", "runs", false),
+        CommandInfo {
+            is_async: true,
+            ..cmd_danger("delete_runs_older_than", "runs")
+        },
+        cmd("set_run_labels", "runs", false),
+        cmd("get_runs_by_label", "runs", true),
+        cmd("get_run_label_counts", "runs", true),
         cmd("get_run_logs", "runs", true),
         cmd("get_run_logs_tail", "runs", true),
         cmd("get_run_logs_full", "runs", true),
+        cmd("get_child_process_environment", "runs", true),
         cmd("get_flow_run_logs", "flows", true),
         cmd("get_flow_run_logs_tail", "flows", true),
         cmd("get_flow_run_logs_full", "flows", true),
@@ -480,14 +584,21 @@ fn get_commands_list() -> serde_json::Value {
         cmd_long("launch_jupyter", "jupyter", false),
         cmd_async("stop_jupyter", "jupyter", false),
         cmd_long("reset_jupyter", "jupyter", false),
+        cmd_async("list_jupyter_servers", "jupyter", true),
+        cmd_async("stop_all_jupyter_servers", "jupyter", false),
+        cmd_async("discover_orphaned_jupyter", "jupyter", false),
         // Logs
         cmd("get_command_logs", "logs", true),
         cmd("get_desktop_log_dir", "logs", true),
+        cmd("list_log_files", "logs", true),
+        cmd("open_log_file", "logs", false),
         cmd("get_desktop_log_text", "logs", true),
         cmd("clear_desktop_log", "logs", false),
         cmd("clear_command_logs", "logs", false),
         cmd("get_queue_info", "logs", true),
         cmd("get_queue_processor_status", "logs", true),
+        cmd("get_queue_metrics", "logs", true),
+        cmd("get_queue_throughput_stats", "logs", true),
         // SQL
         cmd("sql_list_tables", "sql", true),
         cmd("sql_get_table_schema", "sql", true),
@@ -965,6 +1076,22 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
     if !is_bridge_enabled() {
         return Err("Agent bridge disabled by settings".to_string());
     }
+    dispatch_command(app, cmd, args).await
+}
+
+/// Boxed entry into `dispatch_command` for `agent_execute`, which is itself
+/// dispatched from here; the explicit type breaks the async recursion cycle.
+pub(crate) fn dispatch_command_boxed<'a>(
+    app: &'a AppHandle,
+    cmd: &'a str,
+    args: Value,
+) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Value, String>> + Send + 'a>> {
+    Box::pin(dispatch_command(app, cmd, args))
+}
+
+/// Dispatch a bridge command without the transport-level enabled check (the
+/// agent policy blocklist still applies).
+async fn dispatch_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Value, String> {
     if is_command_blocked(cmd) {
         return Err(format!("Command '{}' blocked by agent policy", cmd));
     }
@@ -1055,6 +1182,22 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
             let commands = crate::commands::agent_api::get_agent_api_commands(app.clone())?;
             Ok(serde_json::to_value(commands).unwrap())
         }
+        "agent_list_actions" => {
+            let result = crate::commands::agent_api::agent_list_actions();
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "agent_execute" => {
+            let action: String = serde_json::from_value(
+                args.get("action")
+                    .cloned()
+                    .ok_or_else(|| "Missing action".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse action: {}", e))?;
+            let params = args.get("params").cloned();
+            let result =
+                crate::commands::agent_api::agent_execute(app.clone(), action, params).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "agent_api_list_commands" => {
             // Return a lightweight list of available commands with basic metadata
             // This is faster than get_schema for agents that just need command names
@@ -1198,7 +1341,42 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
             Ok(serde_json::to_value(result).unwrap())
         }
         "get_files" | "list_files" => {
-            let result = crate::get_files(state).map_err(|e| e.to_string())?;
+            let filter: Option<crate::commands::files::FileFilter> = args
+                .get("filter")
+                .cloned()
+                .filter(|v| !v.is_null())
+                .map(serde_json::from_value)
+                .transpose()
+                .map_err(|e| format!("Failed to parse filter: {}", e))?;
+            let limit: Option<i64> = args.get("limit").and_then(|v| v.as_i64());
+            let offset: Option<i64> = args.get("offset").and_then(|v| v.as_i64());
+            let result =
+                crate::get_files(state, filter, limit, offset).map_err(|e| e.to_string())?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "get_files_page" => {
+            let filter: Option<crate::commands::files::FileFilter> = args
+                .get("filter")
+                .cloned()
+                .filter(|v| !v.is_null())
+                .map(serde_json::from_value)
+                .transpose()
+                .map_err(|e| format!("Failed to parse filter: {}", e))?;
+            let limit: Option<i64> = args.get("limit").and_then(|v| v.as_i64());
+            let offset: Option<i64> = args.get("offset").and_then(|v| v.as_i64());
+            let result = crate::commands::files::get_files_page(state, filter, limit, offset)
+                .map_err(|e| e.to_string())?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "get_files_by_origin" => {
+            let origin: String = serde_json::from_value(
+                args.get("origin")
+                    .cloned()
+                    .ok_or_else(|| "Missing origin".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse origin: {}", e))?;
+            let result = crate::commands::files::get_files_by_origin(state, origin)
+                .map_err(|e| e.to_string())?;
             Ok(serde_json::to_value(result).unwrap())
         }
         "get_modules" => {
@@ -1220,6 +1398,62 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
             crate::commands::runs::delete_run(state, run_id).map_err(|e| e.to_string())?;
             Ok(serde_json::Value::Null)
         }
+        "delete_runs_older_than" => {
+            let days: u32 = serde_json::from_value(
+                args.get("days")
+                    .cloned()
+                    .ok_or_else(|| "Missing days".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse days: {}", e))?;
+            let keep_labeled = args
+                .get("keepLabeled")
+                .or_else(|| args.get("keep_labeled"))
+                .and_then(|v| v.as_bool());
+            let delete_work_dirs = args
+                .get("deleteWorkDirs")
+                .or_else(|| args.get("delete_work_dirs"))
+                .and_then(|v| v.as_bool());
+            let result = crate::commands::runs::delete_runs_older_than(
+                app.clone(),
+                state,
+                days,
+                keep_labeled,
+                delete_work_dirs,
+            )
+            .await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "set_run_labels" => {
+            let run_id: i64 = serde_json::from_value(
+                args.get("runId")
+                    .or_else(|| args.get("run_id"))
+                    .cloned()
+                    .ok_or_else(|| "Missing runId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse runId: {}", e))?;
+            let labels: Vec<String> = serde_json::from_value(
+                args.get("labels")
+                    .cloned()
+                    .ok_or_else(|| "Missing labels".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse labels: {}", e))?;
+            let result = crate::commands::runs::set_run_labels(state, run_id, labels)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "get_runs_by_label" => {
+            let label: String = serde_json::from_value(
+                args.get("label")
+                    .cloned()
+                    .ok_or_else(|| "Missing label".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse label: {}", e))?;
+            let result = crate::commands::runs::get_runs_by_label(state, label)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "get_run_label_counts" => {
+            let result = crate::commands::runs::get_run_label_counts(state)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "get_run_logs" => {
             let run_id: i64 = serde_json::from_value(
                 args.get("runId")
@@ -1260,6 +1494,10 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
                 .map_err(|e| e.to_string())?;
             Ok(serde_json::to_value(result).unwrap())
         }
+        "get_child_process_environment" => {
+            let result = crate::commands::runs::get_child_process_environment()?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "reconcile_flow_runs" => {
             crate::commands::flows::reconcile_flow_runs(state)
                 .await
@@ -1451,6 +1689,88 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
             let result = crate::get_settings().map_err(|e| e.to_string())?;
             Ok(serde_json::to_value(result).unwrap())
         }
+        "get_run_directory_roots" => {
+            let result = crate::commands::settings::get_run_directory_roots()?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "get_default_max_forks" => {
+            let result = crate::commands::settings::get_default_max_forks()?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "set_default_max_forks" => {
+            let max_forks: Option<u32> = args
+                .get("maxForks")
+                .or_else(|| args.get("max_forks"))
+                .cloned()
+                .and_then(|v| serde_json::from_value(v).ok());
+            let result = crate::commands::settings::set_default_max_forks(max_forks)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "get_results_message_template" => {
+            let result = crate::commands::settings::get_results_message_template()?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "set_results_message_template" => {
+            let template: Option<String> = args
+                .get("template")
+                .cloned()
+                .and_then(|v| serde_json::from_value(v).ok());
+            let result = crate::commands::settings::set_results_message_template(template)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "get_queue_autostart" => {
+            let result = crate::commands::settings::get_queue_autostart()?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "set_queue_autostart" => {
+            let enabled: bool = serde_json::from_value(
+                args.get("enabled")
+                    .cloned()
+                    .ok_or_else(|| "Missing enabled".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse enabled: {}", e))?;
+            let result = crate::commands::settings::set_queue_autostart(enabled)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "set_run_directory_roots" => {
+            let work_dir_root: Option<String> = args
+                .get("workDirRoot")
+                .or_else(|| args.get("work_dir_root"))
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string());
+            let results_dir_root: Option<String> = args
+                .get("resultsDirRoot")
+                .or_else(|| args.get("results_dir_root"))
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string());
+            let result = crate::commands::settings::set_run_directory_roots(
+                work_dir_root,
+                results_dir_root,
+            )?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "export_settings_profile" => {
+            let dest_path: String = serde_json::from_value(
+                args.get("destPath")
+                    .or_else(|| args.get("dest_path"))
+                    .cloned()
+                    .ok_or_else(|| "Missing destPath".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse destPath: {}", e))?;
+            let result =
+                crate::commands::settings::export_settings_profile(state.clone(), dest_path)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "import_settings_profile" => {
+            let path: String = serde_json::from_value(
+                args.get("path")
+                    .cloned()
+                    .ok_or_else(|| "Missing path".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse path: {}", e))?;
+            let result = crate::commands::settings::import_settings_profile(state.clone(), path)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "save_settings" => {
             let current = crate::get_settings().map_err(|e| e.to_string())?;
             let mut settings_value = args.get("settings").cloned().unwrap_or(args.clone());
@@ -1566,6 +1886,30 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
                 .map_err(|e| e.to_string())?;
             Ok(serde_json::Value::Null)
         }
+        "list_profiles" => {
+            let result = crate::commands::profiles::list_profiles()?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "create_profile" => {
+            let name: String = serde_json::from_value(
+                args.get("name")
+                    .cloned()
+                    .ok_or_else(|| "Missing name".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse name: {}", e))?;
+            let result = crate::commands::profiles::create_profile(name)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "switch_profile" => {
+            let name: String = serde_json::from_value(
+                args.get("name")
+                    .cloned()
+                    .ok_or_else(|| "Missing name".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse name: {}", e))?;
+            let result = crate::commands::profiles::switch_profile(app.clone(), state, name)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "profiles_open_picker" => {
             crate::commands::profiles::profiles_open_picker(app.clone())
                 .map_err(|e| e.to_string())?;
@@ -1660,6 +2004,10 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
                 .map_err(|e| e.to_string())?;
             Ok(serde_json::Value::Null)
         }
+        "check_java_compatibility" => {
+            let result = crate::commands::dependencies::check_java_compatibility().await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "check_dependencies" => {
             let result = crate::check_dependencies()
                 .await
@@ -1675,6 +2023,10 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
                 .map_err(|e| e.to_string())?;
             Ok(serde_json::to_value(true).unwrap())
         }
+        "refresh_bundled_binaries" => {
+            let result = crate::commands::dependencies::refresh_bundled_binaries(app.clone())?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "update_saved_dependency_states" => {
             // Run in blocking thread pool since this calls subprocess checks (java, docker, etc.)
             tokio::task::spawn_blocking(crate::update_saved_dependency_states)
@@ -1768,6 +2120,10 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
             let result = crate::check_is_onboarded().map_err(|e| e.to_string())?;
             Ok(serde_json::to_value(result).unwrap())
         }
+        "validate_startup_config" => {
+            let result = crate::validate_startup_config()?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "complete_onboarding" => {
             let email: String = serde_json::from_value(
                 args.get("email")
@@ -1788,6 +2144,14 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
             let result = crate::get_queue_processor_status(state).map_err(|e| e.to_string())?;
             Ok(serde_json::to_value(result).unwrap())
         }
+        "get_queue_metrics" => {
+            let result = crate::get_queue_metrics(state).map_err(|e| e.to_string())?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "get_queue_throughput_stats" => {
+            let result = crate::get_queue_throughput_stats(state).map_err(|e| e.to_string())?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "get_saved_dependency_states" => {
             let result = crate::get_saved_dependency_states().map_err(|e| e.to_string())?;
             Ok(serde_json::to_value(result).unwrap())
@@ -1804,10 +2168,32 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
             let result = crate::stop_syftbox_client().map_err(|e| e.to_string())?;
             Ok(serde_json::to_value(result).unwrap())
         }
+        "get_syftbox_token_status" => {
+            let result = crate::commands::syftbox::get_syftbox_token_status()?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "refresh_syftbox_token" => {
+            let result = crate::commands::syftbox::refresh_syftbox_token(app.clone()).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "get_syftbox_config_info" => {
             let result = crate::get_syftbox_config_info().map_err(|e| e.to_string())?;
             Ok(serde_json::to_value(result).unwrap())
         }
+        "detect_syftbox_data_dir" => {
+            let result = crate::commands::syftbox::detect_syftbox_data_dir()?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "set_syftbox_data_dir" => {
+            let path: String = serde_json::from_value(
+                args.get("path")
+                    .cloned()
+                    .ok_or_else(|| "Missing path".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse path: {}", e))?;
+            let result = crate::commands::syftbox::set_syftbox_data_dir(path)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "get_default_syftbox_server_url" => {
             let result = crate::get_default_syftbox_server_url();
             Ok(serde_json::to_value(result).unwrap())
@@ -1874,6 +2260,29 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
                 .map_err(|e| e.to_string())?;
             Ok(serde_json::to_value(result).unwrap())
         }
+        "validate_module_references" => {
+            let module_path: String = serde_json::from_value(
+                args.get("modulePath")
+                    .or_else(|| args.get("module_path"))
+                    .cloned()
+                    .ok_or_else(|| "Missing modulePath".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse modulePath: {}", e))?;
+            let result = crate::commands::modules::validate_module_references(module_path)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "check_module_runnable" => {
+            let module_id: i64 = serde_json::from_value(
+                args.get("moduleId")
+                    .or_else(|| args.get("module_id"))
+                    .cloned()
+                    .ok_or_else(|| "Missing moduleId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse moduleId: {}", e))?;
+            let result =
+                crate::commands::modules::check_module_runnable(state.clone(), module_id).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "save_module_editor" => {
             let module_id: Option<i64> = args
                 .get("moduleId")
@@ -1944,6 +2353,21 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
                 .map_err(|e| e.to_string())?;
             Ok(serde_json::to_value(result).unwrap())
         }
+        "list_jupyter_servers" => {
+            let result = crate::commands::jupyter::list_jupyter_servers().await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "stop_all_jupyter_servers" => {
+            let result = crate::commands::jupyter::stop_all_jupyter_servers().await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "discover_orphaned_jupyter" => {
+            let action: Option<String> = args
+                .get("action")
+                .and_then(|v| serde_json::from_value(v.clone()).ok());
+            let result = crate::commands::jupyter::discover_orphaned_jupyter(action).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "key_get_status" => {
             let email: Option<String> = args
                 .get("email")
@@ -2111,6 +2535,49 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
                 .map_err(|e| e.to_string())?;
             Ok(serde_json::Value::Null)
         }
+        "list_active_uploads" => {
+            let result = crate::commands::syftbox::list_active_uploads().await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "cancel_upload" => {
+            let target: String = serde_json::from_value(
+                args.get("filePathOrId")
+                    .or_else(|| args.get("file_path_or_id"))
+                    .cloned()
+                    .ok_or_else(|| "Missing filePathOrId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse filePathOrId: {}", e))?;
+            let result = crate::commands::syftbox::cancel_upload(app.clone(), target).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "set_syftbox_bandwidth_limit" => {
+            let kbps: u64 = serde_json::from_value(
+                args.get("kbps")
+                    .cloned()
+                    .ok_or_else(|| "Missing kbps".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse kbps: {}", e))?;
+            let result = crate::commands::syftbox::set_syftbox_bandwidth_limit(kbps).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "get_syftbox_bandwidth_status" => {
+            let result = crate::commands::syftbox::get_syftbox_bandwidth_status().await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "get_hotlink_transport_mode" => {
+            let result = crate::commands::syftbox::get_hotlink_transport_mode()?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "set_hotlink_transport_mode" => {
+            let mode: String = serde_json::from_value(
+                args.get("mode")
+                    .cloned()
+                    .ok_or_else(|| "Missing mode".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse mode: {}", e))?;
+            let result = crate::commands::syftbox::set_hotlink_transport_mode(mode)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "syftbox_request_otp" => {
             let email: String = serde_json::from_value(
                 args.get("email")
@@ -2237,6 +2704,30 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
                 .map_err(|e| e.to_string())?;
             Ok(serde_json::Value::Null)
         }
+        "list_sync_conflicts" => {
+            let result = crate::commands::sync_tree::list_sync_conflicts()
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "resolve_sync_conflict" => {
+            let path: String = serde_json::from_value(
+                args.get("path")
+                    .cloned()
+                    .ok_or_else(|| "Missing path".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse path: {}", e))?;
+            let choice: String = serde_json::from_value(
+                args.get("choice")
+                    .cloned()
+                    .ok_or_else(|| "Missing choice".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse choice: {}", e))?;
+            crate::commands::sync_tree::resolve_sync_conflict(path, choice)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(serde_json::Value::Null)
+        }
         "sync_tree_set_subscription" => {
             let path: String = serde_json::from_value(
                 args.get("path")
@@ -2282,6 +2773,25 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
             crate::delete_message(message_id).map_err(|e| e.to_string())?;
             Ok(serde_json::to_value(()).unwrap())
         }
+        "verify_message_attachment" => {
+            let message_id: String = serde_json::from_value(
+                args.get("messageId")
+                    .cloned()
+                    .or_else(|| args.get("message_id").cloned())
+                    .ok_or_else(|| "Missing messageId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse messageId: {}", e))?;
+            let file_name: String = serde_json::from_value(
+                args.get("fileName")
+                    .cloned()
+                    .or_else(|| args.get("file_name").cloned())
+                    .ok_or_else(|| "Missing fileName".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse fileName: {}", e))?;
+            let result =
+                crate::commands::messages::verify_message_attachment(message_id, file_name)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "delete_thread" => {
             let thread_id: String = serde_json::from_value(
                 args.get("threadId")
@@ -2702,10 +3212,28 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
             let result = crate::get_app_version();
             Ok(serde_json::to_value(result).unwrap())
         }
+        "get_embedded_cli_version" => {
+            let result = crate::get_embedded_cli_version();
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "get_desktop_log_dir" => {
             let result = crate::get_desktop_log_dir().map_err(|e| e.to_string())?;
             Ok(serde_json::to_value(result).unwrap())
         }
+        "list_log_files" => {
+            let result = crate::list_log_files()?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "open_log_file" => {
+            let name: String = serde_json::from_value(
+                args.get("name")
+                    .cloned()
+                    .ok_or_else(|| "Missing name".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse name: {}", e))?;
+            crate::open_log_file(name)?;
+            Ok(serde_json::Value::Null)
+        }
         "key_check_vault_debug" => {
             let result = crate::key_check_vault_debug().map_err(|e| e.to_string())?;
             Ok(serde_json::to_value(result).unwrap())
@@ -2732,6 +3260,24 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
             let result = crate::get_autostart_enabled((*app).clone()).map_err(|e| e.to_string())?;
             Ok(serde_json::to_value(result).unwrap())
         }
+        "enter_quiet_mode" => {
+            let pause_flows: Option<bool> = args
+                .get("pauseFlows")
+                .or_else(|| args.get("pause_flows"))
+                .and_then(|v| serde_json::from_value(v.clone()).ok());
+            let result =
+                crate::commands::quiet_mode::enter_quiet_mode(state.clone(), pause_flows).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "exit_quiet_mode" => {
+            let result =
+                crate::commands::quiet_mode::exit_quiet_mode(app.clone(), state.clone()).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "get_quiet_mode_status" => {
+            let result = crate::commands::quiet_mode::get_quiet_mode_status()?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "get_syftbox_diagnostics" => {
             let result = crate::get_syftbox_diagnostics().map_err(|e| e.to_string())?;
             Ok(serde_json::to_value(result).unwrap())
@@ -2788,6 +3334,10 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
                 crate::commands::syftbox::test_peer_link(options).map_err(|e| e.to_string())?;
             Ok(serde_json::to_value(result).unwrap())
         }
+        "get_database_schema_version" => {
+            let result = crate::commands::settings::get_database_schema_version(state.clone())?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "get_database_path" => {
             let result = crate::commands::settings::get_database_path()?;
             Ok(serde_json::to_value(result).unwrap())
@@ -2971,9 +3521,18 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
                     .ok_or_else(|| "Missing fileMetadata".to_string())?,
             )
             .map_err(|e| format!("Failed to parse fileMetadata: {}", e))?;
-            let result = crate::commands::files::import::import_files_pending(state, file_metadata)
-                .await
-                .map_err(|e| e.to_string())?;
+            let import_origin: Option<String> = args
+                .get("importOrigin")
+                .or_else(|| args.get("import_origin"))
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string());
+            let result = crate::commands::files::import::import_files_pending(
+                state,
+                file_metadata,
+                import_origin,
+            )
+            .await
+            .map_err(|e| e.to_string())?;
             Ok(serde_json::to_value(result).unwrap())
         }
         // --------------------------------------------------------------------
@@ -3102,16 +3661,118 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
                 .map_err(|e| e.to_string())?;
             Ok(serde_json::to_value(result).unwrap())
         }
-        "run_flow" => {
-            // Try to get the main window for event emission (optional in WS bridge mode)
-            let window = app.get_webview_window("main");
-
-            let flow_id: i64 = serde_json::from_value(
-                args.get("flowId")
+        "import_flow_from_git" => {
+            let url: String = serde_json::from_value(
+                args.get("url")
                     .cloned()
-                    .ok_or_else(|| "Missing flowId".to_string())?,
+                    .ok_or_else(|| "Missing url".to_string())?,
             )
-            .map_err(|e| format!("Failed to parse flowId: {}", e))?;
+            .map_err(|e| format!("Failed to parse url: {}", e))?;
+            let git_ref: Option<String> = args
+                .get("gitRef")
+                .or_else(|| args.get("git_ref"))
+                .or_else(|| args.get("ref"))
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string());
+            let overwrite = args.get("overwrite").and_then(|v| v.as_bool());
+            let result = crate::commands::flows::import_flow_from_git(
+                state.clone(),
+                url,
+                git_ref,
+                overwrite,
+            )
+            .await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "check_dataset_shape_compatibility" => {
+            let flow_id: i64 = serde_json::from_value(
+                args.get("flowId")
+                    .or_else(|| args.get("flow_id"))
+                    .cloned()
+                    .ok_or_else(|| "Missing flowId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse flowId: {}", e))?;
+            let input_name: String = serde_json::from_value(
+                args.get("inputName")
+                    .or_else(|| args.get("input_name"))
+                    .cloned()
+                    .ok_or_else(|| "Missing inputName".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse inputName: {}", e))?;
+            let dataset_name: String = serde_json::from_value(
+                args.get("datasetName")
+                    .or_else(|| args.get("dataset_name"))
+                    .cloned()
+                    .ok_or_else(|| "Missing datasetName".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse datasetName: {}", e))?;
+            let data_type: Option<String> = args
+                .get("dataType")
+                .or_else(|| args.get("data_type"))
+                .cloned()
+                .and_then(|v| serde_json::from_value(v).ok());
+            let result = crate::commands::flows::check_dataset_shape_compatibility(
+                state.clone(),
+                flow_id,
+                input_name,
+                dataset_name,
+                data_type,
+            )
+            .await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "get_flow_spec_digest" => {
+            let flow_id: i64 = serde_json::from_value(
+                args.get("flowId")
+                    .or_else(|| args.get("flow_id"))
+                    .cloned()
+                    .ok_or_else(|| "Missing flowId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse flowId: {}", e))?;
+            let result = crate::commands::flows::get_flow_spec_digest(state.clone(), flow_id)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "describe_flow_inputs" => {
+            let flow_id: i64 = serde_json::from_value(
+                args.get("flowId")
+                    .or_else(|| args.get("flow_id"))
+                    .cloned()
+                    .ok_or_else(|| "Missing flowId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse flowId: {}", e))?;
+            let result =
+                crate::commands::flows::describe_flow_inputs(state.clone(), flow_id).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "preview_run_inputs" => {
+            let flow_id: i64 = serde_json::from_value(
+                args.get("flowId")
+                    .or_else(|| args.get("flow_id"))
+                    .cloned()
+                    .ok_or_else(|| "Missing flowId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse flowId: {}", e))?;
+            let selection: crate::commands::flows::FlowRunSelection = serde_json::from_value(
+                args.get("selection")
+                    .cloned()
+                    .ok_or_else(|| "Missing selection".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse selection: {}", e))?;
+            let result =
+                crate::commands::flows::preview_run_inputs(state.clone(), flow_id, selection)
+                    .await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "run_flow" => {
+            // Try to get the main window for event emission (optional in WS bridge mode)
+            let window = app.get_webview_window("main");
+
+            let flow_id: i64 = serde_json::from_value(
+                args.get("flowId")
+                    .cloned()
+                    .ok_or_else(|| "Missing flowId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse flowId: {}", e))?;
 
             let input_overrides: std::collections::HashMap<String, String> =
                 serde_json::from_value(
@@ -3145,6 +3806,7 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
 
             let result = crate::commands::flows::run_flow_impl(
                 state.clone(),
+                app.clone(),
                 window,
                 flow_id,
                 input_overrides,
@@ -3247,6 +3909,50 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
             let result = crate::commands::datasets::delete_dataset(state.clone(), name)?;
             Ok(serde_json::to_value(result).unwrap())
         }
+        "find_duplicate_datasets" => {
+            let result = crate::commands::datasets::find_duplicate_datasets(state.clone())?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "audit_dataset_links" => {
+            let dataset_name: String = serde_json::from_value(
+                args.get("datasetName")
+                    .or_else(|| args.get("dataset_name"))
+                    .cloned()
+                    .ok_or_else(|| "Missing datasetName".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse datasetName: {}", e))?;
+            let result =
+                crate::commands::datasets::audit_dataset_links(state.clone(), dataset_name)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "repair_dataset_links" => {
+            let dataset_name: String = serde_json::from_value(
+                args.get("datasetName")
+                    .or_else(|| args.get("dataset_name"))
+                    .cloned()
+                    .ok_or_else(|| "Missing datasetName".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse datasetName: {}", e))?;
+            let result =
+                crate::commands::datasets::repair_dataset_links(state.clone(), dataset_name)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "merge_datasets" => {
+            let source: String = serde_json::from_value(
+                args.get("source")
+                    .cloned()
+                    .ok_or_else(|| "Missing source".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse source: {}", e))?;
+            let target: String = serde_json::from_value(
+                args.get("target")
+                    .cloned()
+                    .ok_or_else(|| "Missing target".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse target: {}", e))?;
+            let result = crate::commands::datasets::merge_datasets(state.clone(), source, target)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "publish_dataset" => {
             let manifest_path: Option<String> = args
                 .get("manifestPath")
@@ -3271,6 +3977,32 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
             .map_err(|e| e.to_string())?;
             Ok(serde_json::Value::Null)
         }
+        "publish_datasets_bulk" => {
+            let names: Vec<String> = serde_json::from_value(
+                args.get("names")
+                    .cloned()
+                    .ok_or_else(|| "Missing names".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse names: {}", e))?;
+            let recipients: Option<Vec<String>> = args
+                .get("recipients")
+                .cloned()
+                .and_then(|v| serde_json::from_value(v).ok());
+            let copy_mock: Option<bool> = args
+                .get("copyMock")
+                .or_else(|| args.get("copy_mock"))
+                .cloned()
+                .and_then(|v| serde_json::from_value(v).ok());
+            let result = crate::commands::datasets::publish_datasets_bulk(
+                app.clone(),
+                state.clone(),
+                names,
+                recipients,
+                copy_mock,
+            )
+            .await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "unpublish_dataset" => {
             let name: String = serde_json::from_value(
                 args.get("name")
@@ -3383,6 +4115,148 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
             let result = crate::commands::files::delete_files_bulk(state.clone(), file_ids)?;
             Ok(serde_json::to_value(result).unwrap())
         }
+        "update_files_metadata_bulk" => {
+            let file_ids: Vec<i64> = serde_json::from_value(
+                args.get("fileIds")
+                    .or_else(|| args.get("file_ids"))
+                    .cloned()
+                    .ok_or_else(|| "Missing fileIds".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse fileIds: {}", e))?;
+            let metadata: crate::commands::files::FileMetadata = serde_json::from_value(
+                args.get("metadata")
+                    .cloned()
+                    .ok_or_else(|| "Missing metadata".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse metadata: {}", e))?;
+            let result = crate::commands::files::update_files_metadata_bulk(
+                state.clone(),
+                file_ids,
+                metadata,
+            )?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "check_files_exist" => {
+            let file_ids: Option<Vec<i64>> = args
+                .get("fileIds")
+                .or_else(|| args.get("file_ids"))
+                .cloned()
+                .and_then(|v| serde_json::from_value(v).ok());
+            let result =
+                crate::commands::files::check_files_exist(app.clone(), state.clone(), file_ids)
+                    .await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "verify_files" => {
+            let file_ids: Option<Vec<i64>> = args
+                .get("fileIds")
+                .or_else(|| args.get("file_ids"))
+                .cloned()
+                .and_then(|v| serde_json::from_value(v).ok());
+            let result = crate::commands::files::verify_files(state.clone(), file_ids).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "prune_missing_files" => {
+            let file_ids: Vec<i64> = serde_json::from_value(
+                args.get("fileIds")
+                    .or_else(|| args.get("file_ids"))
+                    .cloned()
+                    .ok_or_else(|| "Missing fileIds".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse fileIds: {}", e))?;
+            let confirm = args
+                .get("confirm")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            let result =
+                crate::commands::files::prune_missing_files(state.clone(), file_ids, confirm)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "get_sex_inference_confidence" => {
+            let file_id: i64 = serde_json::from_value(
+                args.get("fileId")
+                    .or_else(|| args.get("file_id"))
+                    .cloned()
+                    .ok_or_else(|| "Missing fileId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse fileId: {}", e))?;
+            let result =
+                crate::commands::files::get_sex_inference_confidence(state.clone(), file_id)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "snapshot_library" => {
+            let label: String = serde_json::from_value(
+                args.get("label")
+                    .cloned()
+                    .ok_or_else(|| "Missing label".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse label: {}", e))?;
+            let result = crate::commands::files::snapshot_library(state.clone(), label)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "list_library_snapshots" => {
+            let result = crate::commands::files::list_library_snapshots(state.clone())?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "diff_library_snapshots" => {
+            let a: i64 = serde_json::from_value(
+                args.get("a")
+                    .cloned()
+                    .ok_or_else(|| "Missing a".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse a: {}", e))?;
+            let b: i64 = serde_json::from_value(
+                args.get("b")
+                    .cloned()
+                    .ok_or_else(|| "Missing b".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse b: {}", e))?;
+            let result = crate::commands::files::diff_library_snapshots(state.clone(), a, b)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "delete_library_snapshot" => {
+            let id: i64 = serde_json::from_value(
+                args.get("id")
+                    .cloned()
+                    .ok_or_else(|| "Missing id".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse id: {}", e))?;
+            let result = crate::commands::files::delete_library_snapshot(state.clone(), id)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "register_data_type" => {
+            let name: String = serde_json::from_value(
+                args.get("name")
+                    .cloned()
+                    .ok_or_else(|| "Missing name".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse name: {}", e))?;
+            let detection_rules: crate::commands::files::DataTypeDetectionRules =
+                serde_json::from_value(
+                    args.get("detectionRules")
+                        .or_else(|| args.get("detection_rules"))
+                        .cloned()
+                        .ok_or_else(|| "Missing detectionRules".to_string())?,
+                )
+                .map_err(|e| format!("Failed to parse detectionRules: {}", e))?;
+            let result =
+                crate::commands::files::register_data_type(state.clone(), name, detection_rules)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "list_data_types" => {
+            let result = crate::commands::files::list_data_types(state.clone())?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "remove_data_type" => {
+            let name: String = serde_json::from_value(
+                args.get("name")
+                    .cloned()
+                    .ok_or_else(|| "Missing name".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse name: {}", e))?;
+            let result = crate::commands::files::remove_data_type(state.clone(), name)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "analyze_file_types" => {
             let files: Vec<String> = serde_json::from_value(
                 args.get("files")
@@ -3393,6 +4267,17 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
             let result = crate::commands::files::analyze_file_types(state.clone(), files).await?;
             Ok(serde_json::to_value(result).unwrap())
         }
+        "reanalyze_file" => {
+            let file_id: i64 = serde_json::from_value(
+                args.get("fileId")
+                    .or_else(|| args.get("file_id"))
+                    .cloned()
+                    .ok_or_else(|| "Missing fileId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse fileId: {}", e))?;
+            let result = crate::commands::files::reanalyze_file(state.clone(), file_id)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "import_files" => {
             let files: Vec<String> = serde_json::from_value(
                 args.get("files")
@@ -3433,34 +4318,162 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
                     .await?;
             Ok(serde_json::to_value(result).unwrap())
         }
-        "process_queue" => {
-            let limit: usize = args
-                .get("limit")
+        "preview_import" => {
+            let file_metadata: std::collections::HashMap<
+                String,
+                crate::commands::files::FileMetadata,
+            > = serde_json::from_value(
+                args.get("fileMetadata")
+                    .or_else(|| args.get("file_metadata"))
+                    .cloned()
+                    .ok_or_else(|| "Missing fileMetadata".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse fileMetadata: {}", e))?;
+            let result =
+                crate::commands::files::preview_import(state.clone(), file_metadata).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "watch_import_folder" => {
+            let path: String = serde_json::from_value(
+                args.get("path")
+                    .cloned()
+                    .ok_or_else(|| "Missing path".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse path: {}", e))?;
+            let pattern: Option<String> = args
+                .get("pattern")
                 .cloned()
-                .and_then(|v| serde_json::from_value(v).ok())
-                .unwrap_or(100);
-            let result = crate::commands::files::process_queue(state.clone(), limit).await?;
-            Ok(result)
+                .map(serde_json::from_value)
+                .transpose()
+                .map_err(|e| format!("Failed to parse pattern: {}", e))?;
+            let result = crate::commands::files::watch_import_folder(app.clone(), path, pattern)?;
+            Ok(serde_json::to_value(result).unwrap())
         }
-        "pause_queue_processor" => {
-            let result = crate::commands::files::pause_queue_processor(state.clone())?;
+        "unwatch_import_folder" => {
+            let path: String = serde_json::from_value(
+                args.get("path")
+                    .cloned()
+                    .ok_or_else(|| "Missing path".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse path: {}", e))?;
+            let result = crate::commands::files::unwatch_import_folder(path)?;
             Ok(serde_json::to_value(result).unwrap())
         }
-        "resume_queue_processor" => {
-            let result = crate::commands::files::resume_queue_processor(state.clone())?;
+        "list_watched_folders" => {
+            let result = crate::commands::files::list_watched_folders()?;
             Ok(serde_json::to_value(result).unwrap())
         }
-        "clear_pending_queue" => {
-            let result = crate::commands::files::clear_pending_queue(state.clone())?;
+        "get_import_conflicts" => {
+            let result = crate::commands::files::get_import_conflicts(state.clone())?;
             Ok(serde_json::to_value(result).unwrap())
         }
-
-        // =====================================================================
-        // Additional Participant Commands
-        // =====================================================================
-        "delete_participant" => {
-            let participant_id: i64 = serde_json::from_value(
-                args.get("participantId")
+        "import_genotype_zip" => {
+            let path: String = serde_json::from_value(
+                args.get("path")
+                    .cloned()
+                    .ok_or_else(|| "Missing path".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse path: {}", e))?;
+            let participant_id: String = serde_json::from_value(
+                args.get("participantId")
+                    .or_else(|| args.get("participant_id"))
+                    .cloned()
+                    .ok_or_else(|| "Missing participantId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse participantId: {}", e))?;
+            let entry: Option<String> = args
+                .get("entry")
+                .cloned()
+                .map(serde_json::from_value)
+                .transpose()
+                .map_err(|e| format!("Failed to parse entry: {}", e))?;
+            let result = crate::commands::files::import_genotype_zip(
+                state.clone(),
+                path,
+                participant_id,
+                entry,
+            )
+            .await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "process_queue" => {
+            let limit: usize = args
+                .get("limit")
+                .cloned()
+                .and_then(|v| serde_json::from_value(v).ok())
+                .unwrap_or(100);
+            let result = crate::commands::files::process_queue(state.clone(), limit).await?;
+            Ok(result)
+        }
+        "pause_queue_processor" => {
+            let result = crate::commands::files::pause_queue_processor(state.clone())?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "resume_queue_processor" => {
+            let result = crate::commands::files::resume_queue_processor(state.clone())?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "list_queued_files" => {
+            let result = crate::commands::files::list_queued_files(state.clone())?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "cancel_queued_files" => {
+            let file_ids: Vec<i64> = serde_json::from_value(
+                args.get("fileIds")
+                    .or_else(|| args.get("file_ids"))
+                    .cloned()
+                    .ok_or_else(|| "Missing fileIds".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse fileIds: {}", e))?;
+            let result =
+                crate::commands::files::cancel_queued_files(app.clone(), state.clone(), file_ids)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "set_file_priority" => {
+            let file_id: i64 = serde_json::from_value(
+                args.get("fileId")
+                    .or_else(|| args.get("file_id"))
+                    .cloned()
+                    .ok_or_else(|| "Missing fileId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse fileId: {}", e))?;
+            let priority: i64 = serde_json::from_value(
+                args.get("priority")
+                    .cloned()
+                    .ok_or_else(|| "Missing priority".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse priority: {}", e))?;
+            crate::commands::files::set_file_priority(
+                app.clone(),
+                state.clone(),
+                file_id,
+                priority,
+            )?;
+            Ok(serde_json::Value::Null)
+        }
+        "prioritize_files" => {
+            let file_ids: Vec<i64> = serde_json::from_value(
+                args.get("fileIds")
+                    .or_else(|| args.get("file_ids"))
+                    .cloned()
+                    .ok_or_else(|| "Missing fileIds".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse fileIds: {}", e))?;
+            let result =
+                crate::commands::files::prioritize_files(app.clone(), state.clone(), file_ids)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "clear_pending_queue" => {
+            let result = crate::commands::files::clear_pending_queue(state.clone())?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+
+        // =====================================================================
+        // Additional Participant Commands
+        // =====================================================================
+        "delete_participant" => {
+            let participant_id: i64 = serde_json::from_value(
+                args.get("participantId")
                     .or_else(|| args.get("participant_id"))
                     .cloned()
                     .ok_or_else(|| "Missing participantId".to_string())?,
@@ -3483,6 +4496,41 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
             )?;
             Ok(serde_json::to_value(result).unwrap())
         }
+        "check_cohort_completeness" => {
+            let participant_ids: Vec<String> = serde_json::from_value(
+                args.get("participantIds")
+                    .or_else(|| args.get("participant_ids"))
+                    .cloned()
+                    .ok_or_else(|| "Missing participantIds".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse participantIds: {}", e))?;
+            let expected_roles = args
+                .get("expectedRoles")
+                .or_else(|| args.get("expected_roles"))
+                .cloned()
+                .map(serde_json::from_value)
+                .transpose()
+                .map_err(|e| format!("Failed to parse expectedRoles: {}", e))?;
+            let result = crate::commands::participants::check_cohort_completeness(
+                state.clone(),
+                participant_ids,
+                expected_roles,
+            )?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "check_participant_id_conflicts" => {
+            let extractions: Vec<crate::types::SampleExtraction> = serde_json::from_value(
+                args.get("extractions")
+                    .cloned()
+                    .ok_or_else(|| "Missing extractions".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse extractions: {}", e))?;
+            let result = crate::commands::participants::check_participant_id_conflicts(
+                state.clone(),
+                extractions,
+            )?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
 
         // =====================================================================
         // Additional Message Commands
@@ -3507,6 +4555,24 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
             let result = crate::commands::messages::delete_failed_message(id)?;
             Ok(serde_json::to_value(result).unwrap())
         }
+        "migrate_identity" => {
+            let old_email: String = serde_json::from_value(
+                args.get("oldEmail")
+                    .cloned()
+                    .or_else(|| args.get("old_email").cloned())
+                    .ok_or_else(|| "Missing oldEmail".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse oldEmail: {}", e))?;
+            let new_email: String = serde_json::from_value(
+                args.get("newEmail")
+                    .cloned()
+                    .or_else(|| args.get("new_email").cloned())
+                    .ok_or_else(|| "Missing newEmail".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse newEmail: {}", e))?;
+            let result = crate::commands::messages::migrate_identity(old_email, new_email)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
 
         // =====================================================================
         // Additional Module Commands
@@ -3549,6 +4615,23 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
             crate::commands::modules::delete_module(state.clone(), module_id)?;
             Ok(serde_json::Value::Null)
         }
+        "find_flows_using_module" => {
+            // Accept either a numeric id or a module name.
+            let module_id_or_name = match args
+                .get("moduleIdOrName")
+                .or_else(|| args.get("module_id_or_name"))
+                .cloned()
+                .ok_or_else(|| "Missing moduleIdOrName".to_string())?
+            {
+                serde_json::Value::String(value) => value,
+                other => other.to_string(),
+            };
+            let result = crate::commands::modules::find_flows_using_module(
+                state.clone(),
+                module_id_or_name,
+            )?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "delete_module_folder" => {
             let module_path: String = serde_json::from_value(
                 args.get("modulePath")
@@ -3603,6 +4686,23 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
             let result = crate::commands::modules::get_flow_template_catalog(app.clone())?;
             Ok(serde_json::to_value(result).unwrap())
         }
+        "get_available_flow_examples" => {
+            let result = crate::commands::modules::get_available_flow_examples(app.clone())?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "create_flow_from_example" => {
+            let name: String = serde_json::from_value(
+                args.get("name")
+                    .cloned()
+                    .ok_or_else(|| "Missing name".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse name: {}", e))?;
+            let overwrite = args.get("overwrite").and_then(|v| v.as_bool());
+            let result =
+                crate::commands::modules::create_flow_from_example(app.clone(), name, overwrite)
+                    .await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
 
         // =====================================================================
         // Additional Run Commands
@@ -3769,108 +4869,324 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
             crate::commands::flows::delete_run_config(state.clone(), config_id).await?;
             Ok(serde_json::Value::Null)
         }
-
-        // =====================================================================
-        // Additional Session Commands
-        // =====================================================================
-        "create_session_with_datasets" => {
-            let request: crate::types::CreateSessionRequest = serde_json::from_value(
-                args.get("request")
+        "estimate_run_duration" => {
+            let flow_name: String = serde_json::from_value(
+                args.get("flowName")
+                    .or_else(|| args.get("flow_name"))
                     .cloned()
-                    .ok_or_else(|| "Missing request".to_string())?,
+                    .ok_or_else(|| "Missing flowName".to_string())?,
             )
-            .map_err(|e| format!("Failed to parse request: {}", e))?;
-            let datasets: Vec<String> = serde_json::from_value(
-                args.get("datasets")
+            .map_err(|e| format!("Failed to parse flowName: {}", e))?;
+            let participant_count: Option<usize> = args
+                .get("participantCount")
+                .or_else(|| args.get("participant_count"))
+                .and_then(|v| serde_json::from_value(v.clone()).ok());
+            let file_count: Option<usize> = args
+                .get("fileCount")
+                .or_else(|| args.get("file_count"))
+                .and_then(|v| serde_json::from_value(v.clone()).ok());
+            let input_bytes: Option<u64> = args
+                .get("inputBytes")
+                .or_else(|| args.get("input_bytes"))
+                .and_then(|v| serde_json::from_value(v.clone()).ok());
+            let result = crate::commands::flows::estimate_run_duration(
+                state.clone(),
+                flow_name,
+                participant_count,
+                file_count,
+                input_bytes,
+            )?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "check_run_space_requirements" => {
+            let config_id: i64 = serde_json::from_value(
+                args.get("configId")
+                    .or_else(|| args.get("config_id"))
                     .cloned()
-                    .ok_or_else(|| "Missing datasets".to_string())?,
+                    .ok_or_else(|| "Missing configId".to_string())?,
             )
-            .map_err(|e| format!("Failed to parse datasets: {}", e))?;
+            .map_err(|e| format!("Failed to parse configId: {}", e))?;
             let result =
-                crate::commands::sessions::create_session_with_datasets(request, datasets)?;
+                crate::commands::flows::check_run_space_requirements(state.clone(), config_id)
+                    .await?;
             Ok(serde_json::to_value(result).unwrap())
         }
-        "update_session_peer" => {
-            let session_id: String = serde_json::from_value(
-                args.get("sessionId")
-                    .or_else(|| args.get("session_id"))
+        "diff_run_outputs" => {
+            let run_id_a: i64 = serde_json::from_value(
+                args.get("runIdA")
+                    .or_else(|| args.get("run_id_a"))
                     .cloned()
-                    .ok_or_else(|| "Missing sessionId".to_string())?,
+                    .ok_or_else(|| "Missing runIdA".to_string())?,
             )
-            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
-            let peer: Option<String> = args
-                .get("peer")
-                .cloned()
-                .and_then(|v| serde_json::from_value(v).ok());
-            let result = crate::commands::sessions::update_session_peer(session_id, peer)?;
+            .map_err(|e| format!("Failed to parse runIdA: {}", e))?;
+            let run_id_b: i64 = serde_json::from_value(
+                args.get("runIdB")
+                    .or_else(|| args.get("run_id_b"))
+                    .cloned()
+                    .ok_or_else(|| "Missing runIdB".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse runIdB: {}", e))?;
+            let result =
+                crate::commands::flows::diff_run_outputs(state.clone(), run_id_a, run_id_b).await?;
             Ok(serde_json::to_value(result).unwrap())
         }
-        "get_session_messages" => {
-            let session_id: String = serde_json::from_value(
-                args.get("sessionId")
-                    .or_else(|| args.get("session_id"))
+        "get_run_input_sources" => {
+            let run_id: i64 = serde_json::from_value(
+                args.get("runId")
+                    .or_else(|| args.get("run_id"))
                     .cloned()
-                    .ok_or_else(|| "Missing sessionId".to_string())?,
+                    .ok_or_else(|| "Missing runId".to_string())?,
             )
-            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
-            let result = crate::commands::sessions::get_session_messages(session_id)?;
+            .map_err(|e| format!("Failed to parse runId: {}", e))?;
+            let result =
+                crate::commands::flows::get_run_input_sources(state.clone(), run_id).await?;
             Ok(serde_json::to_value(result).unwrap())
         }
-        "send_session_message" => {
-            let session_id: String = serde_json::from_value(
-                args.get("sessionId")
-                    .or_else(|| args.get("session_id"))
+        "get_runs_for_dataset" => {
+            let dataset_name: String = serde_json::from_value(
+                args.get("datasetName")
+                    .or_else(|| args.get("dataset_name"))
                     .cloned()
-                    .ok_or_else(|| "Missing sessionId".to_string())?,
+                    .ok_or_else(|| "Missing datasetName".to_string())?,
             )
-            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
-            let body: String = serde_json::from_value(
-                args.get("body")
+            .map_err(|e| format!("Failed to parse datasetName: {}", e))?;
+            let result =
+                crate::commands::flows::get_runs_for_dataset(state.clone(), dataset_name).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "get_run_image_status" => {
+            let run_id: i64 = serde_json::from_value(
+                args.get("runId")
+                    .or_else(|| args.get("run_id"))
                     .cloned()
-                    .ok_or_else(|| "Missing body".to_string())?,
+                    .ok_or_else(|| "Missing runId".to_string())?,
             )
-            .map_err(|e| format!("Failed to parse body: {}", e))?;
-            let result = crate::commands::sessions::send_session_message(session_id, body)?;
+            .map_err(|e| format!("Failed to parse runId: {}", e))?;
+            let result =
+                crate::commands::container_images::get_run_image_status(state.clone(), run_id)
+                    .await?;
             Ok(serde_json::to_value(result).unwrap())
         }
-        "open_session_folder" => {
-            let session_id: String = serde_json::from_value(
-                args.get("sessionId")
-                    .or_else(|| args.get("session_id"))
+        "prefetch_run_images" => {
+            let config_id: i64 = serde_json::from_value(
+                args.get("configId")
+                    .or_else(|| args.get("config_id"))
                     .cloned()
-                    .ok_or_else(|| "Missing sessionId".to_string())?,
+                    .ok_or_else(|| "Missing configId".to_string())?,
             )
-            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
-            crate::commands::sessions::open_session_folder(session_id)?;
-            Ok(serde_json::Value::Null)
+            .map_err(|e| format!("Failed to parse configId: {}", e))?;
+            let window = app
+                .get_webview_window("main")
+                .ok_or_else(|| "Main window not available".to_string())?;
+            let result = crate::commands::container_images::prefetch_run_images(
+                window,
+                state.clone(),
+                config_id,
+            )
+            .await?;
+            Ok(serde_json::to_value(result).unwrap())
         }
-
-        // =====================================================================
-        // Additional Key Commands
-        // =====================================================================
-        "key_republish" => {
-            let email: Option<String> = args
-                .get("email")
-                .cloned()
-                .and_then(|v| serde_json::from_value(v).ok());
-            let result = crate::commands::key::key_republish(email)?;
+        "get_run_completion_webhook" => {
+            let result = crate::commands::webhooks::get_run_completion_webhook()?;
             Ok(serde_json::to_value(result).unwrap())
         }
-        "key_refresh_contacts" => {
-            let result = crate::commands::key::key_refresh_contacts(state.clone()).await?;
+        "set_run_completion_webhook" => {
+            let url: Option<String> = args
+                .get("url")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string());
+            let result = crate::commands::webhooks::set_run_completion_webhook(url)?;
             Ok(serde_json::to_value(result).unwrap())
         }
-
-        // =====================================================================
-        // Additional Network Commands
-        // =====================================================================
-        "network_remove_contact" => {
-            let identity: String = serde_json::from_value(
-                args.get("identity")
+        "set_run_webhook_override" => {
+            let run_id: i64 = serde_json::from_value(
+                args.get("runId")
+                    .or_else(|| args.get("run_id"))
                     .cloned()
-                    .ok_or_else(|| "Missing identity".to_string())?,
+                    .ok_or_else(|| "Missing runId".to_string())?,
             )
-            .map_err(|e| format!("Failed to parse identity: {}", e))?;
+            .map_err(|e| format!("Failed to parse runId: {}", e))?;
+            let url: Option<String> = args
+                .get("url")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string());
+            let result =
+                crate::commands::webhooks::set_run_webhook_override(state.clone(), run_id, url)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "test_webhook" => {
+            let url: Option<String> = args
+                .get("url")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string());
+            let result = crate::commands::webhooks::test_webhook(url).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "get_run_notification" => {
+            let run_id: i64 = serde_json::from_value(
+                args.get("runId")
+                    .or_else(|| args.get("run_id"))
+                    .cloned()
+                    .ok_or_else(|| "Missing runId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse runId: {}", e))?;
+            let result =
+                crate::commands::notifications::get_run_notification(state.clone(), run_id)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "set_run_notification" => {
+            let run_id: i64 = serde_json::from_value(
+                args.get("runId")
+                    .or_else(|| args.get("run_id"))
+                    .cloned()
+                    .ok_or_else(|| "Missing runId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse runId: {}", e))?;
+            let enabled: Option<bool> = args.get("enabled").and_then(|v| v.as_bool());
+            let result = crate::commands::notifications::set_run_notification(
+                state.clone(),
+                run_id,
+                enabled,
+            )?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "get_run_notifications_default" => {
+            let result = crate::commands::notifications::get_run_notifications_default()?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "set_run_notifications_default" => {
+            let enabled: bool = serde_json::from_value(
+                args.get("enabled")
+                    .cloned()
+                    .ok_or_else(|| "Missing enabled".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse enabled: {}", e))?;
+            let result = crate::commands::notifications::set_run_notifications_default(enabled)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "get_notification_preferences" => {
+            let result = crate::commands::notifications::get_notification_preferences()?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "set_notification_preference" => {
+            let event: String = serde_json::from_value(
+                args.get("event")
+                    .cloned()
+                    .ok_or_else(|| "Missing event".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse event: {}", e))?;
+            let enabled: bool = serde_json::from_value(
+                args.get("enabled")
+                    .cloned()
+                    .ok_or_else(|| "Missing enabled".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse enabled: {}", e))?;
+            let result =
+                crate::commands::notifications::set_notification_preference(event, enabled)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+
+        // =====================================================================
+        // Additional Session Commands
+        // =====================================================================
+        "create_session_with_datasets" => {
+            let request: crate::types::CreateSessionRequest = serde_json::from_value(
+                args.get("request")
+                    .cloned()
+                    .ok_or_else(|| "Missing request".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse request: {}", e))?;
+            let datasets: Vec<String> = serde_json::from_value(
+                args.get("datasets")
+                    .cloned()
+                    .ok_or_else(|| "Missing datasets".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse datasets: {}", e))?;
+            let result =
+                crate::commands::sessions::create_session_with_datasets(request, datasets)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "update_session_peer" => {
+            let session_id: String = serde_json::from_value(
+                args.get("sessionId")
+                    .or_else(|| args.get("session_id"))
+                    .cloned()
+                    .ok_or_else(|| "Missing sessionId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
+            let peer: Option<String> = args
+                .get("peer")
+                .cloned()
+                .and_then(|v| serde_json::from_value(v).ok());
+            let result = crate::commands::sessions::update_session_peer(session_id, peer)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "get_session_messages" => {
+            let session_id: String = serde_json::from_value(
+                args.get("sessionId")
+                    .or_else(|| args.get("session_id"))
+                    .cloned()
+                    .ok_or_else(|| "Missing sessionId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
+            let result = crate::commands::sessions::get_session_messages(session_id)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "send_session_message" => {
+            let session_id: String = serde_json::from_value(
+                args.get("sessionId")
+                    .or_else(|| args.get("session_id"))
+                    .cloned()
+                    .ok_or_else(|| "Missing sessionId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
+            let body: String = serde_json::from_value(
+                args.get("body")
+                    .cloned()
+                    .ok_or_else(|| "Missing body".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse body: {}", e))?;
+            let result = crate::commands::sessions::send_session_message(session_id, body)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "open_session_folder" => {
+            let session_id: String = serde_json::from_value(
+                args.get("sessionId")
+                    .or_else(|| args.get("session_id"))
+                    .cloned()
+                    .ok_or_else(|| "Missing sessionId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
+            crate::commands::sessions::open_session_folder(session_id)?;
+            Ok(serde_json::Value::Null)
+        }
+
+        // =====================================================================
+        // Additional Key Commands
+        // =====================================================================
+        "key_republish" => {
+            let email: Option<String> = args
+                .get("email")
+                .cloned()
+                .and_then(|v| serde_json::from_value(v).ok());
+            let result = crate::commands::key::key_republish(email)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "key_refresh_contacts" => {
+            let result = crate::commands::key::key_refresh_contacts(state.clone()).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+
+        // =====================================================================
+        // Additional Network Commands
+        // =====================================================================
+        "network_remove_contact" => {
+            let identity: String = serde_json::from_value(
+                args.get("identity")
+                    .cloned()
+                    .ok_or_else(|| "Missing identity".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse identity: {}", e))?;
             crate::commands::key::network_remove_contact(identity)?;
             Ok(serde_json::Value::Null)
         }
@@ -3927,6 +5243,34 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
         }
 
         // Multiparty flow commands
+        "get_flow_role_defaults" => {
+            let flow_name: String = serde_json::from_value(
+                args.get("flowName")
+                    .or_else(|| args.get("flow_name"))
+                    .cloned()
+                    .ok_or_else(|| "Missing flowName".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse flowName: {}", e))?;
+            let result = crate::commands::multiparty::get_flow_role_defaults(flow_name)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "save_flow_role_defaults" => {
+            let flow_name: String = serde_json::from_value(
+                args.get("flowName")
+                    .or_else(|| args.get("flow_name"))
+                    .cloned()
+                    .ok_or_else(|| "Missing flowName".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse flowName: {}", e))?;
+            let roles: std::collections::BTreeMap<String, Vec<String>> = serde_json::from_value(
+                args.get("roles")
+                    .cloned()
+                    .ok_or_else(|| "Missing roles".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse roles: {}", e))?;
+            let result = crate::commands::multiparty::save_flow_role_defaults(flow_name, roles)?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "send_flow_invitation" => {
             let thread_id: String = serde_json::from_value(
                 args.get("threadId")
@@ -4012,6 +5356,37 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
             .map_err(|e| e.to_string())?;
             Ok(serde_json::to_value(result).unwrap())
         }
+        "verify_invitation_against_template" => {
+            let session_id: String = serde_json::from_value(
+                args.get("sessionId")
+                    .cloned()
+                    .ok_or_else(|| "Missing sessionId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
+            let template_name: String = serde_json::from_value(
+                args.get("templateName")
+                    .cloned()
+                    .or_else(|| args.get("template_name").cloned())
+                    .ok_or_else(|| "Missing templateName".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse templateName: {}", e))?;
+            let result = crate::commands::multiparty::verify_invitation_against_template(
+                session_id,
+                template_name,
+            )
+            .await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "inspect_invitation" => {
+            let session_id: String = serde_json::from_value(
+                args.get("sessionId")
+                    .cloned()
+                    .ok_or_else(|| "Missing sessionId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
+            let result = crate::commands::multiparty::inspect_invitation(session_id).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "get_multiparty_flow_state" => {
             let session_id: String = serde_json::from_value(
                 args.get("sessionId")
@@ -4049,6 +5424,26 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
                 .map_err(|e| e.to_string())?;
             Ok(serde_json::to_value(result).unwrap())
         }
+        "get_session_join_status" => {
+            let session_id: String = serde_json::from_value(
+                args.get("sessionId")
+                    .cloned()
+                    .ok_or_else(|| "Missing sessionId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
+            let result = crate::commands::multiparty::get_session_join_status(session_id).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "get_session_platform_info" => {
+            let session_id: String = serde_json::from_value(
+                args.get("sessionId")
+                    .cloned()
+                    .ok_or_else(|| "Missing sessionId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
+            let result = crate::commands::multiparty::get_session_platform_info(session_id).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "get_multiparty_step_diagnostics" => {
             let session_id: String = serde_json::from_value(
                 args.get("sessionId")
@@ -4068,6 +5463,10 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
                     .map_err(|e| e.to_string())?;
             Ok(serde_json::to_value(result).unwrap())
         }
+        "get_hotlink_telemetry" => {
+            let result = crate::commands::multiparty::get_hotlink_telemetry().await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "get_multiparty_step_logs" => {
             let session_id: String = serde_json::from_value(
                 args.get("sessionId")
@@ -4114,11 +5513,233 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
                     .ok_or_else(|| "Missing autoRun".to_string())?,
             )
             .map_err(|e| format!("Failed to parse autoRun: {}", e))?;
-            crate::commands::multiparty::set_step_auto_run(session_id, step_id, auto_run)
-                .await
-                .map_err(|e| e.to_string())?;
+            crate::commands::multiparty::set_step_auto_run(
+                app.clone(),
+                session_id,
+                step_id,
+                auto_run,
+            )
+            .await
+            .map_err(|e| e.to_string())?;
             Ok(serde_json::Value::Null)
         }
+        "override_step_module" => {
+            let session_id: String = serde_json::from_value(
+                args.get("sessionId")
+                    .cloned()
+                    .ok_or_else(|| "Missing sessionId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
+            let step_id: String = serde_json::from_value(
+                args.get("stepId")
+                    .cloned()
+                    .ok_or_else(|| "Missing stepId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse stepId: {}", e))?;
+            let module_path: String = serde_json::from_value(
+                args.get("modulePath")
+                    .cloned()
+                    .ok_or_else(|| "Missing modulePath".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse modulePath: {}", e))?;
+            let result =
+                crate::commands::multiparty::override_step_module(session_id, step_id, module_path)
+                    .await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "clear_step_module_override" => {
+            let session_id: String = serde_json::from_value(
+                args.get("sessionId")
+                    .cloned()
+                    .ok_or_else(|| "Missing sessionId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
+            let step_id: String = serde_json::from_value(
+                args.get("stepId")
+                    .cloned()
+                    .ok_or_else(|| "Missing stepId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse stepId: {}", e))?;
+            let result =
+                crate::commands::multiparty::clear_step_module_override(session_id, step_id)
+                    .await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "verify_flow_spec_consistency" => {
+            let session_id: String = serde_json::from_value(
+                args.get("sessionId")
+                    .cloned()
+                    .ok_or_else(|| "Missing sessionId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
+            let result =
+                crate::commands::multiparty::verify_flow_spec_consistency(session_id).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "describe_session_layout" => {
+            let session_id: String = serde_json::from_value(
+                args.get("sessionId")
+                    .cloned()
+                    .ok_or_else(|| "Missing sessionId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
+            let result = crate::commands::multiparty::describe_session_layout(session_id).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "get_session_step_timings" => {
+            let session_id: String = serde_json::from_value(
+                args.get("sessionId")
+                    .cloned()
+                    .ok_or_else(|| "Missing sessionId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
+            let result = crate::commands::multiparty::get_session_step_timings(session_id).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "export_session_audit" => {
+            let session_id: String = serde_json::from_value(
+                args.get("sessionId")
+                    .cloned()
+                    .ok_or_else(|| "Missing sessionId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
+            let dest_path: String = serde_json::from_value(
+                args.get("destPath")
+                    .or_else(|| args.get("dest_path"))
+                    .cloned()
+                    .ok_or_else(|| "Missing destPath".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse destPath: {}", e))?;
+            let result =
+                crate::commands::multiparty::export_session_audit(session_id, dest_path).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "export_progress_matrix" => {
+            let session_id: String = serde_json::from_value(
+                args.get("sessionId")
+                    .cloned()
+                    .ok_or_else(|| "Missing sessionId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
+            let dest_path: String = serde_json::from_value(
+                args.get("destPath")
+                    .or_else(|| args.get("dest_path"))
+                    .cloned()
+                    .ok_or_else(|| "Missing destPath".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse destPath: {}", e))?;
+            let result =
+                crate::commands::multiparty::export_progress_matrix(session_id, dest_path).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "get_session_identity_changes" => {
+            let session_id: String = serde_json::from_value(
+                args.get("sessionId")
+                    .cloned()
+                    .ok_or_else(|| "Missing sessionId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
+            let result =
+                crate::commands::multiparty::get_session_identity_changes(session_id).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "generate_minimal_repro" => {
+            let session_id: String = serde_json::from_value(
+                args.get("sessionId")
+                    .cloned()
+                    .ok_or_else(|| "Missing sessionId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
+            let result = crate::commands::multiparty::generate_minimal_repro(session_id).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "replay_step" => {
+            let session_id: String = serde_json::from_value(
+                args.get("sessionId")
+                    .cloned()
+                    .ok_or_else(|| "Missing sessionId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
+            let step_id: String = serde_json::from_value(
+                args.get("stepId")
+                    .cloned()
+                    .ok_or_else(|| "Missing stepId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse stepId: {}", e))?;
+            let keep_scratch = args
+                .get("keepScratch")
+                .or_else(|| args.get("keep_scratch"))
+                .and_then(|v| v.as_bool());
+            let result =
+                crate::commands::multiparty::replay_step(session_id, step_id, keep_scratch).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "set_flow_auto_run_policy" => {
+            let session_id: String = serde_json::from_value(
+                args.get("sessionId")
+                    .cloned()
+                    .ok_or_else(|| "Missing sessionId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
+            let policy: crate::commands::multiparty::AutoRunPolicy = serde_json::from_value(
+                args.get("policy")
+                    .cloned()
+                    .ok_or_else(|| "Missing policy".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse policy: {}", e))?;
+            let result = crate::commands::multiparty::set_flow_auto_run_policy(
+                app.clone(),
+                session_id,
+                policy,
+            )
+            .await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "get_session_mpc_verbose_logging" => {
+            let session_id: String = serde_json::from_value(
+                args.get("sessionId")
+                    .cloned()
+                    .ok_or_else(|| "Missing sessionId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
+            let result =
+                crate::commands::multiparty::get_session_mpc_verbose_logging(session_id).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "set_session_mpc_verbose_logging" => {
+            let session_id: String = serde_json::from_value(
+                args.get("sessionId")
+                    .cloned()
+                    .ok_or_else(|| "Missing sessionId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
+            let enabled: bool = serde_json::from_value(
+                args.get("enabled")
+                    .cloned()
+                    .ok_or_else(|| "Missing enabled".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse enabled: {}", e))?;
+            let result =
+                crate::commands::multiparty::set_session_mpc_verbose_logging(session_id, enabled)
+                    .await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "set_session_output_budget" => {
+            let session_id: String = serde_json::from_value(
+                args.get("sessionId")
+                    .cloned()
+                    .ok_or_else(|| "Missing sessionId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
+            let max_bytes: Option<u64> = args
+                .get("maxBytes")
+                .or_else(|| args.get("max_bytes"))
+                .and_then(|v| v.as_u64());
+            let result =
+                crate::commands::multiparty::set_session_output_budget(session_id, max_bytes)
+                    .await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "run_flow_step" => {
             let session_id: String = serde_json::from_value(
                 args.get("sessionId")
@@ -4136,16 +5757,38 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
                 .get("force")
                 .cloned()
                 .and_then(|v| serde_json::from_value(v).ok());
+            let seed: Option<u64> = args
+                .get("seed")
+                .cloned()
+                .and_then(|v| serde_json::from_value(v).ok());
             let result = crate::commands::multiparty::run_flow_step(
+                app.clone(),
                 state.clone(),
                 session_id,
                 step_id,
                 force,
+                seed,
             )
             .await
             .map_err(|e| e.to_string())?;
             Ok(serde_json::to_value(result).unwrap())
         }
+        "get_step_output" => {
+            let session_id: String = serde_json::from_value(
+                args.get("sessionId")
+                    .cloned()
+                    .ok_or_else(|| "Missing sessionId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
+            let step_id: String = serde_json::from_value(
+                args.get("stepId")
+                    .cloned()
+                    .ok_or_else(|| "Missing stepId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse stepId: {}", e))?;
+            let result = crate::commands::multiparty::get_step_output(session_id, step_id).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "force_complete_flow_step" => {
             let session_id: String = serde_json::from_value(
                 args.get("sessionId")
@@ -4186,6 +5829,35 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
                 .map_err(|e| e.to_string())?;
             Ok(serde_json::Value::Null)
         }
+        "list_unshared_completed_steps" => {
+            let session_id: String = serde_json::from_value(
+                args.get("sessionId")
+                    .cloned()
+                    .ok_or_else(|| "Missing sessionId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
+            let result =
+                crate::commands::multiparty::list_unshared_completed_steps(session_id).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
+        "retry_share_step" => {
+            let session_id: String = serde_json::from_value(
+                args.get("sessionId")
+                    .cloned()
+                    .ok_or_else(|| "Missing sessionId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
+            let step_id: String = serde_json::from_value(
+                args.get("stepId")
+                    .cloned()
+                    .ok_or_else(|| "Missing stepId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse stepId: {}", e))?;
+            let result =
+                crate::commands::multiparty::retry_share_step(state.clone(), session_id, step_id)
+                    .await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "share_step_outputs_to_chat" => {
             let session_id: String = serde_json::from_value(
                 args.get("sessionId")
@@ -4208,6 +5880,16 @@ async fn execute_command(app: &AppHandle, cmd: &str, args: Value) -> Result<Valu
             .map_err(|e| e.to_string())?;
             Ok(serde_json::to_value(result).unwrap())
         }
+        "audit_session_sharing" => {
+            let session_id: String = serde_json::from_value(
+                args.get("sessionId")
+                    .cloned()
+                    .ok_or_else(|| "Missing sessionId".to_string())?,
+            )
+            .map_err(|e| format!("Failed to parse sessionId: {}", e))?;
+            let result = crate::commands::multiparty::audit_session_sharing(session_id).await?;
+            Ok(serde_json::to_value(result).unwrap())
+        }
         "get_step_output_files" => {
             let session_id: String = serde_json::from_value(
                 args.get("sessionId")
@@ -616,6 +616,17 @@ export function setupEventHandlers({
 				appVersionEl.textContent = 'Unknown'
 			})
 	}
+	const cliVersionEl = document.getElementById('embedded-cli-version')
+	if (cliVersionEl) {
+		invoke('get_embedded_cli_version')
+			.then((version) => {
+				cliVersionEl.textContent = version === 'unknown' ? 'Unknown' : `v${version}`
+			})
+			.catch((err) => {
+				console.error('Failed to get embedded CLI version:', err)
+				cliVersionEl.textContent = 'Unknown'
+			})
+	}
 
 	// Settings - Check for updates
 	const checkUpdatesBtn = document.getElementById('check-updates-btn')
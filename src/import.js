@@ -2328,6 +2328,7 @@ export function createImportModule({
 			// Fast import - add all files instantly (queue disabled)
 			const result = await invoke('import_files_pending', {
 				fileMetadata: fileMetadata,
+				importOrigin: selectedFolder,
 			})
 			if (progressText) progressText.textContent = `Complete! Imported ${totalFiles} files`
 			if (progressBarFill) progressBarFill.style.width = '100%'
@@ -1532,7 +1532,26 @@ export function createMessagesModule({
 		return notificationPermission === 'granted'
 	}
 
-	async function showSystemNotification(thread) {
+	// Map a thread to the notification event it represents (see get_notification_preferences)
+	function notificationEventForThread(thread) {
+		const subject = thread?.subject || ''
+		if (subject.startsWith('Multiparty Flow:')) return 'flow_invitation'
+		if (subject.startsWith('Flow Results:')) return 'step_shared'
+		return 'new_message'
+	}
+
+	async function isNotificationEventEnabled(event) {
+		try {
+			const prefs = await invoke('get_notification_preferences')
+			const pref = (prefs || []).find((p) => p.event === event)
+			return pref ? pref.enabled !== false : true
+		} catch (error) {
+			console.warn('🔔 Failed to load notification preferences:', error)
+			return true
+		}
+	}
+
+	async function showSystemNotification(thread, { force = false } = {}) {
 		console.log('🔔 showSystemNotification called', {
 			thread_id: thread?.thread_id,
 			subject: thread?.subject,
@@ -1542,6 +1561,12 @@ export function createMessagesModule({
 			return
 		}
 
+		const event = notificationEventForThread(thread)
+		if (!force && !(await isNotificationEventEnabled(event))) {
+			console.log('🔔 Notifications disabled for event, skipping:', event)
+			return
+		}
+
 		const granted = await ensureNotificationPermission()
 		console.log('🔔 Notification permission granted:', granted)
 		if (!granted) {
@@ -2442,12 +2467,15 @@ export function createMessagesModule({
 	}
 
 	async function triggerTestNotification() {
-		await showSystemNotification({
-			thread_id: 'test-thread',
-			subject: 'Test Notification',
-			last_message_preview: 'This is a test notification from BioVault.',
-			participants: ['demo@sandbox.local'],
-		})
+		await showSystemNotification(
+			{
+				thread_id: 'test-thread',
+				subject: 'Test Notification',
+				last_message_preview: 'This is a test notification from BioVault.',
+				participants: ['demo@sandbox.local'],
+			},
+			{ force: true },
+		)
 	}
 
 	async function getInviteData(type = 'message') {
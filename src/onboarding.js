@@ -1242,9 +1242,26 @@ export function initOnboarding({
 				statusIcon = '⚠️' // Warning icon
 			}
 
+			// Java is installed but outside Nextflow's supported version range
+			const javaCompat = result.java_compatibility
+			const javaWarning =
+				dep.name?.toLowerCase?.() === 'java' &&
+				dep.found &&
+				javaCompat?.version &&
+				javaCompat.compatible === false
+					? javaCompat.error || `Nextflow requires Java ${javaCompat.required_range}`
+					: null
+			if (javaWarning) {
+				statusColor = '#ffc107'
+				statusIcon = '⚠️'
+			}
+			const javaWarningAttr = javaWarning
+				? ` title="${javaWarning.replace(/"/g, '&quot;')}"`
+				: ''
+
 			html += `
 				<div class="dep-item" data-dep-index="${originalIndex}" data-dep-name="${safeNameAttr}" style="display: flex; align-items: center; gap: 8px; padding: 10px; background: white; border-radius: 6px; margin-bottom: 8px; cursor: pointer; border: 2px solid transparent; transition: all 0.2s;">
-					<span class="dep-status" style="color: ${statusColor};">${statusIcon}</span>
+					<span class="dep-status" style="color: ${statusColor};"${javaWarningAttr}>${statusIcon}</span>
 					<strong style="font-size: 13px; color: #333; flex: 1;">${dep.name}</strong>
 				</div>
 			`